@@ -17,6 +17,7 @@ use cclv::view_state::layout_params::LayoutParams;
 use cclv::view_state::types::LineOffset;
 use chrono::Utc;
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use std::sync::Arc;
 
 /// Create a simple valid entry for benchmarking.
 fn make_entry(uuid: &str) -> ConversationEntry {
@@ -30,7 +31,7 @@ fn make_entry(uuid: &str) -> ConversationEntry {
         Message::new(Role::User, MessageContent::Text("Test message".to_string())),
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 /// Generate a conversation state with the specified number of entries.
@@ -43,7 +44,7 @@ fn generate_conversation_state(num_entries: usize) -> ConversationViewState {
         None,
         None,
         entries,
-        200_000,
+        cclv::model::ContextWindows::default(),
         cclv::model::PricingConfig::default(),
     );
 
@@ -17,6 +17,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Load the large fixture file and create a baseline app state.
 ///
@@ -36,7 +37,7 @@ fn load_fixture() -> (AppState, usize) {
     // Convert LogEntry to ConversationEntry
     let entries: Vec<cclv::model::ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| cclv::model::ConversationEntry::Valid(Box::new(e)))
+        .map(|e| cclv::model::ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create app state and populate with entries
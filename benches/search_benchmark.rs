@@ -14,6 +14,7 @@ use cclv::state::search::{SearchQuery, execute_search};
 use cclv::view_state::session::SessionViewState;
 use chrono::Utc;
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use std::sync::Arc;
 
 /// Generate a large session with ~50MB of text content.
 ///
@@ -88,7 +89,7 @@ fn generate_large_session() -> SessionViewState {
             EntryMetadata::default(),
         );
 
-        let conv_entry = ConversationEntry::Valid(Box::new(entry));
+        let conv_entry = ConversationEntry::Valid(Arc::new(entry));
 
         // Add to appropriate conversation
         if let Some(aid) = agent_id {
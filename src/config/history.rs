@@ -0,0 +1,101 @@
+//! Search history persistence.
+//!
+//! History is a plain text file with one query per line, oldest first,
+//! stored in the state directory (see `default_search_history_path`).
+
+use crate::config::ConfigError;
+use std::path::Path;
+
+/// Load stored search queries, oldest first.
+///
+/// Returns an empty list if the file doesn't exist (first run).
+/// Blank lines are skipped.
+///
+/// # Errors
+///
+/// Returns error if the file exists but cannot be read.
+pub fn load_search_history(path: &Path) -> Result<Vec<String>, ConfigError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = std::fs::read_to_string(path).map_err(|e| ConfigError::ReadError {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })?;
+
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Write search queries to the history file, oldest first.
+///
+/// Creates the parent directory if needed. Embedded newlines are replaced
+/// with spaces so each query stays on one line.
+///
+/// # Errors
+///
+/// Returns error if the directory or file cannot be written.
+pub fn save_search_history(path: &Path, entries: &[String]) -> Result<(), ConfigError> {
+    let write_error = |e: std::io::Error| ConfigError::WriteError {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(write_error)?;
+    }
+
+    let mut contents = String::new();
+    for entry in entries {
+        contents.push_str(&entry.replace(['\n', '\r'], " "));
+        contents.push('\n');
+    }
+
+    std::fs::write(path, contents).map_err(write_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_search_history_returns_empty_for_missing_file() {
+        let path = std::env::temp_dir().join("cclv_test_missing_search_history");
+        let _ = std::fs::remove_file(&path);
+
+        let result = load_search_history(&path);
+
+        assert_eq!(result, Ok(Vec::new()));
+    }
+
+    #[test]
+    fn save_then_load_round_trips_entries() {
+        let dir = std::env::temp_dir().join("cclv_test_search_history_dir");
+        let path = dir.join("search_history");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let entries = vec!["error".to_string(), "multi\nline".to_string()];
+        save_search_history(&path, &entries).expect("Should create dir and write history");
+
+        let loaded = load_search_history(&path).expect("Should read history back");
+        assert_eq!(loaded, vec!["error".to_string(), "multi line".to_string()]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn load_search_history_skips_blank_lines() {
+        let path = std::env::temp_dir().join("cclv_test_search_history_blank");
+        std::fs::write(&path, "a\n\n  \nb\n").expect("Should write test file");
+
+        let loaded = load_search_history(&path).expect("Should read history");
+        assert_eq!(loaded, vec!["a".to_string(), "b".to_string()]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -204,6 +204,10 @@ impl Default for KeyBindings {
             KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
             KeyAction::SubmitSearch,
         );
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL),
+            KeyAction::ToggleSavedSearches,
+        );
 
         // Stats
         bindings.insert(
@@ -222,6 +226,26 @@ impl Default for KeyBindings {
             KeyEvent::new(KeyCode::Char('#'), KeyModifiers::NONE),
             KeyAction::FilterSubagent,
         );
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE),
+            KeyAction::ToggleChartAxis,
+        );
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE),
+            KeyAction::NextStatsPage,
+        );
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE),
+            KeyAction::CycleToolSort,
+        );
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('F'), KeyModifiers::SHIFT),
+            KeyAction::ToggleFilesModal,
+        );
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT),
+            KeyAction::ToggleSubagentTable,
+        );
 
         // Session navigation
         bindings.insert(
@@ -245,6 +269,34 @@ impl Default for KeyBindings {
             KeyAction::ToggleGlobalWrap,
         );
 
+        // Time gutter
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT),
+            KeyAction::CycleTimeGutter,
+        );
+
+        // Session header
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE),
+            KeyAction::ToggleSessionHeader,
+        );
+
+        // Continuous sessions
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT),
+            KeyAction::ToggleContinuousSessions,
+        );
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE),
+            KeyAction::ToggleSessionFold,
+        );
+
+        // Export
+        bindings.insert(
+            KeyEvent::new(KeyCode::Char('E'), KeyModifiers::SHIFT),
+            KeyAction::ToggleExportPicker,
+        );
+
         // Application controls
         bindings.insert(
             KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE),
@@ -56,6 +56,15 @@ pub enum ConfigError {
         /// Parse error details.
         reason: String,
     },
+
+    /// Failed to write a state file (e.g. search history).
+    #[error("Failed to write {path}: {reason}")]
+    WriteError {
+        /// Path that failed to write.
+        path: PathBuf,
+        /// Reason for failure.
+        reason: String,
+    },
 }
 
 /// TOML configuration file structure.
@@ -105,16 +114,31 @@ pub struct ConfigFile {
     /// Default: 200,000 tokens (Claude Opus 4.5 context window).
     #[serde(default)]
     pub max_context_tokens: Option<u64>,
+
+    /// Named saved searches, e.g. `errors = "error"`.
+    /// Shown in the saved search picker, ordered by name.
+    #[serde(default)]
+    pub searches: Option<std::collections::BTreeMap<String, String>>,
+
+    /// Context window sizes by model id or id fragment, e.g.
+    /// `sonnet = 1000000`. Models not listed use `max_context_tokens`.
+    #[serde(default)]
+    pub context_windows: Option<std::collections::HashMap<String, u64>>,
 }
 
 /// Pricing configuration section from TOML.
 ///
 /// Structure matches the TOML format:
 /// ```toml
+/// [pricing]
+/// cost_tolerance_percent = 10.0
+///
 /// [pricing.models.opus]
-/// input = 15.0
-/// output = 75.0
-/// cached_input = 1.5
+/// input = 5.0
+/// output = 25.0
+/// cache_write_5m = 6.25
+/// cache_write_1h = 10.0
+/// cache_read = 0.5
 /// ```
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
@@ -126,6 +150,11 @@ pub struct PricingConfigSection {
     /// Default pricing for unknown models.
     #[serde(default)]
     pub default: Option<PricingEntry>,
+
+    /// Percent divergence between estimated and reported cost before the
+    /// stats panel flags it (default 10).
+    #[serde(default)]
+    pub cost_tolerance_percent: Option<f64>,
 }
 
 /// Pricing entry for a specific model.
@@ -141,8 +170,22 @@ pub struct PricingEntry {
     pub output: f64,
 
     /// Cost per million cached input tokens (optional).
+    ///
+    /// Legacy name for `cache_read`; used only when `cache_read` is unset.
     #[serde(default)]
     pub cached_input: Option<f64>,
+
+    /// Cost per million tokens written to the 5-minute prompt cache (optional).
+    #[serde(default)]
+    pub cache_write_5m: Option<f64>,
+
+    /// Cost per million tokens written to the 1-hour prompt cache (optional).
+    #[serde(default)]
+    pub cache_write_1h: Option<f64>,
+
+    /// Cost per million tokens read from the prompt cache (optional).
+    #[serde(default)]
+    pub cache_read: Option<f64>,
 }
 
 /// Resolved configuration after applying precedence rules.
@@ -184,22 +227,39 @@ impl Default for ResolvedConfig {
     }
 }
 
-/// Resolve default log file path.
+/// Resolve the cclv state directory.
 ///
-/// Returns `~/.local/state/cclv/cclv.log` on Unix-like systems,
-/// or appropriate platform path on other systems (FR-055).
+/// Returns `~/.local/state/cclv` on Unix-like systems,
+/// or appropriate platform path on other systems.
 ///
 /// If state directory cannot be determined, falls back to current directory.
-pub fn default_log_path() -> PathBuf {
+pub fn default_state_dir() -> PathBuf {
     // Try to get platform-appropriate state directory
     if let Some(state_dir) = dirs::state_dir() {
-        state_dir.join("cclv").join("cclv.log")
+        state_dir.join("cclv")
     } else {
         // Fallback to current directory
-        PathBuf::from("cclv.log")
+        PathBuf::new()
     }
 }
 
+/// Resolve default log file path.
+///
+/// Returns `~/.local/state/cclv/cclv.log` on Unix-like systems,
+/// or appropriate platform path on other systems (FR-055).
+///
+/// If state directory cannot be determined, falls back to current directory.
+pub fn default_log_path() -> PathBuf {
+    default_state_dir().join("cclv.log")
+}
+
+/// Resolve default search history file path.
+///
+/// Lives next to the log file: `~/.local/state/cclv/search_history`.
+pub fn default_search_history_path() -> PathBuf {
+    default_state_dir().join("search_history")
+}
+
 /// Load configuration file from a specific path.
 ///
 /// Returns `Ok(None)` if file doesn't exist (not an error - use defaults).
@@ -431,6 +491,8 @@ mod log_path_tests {
             keybindings: None,
             pricing: None,
             max_context_tokens: None,
+            searches: None,
+            context_windows: None,
         };
 
         let resolved = merge_config(Some(config_file));
@@ -453,6 +515,8 @@ mod log_path_tests {
             keybindings: None,
             pricing: None,
             max_context_tokens: None,
+            searches: None,
+            context_windows: None,
         };
 
         let resolved = merge_config(Some(config_file));
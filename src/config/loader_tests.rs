@@ -147,6 +147,8 @@ fn merge_config_overrides_with_config_file_values() {
         keybindings: None,
         pricing: None,
         max_context_tokens: None,
+        searches: None,
+        context_windows: None,
     };
 
     let resolved = merge_config(Some(config_file));
@@ -172,6 +174,8 @@ fn merge_config_uses_defaults_for_none_fields() {
         keybindings: None,
         pricing: None,
         max_context_tokens: None,
+        searches: None,
+        context_windows: None,
     };
 
     let resolved = merge_config(Some(config_file));
@@ -469,6 +473,41 @@ output = 50.0
     );
 }
 
+#[test]
+fn pricing_entry_parses_cache_write_and_read_rates() {
+    let toml_content = r#"
+[pricing.models.opus]
+input = 5.0
+output = 25.0
+cache_write_5m = 6.25
+cache_write_1h = 10.0
+cache_read = 0.5
+"#;
+
+    let config: ConfigFile = toml::from_str(toml_content).expect("Should parse cache rates");
+
+    let pricing = config.pricing.expect("Pricing section should be present");
+    let opus = pricing.models.get("opus").expect("Should have opus");
+
+    assert_eq!(opus.cache_write_5m, Some(6.25));
+    assert_eq!(opus.cache_write_1h, Some(10.0));
+    assert_eq!(opus.cache_read, Some(0.5));
+    assert_eq!(opus.cached_input, None);
+}
+
+#[test]
+fn pricing_section_parses_cost_tolerance() {
+    let toml_content = r#"
+[pricing]
+cost_tolerance_percent = 5.0
+"#;
+
+    let config: ConfigFile = toml::from_str(toml_content).expect("Should parse cost tolerance");
+
+    let pricing = config.pricing.expect("Pricing section should be present");
+    assert_eq!(pricing.cost_tolerance_percent, Some(5.0));
+}
+
 #[test]
 fn pricing_section_parses_default_pricing() {
     let toml_content = r#"
@@ -654,6 +693,8 @@ fn precedence_chain_defaults_to_config_file() {
         keybindings: None,
         pricing: None,
         max_context_tokens: None,
+        searches: None,
+        context_windows: None,
     };
 
     let resolved = merge_config(Some(config_file));
@@ -687,6 +728,8 @@ fn precedence_chain_config_file_to_env_vars() {
         keybindings: None,
         pricing: None,
         max_context_tokens: None,
+        searches: None,
+        context_windows: None,
     };
 
     let merged = merge_config(Some(config_file));
@@ -754,6 +797,8 @@ fn precedence_chain_full_defaults_to_cli() {
         keybindings: None,
         pricing: None,
         max_context_tokens: None,
+        searches: None,
+        context_windows: None,
     };
 
     // Step 1: Defaults → Config File
@@ -830,6 +875,8 @@ fn merge_config_uses_config_file_max_context_tokens() {
         keybindings: None,
         pricing: None,
         max_context_tokens: Some(500_000),
+        searches: None,
+        context_windows: None,
     };
 
     let resolved = merge_config(Some(config_file));
@@ -853,6 +900,8 @@ fn merge_config_uses_default_when_max_context_tokens_none() {
         keybindings: None,
         pricing: None,
         max_context_tokens: None,
+        searches: None,
+        context_windows: None,
     };
 
     let resolved = merge_config(Some(config_file));
@@ -947,3 +996,58 @@ fn valid_themes_array_contains_common_themes() {
         "VALID_THEMES must contain dracula"
     );
 }
+
+// ===== Saved searches =====
+
+#[test]
+fn config_file_parses_searches_table() {
+    let toml_content = r#"
+[searches]
+panics = "panicked at"
+todos = "TODO"
+"#;
+
+    let config: ConfigFile = toml::from_str(toml_content).expect("Should parse searches table");
+    let searches = config.searches.expect("Searches table should be present");
+
+    assert_eq!(searches.len(), 2);
+    assert_eq!(searches.get("panics").map(String::as_str), Some("panicked at"));
+    assert_eq!(searches.get("todos").map(String::as_str), Some("TODO"));
+}
+
+#[test]
+fn config_file_allows_missing_searches() {
+    let config: ConfigFile = toml::from_str("theme = \"monokai\"").expect("Should parse");
+
+    assert_eq!(config.searches, None);
+}
+
+#[test]
+fn default_search_history_path_is_next_to_log_file() {
+    assert_eq!(
+        default_search_history_path().parent(),
+        default_log_path().parent(),
+        "Search history should live in the same state directory as the log"
+    );
+}
+
+// ===== context_windows Tests =====
+
+#[test]
+fn config_file_parses_context_windows_table() {
+    let toml_content = r#"
+max_context_tokens = 200000
+
+[context_windows]
+sonnet = 1000000
+"claude-haiku-4-5" = 150000
+"#;
+
+    let config: ConfigFile = toml::from_str(toml_content).expect("Should parse context_windows");
+    let windows = config
+        .context_windows
+        .expect("context_windows should be parsed");
+
+    assert_eq!(windows.get("sonnet"), Some(&1_000_000));
+    assert_eq!(windows.get("claude-haiku-4-5"), Some(&150_000));
+}
@@ -1,12 +1,15 @@
 //! Configuration module.
 
+pub mod history;
 pub mod keybindings;
 pub mod loader;
 
+pub use history::{load_search_history, save_search_history};
 pub use keybindings::KeyBindings;
 pub use loader::{
     ConfigError, ConfigFile, PricingConfigSection, PricingEntry, ResolvedConfig,
     THEME_BASE16_OCEAN, THEME_DEFAULT, THEME_MONOKAI, THEME_SOLARIZED_DARK, THEME_SOLARIZED_LIGHT,
-    VALID_THEMES, apply_cli_overrides, apply_env_overrides, default_config_path, load_config_file,
-    load_config_with_precedence, merge_config,
+    VALID_THEMES, apply_cli_overrides, apply_env_overrides, default_config_path,
+    default_search_history_path, default_state_dir, load_config_file, load_config_with_precedence,
+    merge_config,
 };
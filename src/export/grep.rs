@@ -0,0 +1,196 @@
+//! Search of `cclv grep`.
+//!
+//! Every line is parsed with the graceful parser and matched through
+//! `SearchDocument`, so matches are exactly the ones the in-app search
+//! highlights: text, thinking and tool results, case-insensitive (tool
+//! inputs on request). Hits are grouped by content block and printed as the
+//! block's matching lines prefixed with `file:line:session:agent:` (context
+//! lines use `-` separators and groups are split by `--`, as grep does), or
+//! as JSON Lines.
+
+use serde::Serialize;
+
+use crate::parser::{ParseResult, parse_entry_graceful};
+use crate::state::search::{SearchDocument, SearchQuery};
+
+/// Matching lines of one content block of one log line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockHits {
+    /// Input the line comes from (`-` for stdin).
+    pub file: String,
+    /// JSONL line number (1-based).
+    pub line: usize,
+    /// Session of the entry.
+    pub session_id: String,
+    /// Subagent of the entry; `None` for the main agent.
+    pub agent_id: Option<String>,
+    /// UUID of the entry.
+    pub entry_uuid: String,
+    /// Index of the content block within the entry's message.
+    pub block_index: usize,
+    /// Lines of the block's text.
+    pub text: Vec<String>,
+    /// Indices into `text` of lines with a match, ascending.
+    pub hit_lines: Vec<usize>,
+}
+
+impl BlockHits {
+    /// `file:line:session:agent:` with `separator` in place of `:`.
+    fn prefix(&self, separator: char) -> String {
+        let agent = self.agent_id.as_deref().unwrap_or("main");
+        format!(
+            "{file}{s}{line}{s}{session}{s}{agent}{s}",
+            file = self.file,
+            line = self.line,
+            session = self.session_id,
+            agent = agent,
+            s = separator
+        )
+    }
+
+    /// Line ranges (inclusive) to print: each hit widened by `context`,
+    /// overlapping or adjacent ranges merged.
+    fn runs(&self, context: usize) -> Vec<(usize, usize)> {
+        let last = self.text.len().saturating_sub(1);
+        let mut runs: Vec<(usize, usize)> = Vec::new();
+        for &hit in &self.hit_lines {
+            let start = hit.saturating_sub(context);
+            let end = (hit + context).min(last);
+            match runs.last_mut() {
+                Some((_, run_end)) if start <= *run_end + 1 => *run_end = (*run_end).max(end),
+                _ => runs.push((start, end)),
+            }
+        }
+        runs
+    }
+}
+
+/// Search every line of one input for `query`.
+///
+/// Malformed and blank lines are skipped, as the TUI does not search them.
+pub fn grep_lines(
+    file: &str,
+    lines: &[String],
+    query: &SearchQuery,
+    tool_inputs: bool,
+) -> Vec<BlockHits> {
+    let query_lower = query.as_str().to_lowercase();
+    let mut hits = Vec::new();
+    for (index, raw) in lines.iter().enumerate() {
+        if raw.trim().is_empty() {
+            continue;
+        }
+        let ParseResult::Valid(entry) = parse_entry_graceful(raw, index + 1) else {
+            continue;
+        };
+        let mut document = SearchDocument::from_entry(&entry);
+        if tool_inputs {
+            document = document.with_tool_inputs();
+        }
+        let mut matches = Vec::new();
+        document.find_matches(&query_lower, &mut matches);
+        if matches.is_empty() {
+            continue;
+        }
+
+        for (block_index, text) in document.blocks() {
+            // Offsets index the lowercased text, so count lines there
+            let text_lower = text.to_lowercase();
+            let mut hit_lines: Vec<usize> = matches
+                .iter()
+                .filter(|m| m.block_index == block_index)
+                .map(|m| text_lower[..m.char_offset].matches('\n').count())
+                .collect();
+            hit_lines.dedup();
+            if hit_lines.is_empty() {
+                continue;
+            }
+            hits.push(BlockHits {
+                file: file.to_string(),
+                line: index + 1,
+                session_id: document.session_id().to_string(),
+                agent_id: document.agent_id().map(|a| a.to_string()),
+                entry_uuid: document.entry_uuid().to_string(),
+                block_index,
+                text: text.lines().map(str::to_string).collect(),
+                hit_lines,
+            });
+        }
+    }
+    hits
+}
+
+/// Matching lines with `context` lines around them, grep style.
+pub fn grep_text(hits: &[BlockHits], context: usize) -> String {
+    let mut out = String::new();
+    let mut first_run = true;
+    for block in hits {
+        for (start, end) in block.runs(context) {
+            if context > 0 && !first_run {
+                out.push_str("--\n");
+            }
+            first_run = false;
+            for index in start..=end {
+                let separator = if block.hit_lines.binary_search(&index).is_ok() {
+                    ':'
+                } else {
+                    '-'
+                };
+                let text = block.text.get(index).map_or("", String::as_str);
+                out.push_str(&block.prefix(separator));
+                out.push_str(text);
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+/// One matching line in JSON Lines output.
+#[derive(Serialize)]
+struct JsonHit<'a> {
+    file: &'a str,
+    line: usize,
+    session_id: &'a str,
+    agent_id: Option<&'a str>,
+    entry_uuid: &'a str,
+    block_index: usize,
+    /// Line within the block (1-based).
+    block_line: usize,
+    text: &'a str,
+    before: &'a [String],
+    after: &'a [String],
+}
+
+/// One JSON object per matching line, with `context` lines before and after.
+pub fn grep_json(hits: &[BlockHits], context: usize) -> String {
+    let mut out = String::new();
+    for block in hits {
+        for &index in &block.hit_lines {
+            let Some(text) = block.text.get(index) else {
+                continue;
+            };
+            let before = &block.text[index.saturating_sub(context)..index];
+            let after = &block.text[index + 1..(index + 1 + context).min(block.text.len())];
+            let hit = JsonHit {
+                file: &block.file,
+                line: block.line,
+                session_id: &block.session_id,
+                agent_id: block.agent_id.as_deref(),
+                entry_uuid: &block.entry_uuid,
+                block_index: block.block_index,
+                block_line: index + 1,
+                text,
+                before,
+                after,
+            };
+            out.push_str(&serde_json::to_string(&hit).unwrap_or_default());
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "grep_tests.rs"]
+mod tests;
@@ -0,0 +1,106 @@
+//! Tests for `cclv grep` matching and output.
+
+use super::*;
+
+fn line(uuid: &str, agent: Option<&str>, content: serde_json::Value) -> String {
+    let mut entry = serde_json::json!({
+        "type": "assistant",
+        "uuid": uuid,
+        "session_id": "s1",
+        "timestamp": "2025-01-15T14:00:00Z",
+        "message": {"role": "assistant", "content": content},
+    });
+    if let Some(agent) = agent {
+        entry["agentId"] = serde_json::json!(agent);
+        entry["isSidechain"] = serde_json::json!(true);
+    }
+    entry.to_string()
+}
+
+fn lines() -> Vec<String> {
+    vec![
+        line(
+            "a1",
+            None,
+            serde_json::json!("one\nan Error here\nthree\nfour"),
+        ),
+        String::new(),
+        "not json, error".to_string(),
+        line(
+            "a2",
+            Some("agent-1"),
+            serde_json::json!([
+                {"type": "tool_use", "id": "t1", "name": "Bash", "input": {"command": "grep error"}},
+                {"type": "thinking", "thinking": "error? error!"},
+            ]),
+        ),
+    ]
+}
+
+fn query(pattern: &str) -> SearchQuery {
+    SearchQuery::new(pattern).unwrap()
+}
+
+#[test]
+fn matches_follow_in_app_search() {
+    let hits = grep_lines("run.jsonl", &lines(), &query("ERROR"), false);
+
+    assert_eq!(hits.len(), 2, "{:?}", hits);
+    assert_eq!(hits[0].line, 1);
+    assert_eq!(hits[0].agent_id, None);
+    assert_eq!(hits[0].hit_lines, vec![1]);
+    assert_eq!(hits[1].line, 4);
+    assert_eq!(hits[1].agent_id.as_deref(), Some("agent-1"));
+    assert_eq!(
+        hits[1].block_index, 1,
+        "tool input skipped, thinking searched"
+    );
+    assert_eq!(hits[1].hit_lines, vec![0], "one line however many matches");
+
+    let hits = grep_lines("run.jsonl", &lines(), &query("error"), true);
+    assert_eq!(hits.len(), 3);
+    assert_eq!(hits[1].block_index, 0);
+    assert_eq!(
+        hits[1].text[hits[1].hit_lines[0]],
+        "  \"command\": \"grep error\""
+    );
+}
+
+#[test]
+fn text_output_is_prefixed_with_location() {
+    let hits = grep_lines("run.jsonl", &lines(), &query("error"), false);
+
+    assert_eq!(
+        grep_text(&hits, 0),
+        "run.jsonl:1:s1:main:an Error here\nrun.jsonl:4:s1:agent-1:error? error!\n"
+    );
+    assert_eq!(
+        grep_text(&hits, 1),
+        "run.jsonl-1-s1-main-one\n\
+         run.jsonl:1:s1:main:an Error here\n\
+         run.jsonl-1-s1-main-three\n\
+         --\n\
+         run.jsonl:4:s1:agent-1:error? error!\n"
+    );
+}
+
+#[test]
+fn json_output_has_one_object_per_matching_line() {
+    let hits = grep_lines("-", &lines(), &query("error"), false);
+
+    let json = grep_json(&hits, 2);
+    let objects: Vec<serde_json::Value> = json
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+
+    assert_eq!(objects.len(), 2);
+    assert_eq!(objects[0]["file"], "-");
+    assert_eq!(objects[0]["line"], 1);
+    assert_eq!(objects[0]["agent_id"], serde_json::Value::Null);
+    assert_eq!(objects[0]["block_line"], 2);
+    assert_eq!(objects[0]["text"], "an Error here");
+    assert_eq!(objects[0]["before"], serde_json::json!(["one"]));
+    assert_eq!(objects[0]["after"], serde_json::json!(["three", "four"]));
+    assert_eq!(objects[1]["agent_id"], "agent-1");
+}
@@ -0,0 +1,527 @@
+//! Self-contained HTML export.
+//!
+//! One page with embedded CSS and JS, readable without cclv:
+//! - One `<section>` per session: heading, stats summary (from
+//!   `SessionStats`) and a tab bar switching between the main agent and each
+//!   subagent; Task calls link to their subagent's tab
+//! - One `<details>` per entry; entries longer than the collapse threshold
+//!   start closed, as they start collapsed in the TUI
+//! - Content kinds rendered as in the TUI: Markdown text with fenced code
+//!   highlighted, tool calls with their JSON input, tool results as plain
+//!   text (red on error), thinking in italics
+//! - Code highlighted by syntect with the configured theme, as inline styles
+//! - A search box that hides entries not containing the query
+//!
+//! Raw HTML in log text is escaped, never passed through. Markdown links
+//! keep only http(s), mailto and relative targets, and images become links,
+//! so a shared page runs no script and loads nothing remote.
+
+use std::collections::HashMap;
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use super::markdown::{entry_title, format_duration, subagent_title, usage_summary};
+use crate::model::{
+    AgentId, ContentBlock, EntryType, LogEntry, MessageContent, PricingConfig, ToolCall, ToolName,
+    ToolUseId,
+};
+use crate::view::{format_cost, format_tokens};
+use crate::view_state::conversation::ConversationViewState;
+use crate::view_state::highlighter::{SyntaxHighlighter, get_configured_theme};
+use crate::view_state::log::LogViewState;
+use crate::view_state::renderer::{MarkdownChunk, parse_markdown_chunks};
+use crate::view_state::session::SessionViewState;
+
+/// Tools listed in a session's stats summary; the rest are summed up.
+const SUMMARY_TOOLS: usize = 8;
+
+/// Every session of the log.
+///
+/// Entries with more than `collapse_threshold` lines of content start closed.
+pub fn log_html(log: &LogViewState, pricing: &PricingConfig, collapse_threshold: usize) -> String {
+    let count = log.session_count();
+    let mut writer = Writer::new(pricing, collapse_threshold);
+    for (index, session) in log.sessions().enumerate() {
+        writer.session(index, session, &format!("Session {}/{}", index + 1, count));
+    }
+    writer.finish()
+}
+
+/// One session.
+///
+/// Entries with more than `collapse_threshold` lines of content start closed.
+pub fn session_html(
+    session: &SessionViewState,
+    pricing: &PricingConfig,
+    collapse_threshold: usize,
+) -> String {
+    let mut writer = Writer::new(pricing, collapse_threshold);
+    writer.session(0, session, "Session");
+    writer.finish()
+}
+
+/// Accumulates the page body.
+struct Writer<'a> {
+    pricing: &'a PricingConfig,
+    collapse_threshold: usize,
+    highlighter: SyntaxHighlighter,
+    out: String,
+    /// Tool names by call ID, to label results.
+    tool_names: HashMap<ToolUseId, ToolName>,
+    /// Tab element IDs of the current session's subagents.
+    tabs: HashMap<AgentId, String>,
+}
+
+impl<'a> Writer<'a> {
+    fn new(pricing: &'a PricingConfig, collapse_threshold: usize) -> Self {
+        Self {
+            pricing,
+            collapse_threshold,
+            highlighter: SyntaxHighlighter::new(get_configured_theme()),
+            out: String::new(),
+            tool_names: HashMap::new(),
+            tabs: HashMap::new(),
+        }
+    }
+
+    /// The whole page: head with styles, search bar, sessions, script.
+    fn finish(self) -> String {
+        let (background, foreground) = self.highlighter.page_colors();
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+             <title>cclv export</title>\n<style>\n:root {{ --bg: {}; --fg: {}; }}\n{}</style>\n\
+             </head>\n<body>\n<header class=\"search\">\n\
+             <input id=\"search\" type=\"search\" placeholder=\"Search entries\" autocomplete=\"off\">\n\
+             <span id=\"search-count\"></span>\n</header>\n<main>\n{}</main>\n\
+             <script>\n{}</script>\n</body>\n</html>\n",
+            background, foreground, STYLE, self.out, SCRIPT
+        )
+    }
+
+    fn session(&mut self, index: usize, session: &SessionViewState, title: &str) {
+        let id = format!("s{}", index + 1);
+        let title = match session.start_time() {
+            Some(start) => format!("{} · {}", title, start.format("%Y-%m-%d %H:%M UTC")),
+            None => title.to_string(),
+        };
+        self.out.push_str(&format!(
+            "<section class=\"session\" id=\"{}\">\n<h1>{}</h1>\n",
+            id,
+            escape(&title)
+        ));
+        self.stats(session);
+
+        // Subagent tabs in start order
+        let mut subagents: Vec<(&AgentId, &ConversationViewState)> =
+            session.initialized_subagents().collect();
+        subagents.sort_by_key(|(agent_id, conversation)| {
+            let start = conversation
+                .iter()
+                .find_map(|e| e.entry().as_valid().map(|e| e.timestamp()));
+            (start, agent_id.as_str().to_string())
+        });
+        self.tabs = subagents
+            .iter()
+            .enumerate()
+            .map(|(n, (agent_id, _))| ((*agent_id).clone(), format!("{}-t{}", id, n + 1)))
+            .collect();
+
+        let main_tab = format!("{}-main", id);
+        self.out.push_str("<nav class=\"tabs\">\n");
+        self.out.push_str(&format!(
+            "<button class=\"active\" data-tab=\"{}\">Main agent</button>\n",
+            main_tab
+        ));
+        for (agent_id, conversation) in &subagents {
+            self.out.push_str(&format!(
+                "<button data-tab=\"{}\">{} ({})</button>\n",
+                self.tabs[*agent_id],
+                escape(&subagent_title(session, agent_id)),
+                conversation.len()
+            ));
+        }
+        self.out.push_str("</nav>\n");
+
+        self.conversation(session, session.main(), &main_tab, false);
+        for (agent_id, conversation) in &subagents {
+            let tab = self.tabs[*agent_id].clone();
+            self.conversation(session, conversation, &tab, true);
+        }
+        self.out.push_str("</section>\n");
+    }
+
+    /// Stats summary of a session as a definition list.
+    fn stats(&mut self, session: &SessionViewState) {
+        let info = session.info();
+        let stats = session.stats();
+        let mut facts = vec![(
+            "Session ID",
+            format!("<code>{}</code>", escape(session.session_id().as_str())),
+        )];
+        if let Some(model) = &info.model {
+            facts.push(("Model", escape(model.display_name())));
+        }
+        if let Some(cwd) = &info.cwd {
+            let branch = info
+                .git_branch
+                .as_ref()
+                .map(|b| format!(" (branch <code>{}</code>)", escape(b)))
+                .unwrap_or_default();
+            facts.push((
+                "Directory",
+                format!(
+                    "<code>{}</code>{}",
+                    escape(&cwd.display().to_string()),
+                    branch
+                ),
+            ));
+        }
+        if let (Some(start), Some(end)) = (session.start_time(), session.end_time()) {
+            facts.push(("Duration", format_duration((end - start).num_seconds())));
+        }
+        facts.push(("Entries", stats.entry_count.to_string()));
+        let usage = &stats.total_usage;
+        facts.push((
+            "Tokens",
+            format!(
+                "{} in · {} out · {} cache read · {} cache write",
+                format_tokens(usage.input_tokens),
+                format_tokens(usage.output_tokens),
+                format_tokens(usage.cache_read_input_tokens),
+                format_tokens(usage.cache_creation_input_tokens)
+            ),
+        ));
+        facts.push((
+            "Estimated cost",
+            format_cost(stats.estimated_cost(self.pricing, session.main().model_id())),
+        ));
+        if let Some(actual) = stats.actual_cost_usd {
+            facts.push(("Reported cost", format_cost(actual)));
+        }
+        if session.has_subagents() {
+            facts.push(("Subagents", session.subagents().len().to_string()));
+        }
+        if !stats.tool_counts.is_empty() {
+            let mut tools: Vec<(&ToolName, &u32)> = stats.tool_counts.iter().collect();
+            tools.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+            let mut parts: Vec<String> = tools
+                .iter()
+                .take(SUMMARY_TOOLS)
+                .map(|(name, count)| format!("{} {}", escape(name.as_str()), count))
+                .collect();
+            let rest: u32 = tools.iter().skip(SUMMARY_TOOLS).map(|(_, c)| **c).sum();
+            if rest > 0 {
+                parts.push(format!("other {}", rest));
+            }
+            facts.push(("Tool calls", parts.join(" · ")));
+        }
+
+        self.out.push_str("<dl class=\"stats\">\n");
+        for (term, value) in facts {
+            self.out
+                .push_str(&format!("<dt>{}</dt><dd>{}</dd>\n", term, value));
+        }
+        self.out.push_str("</dl>\n");
+    }
+
+    fn conversation(
+        &mut self,
+        session: &SessionViewState,
+        conversation: &ConversationViewState,
+        tab: &str,
+        hidden: bool,
+    ) {
+        self.out.push_str(&format!(
+            "<div class=\"conversation\" id=\"{}\"{}>\n",
+            tab,
+            if hidden { " hidden" } else { "" }
+        ));
+        for entry_view in conversation.iter() {
+            match entry_view.entry().as_valid() {
+                Some(entry) => self.entry(session, entry),
+                None => {
+                    if let Some(malformed) = entry_view.entry().as_malformed() {
+                        self.out.push_str(&format!(
+                            "<div class=\"malformed\">Malformed line {}: {}</div>\n",
+                            malformed.line_number(),
+                            escape(malformed.error_message())
+                        ));
+                    }
+                }
+            }
+        }
+        self.out.push_str("</div>\n");
+    }
+
+    fn entry(&mut self, session: &SessionViewState, entry: &LogEntry) {
+        if entry
+            .system_metadata()
+            .is_some_and(|meta| meta.is_compact_boundary())
+        {
+            self.out
+                .push_str("<div class=\"compact\">Conversation compacted</div>\n");
+            return;
+        }
+        let message = entry.message();
+        // System entries only carry metadata unless they have text
+        if entry.entry_type() == EntryType::System && message.text().trim().is_empty() {
+            return;
+        }
+
+        let mut body = String::new();
+        let mut lines = 0;
+        match message.content() {
+            MessageContent::Text(text) => lines += self.text(text, &mut body),
+            MessageContent::Blocks(blocks) => {
+                for block in blocks {
+                    lines += self.block(block, &mut body);
+                }
+            }
+        }
+        if let Some(result) = entry.result_metadata() {
+            let status = if result.is_error { "error" } else { "success" };
+            body.push_str(&format!(
+                "<ul class=\"facts\"><li>Status: {}</li><li>Turns: {}</li>\
+                 <li>Duration: {}</li><li>Reported cost: {}</li></ul>\n",
+                status,
+                result.num_turns,
+                format_duration((result.duration_ms / 1000) as i64),
+                format_cost(result.total_cost_usd)
+            ));
+            lines += self.text(&result.result_text, &mut body);
+        }
+        if let Some(usage) = usage_summary(session, entry, self.pricing) {
+            body.push_str(&format!("<div class=\"usage\">Tokens: {}</div>\n", usage));
+        }
+
+        let collapsed = lines > self.collapse_threshold;
+        self.out.push_str(&format!(
+            "<details class=\"entry {}\"{}>\n<summary>{}</summary>\n<div class=\"body\">\n{}</div>\n</details>\n",
+            entry_class(entry),
+            if collapsed { " data-collapsed" } else { " open" },
+            escape(&entry_title(entry)),
+            body
+        ));
+    }
+
+    /// Append a content block; returns its number of lines.
+    fn block(&mut self, block: &ContentBlock, body: &mut String) -> usize {
+        match block {
+            ContentBlock::Text { text } => self.text(text, body),
+            ContentBlock::Thinking { thinking } => {
+                body.push_str(&format!(
+                    "<div class=\"thinking\">{}</div>\n",
+                    escape(thinking.trim_end())
+                ));
+                thinking.lines().count()
+            }
+            ContentBlock::ToolUse(call) => {
+                self.tool_names
+                    .insert(call.id().clone(), call.name().clone());
+                self.tool_use(call, body)
+            }
+            ContentBlock::ToolResult {
+                tool_use_id,
+                content,
+                is_error,
+            } => {
+                let kind = if *is_error { "Error" } else { "Result" };
+                let label = match self.tool_names.get(tool_use_id) {
+                    Some(name) => format!("{}: {}", kind, name.as_str()),
+                    None => kind.to_string(),
+                };
+                body.push_str(&format!(
+                    "<div class=\"tool-result{}\">\n<div class=\"label\">{}</div>\n<pre>{}</pre>\n</div>\n",
+                    if *is_error { " error" } else { "" },
+                    escape(&label),
+                    escape(content.trim_end())
+                ));
+                content.lines().count()
+            }
+        }
+    }
+
+    fn tool_use(&mut self, call: &ToolCall, body: &mut String) -> usize {
+        let input = serde_json::to_string_pretty(call.input()).unwrap_or_default();
+        body.push_str(&format!(
+            "<div class=\"tool-call\">\n<div class=\"label\">🔧 Tool: {}</div>\n{}",
+            escape(call.name().as_str()),
+            self.highlighter.highlight_html(&input, Some("json"))
+        ));
+        let tab = AgentId::new(call.id().as_str())
+            .ok()
+            .filter(|_| call.name() == &ToolName::Task)
+            .and_then(|agent_id| self.tabs.get(&agent_id));
+        if let Some(tab) = tab {
+            body.push_str(&format!(
+                "<button class=\"open-tab\" data-tab=\"{}\">Open subagent</button>\n",
+                tab
+            ));
+        }
+        body.push_str("</div>\n");
+        input.lines().count()
+    }
+
+    /// Append Markdown text, fenced code highlighted; returns its number of lines.
+    fn text(&self, text: &str, body: &mut String) -> usize {
+        if text.trim().is_empty() {
+            return 0;
+        }
+        body.push_str("<div class=\"text\">\n");
+        for chunk in parse_markdown_chunks(text) {
+            match chunk {
+                MarkdownChunk::Text(markdown) => markdown_html(markdown, body),
+                MarkdownChunk::CodeBlock { language, code } => {
+                    body.push_str(&self.highlighter.highlight_html(code, language));
+                }
+            }
+        }
+        body.push_str("</div>\n");
+        text.lines().count()
+    }
+}
+
+/// Render Markdown (without fenced code) as HTML, escaping raw HTML.
+///
+/// Links keep only safe targets (see `is_safe_url`), anything else becomes
+/// plain text. Images become links to their source labelled with the alt
+/// text, so the page loads nothing remote.
+fn markdown_html(markdown: &str, out: &mut String) {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    // Whether the open link or image was kept as a link; links do not nest
+    let mut open_link = None;
+    let events = Parser::new_ext(markdown, options).filter_map(|event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Some(Event::Text(html)),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        })
+        | Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => {
+            let keep = open_link.is_none() && is_safe_url(&dest_url);
+            open_link = Some(keep);
+            keep.then_some(Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }))
+        }
+        Event::End(TagEnd::Link | TagEnd::Image) => open_link
+            .take()
+            .unwrap_or(false)
+            .then_some(Event::End(TagEnd::Link)),
+        event => Some(event),
+    });
+    pulldown_cmark::html::push_html(out, events);
+}
+
+/// Whether a link target is http(s), mailto or relative.
+///
+/// Browsers ignore whitespace and control characters in the scheme, so they
+/// are ignored here too.
+fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+        .collect();
+    match url.find([':', '/', '?', '#']) {
+        Some(end) if url[end..].starts_with(':') => {
+            let scheme = url[..end].to_ascii_lowercase();
+            matches!(scheme.as_str(), "http" | "https" | "mailto")
+        }
+        _ => true,
+    }
+}
+
+/// CSS class of an entry, matching the TUI's role colors.
+fn entry_class(entry: &LogEntry) -> &'static str {
+    match entry_title(entry).split(' ').next() {
+        Some("User") => "user",
+        Some("Assistant") => "assistant",
+        Some("Tool") => "tool-result",
+        _ => "system",
+    }
+}
+
+/// Escape text for HTML element content and attribute values.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Page styles; colors follow the highlighting theme via `--bg`/`--fg`.
+const STYLE: &str = r#"* { box-sizing: border-box; }
+body { margin: 0; background: var(--bg); color: var(--fg); font: 14px/1.5 system-ui, sans-serif; }
+main { max-width: 1100px; margin: 0 auto; padding: 0 1rem 4rem; }
+code, pre { font: 13px/1.4 ui-monospace, SFMono-Regular, Menlo, monospace; }
+pre { padding: 0.5rem 0.75rem; overflow-x: auto; border-radius: 4px; white-space: pre; }
+[hidden] { display: none !important; }
+.search { position: sticky; top: 0; z-index: 1; display: flex; gap: 1rem; align-items: center; padding: 0.5rem 1rem; background: var(--bg); border-bottom: 1px solid color-mix(in srgb, var(--fg) 25%, transparent); }
+.search input { flex: 0 1 28rem; padding: 0.3rem 0.5rem; font: inherit; color: inherit; background: transparent; border: 1px solid color-mix(in srgb, var(--fg) 40%, transparent); border-radius: 4px; }
+#search-count { opacity: 0.7; }
+.session { margin-top: 1.5rem; }
+.stats { display: grid; grid-template-columns: max-content 1fr; gap: 0.2rem 1rem; margin: 0 0 1rem; }
+.stats dt { font-weight: 600; }
+.stats dd { margin: 0; }
+.tabs { display: flex; flex-wrap: wrap; gap: 0.25rem; margin-bottom: 0.75rem; }
+.tabs button, .open-tab { font: inherit; color: inherit; background: transparent; border: 1px solid color-mix(in srgb, var(--fg) 40%, transparent); border-radius: 4px; padding: 0.2rem 0.6rem; cursor: pointer; }
+.tabs button.active { background: color-mix(in srgb, var(--fg) 15%, transparent); font-weight: 600; }
+.tabs button.has-match { border-color: #d7a000; }
+.entry { margin: 0.5rem 0; padding: 0.25rem 0.75rem; border-left: 3px solid; }
+.entry > summary { cursor: pointer; font-weight: 600; }
+.entry.user { border-color: #2aa1b3; }
+.entry.user > summary { color: #2aa1b3; }
+.entry.assistant { border-color: #3fa64b; }
+.entry.assistant > summary { color: #3fa64b; }
+.entry.tool-result, .entry.system { border-color: color-mix(in srgb, var(--fg) 40%, transparent); }
+.tool-call > .label { color: #d7a000; font-weight: 600; }
+.tool-result > .label { opacity: 0.8; font-weight: 600; }
+.tool-result.error, .tool-result.error > .label { color: #e05252; }
+.thinking { font-style: italic; opacity: 0.7; white-space: pre-wrap; margin: 0.5rem 0; }
+.usage, .compact, .malformed { opacity: 0.7; font-size: 0.9em; margin: 0.25rem 0; }
+.compact { text-align: center; border-top: 1px dashed; padding-top: 0.25rem; }
+.malformed { color: #e05252; }
+"#;
+
+/// Tab switching and search.
+const SCRIPT: &str = r#"function showTab(id) {
+  const panel = document.getElementById(id);
+  const section = panel.closest('.session');
+  section.querySelectorAll('.conversation').forEach(c => { c.hidden = c !== panel; });
+  section.querySelectorAll('.tabs button').forEach(b => b.classList.toggle('active', b.dataset.tab === id));
+  return panel;
+}
+document.querySelectorAll('.tabs button').forEach(b => b.addEventListener('click', () => showTab(b.dataset.tab)));
+document.querySelectorAll('.open-tab').forEach(b => b.addEventListener('click', () => showTab(b.dataset.tab).scrollIntoView()));
+const search = document.getElementById('search');
+search.addEventListener('input', () => {
+  const query = search.value.trim().toLowerCase();
+  let count = 0;
+  document.querySelectorAll('.entry').forEach(e => {
+    const hit = query === '' || e.textContent.toLowerCase().includes(query);
+    e.hidden = !hit;
+    e.open = query === '' ? !e.hasAttribute('data-collapsed') : hit;
+    if (hit && query !== '') count++;
+  });
+  document.querySelectorAll('.tabs button').forEach(b => {
+    const panel = document.getElementById(b.dataset.tab);
+    b.classList.toggle('has-match', query !== '' && panel.querySelector('.entry:not([hidden])') !== null);
+  });
+  document.getElementById('search-count').textContent = query === '' ? '' : count + ' matching entries';
+});
+"#;
+
+#[cfg(test)]
+#[path = "html_tests.rs"]
+mod tests;
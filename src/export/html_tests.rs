@@ -0,0 +1,191 @@
+//! Tests for the HTML export.
+
+use super::*;
+use crate::export::log_view_from_entries;
+use crate::model::{EntryMetadata, EntryUuid, Message, Role, SessionId, TokenUsage};
+use chrono::{TimeZone, Utc};
+
+fn entry(uuid: &str, agent: Option<&str>, role: Role, content: MessageContent) -> LogEntry {
+    let entry_type = match role {
+        Role::User => EntryType::User,
+        Role::Assistant => EntryType::Assistant,
+    };
+    LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        agent.map(|a| AgentId::new(a).unwrap()),
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+        entry_type,
+        Message::new(role, content),
+        EntryMetadata::default(),
+    )
+}
+
+fn text(s: &str) -> MessageContent {
+    MessageContent::Text(s.to_string())
+}
+
+fn blocks(blocks: Vec<ContentBlock>) -> MessageContent {
+    MessageContent::Blocks(blocks)
+}
+
+fn export(entries: Vec<LogEntry>) -> String {
+    log_html(
+        &log_view_from_entries(entries),
+        &PricingConfig::default(),
+        10,
+    )
+}
+
+#[test]
+fn page_is_self_contained_with_search() {
+    let html = export(vec![entry("u1", None, Role::User, text("Fix the parser"))]);
+
+    assert!(html.starts_with("<!DOCTYPE html>\n"));
+    assert!(html.contains("<style>\n:root { --bg: #"));
+    assert!(html.contains("<input id=\"search\" type=\"search\""));
+    assert!(html.contains("<script>\nfunction showTab(id)"));
+    assert!(!html.contains(" src="), "no external scripts");
+    assert!(!html.contains("<link"), "no external stylesheets");
+    assert!(html.ends_with("</html>\n"));
+}
+
+#[test]
+fn stats_summary_and_entries() {
+    let message = Message::new(Role::Assistant, text("Done.")).with_usage(TokenUsage {
+        input_tokens: 1_000_000,
+        output_tokens: 1_000,
+        ..Default::default()
+    });
+    let assistant = LogEntry::new(
+        EntryUuid::new("a1").unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        None,
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 3).unwrap(),
+        EntryType::Assistant,
+        message,
+        EntryMetadata::default(),
+    );
+
+    let html = export(vec![
+        entry("u1", None, Role::User, text("Fix the parser")),
+        assistant,
+    ]);
+
+    assert!(html.contains("<h1>Session 1/1 · 2025-01-15 14:00 UTC</h1>"));
+    assert!(html.contains("<dt>Entries</dt><dd>2</dd>"));
+    assert!(html.contains("<dt>Tokens</dt><dd>1,000,000 in · 1,000 out"));
+    assert!(html.contains("<dt>Estimated cost</dt><dd>$"));
+    assert!(
+        html.contains("<details class=\"entry user\" open>\n<summary>User · 14:00:00</summary>")
+    );
+    assert!(html.contains("<p>Fix the parser</p>"));
+    assert!(html.contains("<div class=\"usage\">Tokens: 1,000,000 in · 1,000 out"));
+}
+
+#[test]
+fn subagents_get_tabs_linked_from_task_calls() {
+    let html = export(vec![
+        entry(
+            "a1",
+            None,
+            Role::Assistant,
+            blocks(vec![ContentBlock::ToolUse(ToolCall::new(
+                ToolUseId::new("task-1").unwrap(),
+                ToolName::Task,
+                serde_json::json!({"description": "Explore parser"}),
+            ))]),
+        ),
+        entry("s1", Some("task-1"), Role::Assistant, text("Found it")),
+    ]);
+
+    assert!(html.contains("<button class=\"active\" data-tab=\"s1-main\">Main agent</button>"));
+    assert!(html.contains("<button data-tab=\"s1-t1\">Subagent: Explore parser (1)</button>"));
+    assert!(html.contains("<div class=\"conversation\" id=\"s1-t1\" hidden>"));
+    assert!(html.contains("<button class=\"open-tab\" data-tab=\"s1-t1\">Open subagent</button>"));
+}
+
+#[test]
+fn long_entries_start_collapsed() {
+    let long_output = (1..=11)
+        .map(|i| format!("line {}", i))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let html = export(vec![
+        entry(
+            "a1",
+            None,
+            Role::Assistant,
+            blocks(vec![ContentBlock::ToolUse(ToolCall::new(
+                ToolUseId::new("t1").unwrap(),
+                ToolName::Bash,
+                serde_json::json!({"command": "seq 11"}),
+            ))]),
+        ),
+        entry(
+            "u1",
+            None,
+            Role::User,
+            blocks(vec![ContentBlock::ToolResult {
+                tool_use_id: ToolUseId::new("t1").unwrap(),
+                content: long_output,
+                is_error: true,
+            }]),
+        ),
+    ]);
+
+    assert!(html.contains("<details class=\"entry assistant\" open>"));
+    assert!(html.contains("<details class=\"entry tool-result\" data-collapsed>"));
+    assert!(
+        html.contains("<div class=\"tool-result error\">\n<div class=\"label\">Error: Bash</div>")
+    );
+}
+
+#[test]
+fn code_is_highlighted_and_raw_html_escaped() {
+    let html = export(vec![entry(
+        "a1",
+        None,
+        Role::Assistant,
+        blocks(vec![
+            ContentBlock::Text {
+                text: "**Fixed** <script>alert(1)</script>\n\n```rust\nfn main() {}\n```\n"
+                    .to_string(),
+            },
+            ContentBlock::Thinking {
+                thinking: "a < b".to_string(),
+            },
+        ]),
+    )]);
+
+    assert!(html.contains("<strong>Fixed</strong>"));
+    assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+    assert!(!html.contains("<script>alert"));
+    assert!(html.contains("<pre style=\"background-color:#"));
+    assert!(html.contains("<div class=\"thinking\">a &lt; b</div>"));
+}
+
+#[test]
+fn unsafe_links_become_text_and_images_become_links() {
+    let html = export(vec![entry(
+        "a1",
+        None,
+        Role::Assistant,
+        text(
+            "[docs](https://example.com/a) [click](javascript:alert(1)) \
+             [tab](JaVa\tScript:alert(2)) [rel](notes/plan.md)\n\n\
+             ![chart](https://example.com/c.png) ![pixel](data:image/png;base64,AAAA)",
+        ),
+    )]);
+
+    assert!(html.contains("<a href=\"https://example.com/a\">docs</a>"));
+    assert!(html.contains("<a href=\"notes/plan.md\">rel</a>"));
+    assert!(!html.to_lowercase().contains("javascript:"));
+    assert!(html.contains(" click "));
+    assert!(html.contains("<a href=\"https://example.com/c.png\">chart</a>"));
+    assert!(html.contains(" pixel"));
+    assert!(!html.contains("<img"));
+    assert!(!html.contains("data:image"));
+}
@@ -0,0 +1,454 @@
+//! Markdown export.
+//!
+//! Layout of an exported session:
+//! - `#` heading with start time, then a list of model, directory, tokens and
+//!   estimated cost
+//! - One heading per entry: role, model and time
+//! - Tool inputs and outputs in fenced code blocks; thinking and results
+//!   longer than `LONG_RESULT_LINES` collapsed into `<details>`
+//! - Token and cost line under each entry that reports usage
+//! - Subagent conversations nested under their Task call in `<details>`,
+//!   one heading level deeper; subagents without a Task call follow the
+//!   main conversation
+
+use std::collections::HashMap;
+
+use crate::model::{
+    AgentId, ContentBlock, EntryType, LogEntry, MessageContent, PricingConfig, Role, ToolCall,
+    ToolName, ToolUseId,
+};
+use crate::view::{format_cost, format_tokens};
+use crate::view_state::conversation::ConversationViewState;
+use crate::view_state::log::LogViewState;
+use crate::view_state::session::SessionViewState;
+
+/// Lines of tool output shown before a result is collapsed into `<details>`.
+pub const LONG_RESULT_LINES: usize = 20;
+
+/// Deepest Markdown heading level; deeper nesting reuses it.
+const MAX_HEADING_LEVEL: usize = 6;
+
+/// Every session of the log.
+pub fn log_markdown(log: &LogViewState, pricing: &PricingConfig) -> String {
+    let count = log.session_count();
+    let mut writer = Writer::new(pricing);
+    for (index, session) in log.sessions().enumerate() {
+        writer.session(session, &format!("Session {}/{}", index + 1, count));
+    }
+    writer.finish()
+}
+
+/// One session, with subagents nested under their Task calls.
+pub fn session_markdown(session: &SessionViewState, pricing: &PricingConfig) -> String {
+    let mut writer = Writer::new(pricing);
+    writer.session(session, "Session");
+    writer.finish()
+}
+
+/// One conversation of `session`: the main agent's (`None`, with subagents
+/// nested under their Task calls) or one subagent's.
+pub fn conversation_markdown(
+    session: &SessionViewState,
+    agent: Option<&AgentId>,
+    pricing: &PricingConfig,
+) -> String {
+    let mut writer = Writer::new(pricing);
+    match agent {
+        None => {
+            writer.heading(1, "Main agent");
+            writer.conversation(session, session.main(), 2);
+        }
+        Some(agent_id) => {
+            if let Some(conversation) = session.get_subagent(agent_id) {
+                writer.heading(1, &subagent_title(session, agent_id));
+                writer.conversation(session, conversation, 2);
+            }
+        }
+    }
+    writer.finish()
+}
+
+/// A single entry of `session`.
+pub fn entry_markdown(
+    session: &SessionViewState,
+    entry: &LogEntry,
+    pricing: &PricingConfig,
+) -> String {
+    let mut writer = Writer::new(pricing);
+    writer.entry(session, entry, 1);
+    writer.finish()
+}
+
+/// Accumulates the document.
+struct Writer<'a> {
+    pricing: &'a PricingConfig,
+    out: String,
+    /// Tool names by call ID, to label results.
+    tool_names: HashMap<ToolUseId, ToolName>,
+}
+
+impl<'a> Writer<'a> {
+    fn new(pricing: &'a PricingConfig) -> Self {
+        Self {
+            pricing,
+            out: String::new(),
+            tool_names: HashMap::new(),
+        }
+    }
+
+    /// The document, ending in exactly one newline.
+    fn finish(self) -> String {
+        let mut out = self.out.trim_end().to_string();
+        out.push('\n');
+        out
+    }
+
+    /// Append a paragraph followed by a blank line.
+    fn paragraph(&mut self, text: &str) {
+        self.out.push_str(text.trim_end());
+        self.out.push_str("\n\n");
+    }
+
+    fn heading(&mut self, level: usize, title: &str) {
+        self.paragraph(&format!(
+            "{} {}",
+            "#".repeat(level.min(MAX_HEADING_LEVEL)),
+            title
+        ));
+    }
+
+    /// `<details>` block; the body is written by `body`.
+    fn details(&mut self, summary: &str, body: impl FnOnce(&mut Self)) {
+        self.paragraph(&format!(
+            "<details>\n<summary>{}</summary>",
+            escape_html(summary)
+        ));
+        body(self);
+        self.paragraph("</details>");
+    }
+
+    fn session(&mut self, session: &SessionViewState, title: &str) {
+        let title = match session.start_time() {
+            Some(start) => format!("{} · {}", title, start.format("%Y-%m-%d %H:%M UTC")),
+            None => title.to_string(),
+        };
+        self.heading(1, &title);
+
+        let info = session.info();
+        let stats = session.stats();
+        let mut facts = vec![format!("- Session ID: `{}`", session.session_id())];
+        if let Some(model) = &info.model {
+            facts.push(format!("- Model: {}", model.display_name()));
+        }
+        if let Some(cwd) = &info.cwd {
+            let branch = info
+                .git_branch
+                .as_ref()
+                .map(|b| format!(" (branch `{}`)", b))
+                .unwrap_or_default();
+            facts.push(format!("- Directory: `{}`{}", cwd.display(), branch));
+        }
+        if let (Some(start), Some(end)) = (session.start_time(), session.end_time()) {
+            facts.push(format!(
+                "- Duration: {}",
+                format_duration((end - start).num_seconds())
+            ));
+        }
+        facts.push(format!(
+            "- Tokens: {} in · {} out",
+            format_tokens(stats.total_usage.total_input()),
+            format_tokens(stats.total_usage.output_tokens)
+        ));
+        facts.push(format!(
+            "- Estimated cost: {}",
+            format_cost(stats.estimated_cost(self.pricing, session.main().model_id()))
+        ));
+        if session.has_subagents() {
+            facts.push(format!("- Subagents: {}", session.subagents().len()));
+        }
+        self.paragraph(&facts.join("\n"));
+
+        self.conversation(session, session.main(), 2);
+
+        // Subagents whose Task call is not in the main conversation
+        let mut orphans: Vec<(&AgentId, &ConversationViewState)> = session
+            .initialized_subagents()
+            .filter(|(id, _)| task_call(session, id).is_none())
+            .collect();
+        orphans.sort_by_key(|(id, conversation)| {
+            let start = conversation
+                .iter()
+                .find_map(|e| e.entry().as_valid().map(|e| e.timestamp()));
+            (start, id.as_str().to_string())
+        });
+        for (agent_id, conversation) in orphans {
+            self.heading(2, &subagent_title(session, agent_id));
+            self.conversation(session, conversation, 3);
+        }
+    }
+
+    fn conversation(
+        &mut self,
+        session: &SessionViewState,
+        conversation: &ConversationViewState,
+        level: usize,
+    ) {
+        for entry_view in conversation.iter() {
+            match entry_view.entry().as_valid() {
+                Some(entry) => self.entry(session, entry, level),
+                None => {
+                    if let Some(malformed) = entry_view.entry().as_malformed() {
+                        self.paragraph(&format!(
+                            "> Malformed line {}: {}",
+                            malformed.line_number(),
+                            malformed.error_message()
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    fn entry(&mut self, session: &SessionViewState, entry: &LogEntry, level: usize) {
+        if entry
+            .system_metadata()
+            .is_some_and(|meta| meta.is_compact_boundary())
+        {
+            self.paragraph("---");
+            self.paragraph("_Conversation compacted_");
+            return;
+        }
+        let message = entry.message();
+        // System entries only carry metadata unless they have text
+        if entry.entry_type() == EntryType::System && message.text().trim().is_empty() {
+            return;
+        }
+
+        self.heading(level, &entry_title(entry));
+
+        match message.content() {
+            MessageContent::Text(text) => {
+                if !text.trim().is_empty() {
+                    self.paragraph(text);
+                }
+            }
+            MessageContent::Blocks(blocks) => {
+                for block in blocks {
+                    self.block(session, block, level);
+                }
+            }
+        }
+
+        if let Some(result) = entry.result_metadata() {
+            let status = if result.is_error { "error" } else { "success" };
+            self.paragraph(&format!(
+                "- Status: {}\n- Turns: {}\n- Duration: {}\n- Reported cost: {}",
+                status,
+                result.num_turns,
+                format_duration((result.duration_ms / 1000) as i64),
+                format_cost(result.total_cost_usd)
+            ));
+            if !result.result_text.trim().is_empty() {
+                self.paragraph(&result.result_text);
+            }
+        }
+
+        if let Some(usage) = usage_summary(session, entry, self.pricing) {
+            self.paragraph(&format!("_Tokens: {}_", usage));
+        }
+    }
+
+    fn block(&mut self, session: &SessionViewState, block: &ContentBlock, level: usize) {
+        match block {
+            ContentBlock::Text { text } => {
+                if !text.trim().is_empty() {
+                    self.paragraph(text);
+                }
+            }
+            ContentBlock::Thinking { thinking } => {
+                self.details("Thinking", |w| w.paragraph(thinking));
+            }
+            ContentBlock::ToolUse(call) => {
+                self.tool_names
+                    .insert(call.id().clone(), call.name().clone());
+                self.tool_use(session, call, level);
+            }
+            ContentBlock::ToolResult {
+                tool_use_id,
+                content,
+                is_error,
+            } => {
+                let kind = if *is_error { "Error" } else { "Result" };
+                let label = match self.tool_names.get(tool_use_id) {
+                    Some(name) => format!("{}: {}", kind, name.as_str()),
+                    None => kind.to_string(),
+                };
+                let lines = content.lines().count();
+                if lines > LONG_RESULT_LINES {
+                    self.details(&format!("{} ({} lines)", label, lines), |w| {
+                        w.paragraph(&fenced(content, ""))
+                    });
+                } else {
+                    self.paragraph(&format!("**{}**", label));
+                    self.paragraph(&fenced(content, ""));
+                }
+            }
+        }
+    }
+
+    fn tool_use(&mut self, session: &SessionViewState, call: &ToolCall, level: usize) {
+        self.paragraph(&format!("**Tool: {}**", call.name().as_str()));
+        let input = serde_json::to_string_pretty(call.input()).unwrap_or_default();
+        self.paragraph(&fenced(&input, "json"));
+
+        if call.name() != &ToolName::Task {
+            return;
+        }
+        let Some(agent_id) = AgentId::new(call.id().as_str()).ok() else {
+            return;
+        };
+        if let Some(conversation) = session.get_subagent(&agent_id) {
+            let summary = format!(
+                "{} ({} entries)",
+                subagent_title(session, &agent_id),
+                conversation.len()
+            );
+            self.details(&summary, |w| {
+                w.conversation(session, conversation, level + 1)
+            });
+        }
+    }
+}
+
+/// Heading of an entry: role, model and time, e.g. "Assistant (Sonnet) · 14:00:03".
+pub(super) fn entry_title(entry: &LogEntry) -> String {
+    let message = entry.message();
+    let blocks: &[ContentBlock] = match message.content() {
+        MessageContent::Text(_) => &[],
+        MessageContent::Blocks(blocks) => blocks,
+    };
+    let mut title = match entry.entry_type() {
+        EntryType::User
+            if !blocks.is_empty()
+                && blocks
+                    .iter()
+                    .all(|b| matches!(b, ContentBlock::ToolResult { .. })) =>
+        {
+            "Tool result".to_string()
+        }
+        EntryType::User | EntryType::Assistant => match message.role() {
+            Role::User => "User".to_string(),
+            Role::Assistant => "Assistant".to_string(),
+        },
+        EntryType::Summary => "Summary".to_string(),
+        EntryType::System => "System".to_string(),
+        EntryType::Result => "Result".to_string(),
+    };
+    if let Some(model) = message.model() {
+        title.push_str(&format!(" ({})", model.display_name()));
+    }
+    if let Some(time) = entry.known_timestamp() {
+        title.push_str(&format!(" · {}", time.format("%H:%M:%S")));
+    }
+    title
+}
+
+/// Token usage and cost of an entry, e.g. "1,000 in · 200 out · $0.01".
+pub(super) fn usage_summary(
+    session: &SessionViewState,
+    entry: &LogEntry,
+    pricing: &PricingConfig,
+) -> Option<String> {
+    let message = entry.message();
+    let usage = message.usage()?;
+    let model_id = message
+        .model()
+        .map(|m| m.id())
+        .or(session.main().model_id())
+        .unwrap_or("opus");
+    let mut parts = vec![
+        format!("{} in", format_tokens(usage.input_tokens)),
+        format!("{} out", format_tokens(usage.output_tokens)),
+    ];
+    if usage.cache_read_input_tokens > 0 {
+        parts.push(format!(
+            "{} cache read",
+            format_tokens(usage.cache_read_input_tokens)
+        ));
+    }
+    if usage.cache_creation_input_tokens > 0 {
+        parts.push(format!(
+            "{} cache write",
+            format_tokens(usage.cache_creation_input_tokens)
+        ));
+    }
+    parts.push(format_turn_cost(pricing.get(model_id).cost(usage)));
+    Some(parts.join(" · "))
+}
+
+/// The Task call that spawned `agent_id`, if it is in the main conversation.
+pub(super) fn task_call<'s>(
+    session: &'s SessionViewState,
+    agent_id: &AgentId,
+) -> Option<&'s ToolCall> {
+    session
+        .main()
+        .iter()
+        .filter_map(|e| e.entry().as_valid())
+        .flat_map(|e| match e.message().content() {
+            MessageContent::Text(_) => [].iter(),
+            MessageContent::Blocks(blocks) => blocks.iter(),
+        })
+        .find_map(|block| match block {
+            ContentBlock::ToolUse(call) if call.id().as_str() == agent_id.as_str() => Some(call),
+            _ => None,
+        })
+}
+
+/// "Subagent: <Task description>", or the agent ID without a Task call.
+pub(super) fn subagent_title(session: &SessionViewState, agent_id: &AgentId) -> String {
+    let description = task_call(session, agent_id)
+        .and_then(|call| call.input().get("description"))
+        .and_then(|d| d.as_str());
+    match description {
+        Some(description) => format!("Subagent: {}", description),
+        None => format!("Subagent: {}", agent_id.as_str()),
+    }
+}
+
+/// Cost of one turn; sub-cent costs show as "<$0.01" rather than "$0.00".
+pub(super) fn format_turn_cost(cost: f64) -> String {
+    if cost > 0.0 && cost < 0.005 {
+        "<$0.01".to_string()
+    } else {
+        format_cost(cost)
+    }
+}
+
+/// Fenced code block, with a fence longer than any backtick run in `content`.
+fn fenced(content: &str, language: &str) -> String {
+    let longest_run = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat((longest_run + 1).max(3));
+    format!("{}{}\n{}\n{}", fence, language, content.trim_end(), fence)
+}
+
+/// Escape text for use inside an HTML element.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// "1h 02m", "3m 05s" or "42s".
+pub(super) fn format_duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    match (seconds / 3600, seconds / 60 % 60, seconds % 60) {
+        (0, 0, s) => format!("{}s", s),
+        (0, m, s) => format!("{}m {:02}s", m, s),
+        (h, m, _) => format!("{}h {:02}m", h, m),
+    }
+}
+
+#[cfg(test)]
+#[path = "markdown_tests.rs"]
+mod tests;
@@ -0,0 +1,229 @@
+//! Tests for the Markdown export.
+
+use super::*;
+use crate::export::log_view_from_entries;
+use crate::model::{EntryMetadata, EntryUuid, Message, ModelInfo, SessionId, TokenUsage};
+use chrono::{TimeZone, Utc};
+
+fn entry(uuid: &str, second: u32, role: Role, content: MessageContent) -> LogEntry {
+    let entry_type = match role {
+        Role::User => EntryType::User,
+        Role::Assistant => EntryType::Assistant,
+    };
+    LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        None,
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, second).unwrap(),
+        entry_type,
+        Message::new(role, content),
+        EntryMetadata::default(),
+    )
+}
+
+fn subagent_entry(uuid: &str, agent: &str, text: &str) -> LogEntry {
+    LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        Some(AgentId::new(agent).unwrap()),
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 5).unwrap(),
+        EntryType::Assistant,
+        Message::new(Role::Assistant, MessageContent::Text(text.to_string())),
+        EntryMetadata::default(),
+    )
+}
+
+fn tool_use(id: &str, name: &str, input: serde_json::Value) -> ContentBlock {
+    ContentBlock::ToolUse(ToolCall::new(
+        ToolUseId::new(id).unwrap(),
+        ToolName::parse(name),
+        input,
+    ))
+}
+
+fn tool_result(id: &str, content: &str) -> ContentBlock {
+    ContentBlock::ToolResult {
+        tool_use_id: ToolUseId::new(id).unwrap(),
+        content: content.to_string(),
+        is_error: false,
+    }
+}
+
+fn text(s: &str) -> MessageContent {
+    MessageContent::Text(s.to_string())
+}
+
+fn export(entries: Vec<LogEntry>) -> String {
+    log_markdown(&log_view_from_entries(entries), &PricingConfig::default())
+}
+
+#[test]
+fn exports_role_headings_and_session_facts() {
+    let markdown = export(vec![
+        entry("u1", 0, Role::User, text("Fix the parser")),
+        entry("a1", 3, Role::Assistant, text("Done.")),
+    ]);
+
+    assert!(
+        markdown.starts_with("# Session 1/1 · 2025-01-15 14:00 UTC\n\n- Session ID: `s1`\n"),
+        "{}",
+        markdown
+    );
+    assert!(markdown.contains("- Duration: 3s\n"));
+    assert!(markdown.contains("## User · 14:00:00\n\nFix the parser\n\n"));
+    assert!(markdown.ends_with("## Assistant · 14:00:03\n\nDone.\n"));
+}
+
+#[test]
+fn tool_calls_and_results_are_fenced() {
+    let markdown = export(vec![
+        entry(
+            "a1",
+            0,
+            Role::Assistant,
+            MessageContent::Blocks(vec![tool_use(
+                "t1",
+                "Bash",
+                serde_json::json!({"command": "ls"}),
+            )]),
+        ),
+        entry(
+            "u1",
+            1,
+            Role::User,
+            MessageContent::Blocks(vec![tool_result("t1", "a.rs\n```\nb.rs")]),
+        ),
+    ]);
+
+    assert!(markdown.contains("**Tool: Bash**\n\n```json\n{\n  \"command\": \"ls\"\n}\n```\n"));
+    assert!(markdown.contains("## Tool result · 14:00:01\n\n**Result: Bash**\n\n"));
+    assert!(
+        markdown.contains("````\na.rs\n```\nb.rs\n````"),
+        "fence outlasts backticks in the output:\n{}",
+        markdown
+    );
+}
+
+#[test]
+fn thinking_and_long_results_are_collapsed() {
+    let long_output = (1..=LONG_RESULT_LINES + 1)
+        .map(|i| format!("line {}", i))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let markdown = export(vec![
+        entry(
+            "a1",
+            0,
+            Role::Assistant,
+            MessageContent::Blocks(vec![
+                ContentBlock::Thinking {
+                    thinking: "Let me look".to_string(),
+                },
+                tool_use("t1", "Read", serde_json::json!({"file_path": "x"})),
+            ]),
+        ),
+        entry(
+            "u1",
+            1,
+            Role::User,
+            MessageContent::Blocks(vec![tool_result("t1", &long_output)]),
+        ),
+    ]);
+
+    assert!(
+        markdown.contains("<details>\n<summary>Thinking</summary>\n\nLet me look\n\n</details>")
+    );
+    assert!(
+        markdown.contains("<details>\n<summary>Result: Read (21 lines)</summary>\n\n```\nline 1\n")
+    );
+}
+
+#[test]
+fn usage_adds_token_and_cost_line() {
+    let message = Message::new(Role::Assistant, text("ok"))
+        .with_model(ModelInfo::new("claude-sonnet-4-5"))
+        .with_usage(TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 1_000,
+            ..Default::default()
+        });
+    let assistant = LogEntry::new(
+        EntryUuid::new("a1").unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        None,
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+        EntryType::Assistant,
+        message,
+        EntryMetadata::default(),
+    );
+
+    let markdown = export(vec![assistant]);
+
+    assert!(
+        markdown.contains("## Assistant (Sonnet) · 14:00:00"),
+        "{}",
+        markdown
+    );
+    assert!(markdown.contains("_Tokens: 1,000,000 in · 1,000 out · $3.02_"));
+}
+
+#[test]
+fn subagents_nest_under_their_task_call() {
+    let markdown = export(vec![
+        entry(
+            "a1",
+            0,
+            Role::Assistant,
+            MessageContent::Blocks(vec![tool_use(
+                "task-1",
+                "Task",
+                serde_json::json!({"description": "Explore parser"}),
+            )]),
+        ),
+        subagent_entry("s1", "task-1", "Found it"),
+        subagent_entry("s2", "orphan", "No Task call"),
+    ]);
+
+    let nested = markdown
+        .find("<details>\n<summary>Subagent: Explore parser (1 entries)</summary>\n\n### Assistant · 14:00:05\n\nFound it")
+        .expect(&markdown);
+    let orphan = markdown
+        .find("## Subagent: orphan\n\n### Assistant · 14:00:05\n\nNo Task call")
+        .expect(&markdown);
+    assert!(nested < orphan, "orphans follow the main conversation");
+}
+
+#[test]
+fn conversation_and_entry_scopes() {
+    let log = log_view_from_entries(vec![
+        entry("u1", 0, Role::User, text("Hello")),
+        subagent_entry("s1", "task-1", "Found it"),
+    ]);
+    let session = log.get_session(0).unwrap();
+    let pricing = PricingConfig::default();
+
+    let subagent = conversation_markdown(session, Some(&AgentId::new("task-1").unwrap()), &pricing);
+    assert_eq!(
+        subagent,
+        "# Subagent: task-1\n\n## Assistant · 14:00:05\n\nFound it\n"
+    );
+
+    let main = conversation_markdown(session, None, &pricing);
+    assert_eq!(main, "# Main agent\n\n## User · 14:00:00\n\nHello\n");
+
+    let first = session
+        .main()
+        .iter()
+        .next()
+        .unwrap()
+        .entry()
+        .as_valid()
+        .unwrap();
+    assert_eq!(
+        entry_markdown(session, first, &pricing),
+        "# User · 14:00:00\n\nHello\n"
+    );
+}
@@ -0,0 +1,74 @@
+//! Export of conversations, statistics and reports to documents (pure).
+//!
+//! Exporters read the view-state layer (`LogViewState`, `SessionViewState`,
+//! `ConversationViewState`), or the raw lines for `grep` and `validate`, and
+//! return the whole document as a `String`; callers decide where it goes
+//! (stdout for the headless commands, a file in the working directory from
+//! inside the TUI).
+
+pub mod grep;
+pub mod html;
+pub mod markdown;
+pub mod slice;
+pub mod stats;
+pub mod validate;
+
+use crate::model::LogEntry;
+use crate::view_state::log::LogViewState;
+
+/// Document format of `cclv export`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// GitHub-flavored Markdown.
+    #[default]
+    #[value(name = "md", alias = "markdown")]
+    Markdown,
+    /// Single HTML page with embedded styles and scripts.
+    #[value(name = "html")]
+    Html,
+}
+
+/// Part of the log exported from inside the TUI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportScope {
+    /// The viewed session: main conversation with subagents nested under their Task calls.
+    Session,
+    /// The selected tab's conversation.
+    Tab,
+    /// The focused entry of the selected tab.
+    Entry,
+}
+
+impl ExportScope {
+    /// Every scope, in picker order.
+    pub const ALL: [ExportScope; 3] = [ExportScope::Session, ExportScope::Tab, ExportScope::Entry];
+
+    /// Label shown in the export picker.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportScope::Session => "Session",
+            ExportScope::Tab => "Tab",
+            ExportScope::Entry => "Focused entry",
+        }
+    }
+
+    /// Suffix of the exported file name.
+    pub fn file_suffix(&self) -> &'static str {
+        match self {
+            ExportScope::Session => "session",
+            ExportScope::Tab => "tab",
+            ExportScope::Entry => "entry",
+        }
+    }
+}
+
+/// Build a `LogViewState` from parsed entries, routing subagent entries by
+/// their agent ID (as `AppState::add_entries` does).
+pub fn log_view_from_entries(entries: Vec<LogEntry>) -> LogViewState {
+    let mut log_view = LogViewState::new();
+    for entry in crate::integration::process_entries(entries) {
+        let agent_id = entry.as_valid().and_then(|e| e.agent_id().cloned());
+        log_view.add_entry(entry, agent_id);
+    }
+    log_view
+}
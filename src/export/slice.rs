@@ -0,0 +1,199 @@
+//! Extraction of `cclv slice`.
+//!
+//! Lines are kept or dropped one by one and written back byte for byte,
+//! line endings included, so the slice is a log cclv and other tools open
+//! like the original. Lines that are not UTF-8 count as malformed. Filters
+//! combine: session, agent, line or time range and a search query matched
+//! like the in-app search. Lines without a timestamp take the time of the
+//! nearest timestamped line above them. Malformed lines only pass line
+//! ranges, as nothing else about them is known. With `drop_thinking`,
+//! thinking blocks are removed from the lines that have them. Those lines are
+//! re-serialized with their keys in the original order, and kept even when no
+//! content is left, so their usage and `parentUuid` links survive.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::model::{LogEntry, SessionId};
+use crate::parser::{ParseResult, parse_entry_graceful};
+use crate::state::search::{SearchDocument, SearchQuery};
+use crate::view::print::Tab;
+
+/// One end of a `--from`/`--to` range, inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    /// JSONL line number (1-based).
+    Line(usize),
+    /// Entry time.
+    Time(DateTime<Utc>),
+}
+
+impl Bound {
+    /// Parse a line number or a time: RFC 3339, `YYYY-MM-DD HH:MM[:SS]` in
+    /// UTC, or a date (midnight UTC).
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let raw = raw.trim();
+        if let Ok(line) = raw.parse::<usize>() {
+            return Ok(Self::Line(line));
+        }
+        if let Ok(time) = DateTime::parse_from_rfc3339(raw) {
+            return Ok(Self::Time(time.with_timezone(&Utc)));
+        }
+        for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+            if let Ok(time) = NaiveDateTime::parse_from_str(raw, format) {
+                return Ok(Self::Time(time.and_utc()));
+            }
+        }
+        NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|time| Self::Time(time.and_utc()))
+            .ok_or_else(|| "expected a line number, a date or a date and time".to_string())
+    }
+}
+
+/// Which lines to keep and how.
+#[derive(Debug, Clone, Default)]
+pub struct SliceFilter {
+    /// Only entries of this session.
+    pub session: Option<SessionId>,
+    /// Only entries of this agent.
+    pub agent: Option<Tab>,
+    /// Only lines at or after this bound.
+    pub from: Option<Bound>,
+    /// Only lines at or before this bound.
+    pub to: Option<Bound>,
+    /// Only entries matching this query.
+    pub query: Option<SearchQuery>,
+    /// Remove thinking blocks.
+    pub drop_thinking: bool,
+}
+
+impl SliceFilter {
+    /// Whether line `line` passes the line bounds.
+    fn in_lines(&self, line: usize) -> bool {
+        let after = !matches!(self.from, Some(Bound::Line(from)) if line < from);
+        let before = !matches!(self.to, Some(Bound::Line(to)) if line > to);
+        after && before
+    }
+
+    /// Whether a time passes the time bounds; unknown times only pass
+    /// when there is no lower one.
+    fn in_time(&self, time: Option<DateTime<Utc>>) -> bool {
+        let after = match (self.from, time) {
+            (Some(Bound::Time(from)), Some(time)) => time >= from,
+            (Some(Bound::Time(_)), None) => false,
+            _ => true,
+        };
+        let before = !matches!((self.to, time), (Some(Bound::Time(to)), Some(time)) if time > to);
+        after && before
+    }
+
+    /// Whether a parsed entry passes the session, agent and query filters.
+    fn matches(&self, entry: &Arc<LogEntry>) -> bool {
+        if self
+            .session
+            .as_ref()
+            .is_some_and(|session| session != entry.session_id())
+        {
+            return false;
+        }
+        let agent_matches = match &self.agent {
+            None => true,
+            Some(Tab::Main) => entry.agent_id().is_none(),
+            Some(Tab::Subagent(id)) => entry.agent_id() == Some(id),
+        };
+        if !agent_matches {
+            return false;
+        }
+        match &self.query {
+            None => true,
+            Some(query) => {
+                let mut matches = Vec::new();
+                SearchDocument::from_entry(entry)
+                    .find_matches(&query.as_str().to_lowercase(), &mut matches);
+                !matches.is_empty()
+            }
+        }
+    }
+
+    /// Whether the filter looks at anything but line numbers.
+    fn needs_entry(&self) -> bool {
+        self.session.is_some()
+            || self.agent.is_some()
+            || self.query.is_some()
+            || matches!(self.from, Some(Bound::Time(_)))
+            || matches!(self.to, Some(Bound::Time(_)))
+    }
+}
+
+/// `raw` without its thinking blocks, or None when it has none.
+fn without_thinking(raw: &str) -> Option<String> {
+    let mut value: Value = serde_json::from_str(raw).ok()?;
+    let blocks = value
+        .get_mut("message")
+        .and_then(|m| m.get_mut("content"))
+        .and_then(Value::as_array_mut)?;
+    let before = blocks.len();
+    blocks.retain(|block| block.get("type").and_then(Value::as_str) != Some("thinking"));
+    if blocks.len() == before {
+        return None;
+    }
+    serde_json::to_string(&value).ok()
+}
+
+/// The lines of one input that pass `filter`.
+///
+/// `lines` are raw lines with their terminators (see
+/// `source::read_raw_lines`); kept lines are copied as they are.
+pub fn slice_lines(lines: &[Vec<u8>], filter: &SliceFilter) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut last_time = None;
+    for (index, bytes) in lines.iter().enumerate() {
+        let body = bytes.strip_suffix(b"\n").map_or(bytes.as_slice(), |body| {
+            body.strip_suffix(b"\r").unwrap_or(body)
+        });
+        let ending = &bytes[body.len()..];
+        let text = std::str::from_utf8(body).ok();
+        if text.is_some_and(|raw| raw.trim().is_empty()) {
+            continue;
+        }
+        let line = index + 1;
+        let parsed = text.map(|raw| parse_entry_graceful(raw, line));
+        let entry = match &parsed {
+            Some(ParseResult::Valid(entry)) => Some(entry),
+            Some(ParseResult::Malformed(_)) | None => None,
+        };
+        if let Some(time) = entry.and_then(|e| e.known_timestamp()) {
+            last_time = Some(time);
+        }
+
+        if !filter.in_lines(line) {
+            continue;
+        }
+        let keep = match entry {
+            Some(entry) => filter.in_time(last_time) && filter.matches(entry),
+            None => !filter.needs_entry(),
+        };
+        if !keep {
+            continue;
+        }
+
+        match text
+            .filter(|_| filter.drop_thinking)
+            .and_then(without_thinking)
+        {
+            Some(stripped) => {
+                out.extend_from_slice(stripped.as_bytes());
+                out.extend_from_slice(ending);
+            }
+            None => out.extend_from_slice(bytes),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "slice_tests.rs"]
+mod tests;
@@ -0,0 +1,154 @@
+//! Tests for log slicing.
+
+use super::*;
+use chrono::TimeZone;
+
+const MAIN_1: &str = r#"{"type":"user","session_id":"s1","uuid":"u1","timestamp":"2025-01-15T14:00:00Z","message":{"role":"user","content":"Fix the parser"}}"#;
+const THINKING: &str = r#"{"type":"assistant","session_id":"s1","uuid":"a1","message":{"role":"assistant","content":[{"type":"thinking","thinking":"hmm"}]}}"#;
+const MIXED: &str = r#"{"uuid":"a2","type":"assistant","session_id":"s1","message":{"role":"assistant","content":[{"type":"thinking","thinking":"so"},{"type":"text","text":"Done"}]}}"#;
+const SUBAGENT: &str = r#"{"type":"assistant","session_id":"s1","uuid":"b1","agentId":"agent-1","timestamp":"2025-01-15T14:05:00Z","message":{"role":"assistant","content":"Found the parser bug"}}"#;
+const OTHER: &str = r#"{"type":"user","session_id":"s2","uuid":"u2","timestamp":"2025-01-15T15:00:00Z","message":{"role":"user","content":"Next"}}"#;
+
+fn lines() -> Vec<Vec<u8>> {
+    [MAIN_1, THINKING, "", MIXED, "{broken", SUBAGENT, OTHER]
+        .iter()
+        .map(|l| format!("{l}\n").into_bytes())
+        .collect()
+}
+
+fn kept(filter: SliceFilter) -> Vec<String> {
+    String::from_utf8(slice_lines(&lines(), &filter))
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn no_filter_keeps_every_line_byte_for_byte() {
+    assert_eq!(
+        slice_lines(&lines(), &SliceFilter::default()),
+        format!("{MAIN_1}\n{THINKING}\n{MIXED}\n{{broken\n{SUBAGENT}\n{OTHER}\n").into_bytes()
+    );
+}
+
+#[test]
+fn crlf_and_invalid_utf8_lines_round_trip() {
+    let input = format!("{MAIN_1}\r\n{MIXED}\r\n").into_bytes();
+    let mut input = [
+        input,
+        b"\xff{broken\r\n".to_vec(),
+        OTHER.as_bytes().to_vec(),
+    ]
+    .concat();
+    let lines: Vec<Vec<u8>> = input
+        .split_inclusive(|&byte| byte == b'\n')
+        .map(<[u8]>::to_vec)
+        .collect();
+
+    assert_eq!(slice_lines(&lines, &SliceFilter::default()), input);
+
+    let without_thinking = SliceFilter {
+        drop_thinking: true,
+        to: Some(Bound::Line(2)),
+        ..Default::default()
+    };
+    let sliced = slice_lines(&lines, &without_thinking);
+    assert!(sliced.starts_with(format!("{MAIN_1}\r\n").as_bytes()));
+    assert!(sliced.ends_with(b"}\r\n"), "rewritten lines keep CRLF");
+    assert_eq!(sliced.iter().filter(|&&byte| byte == b'\n').count(), 2);
+
+    input.truncate(input.len() - OTHER.len());
+    let up_to_broken = SliceFilter {
+        to: Some(Bound::Line(3)),
+        ..Default::default()
+    };
+    assert_eq!(slice_lines(&lines, &up_to_broken), input);
+}
+
+#[test]
+fn session_agent_and_query_filters() {
+    let session = SliceFilter {
+        session: Some(SessionId::new("s2").unwrap()),
+        ..Default::default()
+    };
+    assert_eq!(kept(session), vec![OTHER]);
+
+    let subagent = SliceFilter {
+        agent: Some(Tab::parse("agent-1").unwrap()),
+        ..Default::default()
+    };
+    assert_eq!(kept(subagent), vec![SUBAGENT]);
+
+    let main = SliceFilter {
+        agent: Some(Tab::Main),
+        query: SearchQuery::new("PARSER"),
+        ..Default::default()
+    };
+    assert_eq!(kept(main), vec![MAIN_1]);
+}
+
+#[test]
+fn line_and_time_ranges() {
+    let lines_2_to_5 = SliceFilter {
+        from: Some(Bound::Line(2)),
+        to: Some(Bound::Line(5)),
+        ..Default::default()
+    };
+    assert_eq!(kept(lines_2_to_5), vec![THINKING, MIXED, "{broken"]);
+
+    // Untimed lines take the time of the line above; malformed ones drop out
+    let until_14_04 = SliceFilter {
+        to: Some(Bound::parse("2025-01-15 14:04").unwrap()),
+        ..Default::default()
+    };
+    assert_eq!(kept(until_14_04), vec![MAIN_1, THINKING, MIXED]);
+
+    let from_14_05 = SliceFilter {
+        from: Some(Bound::parse("2025-01-15T14:05:00Z").unwrap()),
+        ..Default::default()
+    };
+    assert_eq!(kept(from_14_05), vec![SUBAGENT, OTHER]);
+}
+
+#[test]
+fn bounds_parse_lines_dates_and_times() {
+    assert_eq!(Bound::parse("42"), Ok(Bound::Line(42)));
+    assert_eq!(
+        Bound::parse("2025-01-15"),
+        Ok(Bound::Time(
+            Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap()
+        ))
+    );
+    assert_eq!(
+        Bound::parse("2025-01-15T16:30:00+02:00"),
+        Ok(Bound::Time(
+            Utc.with_ymd_and_hms(2025, 1, 15, 14, 30, 0).unwrap()
+        ))
+    );
+    assert!(Bound::parse("yesterday").is_err());
+}
+
+#[test]
+fn drop_thinking_strips_blocks_and_keeps_entries() {
+    let filter = SliceFilter {
+        drop_thinking: true,
+        to: Some(Bound::Line(4)),
+        ..Default::default()
+    };
+
+    let kept = kept(filter);
+
+    assert_eq!(kept.len(), 3);
+    assert_eq!(kept[0], MAIN_1, "lines without thinking are untouched");
+    assert_eq!(
+        kept[1],
+        THINKING.replace(r#"{"type":"thinking","thinking":"hmm"}"#, ""),
+        "thinking-only entries stay, with empty content"
+    );
+    assert_eq!(
+        kept[2],
+        MIXED.replace(r#"{"type":"thinking","thinking":"so"},"#, ""),
+        "keys keep their order"
+    );
+}
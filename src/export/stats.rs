@@ -0,0 +1,415 @@
+//! Statistics report of `cclv stats`.
+//!
+//! One `ScopeStats` record per `StatsFilter` scope of each input: all
+//! sessions, then each session followed by its main agent and subagents.
+//! Records carry the numbers of the stats panel (token usage, estimated and
+//! reported cost, tool calls and errors) plus entry counts, durations and
+//! per-model and per-tool breakdowns, and render as an aligned table, JSON or
+//! CSV.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::markdown::format_duration;
+use crate::model::{LogEntry, PricingConfig, SessionStats, StatsFilter, TokenUsage};
+use crate::view::{format_cost, format_tokens};
+use crate::view_state::log::LogViewState;
+
+/// Statistics of one scope of one input.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScopeStats {
+    /// Input the scope comes from (`-` for stdin).
+    pub file: String,
+    /// Scope kind: `all`, `session`, `main` or `subagent`.
+    pub scope: &'static str,
+    /// Session of `session` and `main` scopes.
+    pub session_id: Option<String>,
+    /// Agent of `subagent` scopes.
+    pub agent_id: Option<String>,
+    /// Valid log entries in scope.
+    pub entries: usize,
+    /// Seconds from the first to the last timestamped entry in scope.
+    pub duration_secs: Option<i64>,
+    /// Subagents spawned in scope (`None` for a subagent).
+    pub subagents: Option<usize>,
+    /// Token usage in scope.
+    pub tokens: TokenCounts,
+    /// Cost estimated from token usage and the pricing config.
+    pub estimated_cost_usd: f64,
+    /// Cost reported by result entries (sessions and `all` only).
+    pub actual_cost_usd: Option<f64>,
+    /// Tool calls in scope.
+    pub tool_calls: u32,
+    /// Tool results with `is_error` in scope.
+    pub tool_errors: u32,
+    /// Calls and errors per tool, most called first.
+    pub tools: Vec<ToolCount>,
+    /// Usage and estimated cost per model, by model ID.
+    pub models: Vec<ModelStats>,
+}
+
+/// Token counts of a `TokenUsage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TokenCounts {
+    /// Uncached input tokens.
+    pub input: u64,
+    /// Output tokens.
+    pub output: u64,
+    /// Input tokens written to the prompt cache.
+    pub cache_creation: u64,
+    /// Input tokens read from the prompt cache.
+    pub cache_read: u64,
+}
+
+impl TokenCounts {
+    /// Input tokens including cache reads and writes.
+    pub fn total_input(&self) -> u64 {
+        self.input + self.cache_creation + self.cache_read
+    }
+}
+
+impl From<&TokenUsage> for TokenCounts {
+    fn from(usage: &TokenUsage) -> Self {
+        Self {
+            input: usage.input_tokens,
+            output: usage.output_tokens,
+            cache_creation: usage.cache_creation_input_tokens,
+            cache_read: usage.cache_read_input_tokens,
+        }
+    }
+}
+
+/// Calls and errors of one tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ToolCount {
+    /// Tool name.
+    pub name: String,
+    /// Number of calls.
+    pub calls: u32,
+    /// Number of results with `is_error`.
+    pub errors: u32,
+}
+
+/// Usage of one model.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModelStats {
+    /// Full model ID.
+    pub model: String,
+    /// Token usage of this model.
+    pub tokens: TokenCounts,
+    /// Cost estimated with this model's pricing.
+    pub estimated_cost_usd: f64,
+}
+
+/// Statistics of every scope of `log`, or only of `only`.
+///
+/// Usage without a reported model is priced as the session's main model
+/// (the first session's for `all`), as in the stats panel.
+pub fn scope_stats(
+    file: &str,
+    log: &LogViewState,
+    pricing: &PricingConfig,
+    only: Option<&StatsFilter>,
+) -> Vec<ScopeStats> {
+    let stats = log.stats();
+    let builder = ScopeBuilder {
+        file,
+        stats: &stats,
+        pricing,
+    };
+    let wanted = |filter: &StatsFilter| only.is_none_or(|only| only == filter);
+
+    let mut scopes = Vec::new();
+    let all = StatsFilter::AllSessionsCombined;
+    if wanted(&all) {
+        let fallback = log.sessions().next().and_then(|s| s.main().model_id());
+        let subagents = log.sessions().map(|s| s.subagents().len()).sum();
+        scopes.push(builder.build(
+            &all,
+            fallback,
+            log.sessions().flat_map(|s| s.log_entries()),
+            Some(subagents),
+        ));
+    }
+
+    for session in log.sessions() {
+        let fallback = session.main().model_id();
+        let session_id = session.session_id().clone();
+        let subagents = session.subagents().len();
+
+        let filter = StatsFilter::Session(session_id.clone());
+        if wanted(&filter) {
+            scopes.push(builder.build(&filter, fallback, session.log_entries(), Some(subagents)));
+        }
+        let filter = StatsFilter::MainAgent(session_id);
+        if wanted(&filter) {
+            let entries = session.main().iter().filter_map(|e| e.entry().as_valid());
+            scopes.push(builder.build(&filter, fallback, entries, Some(subagents)));
+        }
+
+        let mut agents: Vec<_> = session.initialized_subagents().collect();
+        agents.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+        for (agent_id, conversation) in agents {
+            let filter = StatsFilter::Subagent(agent_id.clone());
+            if wanted(&filter) {
+                let entries = conversation.iter().filter_map(|e| e.entry().as_valid());
+                scopes.push(builder.build(&filter, fallback, entries, None));
+            }
+        }
+    }
+    scopes
+}
+
+/// Builds `ScopeStats` from the rolled-up stats of one input.
+struct ScopeBuilder<'a> {
+    file: &'a str,
+    stats: &'a SessionStats,
+    pricing: &'a PricingConfig,
+}
+
+impl ScopeBuilder<'_> {
+    fn build<'e>(
+        &self,
+        filter: &StatsFilter,
+        fallback_model_id: Option<&str>,
+        entries: impl Iterator<Item = &'e LogEntry>,
+        subagents: Option<usize>,
+    ) -> ScopeStats {
+        let (scope, session_id, agent_id) = match filter {
+            StatsFilter::AllSessionsCombined => ("all", None, None),
+            StatsFilter::Session(id) => ("session", Some(id.to_string()), None),
+            StatsFilter::MainAgent(id) => ("main", Some(id.to_string()), None),
+            StatsFilter::Subagent(id) => ("subagent", None, Some(id.to_string())),
+        };
+
+        let mut count = 0;
+        let mut span: Option<(DateTime<Utc>, DateTime<Utc>)> = None;
+        for entry in entries {
+            count += 1;
+            if let Some(time) = entry.known_timestamp() {
+                span = Some(match span {
+                    None => (time, time),
+                    Some((first, last)) => (first.min(time), last.max(time)),
+                });
+            }
+        }
+
+        let mut tools: Vec<ToolCount> = self
+            .stats
+            .filtered_tool_stats(filter)
+            .iter()
+            .map(|(name, stats)| ToolCount {
+                name: name.as_str().to_string(),
+                calls: stats.calls,
+                errors: stats.errors,
+            })
+            .collect();
+        tools.sort_by(|a, b| b.calls.cmp(&a.calls).then_with(|| a.name.cmp(&b.name)));
+
+        let mut models: Vec<ModelStats> = self
+            .stats
+            .filtered_model_usage(filter)
+            .iter()
+            .map(|(model, usage)| ModelStats {
+                model: model.clone(),
+                tokens: usage.into(),
+                estimated_cost_usd: self.pricing.get(model).cost(usage),
+            })
+            .collect();
+        models.sort_by(|a, b| a.model.cmp(&b.model));
+
+        ScopeStats {
+            file: self.file.to_string(),
+            scope,
+            session_id,
+            agent_id,
+            entries: count,
+            duration_secs: span.map(|(first, last)| (last - first).num_seconds()),
+            subagents,
+            tokens: (&self.stats.filtered_usage(filter)).into(),
+            estimated_cost_usd: self.stats.filtered_estimated_cost(
+                filter,
+                self.pricing,
+                fallback_model_id,
+            ),
+            actual_cost_usd: self.stats.filtered_actual_cost(filter),
+            tool_calls: tools.iter().map(|t| t.calls).sum(),
+            tool_errors: tools.iter().map(|t| t.errors).sum(),
+            tools,
+            models,
+        }
+    }
+}
+
+/// Aligned table, one row per scope with its models indented below.
+///
+/// Inputs are introduced by a `==> file <==` line when there are several.
+pub fn stats_table(scopes: &[ScopeStats]) -> String {
+    const HEADER: [&str; 10] = [
+        "SCOPE",
+        "ENTRIES",
+        "DURATION",
+        "INPUT",
+        "OUTPUT",
+        "EST. COST",
+        "ACTUAL",
+        "TOOLS",
+        "ERRORS",
+        "SUBAGENTS",
+    ];
+    let several_files = scopes.iter().any(|s| s.file != scopes[0].file);
+
+    let mut out = String::new();
+    let mut start = 0;
+    while start < scopes.len() {
+        let file = &scopes[start].file;
+        let end = start
+            + scopes[start..]
+                .iter()
+                .take_while(|s| &s.file == file)
+                .count();
+
+        let mut rows = vec![HEADER.map(str::to_string)];
+        for scope in &scopes[start..end] {
+            rows.push(table_row(scope));
+            for model in &scope.models {
+                let mut row: [String; 10] = Default::default();
+                row[0] = format!("{}  {}", scope_indent(scope), model.model);
+                row[3] = format_tokens(model.tokens.total_input());
+                row[4] = format_tokens(model.tokens.output);
+                row[5] = format_cost(model.estimated_cost_usd);
+                rows.push(row);
+            }
+        }
+
+        let mut widths = [0; 10];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        if several_files {
+            if start > 0 {
+                out.push('\n');
+            }
+            out.push_str(&format!("==> {} <==\n", file));
+        }
+        for row in &rows {
+            let mut line = format!("{:<width$}", row[0], width = widths[0]);
+            for (cell, width) in row.iter().zip(widths).skip(1) {
+                line.push_str(&format!("  {:>width$}", cell, width = width));
+            }
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        start = end;
+    }
+    out
+}
+
+fn table_row(scope: &ScopeStats) -> [String; 10] {
+    let label = match (scope.scope, &scope.session_id, &scope.agent_id) {
+        ("session", Some(id), _) => format!("Session {}", id),
+        ("main", _, _) => "  Main agent".to_string(),
+        ("subagent", _, Some(id)) => format!("  Subagent {}", id),
+        _ => "All".to_string(),
+    };
+    [
+        label,
+        scope.entries.to_string(),
+        scope.duration_secs.map(format_duration).unwrap_or_default(),
+        format_tokens(scope.tokens.total_input()),
+        format_tokens(scope.tokens.output),
+        format_cost(scope.estimated_cost_usd),
+        scope.actual_cost_usd.map(format_cost).unwrap_or_default(),
+        scope.tool_calls.to_string(),
+        scope.tool_errors.to_string(),
+        scope.subagents.map(|n| n.to_string()).unwrap_or_default(),
+    ]
+}
+
+/// Indentation of a scope's label in the table.
+fn scope_indent(scope: &ScopeStats) -> &'static str {
+    match scope.scope {
+        "main" | "subagent" => "  ",
+        _ => "",
+    }
+}
+
+/// Pretty-printed JSON array of the scopes.
+pub fn stats_json(scopes: &[ScopeStats]) -> String {
+    let mut json = serde_json::to_string_pretty(scopes).unwrap_or_default();
+    json.push('\n');
+    json
+}
+
+/// CSV with a header row, one row per scope and one per model of a scope
+/// (the `model` column set, scope-wide columns left empty).
+pub fn stats_csv(scopes: &[ScopeStats]) -> String {
+    let mut out = String::from(
+        "file,scope,session_id,agent_id,model,entries,duration_secs,subagents,\
+         input_tokens,output_tokens,cache_creation_tokens,cache_read_tokens,\
+         estimated_cost_usd,actual_cost_usd,tool_calls,tool_errors\n",
+    );
+    let optional = |value: Option<String>| value.unwrap_or_default();
+    for scope in scopes {
+        let key = [
+            csv_field(&scope.file),
+            scope.scope.to_string(),
+            csv_field(scope.session_id.as_deref().unwrap_or_default()),
+            csv_field(scope.agent_id.as_deref().unwrap_or_default()),
+        ]
+        .join(",");
+        let row = [
+            key.clone(),
+            String::new(),
+            scope.entries.to_string(),
+            optional(scope.duration_secs.map(|d| d.to_string())),
+            optional(scope.subagents.map(|n| n.to_string())),
+            csv_tokens(&scope.tokens),
+            format!("{:.6}", scope.estimated_cost_usd),
+            optional(scope.actual_cost_usd.map(|c| format!("{:.6}", c))),
+            scope.tool_calls.to_string(),
+            scope.tool_errors.to_string(),
+        ];
+        out.push_str(&row.join(","));
+        out.push('\n');
+        for model in &scope.models {
+            let row = [
+                key.clone(),
+                csv_field(&model.model),
+                String::new(),
+                String::new(),
+                String::new(),
+                csv_tokens(&model.tokens),
+                format!("{:.6}", model.estimated_cost_usd),
+                String::new(),
+                String::new(),
+                String::new(),
+            ];
+            out.push_str(&row.join(","));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn csv_tokens(tokens: &TokenCounts) -> String {
+    format!(
+        "{},{},{},{}",
+        tokens.input, tokens.output, tokens.cache_creation, tokens.cache_read
+    )
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "stats_tests.rs"]
+mod tests;
@@ -0,0 +1,215 @@
+//! Tests for the statistics report.
+
+use super::*;
+use crate::export::log_view_from_entries;
+use crate::model::{
+    AgentId, ContentBlock, EntryMetadata, EntryType, EntryUuid, Message, MessageContent, ModelInfo,
+    ResultMetadata, Role, SessionId, ToolCall, ToolName, ToolUseId,
+};
+use chrono::TimeZone;
+
+fn entry(
+    uuid: &str,
+    agent: Option<&str>,
+    second: u32,
+    entry_type: EntryType,
+    message: Message,
+) -> LogEntry {
+    LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        agent.map(|a| AgentId::new(a).unwrap()),
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, second).unwrap(),
+        entry_type,
+        message,
+        EntryMetadata::default(),
+    )
+}
+
+fn assistant(blocks: Vec<ContentBlock>, input_tokens: u64) -> Message {
+    Message::new(Role::Assistant, MessageContent::Blocks(blocks))
+        .with_model(ModelInfo::new("claude-sonnet-4-5"))
+        .with_usage(TokenUsage {
+            input_tokens,
+            output_tokens: 10,
+            ..Default::default()
+        })
+}
+
+fn tool_use(id: &str, name: ToolName) -> ContentBlock {
+    ContentBlock::ToolUse(ToolCall::new(
+        ToolUseId::new(id).unwrap(),
+        name,
+        serde_json::json!({}),
+    ))
+}
+
+fn tool_result(id: &str, is_error: bool) -> Message {
+    Message::new(
+        Role::User,
+        MessageContent::Blocks(vec![ContentBlock::ToolResult {
+            tool_use_id: ToolUseId::new(id).unwrap(),
+            content: "out".to_string(),
+            is_error,
+        }]),
+    )
+}
+
+fn log() -> LogViewState {
+    let result = LogEntry::new_with_result_metadata(
+        EntryUuid::new("r1").unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        None,
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 1, 30).unwrap(),
+        EntryType::Result,
+        Message::new(Role::Assistant, MessageContent::Text("Done".to_string())),
+        EntryMetadata::default(),
+        Some(ResultMetadata {
+            is_error: false,
+            duration_ms: 90_000,
+            num_turns: 2,
+            total_cost_usd: 0.5,
+            result_text: String::new(),
+        }),
+    );
+    log_view_from_entries(vec![
+        entry(
+            "a1",
+            None,
+            0,
+            EntryType::Assistant,
+            assistant(
+                vec![
+                    tool_use("t1", ToolName::Bash),
+                    tool_use("agent-1", ToolName::Task),
+                ],
+                1_000,
+            ),
+        ),
+        entry("u1", None, 5, EntryType::User, tool_result("t1", true)),
+        entry(
+            "s1",
+            Some("agent-1"),
+            10,
+            EntryType::Assistant,
+            assistant(vec![tool_use("t2", ToolName::Read)], 500),
+        ),
+        result,
+    ])
+}
+
+#[test]
+fn every_scope_has_a_record() {
+    let scopes = scope_stats("run.jsonl", &log(), &PricingConfig::default(), None);
+
+    let kinds: Vec<_> = scopes.iter().map(|s| s.scope).collect();
+    assert_eq!(kinds, vec!["all", "session", "main", "subagent"]);
+
+    let all = &scopes[0];
+    assert_eq!(all.file, "run.jsonl");
+    assert_eq!(all.entries, 4);
+    assert_eq!(all.duration_secs, Some(90));
+    assert_eq!(all.subagents, Some(1));
+    assert_eq!(all.tokens.input, 1_500);
+    assert_eq!(all.actual_cost_usd, Some(0.5));
+    assert_eq!(all.tool_calls, 3);
+    assert_eq!(all.tool_errors, 1);
+    assert_eq!(all.models.len(), 1);
+    assert_eq!(all.models[0].model, "claude-sonnet-4-5");
+    assert_eq!(all.models[0].tokens.input, 1_500);
+
+    let main = &scopes[2];
+    assert_eq!(main.session_id.as_deref(), Some("s1"));
+    assert_eq!(main.entries, 3);
+    assert_eq!(main.tokens.input, 1_000);
+    assert_eq!(main.actual_cost_usd, None);
+    assert_eq!(
+        main.tools[0],
+        ToolCount {
+            name: "Bash".to_string(),
+            calls: 1,
+            errors: 1,
+        }
+    );
+
+    let subagent = &scopes[3];
+    assert_eq!(subagent.agent_id.as_deref(), Some("agent-1"));
+    assert_eq!(subagent.subagents, None);
+    assert_eq!(subagent.tool_calls, 1);
+}
+
+#[test]
+fn scope_filter_keeps_one_record() {
+    let filter = StatsFilter::parse("subagent:agent-1").unwrap();
+
+    let scopes = scope_stats("-", &log(), &PricingConfig::default(), Some(&filter));
+
+    assert_eq!(scopes.len(), 1);
+    assert_eq!(scopes[0].scope, "subagent");
+    assert!(
+        scope_stats(
+            "-",
+            &log(),
+            &PricingConfig::default(),
+            Some(&StatsFilter::parse("session:other").unwrap())
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn table_aligns_scopes_and_models() {
+    let scopes = scope_stats("run.jsonl", &log(), &PricingConfig::default(), None);
+
+    let table = stats_table(&scopes);
+    let lines: Vec<&str> = table.lines().collect();
+
+    assert!(lines[0].starts_with("SCOPE"), "{}", table);
+    assert!(lines[0].ends_with("SUBAGENTS"));
+    assert!(lines[1].starts_with("All "));
+    assert!(lines[2].starts_with("  claude-sonnet-4-5 "));
+    assert!(lines.iter().any(|l| l.starts_with("  Main agent ")));
+    assert!(lines.iter().any(|l| l.starts_with("  Subagent agent-1 ")));
+    assert!(
+        !table.contains("==>"),
+        "file headers only for several inputs"
+    );
+
+    let mut two_files = scopes.clone();
+    two_files.extend(scope_stats(
+        "b.jsonl",
+        &log(),
+        &PricingConfig::default(),
+        None,
+    ));
+    let table = stats_table(&two_files);
+    assert!(table.starts_with("==> run.jsonl <==\n"));
+    assert!(table.contains("\n\n==> b.jsonl <==\n"));
+}
+
+#[test]
+fn json_and_csv_carry_the_same_records() {
+    let filter = StatsFilter::AllSessionsCombined;
+    let scopes = scope_stats(
+        "a,b.jsonl",
+        &log(),
+        &PricingConfig::default(),
+        Some(&filter),
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&stats_json(&scopes)).unwrap();
+    assert_eq!(json[0]["scope"], "all");
+    assert_eq!(json[0]["tokens"]["input"], 1_500);
+    assert_eq!(json[0]["actual_cost_usd"], 0.5);
+    assert_eq!(json[0]["tools"][0]["name"], "Bash");
+
+    let csv = stats_csv(&scopes);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3, "header, scope and model rows:\n{}", csv);
+    assert!(lines[0].starts_with("file,scope,session_id,agent_id,model,entries,"));
+    assert!(lines[1].starts_with("\"a,b.jsonl\",all,,,,4,90,1,1500,20,0,0,"));
+    assert!(lines[1].ends_with(",0.500000,3,1"));
+    assert!(lines[2].starts_with("\"a,b.jsonl\",all,,,claude-sonnet-4-5,,,,1500,20,0,0,"));
+}
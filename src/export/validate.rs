@@ -0,0 +1,302 @@
+//! Schema lint of `cclv validate`.
+//!
+//! Every line is checked against what the parser understands: lines that
+//! fail to parse (with the `ParseError` reason, or the unknown entry type or
+//! content block kind that made them fail) are errors, as are tool results
+//! without a matching tool use. Schema drift (top-level fields cclv does not
+//! know), tool uses without results, timestamps going backwards within a
+//! session and, in stream-json output (which has result entries), sessions
+//! without a result entry are warnings.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::model::{ContentBlock, EntryType, MessageContent, ParseError, SessionId, ToolUseId};
+use crate::parser::{
+    CONTENT_BLOCK_TYPES, ENTRY_FIELDS, ENTRY_TYPES, IGNORED_ENTRY_FIELDS, parse_entry,
+};
+
+/// How bad a finding is; errors fail validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The log cannot be fully read or is inconsistent.
+    Error,
+    /// The log is readable but looks incomplete or has drifted.
+    Warning,
+}
+
+impl Severity {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        }
+    }
+}
+
+/// One problem found in an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// How bad the problem is.
+    pub severity: Severity,
+    /// JSONL line (1-based) of the problem; `None` for whole-input findings.
+    pub line: Option<usize>,
+    /// What is wrong.
+    pub message: String,
+}
+
+impl Finding {
+    fn error(line: usize, message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            line: Some(line),
+            message,
+        }
+    }
+
+    fn warning(line: Option<usize>, message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            line,
+            message,
+        }
+    }
+}
+
+/// Result of validating one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Input the report is for (`-` for stdin).
+    pub file: String,
+    /// Non-blank lines read.
+    pub lines: usize,
+    /// Lines parsed into log entries.
+    pub entries: usize,
+    /// Problems, line findings in line order followed by whole-input ones.
+    pub findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+    /// Number of findings of `severity`.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings
+            .iter()
+            .filter(|f| f.severity == severity)
+            .count()
+    }
+}
+
+/// Reason a `ParseError` gives, without the line it already reports.
+///
+/// `well_formed` tells syntax errors from JSON that does not fit the entry
+/// schema, which the parser reports alike.
+fn parse_error_reason(error: &ParseError, well_formed: bool) -> String {
+    match error {
+        ParseError::InvalidJson { message, .. } if well_formed => {
+            format!("does not match the entry schema: {}", message)
+        }
+        ParseError::InvalidJson { message, .. } => format!("invalid JSON: {}", message),
+        ParseError::MissingField { field, .. } => format!("missing required field '{}'", field),
+        ParseError::InvalidTimestamp { raw, .. } => format!("invalid timestamp '{}'", raw),
+    }
+}
+
+/// Why the parser will reject an otherwise well-formed JSON object, if the
+/// cause is an entry type or content block kind it does not know.
+fn unknown_kind(object: &serde_json::Map<String, Value>) -> Option<String> {
+    if let Some(entry_type) = object.get("type").and_then(Value::as_str) {
+        if !ENTRY_TYPES.contains(&entry_type) {
+            return Some(format!("unknown entry type '{}'", entry_type));
+        }
+    }
+    let blocks = object
+        .get("message")
+        .and_then(|m| m.get("content"))
+        .and_then(Value::as_array)?;
+    blocks
+        .iter()
+        .filter_map(|block| block.get("type").and_then(Value::as_str))
+        .find(|kind| !CONTENT_BLOCK_TYPES.contains(kind))
+        .map(|kind| format!("unknown content block type '{}'", kind))
+}
+
+/// Check every line of one input.
+pub fn validate_lines(file: &str, lines: &[String]) -> ValidationReport {
+    let mut findings = Vec::new();
+    let mut line_count = 0;
+    let mut entry_count = 0;
+    // Unknown field → (lines it is on, first line)
+    let mut unknown_fields: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    let mut tool_uses: Vec<(usize, ToolUseId, String)> = Vec::new();
+    let mut tool_use_ids: HashSet<ToolUseId> = HashSet::new();
+    let mut tool_results: HashSet<ToolUseId> = HashSet::new();
+    // Session → (line, timestamp) of its latest timestamped entry
+    let mut last_timestamps: HashMap<SessionId, (usize, DateTime<Utc>)> = HashMap::new();
+    // Sessions in order of appearance, with whether they have a result
+    let mut sessions: Vec<(SessionId, bool)> = Vec::new();
+
+    for (index, raw) in lines.iter().enumerate() {
+        if raw.trim().is_empty() {
+            continue;
+        }
+        let line = index + 1;
+        line_count += 1;
+
+        let value = serde_json::from_str::<Value>(raw).ok();
+        if let Some(Value::Object(object)) = &value {
+            for field in object.keys() {
+                if !ENTRY_FIELDS.contains(&field.as_str())
+                    && !IGNORED_ENTRY_FIELDS.contains(&field.as_str())
+                {
+                    unknown_fields
+                        .entry(field.clone())
+                        .and_modify(|(count, _)| *count += 1)
+                        .or_insert((1, line));
+                }
+            }
+            if let Some(reason) = unknown_kind(object) {
+                findings.push(Finding::error(line, reason));
+                continue;
+            }
+        }
+
+        let entry = match parse_entry(raw, line) {
+            Ok(entry) => entry,
+            Err(error) => {
+                findings.push(Finding::error(
+                    line,
+                    parse_error_reason(&error, value.is_some()),
+                ));
+                continue;
+            }
+        };
+        entry_count += 1;
+
+        let session_id = entry.session_id().clone();
+        match sessions.iter_mut().find(|(id, _)| *id == session_id) {
+            Some((_, has_result)) => *has_result |= entry.entry_type() == EntryType::Result,
+            None => sessions.push((session_id.clone(), entry.entry_type() == EntryType::Result)),
+        }
+
+        if let MessageContent::Blocks(blocks) = entry.message().content() {
+            for block in blocks {
+                match block {
+                    ContentBlock::ToolUse(call) => {
+                        tool_uses.push((line, call.id().clone(), call.name().as_str().to_string()));
+                        tool_use_ids.insert(call.id().clone());
+                    }
+                    ContentBlock::ToolResult { tool_use_id, .. } => {
+                        if !tool_use_ids.contains(tool_use_id) {
+                            findings.push(Finding::error(
+                                line,
+                                format!(
+                                    "tool_result for unknown tool_use '{}'",
+                                    tool_use_id.as_str()
+                                ),
+                            ));
+                        }
+                        tool_results.insert(tool_use_id.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if let Some(timestamp) = entry.known_timestamp() {
+            let went_back = last_timestamps
+                .get(&session_id)
+                .filter(|(_, previous)| timestamp < *previous);
+            if let Some((previous_line, previous)) = went_back {
+                findings.push(Finding::warning(
+                    Some(line),
+                    format!(
+                        "timestamp {} is before {} on line {}",
+                        timestamp.to_rfc3339(),
+                        previous.to_rfc3339(),
+                        previous_line
+                    ),
+                ));
+            }
+            last_timestamps.insert(session_id, (line, timestamp));
+        }
+    }
+
+    for (line, id, name) in &tool_uses {
+        if !tool_results.contains(id) {
+            findings.push(Finding::warning(
+                Some(*line),
+                format!("tool_use '{}' ({}) has no tool_result", id.as_str(), name),
+            ));
+        }
+    }
+    findings.sort_by_key(|f| f.line);
+
+    for (field, (count, first_line)) in unknown_fields {
+        findings.push(Finding::warning(
+            None,
+            format!(
+                "unknown top-level field '{}' on {} line{} (first on line {})",
+                field,
+                count,
+                if count == 1 { "" } else { "s" },
+                first_line
+            ),
+        ));
+    }
+    // Plain session logs never have result entries; only stream-json ones do
+    let has_results = sessions.iter().any(|(_, has_result)| *has_result);
+    for (session_id, has_result) in sessions {
+        if has_results && !has_result {
+            findings.push(Finding::warning(
+                None,
+                format!("session '{}' has no result entry", session_id.as_str()),
+            ));
+        }
+    }
+
+    ValidationReport {
+        file: file.to_string(),
+        lines: line_count,
+        entries: entry_count,
+        findings,
+    }
+}
+
+/// Findings as `file:line: severity: message`, then a summary line per input.
+pub fn validation_text(reports: &[ValidationReport]) -> String {
+    let mut out = String::new();
+    for report in reports {
+        for finding in &report.findings {
+            match finding.line {
+                Some(line) => out.push_str(&format!("{}:{}: ", report.file, line)),
+                None => out.push_str(&format!("{}: ", report.file)),
+            }
+            out.push_str(&format!(
+                "{}: {}\n",
+                finding.severity.label(),
+                finding.message
+            ));
+        }
+    }
+    for report in reports {
+        let errors = report.count(Severity::Error);
+        let warnings = report.count(Severity::Warning);
+        out.push_str(&format!(
+            "{}: {} lines, {} entries, {} error{}, {} warning{}\n",
+            report.file,
+            report.lines,
+            report.entries,
+            errors,
+            if errors == 1 { "" } else { "s" },
+            warnings,
+            if warnings == 1 { "" } else { "s" },
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "validate_tests.rs"]
+mod tests;
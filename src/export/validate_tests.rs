@@ -0,0 +1,148 @@
+//! Tests for the schema lint.
+
+use super::*;
+
+fn lines(raw: &[&str]) -> Vec<String> {
+    raw.iter().map(|l| l.to_string()).collect()
+}
+
+fn messages(report: &ValidationReport) -> Vec<(Severity, Option<usize>, &str)> {
+    report
+        .findings
+        .iter()
+        .map(|f| (f.severity, f.line, f.message.as_str()))
+        .collect()
+}
+
+#[test]
+fn clean_session_has_no_findings() {
+    let report = validate_lines(
+        "run.jsonl",
+        &lines(&[
+            r#"{"type":"system","subtype":"init","session_id":"s1","uuid":"i1","apiKeySource":"none"}"#,
+            r#"{"type":"assistant","session_id":"s1","uuid":"a1","timestamp":"2025-01-15T14:00:00Z","message":{"role":"assistant","content":[{"type":"tool_use","id":"t1","name":"Bash","input":{}}]}}"#,
+            "",
+            r#"{"type":"user","session_id":"s1","uuid":"u1","timestamp":"2025-01-15T14:00:05Z","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"t1","content":"ok"}]}}"#,
+            r#"{"type":"result","subtype":"success","session_id":"s1","uuid":"r1","is_error":false,"duration_ms":5000,"num_turns":1,"total_cost_usd":0.01,"result":"done","usage":{}}"#,
+        ]),
+    );
+
+    assert_eq!(messages(&report), vec![]);
+    assert_eq!((report.lines, report.entries), (4, 4));
+}
+
+#[test]
+fn unparseable_lines_are_errors_with_reasons() {
+    let report = validate_lines(
+        "run.jsonl",
+        &lines(&[
+            "{not json",
+            r#"{"type":"stream_event","session_id":"s1","uuid":"e1"}"#,
+            r#"{"type":"assistant","session_id":"s1","uuid":"a1","message":{"role":"assistant","content":[{"type":"image","source":{}}]}}"#,
+            r#"{"type":"user","session_id":"s1","message":{"role":"user","content":"hi"}}"#,
+            r#"{"type":"user","session_id":"s1","uuid":"u2","timestamp":"yesterday","message":{"role":"user","content":"hi"}}"#,
+        ]),
+    );
+
+    let errors: Vec<_> = messages(&report)
+        .into_iter()
+        .filter(|(severity, _, _)| *severity == Severity::Error)
+        .collect();
+    assert_eq!(errors.len(), 5, "{:?}", report.findings);
+    assert!(errors[0].2.starts_with("invalid JSON: "));
+    assert_eq!(
+        errors[1],
+        (
+            Severity::Error,
+            Some(2),
+            "unknown entry type 'stream_event'"
+        )
+    );
+    assert_eq!(errors[2].2, "unknown content block type 'image'");
+    assert!(
+        errors[3]
+            .2
+            .starts_with("does not match the entry schema: missing field `uuid`")
+    );
+    assert_eq!(errors[4].2, "invalid timestamp 'yesterday'");
+    assert_eq!(report.entries, 0);
+}
+
+#[test]
+fn consistency_checks() {
+    let report = validate_lines(
+        "run.jsonl",
+        &lines(&[
+            r#"{"type":"assistant","session_id":"s1","uuid":"a1","timestamp":"2025-01-15T14:00:10Z","newField":1,"message":{"role":"assistant","content":[{"type":"tool_use","id":"t1","name":"Read","input":{}}]}}"#,
+            r#"{"type":"user","session_id":"s1","uuid":"u1","timestamp":"2025-01-15T14:00:05Z","newField":2,"message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"t9","content":"?"}]}}"#,
+            r#"{"type":"user","session_id":"s2","uuid":"u2","timestamp":"2025-01-15T13:00:00Z","message":{"role":"user","content":"other session"}}"#,
+            r#"{"type":"result","subtype":"success","session_id":"s2","uuid":"r2","is_error":false,"duration_ms":5000,"num_turns":1,"total_cost_usd":0.01,"result":"done","usage":{}}"#,
+        ]),
+    );
+
+    assert_eq!(
+        messages(&report),
+        vec![
+            (
+                Severity::Warning,
+                Some(1),
+                "tool_use 't1' (Read) has no tool_result"
+            ),
+            (
+                Severity::Error,
+                Some(2),
+                "tool_result for unknown tool_use 't9'"
+            ),
+            (
+                Severity::Warning,
+                Some(2),
+                "timestamp 2025-01-15T14:00:05+00:00 is before 2025-01-15T14:00:10+00:00 on line 1"
+            ),
+            (
+                Severity::Warning,
+                None,
+                "unknown top-level field 'newField' on 2 lines (first on line 1)"
+            ),
+            (Severity::Warning, None, "session 's1' has no result entry"),
+        ]
+    );
+    assert_eq!(report.count(Severity::Error), 1);
+}
+
+#[test]
+fn text_lists_findings_then_summaries() {
+    let report = validate_lines(
+        "run.jsonl",
+        &lines(&[
+            "oops",
+            r#"{"type":"user","session_id":"s1","uuid":"u1","message":{"role":"user","content":"hi"}}"#,
+            r#"{"type":"result","subtype":"success","session_id":"s2","uuid":"r2","is_error":false,"duration_ms":5000,"num_turns":1,"total_cost_usd":0.01,"result":"done","usage":{}}"#,
+        ]),
+    );
+
+    let text = validation_text(&[report]);
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert!(lines[0].starts_with("run.jsonl:1: error: invalid JSON: "));
+    assert_eq!(
+        lines[1],
+        "run.jsonl: warning: session 's1' has no result entry"
+    );
+    assert_eq!(
+        lines[2],
+        "run.jsonl: 3 lines, 2 entries, 1 error, 1 warning"
+    );
+}
+
+#[test]
+fn session_logs_without_any_result_entries_are_not_flagged() {
+    let report = validate_lines(
+        "session.jsonl",
+        &lines(&[
+            r#"{"type":"user","session_id":"s1","uuid":"u1","timestamp":"2025-01-15T14:00:00Z","message":{"role":"user","content":"hi"}}"#,
+            r#"{"type":"assistant","session_id":"s1","uuid":"a1","timestamp":"2025-01-15T14:00:05Z","message":{"role":"assistant","content":"hello"}}"#,
+        ]),
+    );
+
+    assert_eq!(messages(&report), vec![]);
+}
@@ -6,6 +6,7 @@
 
 use crate::model::{ConversationEntry, LogEntry, ParseError};
 use crate::parser;
+use std::sync::Arc;
 
 /// Convert parsed LogEntry vector into ConversationEntry vector.
 ///
@@ -22,7 +23,7 @@ use crate::parser;
 pub fn process_entries(entries: Vec<LogEntry>) -> Vec<ConversationEntry> {
     entries
         .into_iter()
-        .map(|entry| ConversationEntry::Valid(Box::new(entry)))
+        .map(|entry| ConversationEntry::Valid(Arc::new(entry)))
         .collect()
 }
 
@@ -10,6 +10,7 @@
 #![allow(deprecated)]
 
 pub mod config;
+pub mod export;
 pub mod logging;
 pub mod model;
 pub mod parser;
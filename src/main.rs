@@ -1,8 +1,14 @@
 //! Claude Code Log Viewer - Entry Point
 
 use cclv::config::VALID_THEMES;
-use clap::Parser;
+use cclv::export::slice::{Bound, SliceFilter};
+use cclv::export::{ExportFormat, grep, slice, stats, validate};
+use cclv::model::StatsFilter;
+use cclv::view::print::{PrintOptions, Tab};
+use clap::{Parser, Subcommand};
+use std::io::{IsTerminal, Write};
 use std::path::PathBuf;
+use std::process::ExitCode;
 use tracing::info;
 
 /// Claude Code Log Viewer - TUI for viewing Claude Code JSONL logs
@@ -11,6 +17,10 @@ use tracing::info;
 #[command(version)]
 #[command(about = "TUI application for viewing Claude Code JSONL session logs")]
 pub struct Args {
+    /// Headless command to run instead of the TUI
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Path to JSONL log file (reads from stdin if not provided)
     pub file: Option<PathBuf>,
 
@@ -37,9 +47,129 @@ pub struct Args {
     /// Path to configuration file
     #[arg(long)]
     pub config: Option<PathBuf>,
+
+    /// Columns to wrap to
+    #[arg(long, default_value_t = 80, value_parser = clap::value_parser!(u16).range(20..), help_heading = PIPE_OPTIONS)]
+    pub width: u16,
+
+    /// Expand every entry instead of collapsing long ones
+    #[arg(long, help_heading = PIPE_OPTIONS)]
+    pub expand_all: bool,
+
+    /// Print plain text instead of ANSI colors (implied by --no-color)
+    #[arg(long, help_heading = PIPE_OPTIONS)]
+    pub plain: bool,
+
+    /// Print only this session (1-based, as in the session list)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..), help_heading = PIPE_OPTIONS)]
+    pub session: Option<u32>,
+
+    /// Tab to print: main or a subagent ID
+    #[arg(long, default_value = "main", value_parser = Tab::parse, help_heading = PIPE_OPTIONS)]
+    pub tab: Tab,
+}
+
+/// Help heading of the options for printing when stdout is not a terminal.
+const PIPE_OPTIONS: &str = "Output when stdout is not a terminal";
+
+/// Headless commands; each writes to stdout and exits.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Export the log as a document
+    Export {
+        /// Path to JSONL log file (reads from stdin if not provided)
+        file: Option<PathBuf>,
+
+        /// Document format
+        #[arg(short, long, value_enum, default_value_t = ExportFormat::Markdown)]
+        format: ExportFormat,
+
+        /// Export only this session (1-based, as in the session list)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        session: Option<u32>,
+    },
+
+    /// Print token usage, cost and tool statistics
+    Stats {
+        /// Paths to JSONL log files (reads from stdin if none are given)
+        files: Vec<PathBuf>,
+
+        /// Print JSON instead of a table
+        #[arg(long, conflicts_with = "csv")]
+        json: bool,
+
+        /// Print CSV instead of a table
+        #[arg(long)]
+        csv: bool,
+
+        /// Only this scope: all, session:<id>, main:<session-id> or subagent:<agent-id>
+        #[arg(long, value_parser = StatsFilter::parse)]
+        scope: Option<StatsFilter>,
+    },
+
+    /// Search logs like the in-app search; exits 1 when nothing matches
+    Grep {
+        /// Case-insensitive text to search for
+        pattern: String,
+
+        /// Paths to JSONL log files (reads from stdin if none are given)
+        files: Vec<PathBuf>,
+
+        /// Lines of context to print around each matching line
+        #[arg(short = 'C', long, default_value_t = 0)]
+        context: usize,
+
+        /// Also search tool inputs
+        #[arg(long)]
+        tool_inputs: bool,
+
+        /// Print JSON Lines instead of text
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Check logs against the schema cclv understands; exits 1 on errors
+    Validate {
+        /// Paths to JSONL log files (reads from stdin if none are given)
+        files: Vec<PathBuf>,
+
+        /// Fail on warnings too
+        #[arg(long)]
+        strict: bool,
+    },
+
+    /// Write the log lines that pass the filters, unchanged, as JSONL
+    Slice {
+        /// Path to JSONL log file (reads from stdin if not provided)
+        file: Option<PathBuf>,
+
+        /// Only entries of this session ID
+        #[arg(long)]
+        session: Option<String>,
+
+        /// Only entries of this agent: main or a subagent ID
+        #[arg(long, value_parser = Tab::parse)]
+        agent: Option<Tab>,
+
+        /// Start at this line number or time (inclusive)
+        #[arg(long, value_parser = Bound::parse)]
+        from: Option<Bound>,
+
+        /// End at this line number or time (inclusive)
+        #[arg(long, value_parser = Bound::parse)]
+        to: Option<Bound>,
+
+        /// Only entries matching this search, as in the in-app search
+        #[arg(long = "where", value_name = "QUERY")]
+        query: Option<String>,
+
+        /// Remove thinking blocks
+        #[arg(long)]
+        drop_thinking: bool,
+    },
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> Result<ExitCode, Box<dyn std::error::Error>> {
     let args = Args::parse();
 
     // Set NO_COLOR env var if --no-color flag is passed
@@ -50,7 +180,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Load configuration with full precedence chain:
     // Defaults → Config File → Env Vars → CLI Args
-    let (config, pricing) = {
+    let (config, pricing, saved_searches, context_windows) = {
         // 1. Load config file (or None if missing)
         let config_file = cclv::config::load_config_with_precedence(args.config.clone())?;
 
@@ -61,6 +191,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .map(|ps| ps.into())
             .unwrap_or_default();
 
+        // Extract saved searches (BTreeMap keeps them ordered by name)
+        let saved_searches: Vec<cclv::state::SavedSearch> = config_file
+            .as_ref()
+            .and_then(|cf| cf.searches.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, query)| cclv::state::SavedSearch::new(name, query))
+            .collect();
+
+        // Per-model context windows (unlisted models use max_context_tokens)
+        let context_windows = config_file
+            .as_ref()
+            .and_then(|cf| cf.context_windows.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .fold(
+                cclv::model::ContextWindows::default(),
+                |windows, (key, tokens)| windows.with_model(key, tokens),
+            );
+
         // 2. Merge with defaults
         let merged = cclv::config::merge_config(config_file);
 
@@ -74,7 +224,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         let config = cclv::config::apply_cli_overrides(with_env, theme_override, stats_override);
 
-        (config, pricing)
+        (config, pricing, saved_searches, context_windows)
     };
 
     // Initialize tracing with configured log file path (FR-054/055)
@@ -88,6 +238,28 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "Configuration loaded and resolved"
     );
 
+    if let Some(command) = args.command {
+        return run_command(command, &pricing, config.collapse_threshold);
+    }
+
+    // Piped output gets the conversation as text instead of the TUI
+    if !std::io::stdout().is_terminal() {
+        let entries = cclv::source::detect_input_source(args.file)?.read_all()?;
+        let mut log_view = cclv::export::log_view_from_entries(entries);
+        log_view
+            .set_context_windows(context_windows.with_default_tokens(config.max_context_tokens));
+        let options = PrintOptions {
+            width: args.width,
+            expand_all: args.expand_all,
+            ansi: !args.plain && std::env::var_os("NO_COLOR").is_none(),
+            session: args.session.map(|n| n as usize - 1),
+            tab: args.tab,
+        };
+        let text = cclv::view::print::print_log(&mut log_view, &options)?;
+        std::io::stdout().lock().write_all(text.as_bytes())?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
     // Detect input source (file or stdin)
     let input_source = cclv::source::detect_input_source(args.file.clone())?;
 
@@ -95,14 +267,184 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli_args = cclv::view::CliArgs::new(
         config.theme,
         config.show_stats,
-        config.max_context_tokens,
+        context_windows.with_default_tokens(config.max_context_tokens),
         pricing,
+        saved_searches,
     );
 
     // Run the TUI with the input source
     cclv::view::run_with_source(input_source, cli_args)?;
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Run a headless command.
+fn run_command(
+    command: Command,
+    pricing: &cclv::model::PricingConfig,
+    collapse_threshold: usize,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    match command {
+        Command::Export {
+            file,
+            format,
+            session,
+        } => {
+            let entries = cclv::source::detect_input_source(file)?.read_all()?;
+            let log_view = cclv::export::log_view_from_entries(entries);
+            let document = match (format, session) {
+                (ExportFormat::Markdown, None) => {
+                    cclv::export::markdown::log_markdown(&log_view, pricing)
+                }
+                (ExportFormat::Markdown, Some(number)) => {
+                    let session = find_session(&log_view, number)?;
+                    cclv::export::markdown::session_markdown(session, pricing)
+                }
+                (ExportFormat::Html, None) => {
+                    cclv::export::html::log_html(&log_view, pricing, collapse_threshold)
+                }
+                (ExportFormat::Html, Some(number)) => {
+                    let session = find_session(&log_view, number)?;
+                    cclv::export::html::session_html(session, pricing, collapse_threshold)
+                }
+            };
+            std::io::stdout().lock().write_all(document.as_bytes())?;
+        }
+        Command::Stats {
+            files,
+            json,
+            csv,
+            scope,
+        } => {
+            let inputs = if files.is_empty() {
+                vec![None]
+            } else {
+                files.into_iter().map(Some).collect()
+            };
+            let mut scopes = Vec::new();
+            for file in inputs {
+                let name = file
+                    .as_ref()
+                    .map_or("-".to_string(), |f| f.display().to_string());
+                let entries = cclv::source::detect_input_source(file)?.read_all()?;
+                let log_view = cclv::export::log_view_from_entries(entries);
+                scopes.extend(stats::scope_stats(
+                    &name,
+                    &log_view,
+                    pricing,
+                    scope.as_ref(),
+                ));
+            }
+            if scope.is_some() && scopes.is_empty() {
+                return Err("scope not found in the input".into());
+            }
+            let report = if json {
+                stats::stats_json(&scopes)
+            } else if csv {
+                stats::stats_csv(&scopes)
+            } else {
+                stats::stats_table(&scopes)
+            };
+            std::io::stdout().lock().write_all(report.as_bytes())?;
+        }
+        Command::Grep {
+            pattern,
+            files,
+            context,
+            tool_inputs,
+            json,
+        } => {
+            let query =
+                cclv::state::SearchQuery::new(&pattern).ok_or("the pattern must not be empty")?;
+            let inputs = if files.is_empty() {
+                vec![None]
+            } else {
+                files.into_iter().map(Some).collect()
+            };
+            let mut hits = Vec::new();
+            for file in inputs {
+                let name = file
+                    .as_ref()
+                    .map_or("-".to_string(), |f| f.display().to_string());
+                let lines = cclv::source::read_lines(file)?;
+                hits.extend(grep::grep_lines(&name, &lines, &query, tool_inputs));
+            }
+            let report = if json {
+                grep::grep_json(&hits, context)
+            } else {
+                grep::grep_text(&hits, context)
+            };
+            std::io::stdout().lock().write_all(report.as_bytes())?;
+            if hits.is_empty() {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Validate { files, strict } => {
+            let inputs = if files.is_empty() {
+                vec![None]
+            } else {
+                files.into_iter().map(Some).collect()
+            };
+            let mut reports = Vec::new();
+            for file in inputs {
+                let name = file
+                    .as_ref()
+                    .map_or("-".to_string(), |f| f.display().to_string());
+                let lines = cclv::source::read_lines(file)?;
+                reports.push(validate::validate_lines(&name, &lines));
+            }
+            let report = validate::validation_text(&reports);
+            std::io::stdout().lock().write_all(report.as_bytes())?;
+            let failed = reports.iter().any(|r| {
+                r.count(validate::Severity::Error) > 0
+                    || (strict && r.count(validate::Severity::Warning) > 0)
+            });
+            if failed {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Slice {
+            file,
+            session,
+            agent,
+            from,
+            to,
+            query,
+            drop_thinking,
+        } => {
+            let filter = SliceFilter {
+                session: session
+                    .map(cclv::model::SessionId::new)
+                    .transpose()
+                    .map_err(|_| "the session ID must not be empty")?,
+                agent,
+                from,
+                to,
+                query: query
+                    .map(|q| cclv::state::SearchQuery::new(&q).ok_or("the query must not be empty"))
+                    .transpose()?,
+                drop_thinking,
+            };
+            let lines = cclv::source::read_raw_lines(file)?;
+            let output = slice::slice_lines(&lines, &filter);
+            std::io::stdout().lock().write_all(&output)?;
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Session `number` (1-based) of the log.
+fn find_session(
+    log_view: &cclv::view_state::log::LogViewState,
+    number: u32,
+) -> Result<&cclv::view_state::session::SessionViewState, String> {
+    log_view.get_session(number as usize - 1).ok_or_else(|| {
+        format!(
+            "session {} not found (the log has {})",
+            number,
+            log_view.session_count()
+        )
+    })
 }
 
 #[cfg(test)]
@@ -238,6 +580,199 @@ mod tests {
         assert_eq!(err.kind(), clap::error::ErrorKind::InvalidValue);
     }
 
+    #[test]
+    fn test_export_subcommand() {
+        let args = Args::parse_from(["cclv", "export", "--format", "md", "session.jsonl"]);
+        match args.command {
+            Some(Command::Export {
+                file,
+                format,
+                session,
+            }) => {
+                assert_eq!(file, Some(PathBuf::from("session.jsonl")));
+                assert_eq!(format, ExportFormat::Markdown);
+                assert_eq!(session, None);
+            }
+            other => panic!("Expected export command, got {:?}", other),
+        }
+        assert_eq!(args.file, None);
+    }
+
+    #[test]
+    fn test_export_defaults_to_markdown_from_stdin() {
+        let args = Args::parse_from(["cclv", "export", "--session", "2"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::Export {
+                file: None,
+                format: ExportFormat::Markdown,
+                session: Some(2),
+            })
+        ));
+    }
+
+    #[test]
+    fn test_export_html_format() {
+        let args = Args::parse_from(["cclv", "export", "-f", "html", "session.jsonl"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::Export {
+                format: ExportFormat::Html,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_stats_subcommand() {
+        let args = Args::parse_from([
+            "cclv", "stats", "a.jsonl", "b.jsonl", "--json", "--scope", "main:abc",
+        ]);
+        match args.command {
+            Some(Command::Stats {
+                files,
+                json,
+                csv,
+                scope,
+            }) => {
+                assert_eq!(
+                    files,
+                    vec![PathBuf::from("a.jsonl"), PathBuf::from("b.jsonl")]
+                );
+                assert!(json);
+                assert!(!csv);
+                assert_eq!(scope, StatsFilter::parse("main:abc").ok());
+            }
+            other => panic!("Expected stats command, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_pipe_output_options() {
+        let args = Args::parse_from([
+            "cclv",
+            "session.jsonl",
+            "--width",
+            "120",
+            "--expand-all",
+            "--plain",
+            "--session",
+            "2",
+            "--tab",
+            "agent-1",
+        ]);
+        assert_eq!(args.width, 120);
+        assert!(args.expand_all);
+        assert!(args.plain);
+        assert_eq!(args.session, Some(2));
+        assert_eq!(args.tab, Tab::parse("agent-1").unwrap());
+
+        let args = Args::parse_from(["cclv", "session.jsonl"]);
+        assert_eq!((args.width, args.tab), (80, Tab::Main));
+        assert!(Args::try_parse_from(["cclv", "--width", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_grep_subcommand() {
+        let args = Args::parse_from([
+            "cclv",
+            "grep",
+            "-C",
+            "2",
+            "--tool-inputs",
+            "TODO",
+            "a.jsonl",
+            "b.jsonl",
+        ]);
+        match args.command {
+            Some(Command::Grep {
+                pattern,
+                files,
+                context,
+                tool_inputs,
+                json,
+            }) => {
+                assert_eq!(pattern, "TODO");
+                assert_eq!(
+                    files,
+                    vec![PathBuf::from("a.jsonl"), PathBuf::from("b.jsonl")]
+                );
+                assert_eq!(context, 2);
+                assert!(tool_inputs);
+                assert!(!json);
+            }
+            other => panic!("Expected grep command, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_subcommand() {
+        let args = Args::parse_from(["cclv", "validate", "--strict", "a.jsonl"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::Validate { files, strict: true }) if files == vec![PathBuf::from("a.jsonl")]
+        ));
+    }
+
+    #[test]
+    fn test_slice_subcommand() {
+        let args = Args::parse_from([
+            "cclv",
+            "slice",
+            "big.jsonl",
+            "--agent",
+            "main",
+            "--from",
+            "120",
+            "--to",
+            "2025-01-15 14:30",
+            "--where",
+            "panic",
+            "--drop-thinking",
+        ]);
+        match args.command {
+            Some(Command::Slice {
+                file,
+                session,
+                agent,
+                from,
+                to,
+                query,
+                drop_thinking,
+            }) => {
+                assert_eq!(file, Some(PathBuf::from("big.jsonl")));
+                assert_eq!(session, None);
+                assert_eq!(agent, Some(Tab::Main));
+                assert_eq!(from, Some(Bound::Line(120)));
+                assert_eq!(to, Bound::parse("2025-01-15 14:30").ok());
+                assert_eq!(query.as_deref(), Some("panic"));
+                assert!(drop_thinking);
+            }
+            other => panic!("Expected slice command, got {:?}", other),
+        }
+        assert!(Args::try_parse_from(["cclv", "slice", "--from", "soon"]).is_err());
+    }
+
+    #[test]
+    fn test_stats_rejects_json_with_csv_and_bad_scope() {
+        let result = Args::try_parse_from(["cclv", "stats", "--json", "--csv"]);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            clap::error::ErrorKind::ArgumentConflict
+        );
+        let result = Args::try_parse_from(["cclv", "stats", "--scope", "everything"]);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            clap::error::ErrorKind::ValueValidation
+        );
+    }
+
+    #[test]
+    fn test_file_without_command_opens_tui() {
+        let args = Args::parse_from(["cclv", "log.jsonl"]);
+        assert!(args.command.is_none());
+    }
+
     #[test]
     fn test_config_path() {
         let args = Args::parse_from(["cclv", "--config", "/custom/config.toml"]);
@@ -280,6 +815,8 @@ mod tests {
             keybindings: None,
             max_context_tokens: None,
             pricing: None,
+            searches: None,
+            context_windows: None,
         };
 
         // Step 1: Merge with defaults
@@ -0,0 +1,205 @@
+//! Context window sizes per model.
+//!
+//! The token divider's "Context: x%" figure divides an entry's context
+//! tokens by its model's window. Windows are looked up the way
+//! `PricingConfig::get` looks up prices, with long-context model ids
+//! (e.g. `claude-sonnet-4-5[1m]`) detected before family matching. Only the
+//! session's init entry carries the `[1m]` marker, so a session started on a
+//! long-context model passes it on with `with_session_model`. Built-in
+//! family windows apply unless configured otherwise.
+
+use std::collections::HashMap;
+
+/// Context window used for models without a configured or detected size.
+pub const DEFAULT_CONTEXT_WINDOW_TOKENS: u64 = 200_000;
+
+/// Context window of long-context (1M) model variants.
+pub const LONG_CONTEXT_WINDOW_TOKENS: u64 = 1_000_000;
+
+/// Whether `model_id` names a long-context variant.
+///
+/// Claude Code marks these with a `[1m]` suffix (`sonnet[1m]`,
+/// `claude-sonnet-4-5-20250929[1m]`); a `-1m` suffix is accepted too.
+pub fn is_long_context_model(model_id: &str) -> bool {
+    let id = model_id.trim().to_lowercase();
+    id.ends_with("[1m]") || id.ends_with("-1m")
+}
+
+/// Model id without its long-context marker.
+fn without_long_context_marker(model_id: &str) -> &str {
+    model_id
+        .strip_suffix("[1m]")
+        .or_else(|| model_id.strip_suffix("-1m"))
+        .unwrap_or(model_id)
+}
+
+/// Context window sizes keyed by model id or id fragment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextWindows {
+    models: HashMap<String, u64>,
+    default_tokens: u64,
+    /// Long-context model the session started on, without its marker.
+    long_context_session_model: Option<String>,
+}
+
+impl Default for ContextWindows {
+    fn default() -> Self {
+        let mut models = HashMap::new();
+
+        // Claude 4.x and 3.x families: 200k (1M variants are marked `[1m]`)
+        for family in ["opus", "sonnet", "haiku", "claude-3"] {
+            models.insert(family.to_string(), 200_000);
+        }
+        // Claude 2.0 and Instant: 100k
+        for family in ["claude-2.0", "claude-instant"] {
+            models.insert(family.to_string(), 100_000);
+        }
+
+        Self {
+            models,
+            default_tokens: DEFAULT_CONTEXT_WINDOW_TOKENS,
+            long_context_session_model: None,
+        }
+    }
+}
+
+impl ContextWindows {
+    /// Set the window used for unknown models (`max_context_tokens` in config).
+    pub fn with_default_tokens(mut self, tokens: u64) -> Self {
+        self.default_tokens = tokens;
+        self
+    }
+
+    /// Set the window for a model id, or for every id containing `key`
+    /// (e.g. `"sonnet"`).
+    pub fn with_model(mut self, key: impl Into<String>, tokens: u64) -> Self {
+        self.models.insert(key.into().to_lowercase(), tokens);
+        self
+    }
+
+    /// Windows for a session started on `init_model` (the system:init
+    /// entry's model): if it is a long-context variant, responses from the
+    /// same model count as long-context too, though their ids lack the marker.
+    pub fn with_session_model(mut self, init_model: &str) -> Self {
+        let normalized = init_model.trim().to_lowercase();
+        self.long_context_session_model = is_long_context_model(&normalized)
+            .then(|| without_long_context_marker(&normalized).to_string());
+        self
+    }
+
+    /// Window used for unknown models.
+    pub fn default_tokens(&self) -> u64 {
+        self.default_tokens
+    }
+
+    /// Get the context window for a model id.
+    ///
+    /// Tries:
+    /// 1. Exact match on model_id
+    /// 2. Long-context detection (`is_long_context_model`), or the session's
+    ///    long-context model (`with_session_model`) contained in model_id
+    /// 3. Longest configured or built-in key contained in model_id
+    /// 4. Default window (also used when the model is unknown)
+    pub fn get(&self, model_id: Option<&str>) -> u64 {
+        let Some(model_id) = model_id else {
+            return self.default_tokens;
+        };
+        let normalized = model_id.to_lowercase();
+
+        if let Some(&tokens) = self.models.get(&normalized) {
+            return tokens;
+        }
+        let session_long_context = self
+            .long_context_session_model
+            .as_ref()
+            .is_some_and(|model| normalized.contains(model.as_str()));
+        if is_long_context_model(&normalized) || session_long_context {
+            return LONG_CONTEXT_WINDOW_TOKENS;
+        }
+        self.models
+            .iter()
+            .filter(|(key, _)| normalized.contains(key.as_str()))
+            .max_by_key(|(key, _)| key.len())
+            .map_or(self.default_tokens, |(_, &tokens)| tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_and_missing_models_use_default() {
+        let windows = ContextWindows::default().with_default_tokens(300_000);
+
+        assert_eq!(windows.get(None), 300_000);
+        assert_eq!(windows.get(Some("claude-next-20270101")), 300_000);
+    }
+
+    #[test]
+    fn families_have_built_in_windows() {
+        let windows = ContextWindows::default().with_default_tokens(300_000);
+
+        assert_eq!(windows.get(Some("claude-opus-4-5-20251101")), 200_000);
+        assert_eq!(windows.get(Some("claude-3-5-haiku-20241022")), 200_000);
+        assert_eq!(windows.get(Some("claude-instant-1.2")), 100_000);
+        assert_eq!(
+            ContextWindows::default()
+                .with_model("opus", 500_000)
+                .get(Some("claude-opus-4-5")),
+            500_000,
+            "Configured windows replace built-in ones"
+        );
+    }
+
+    #[test]
+    fn long_context_session_model_covers_its_responses() {
+        let windows =
+            ContextWindows::default().with_session_model("claude-sonnet-4-5-20250929[1m]");
+
+        assert_eq!(
+            windows.get(Some("claude-sonnet-4-5-20250929")),
+            LONG_CONTEXT_WINDOW_TOKENS
+        );
+        assert_eq!(windows.get(Some("claude-haiku-4-5-20251001")), 200_000);
+
+        let alias = ContextWindows::default().with_session_model("sonnet[1m]");
+        assert_eq!(
+            alias.get(Some("claude-sonnet-4-5-20250929")),
+            LONG_CONTEXT_WINDOW_TOKENS
+        );
+        let standard = ContextWindows::default().with_session_model("claude-sonnet-4-5");
+        assert_eq!(standard.get(Some("claude-sonnet-4-5")), 200_000);
+    }
+
+    #[test]
+    fn long_context_ids_are_detected() {
+        let windows = ContextWindows::default();
+
+        assert!(is_long_context_model("claude-sonnet-4-5-20250929[1m]"));
+        assert!(is_long_context_model("Sonnet[1M]"));
+        assert!(is_long_context_model("claude-sonnet-4-1m"));
+        assert!(!is_long_context_model("claude-sonnet-4-5-20250929"));
+        assert_eq!(
+            windows.get(Some("claude-sonnet-4-5-20250929[1m]")),
+            LONG_CONTEXT_WINDOW_TOKENS
+        );
+    }
+
+    #[test]
+    fn configured_keys_match_exactly_then_by_longest_fragment() {
+        let windows = ContextWindows::default()
+            .with_model("sonnet", 1_000_000)
+            .with_model("claude-sonnet-4-0", 400_000)
+            .with_model("claude-haiku-4-5[1m]", 150_000);
+
+        assert_eq!(windows.get(Some("claude-sonnet-4-5")), 1_000_000);
+        assert_eq!(windows.get(Some("claude-sonnet-4-0-20250514")), 400_000);
+        assert_eq!(
+            windows.get(Some("claude-haiku-4-5[1m]")),
+            150_000,
+            "Exact match beats long-context detection"
+        );
+        assert_eq!(windows.get(Some("claude-opus-4-5")), 200_000);
+    }
+}
@@ -7,6 +7,7 @@
 use crate::model::{EntryUuid, LogEntry, MalformedEntry, SessionId};
 use crate::parser::ParseResult;
 use chrono::{DateTime, Utc};
+use std::sync::Arc;
 
 /// A single entry in an agent conversation.
 ///
@@ -15,7 +16,7 @@ use chrono::{DateTime, Utc};
 #[derive(Debug, Clone)]
 pub enum ConversationEntry {
     /// Successfully parsed log entry
-    Valid(Box<LogEntry>),
+    Valid(Arc<LogEntry>),
     /// Malformed entry that failed parsing
     Malformed(MalformedEntry),
 }
@@ -34,11 +35,11 @@ impl ConversationEntry {
 
     /// Get the timestamp if available.
     ///
-    /// Returns Some for Valid entries (always has timestamp),
-    /// None for Malformed entries (no timestamp available).
+    /// Returns Some for Valid entries whose log line had a timestamp,
+    /// None for Malformed entries and missing timestamps.
     pub fn timestamp(&self) -> Option<DateTime<Utc>> {
         match self {
-            ConversationEntry::Valid(entry) => Some(entry.timestamp()),
+            ConversationEntry::Valid(entry) => entry.known_timestamp(),
             ConversationEntry::Malformed(_) => None,
         }
     }
@@ -165,7 +166,7 @@ mod tests {
             make_message(),
             EntryMetadata::default(),
         );
-        let conv_entry = ConversationEntry::Valid(Box::new(entry));
+        let conv_entry = ConversationEntry::Valid(Arc::new(entry));
 
         let session_id = conv_entry.session_id();
 
@@ -207,7 +208,7 @@ mod tests {
     fn conversation_entry_timestamp_returns_some_for_valid() {
         let entry = make_valid_log_entry();
         let expected_timestamp = entry.timestamp();
-        let conv_entry = ConversationEntry::Valid(Box::new(entry));
+        let conv_entry = ConversationEntry::Valid(Arc::new(entry));
 
         let timestamp = conv_entry.timestamp();
 
@@ -233,7 +234,7 @@ mod tests {
     #[test]
     fn conversation_entry_is_valid_returns_true_for_valid() {
         let entry = make_valid_log_entry();
-        let conv_entry = ConversationEntry::Valid(Box::new(entry));
+        let conv_entry = ConversationEntry::Valid(Arc::new(entry));
 
         assert!(conv_entry.is_valid());
         assert!(!conv_entry.is_malformed());
@@ -254,7 +255,7 @@ mod tests {
     fn conversation_entry_as_valid_returns_some_for_valid() {
         let entry = make_valid_log_entry();
         let uuid = entry.uuid().clone();
-        let conv_entry = ConversationEntry::Valid(Box::new(entry));
+        let conv_entry = ConversationEntry::Valid(Arc::new(entry));
 
         let valid = conv_entry.as_valid();
 
@@ -286,7 +287,7 @@ mod tests {
     #[test]
     fn conversation_entry_as_malformed_returns_none_for_valid() {
         let entry = make_valid_log_entry();
-        let conv_entry = ConversationEntry::Valid(Box::new(entry));
+        let conv_entry = ConversationEntry::Valid(Arc::new(entry));
 
         let malformed = conv_entry.as_malformed();
 
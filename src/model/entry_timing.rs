@@ -0,0 +1,117 @@
+//! Wall-clock timing of conversation entries.
+//!
+//! For each entry: the time since the conversation's first timed entry, the
+//! gap since the previous timed entry and, for user prompts, the latency of
+//! the turn they start (prompt to the turn's last assistant entry). Entries
+//! without a timestamp (see `LogEntry::known_timestamp`) get no timing and
+//! are skipped when measuring gaps.
+
+use crate::model::{ContentBlock, ConversationEntry, EntryType, LogEntry, MessageContent};
+use chrono::{DateTime, Duration, Utc};
+
+/// Gaps at least this long are highlighted as unusual.
+pub const LONG_GAP_SECS: i64 = 60;
+
+/// Timing of one conversation entry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EntryTiming {
+    /// When the entry was logged.
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Time since the conversation's first timed entry.
+    pub elapsed: Option<Duration>,
+    /// Time since the previous timed entry.
+    pub gap: Option<Duration>,
+    /// For user prompts: time until the turn's last assistant entry.
+    pub turn_latency: Option<Duration>,
+}
+
+impl EntryTiming {
+    /// Whether the gap before this entry is unusually long.
+    pub fn is_long_gap(&self) -> bool {
+        self.gap
+            .is_some_and(|gap| gap >= Duration::seconds(LONG_GAP_SECS))
+    }
+
+    /// Compute timings for a conversation's entries, in order.
+    pub fn for_entries<'a>(
+        entries: impl IntoIterator<Item = &'a ConversationEntry>,
+    ) -> Vec<EntryTiming> {
+        let mut timings = EntryTimings::default();
+        for entry in entries {
+            timings.push(entry);
+        }
+        timings.timings
+    }
+}
+
+/// Timings of a conversation's entries, extended as entries are appended.
+///
+/// An open turn's latency grows with each assistant entry, so a prompt's
+/// timing can change until the next prompt arrives.
+#[derive(Debug, Clone, Default)]
+pub struct EntryTimings {
+    timings: Vec<EntryTiming>,
+    start: Option<DateTime<Utc>>,
+    previous: Option<DateTime<Utc>>,
+    /// Open turn: prompt index and time
+    turn: Option<(usize, DateTime<Utc>)>,
+}
+
+impl EntryTimings {
+    /// Add the timing of the conversation's next entry.
+    pub fn push(&mut self, entry: &ConversationEntry) {
+        let timestamp = entry.timestamp();
+        let log_entry = entry.as_valid();
+
+        if log_entry.is_some_and(is_user_prompt) {
+            self.turn = timestamp.map(|timestamp| (self.timings.len(), timestamp));
+        } else if let (Some(timestamp), Some(log_entry), Some((index, prompt))) =
+            (timestamp, log_entry, self.turn)
+        {
+            if log_entry.entry_type() == EntryType::Assistant {
+                self.timings[index].turn_latency = Some(timestamp - prompt);
+            }
+        }
+
+        self.start = self.start.or(timestamp);
+        self.timings.push(EntryTiming {
+            timestamp,
+            elapsed: timestamp.zip(self.start).map(|(t, s)| t - s),
+            gap: timestamp.zip(self.previous).map(|(t, p)| t - p),
+            turn_latency: None,
+        });
+        self.previous = timestamp.or(self.previous);
+    }
+
+    /// Timing of entry `index`.
+    pub fn get(&self, index: usize) -> Option<&EntryTiming> {
+        self.timings.get(index)
+    }
+
+    /// Timings of all entries, in order.
+    pub fn as_slice(&self) -> &[EntryTiming] {
+        &self.timings
+    }
+}
+
+/// Whether `entry` is a prompt typed by the user (not a tool result).
+pub fn is_user_prompt(entry: &LogEntry) -> bool {
+    if entry.entry_type() != EntryType::User {
+        return false;
+    }
+    match entry.message().content() {
+        MessageContent::Text(text) => !text.trim().is_empty(),
+        MessageContent::Blocks(blocks) => {
+            blocks
+                .iter()
+                .any(|block| matches!(block, ContentBlock::Text { .. }))
+                && !blocks
+                    .iter()
+                    .any(|block| matches!(block, ContentBlock::ToolResult { .. }))
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "entry_timing_tests.rs"]
+mod tests;
@@ -0,0 +1,127 @@
+//! Tests for entry timing.
+
+use super::*;
+use crate::model::{EntryMetadata, EntryUuid, Message, Role, SessionId, ToolUseId};
+use chrono::TimeZone;
+use std::sync::Arc;
+
+fn entry(
+    uuid: &str,
+    entry_type: EntryType,
+    secs: Option<u32>,
+    message: Message,
+) -> ConversationEntry {
+    ConversationEntry::Valid(Arc::new(LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        None,
+        secs.map_or(DateTime::UNIX_EPOCH, |s| {
+            Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap() + Duration::seconds(s.into())
+        }),
+        entry_type,
+        message,
+        EntryMetadata::default(),
+    )))
+}
+
+fn prompt(uuid: &str, secs: Option<u32>) -> ConversationEntry {
+    let message = Message::new(Role::User, MessageContent::Text("do it".to_string()));
+    entry(uuid, EntryType::User, secs, message)
+}
+
+fn reply(uuid: &str, secs: Option<u32>) -> ConversationEntry {
+    let message = Message::new(Role::Assistant, MessageContent::Text("done".to_string()));
+    entry(uuid, EntryType::Assistant, secs, message)
+}
+
+fn tool_result(uuid: &str, secs: Option<u32>) -> ConversationEntry {
+    let message = Message::new(
+        Role::User,
+        MessageContent::Blocks(vec![ContentBlock::ToolResult {
+            tool_use_id: ToolUseId::new("toolu_1").unwrap(),
+            content: "ok".to_string(),
+            is_error: false,
+        }]),
+    );
+    entry(uuid, EntryType::User, secs, message)
+}
+
+#[test]
+fn gaps_and_elapsed_skip_untimed_entries() {
+    let entries = [
+        prompt("p1", Some(0)),
+        reply("a1", None),
+        reply("a2", Some(12)),
+    ];
+
+    let timings = EntryTiming::for_entries(&entries);
+
+    assert_eq!(timings[0].gap, None);
+    assert_eq!(
+        timings[1],
+        EntryTiming::default(),
+        "Missing timestamp is unknown"
+    );
+    assert_eq!(timings[2].gap, Some(Duration::seconds(12)));
+    assert_eq!(timings[2].elapsed, Some(Duration::seconds(12)));
+}
+
+#[test]
+fn turn_latency_runs_from_prompt_to_last_assistant_entry() {
+    let entries = [
+        prompt("p1", Some(0)),
+        reply("a1", Some(3)),
+        tool_result("t1", Some(5)),
+        reply("a2", Some(9)),
+        prompt("p2", Some(100)),
+        reply("a3", Some(102)),
+        prompt("p3", Some(110)),
+    ];
+
+    let latencies: Vec<_> = EntryTiming::for_entries(&entries)
+        .iter()
+        .map(|t| t.turn_latency)
+        .collect();
+
+    assert_eq!(latencies[0], Some(Duration::seconds(9)));
+    assert_eq!(latencies[2], None, "Tool results do not start turns");
+    assert_eq!(latencies[4], Some(Duration::seconds(2)));
+    assert_eq!(latencies[6], None, "Unanswered prompt has no latency");
+}
+
+#[test]
+fn long_gaps_are_flagged() {
+    let entries = [
+        reply("a1", Some(0)),
+        reply("a2", Some(59)),
+        reply("a3", Some(200)),
+    ];
+
+    let flags: Vec<bool> = EntryTiming::for_entries(&entries)
+        .iter()
+        .map(EntryTiming::is_long_gap)
+        .collect();
+
+    assert_eq!(flags, vec![false, false, true]);
+}
+
+#[test]
+fn appended_entries_extend_the_open_turn() {
+    let mut timings = EntryTimings::default();
+    timings.push(&prompt("p1", Some(0)));
+    timings.push(&reply("a1", Some(3)));
+    assert_eq!(
+        timings.as_slice()[0].turn_latency,
+        Some(Duration::seconds(3))
+    );
+
+    timings.push(&tool_result("t1", Some(5)));
+    timings.push(&reply("a2", Some(9)));
+
+    assert_eq!(
+        timings.get(0).unwrap().turn_latency,
+        Some(Duration::seconds(9))
+    );
+    assert_eq!(timings.get(3).unwrap().gap, Some(Duration::seconds(4)));
+}
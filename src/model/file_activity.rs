@@ -0,0 +1,288 @@
+//! Files touched by tool calls.
+//!
+//! Read, Write, Edit, MultiEdit, NotebookEdit, Glob and Grep calls are
+//! reduced to a path and an access kind. Aggregation per filter scope lives
+//! in `SessionStats`.
+
+use crate::model::{AgentId, EntryUuid, SessionId, ToolCall, ToolName};
+use chrono::{DateTime, Utc};
+
+// ===== FileAccess =====
+
+/// How a tool call touched a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileAccess {
+    /// Read, Glob or Grep.
+    Read,
+    /// Edit, MultiEdit or NotebookEdit.
+    Edit,
+    /// Write (full content replacement).
+    Write,
+}
+
+impl FileAccess {
+    /// Short label for display.
+    pub fn label(&self) -> &'static str {
+        match self {
+            FileAccess::Read => "read",
+            FileAccess::Edit => "edit",
+            FileAccess::Write => "write",
+        }
+    }
+}
+
+/// Path and access kind of a file tool call, or `None` for other tools.
+///
+/// - Read/Write/Edit/MultiEdit: `file_path`
+/// - NotebookEdit: `notebook_path`
+/// - Glob: `pattern`, prefixed with `path` when given
+/// - Grep: `path` (calls searching the working directory are skipped, the
+///   `pattern` is a regex rather than a path)
+pub fn file_access(tool: &ToolCall) -> Option<(String, FileAccess)> {
+    let input = tool.input();
+    let field = |name: &str| input.get(name).and_then(|v| v.as_str());
+
+    let (path, access) = match tool.name() {
+        ToolName::Read => (field("file_path")?.to_string(), FileAccess::Read),
+        ToolName::Write => (field("file_path")?.to_string(), FileAccess::Write),
+        ToolName::Edit | ToolName::MultiEdit => (field("file_path")?.to_string(), FileAccess::Edit),
+        ToolName::Other(name) if name == "NotebookEdit" => {
+            (field("notebook_path")?.to_string(), FileAccess::Edit)
+        }
+        ToolName::Glob => {
+            let pattern = field("pattern")?;
+            let path = match field("path") {
+                Some(dir) => format!("{}/{}", dir.trim_end_matches('/'), pattern),
+                None => pattern.to_string(),
+            };
+            (path, FileAccess::Read)
+        }
+        ToolName::Grep => (field("path")?.to_string(), FileAccess::Read),
+        _ => return None,
+    };
+
+    (!path.is_empty()).then_some((path, access))
+}
+
+// ===== FileTouch =====
+
+/// A single tool call that touched a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTouch {
+    /// How the file was touched.
+    pub access: FileAccess,
+
+    /// Tool that was invoked.
+    pub tool: ToolName,
+
+    /// Session of the calling entry.
+    pub session_id: SessionId,
+
+    /// Subagent of the calling entry (`None` for the main agent).
+    pub agent_id: Option<AgentId>,
+
+    /// Entry containing the tool call (jump target).
+    pub entry_uuid: EntryUuid,
+
+    /// Timestamp of the calling entry.
+    pub timestamp: DateTime<Utc>,
+}
+
+// ===== FileActivity =====
+
+/// All recorded touches of one path, in recording order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileActivity {
+    /// Path as written in the tool input.
+    pub path: String,
+
+    /// Tool calls that touched the path.
+    pub touches: Vec<FileTouch>,
+}
+
+impl FileActivity {
+    /// Create an activity record with no touches.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            touches: Vec::new(),
+        }
+    }
+
+    /// Number of touches with the given access kind.
+    pub fn count(&self, access: FileAccess) -> usize {
+        self.touches.iter().filter(|t| t.access == access).count()
+    }
+
+    /// Number of reads.
+    pub fn reads(&self) -> usize {
+        self.count(FileAccess::Read)
+    }
+
+    /// Number of edits.
+    pub fn edits(&self) -> usize {
+        self.count(FileAccess::Edit)
+    }
+
+    /// Number of writes.
+    pub fn writes(&self) -> usize {
+        self.count(FileAccess::Write)
+    }
+
+    /// Agents that touched the path (`None` = main agent), main agent first,
+    /// then subagents in order of first touch.
+    pub fn agents(&self) -> Vec<Option<&AgentId>> {
+        let mut agents: Vec<Option<&AgentId>> = Vec::new();
+        for touch in &self.touches {
+            let agent = touch.agent_id.as_ref();
+            if !agents.contains(&agent) {
+                agents.push(agent);
+            }
+        }
+        agents.sort_by_key(|agent| agent.is_some());
+        agents
+    }
+
+    /// Timestamp of the earliest touch.
+    pub fn first_seen(&self) -> Option<DateTime<Utc>> {
+        self.touches.iter().map(|t| t.timestamp).min()
+    }
+
+    /// Timestamp of the latest touch.
+    pub fn last_seen(&self) -> Option<DateTime<Utc>> {
+        self.touches.iter().map(|t| t.timestamp).max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ToolUseId;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn call(name: &str, input: serde_json::Value) -> ToolCall {
+        ToolCall::new(ToolUseId::new("t1").unwrap(), ToolName::parse(name), input)
+    }
+
+    fn touch(access: FileAccess, agent: Option<&str>, secs: u32) -> FileTouch {
+        FileTouch {
+            access,
+            tool: ToolName::Read,
+            session_id: SessionId::new("s1").unwrap(),
+            agent_id: agent.map(|a| AgentId::new(a).unwrap()),
+            entry_uuid: EntryUuid::new("e1").unwrap(),
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, secs).unwrap(),
+        }
+    }
+
+    #[test]
+    fn file_access_reads_path_fields_per_tool() {
+        let cases = [
+            (
+                "Read",
+                json!({"file_path": "/a.rs"}),
+                "/a.rs",
+                FileAccess::Read,
+            ),
+            (
+                "Write",
+                json!({"file_path": "/b.rs"}),
+                "/b.rs",
+                FileAccess::Write,
+            ),
+            (
+                "Edit",
+                json!({"file_path": "/c.rs"}),
+                "/c.rs",
+                FileAccess::Edit,
+            ),
+            (
+                "MultiEdit",
+                json!({"file_path": "/d.rs"}),
+                "/d.rs",
+                FileAccess::Edit,
+            ),
+            (
+                "NotebookEdit",
+                json!({"notebook_path": "/e.ipynb"}),
+                "/e.ipynb",
+                FileAccess::Edit,
+            ),
+            (
+                "Grep",
+                json!({"pattern": "fn", "path": "/src"}),
+                "/src",
+                FileAccess::Read,
+            ),
+        ];
+
+        for (tool, input, path, access) in cases {
+            assert_eq!(
+                file_access(&call(tool, input)),
+                Some((path.to_string(), access)),
+                "{}",
+                tool
+            );
+        }
+    }
+
+    #[test]
+    fn file_access_joins_glob_pattern_with_path() {
+        assert_eq!(
+            file_access(&call(
+                "Glob",
+                json!({"pattern": "**/*.rs", "path": "/src/"})
+            )),
+            Some(("/src/**/*.rs".to_string(), FileAccess::Read))
+        );
+        assert_eq!(
+            file_access(&call("Glob", json!({"pattern": "*.toml"}))),
+            Some(("*.toml".to_string(), FileAccess::Read))
+        );
+    }
+
+    #[test]
+    fn file_access_ignores_other_tools_and_missing_paths() {
+        assert_eq!(file_access(&call("Bash", json!({"command": "ls"}))), None);
+        assert_eq!(file_access(&call("Grep", json!({"pattern": "fn"}))), None);
+        assert_eq!(file_access(&call("Read", json!({}))), None);
+    }
+
+    #[test]
+    fn activity_counts_accesses_and_spans_time() {
+        let mut activity = FileActivity::new("/a.rs");
+        activity.touches = vec![
+            touch(FileAccess::Read, None, 10),
+            touch(FileAccess::Edit, Some("agent-1"), 5),
+            touch(FileAccess::Edit, None, 30),
+        ];
+
+        assert_eq!(activity.reads(), 1);
+        assert_eq!(activity.edits(), 2);
+        assert_eq!(activity.writes(), 0);
+        assert_eq!(
+            activity.first_seen().unwrap().format("%S").to_string(),
+            "05"
+        );
+        assert_eq!(activity.last_seen().unwrap().format("%S").to_string(), "30");
+    }
+
+    #[test]
+    fn activity_lists_main_agent_first() {
+        let mut activity = FileActivity::new("/a.rs");
+        activity.touches = vec![
+            touch(FileAccess::Read, Some("agent-1"), 0),
+            touch(FileAccess::Read, None, 1),
+            touch(FileAccess::Read, Some("agent-1"), 2),
+        ];
+
+        let agents: Vec<Option<&str>> = activity
+            .agents()
+            .into_iter()
+            .map(|a| a.map(|id| id.as_str()))
+            .collect();
+
+        assert_eq!(agents, vec![None, Some("agent-1")]);
+    }
+}
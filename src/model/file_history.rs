@@ -0,0 +1,476 @@
+//! Per-file content history reconstructed from tool calls.
+//!
+//! A Write sets the content, Edit/MultiEdit apply `old_string` → `new_string`
+//! replacements, and full Read results (no `offset`/`limit`) reveal the
+//! content on disk. Read stops at `READ_LINE_LIMIT` lines and truncates lines
+//! at `READ_LINE_CHARS` characters by default, so results that reach either
+//! cap are treated like partial reads. Edits on content that was never read
+//! or written cannot be applied and are flagged with
+//! `ChangeStatus::BaseUnknown` instead of guessed.
+
+use crate::model::{
+    AgentId, ContentBlock, EntryUuid, LogEntry, MessageContent, ToolName, ToolUseId,
+};
+use chrono::{DateTime, Utc};
+use similar::TextDiff;
+use std::collections::HashMap;
+
+/// Lines of context around each hunk in rendered diffs.
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// Lines a Read without `limit` returns at most.
+const READ_LINE_LIMIT: usize = 2000;
+
+/// Characters after which Read truncates a line.
+const READ_LINE_CHARS: usize = 2000;
+
+// ===== ChangeKind / ChangeStatus =====
+
+/// What produced a step in a file's history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Write call (full content).
+    Write,
+    /// Edit or MultiEdit call (string replacements).
+    Edit,
+    /// A Read returned content different from the reconstruction, so the
+    /// file changed outside the logged Write/Edit calls.
+    External,
+}
+
+impl ChangeKind {
+    /// Short label for display.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChangeKind::Write => "write",
+            ChangeKind::Edit => "edit",
+            ChangeKind::External => "external",
+        }
+    }
+}
+
+/// Whether a step could be applied to the reconstructed content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeStatus {
+    /// Applied; `before` (if known) and `after` are exact.
+    Applied,
+    /// Edit on content that was never read or written; only the replaced
+    /// strings are known.
+    BaseUnknown,
+    /// An `old_string` does not occur in the reconstructed content.
+    OldStringNotFound,
+    /// The tool reported an error; the file is unchanged.
+    ToolError,
+}
+
+impl ChangeStatus {
+    /// Whether the step should be flagged to the user.
+    pub fn is_flagged(&self) -> bool {
+        !matches!(self, ChangeStatus::Applied)
+    }
+
+    /// Short explanation for flagged steps.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChangeStatus::Applied => "applied",
+            ChangeStatus::BaseUnknown => "base content unknown",
+            ChangeStatus::OldStringNotFound => "old_string not found",
+            ChangeStatus::ToolError => "tool error",
+        }
+    }
+}
+
+// ===== FileChange =====
+
+/// One `old_string` → `new_string` replacement of an Edit call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement {
+    /// Text to replace.
+    pub old: String,
+    /// Replacement text.
+    pub new: String,
+    /// Replace every occurrence instead of the first.
+    pub all: bool,
+}
+
+/// One step in a file's history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChange {
+    /// What produced the step.
+    pub kind: ChangeKind,
+
+    /// Whether the step could be applied.
+    pub status: ChangeStatus,
+
+    /// Agent of the calling entry (`None` for the main agent).
+    pub agent_id: Option<AgentId>,
+
+    /// Entry containing the tool call (or the Read result for `External`).
+    pub entry_uuid: EntryUuid,
+
+    /// Timestamp of that entry.
+    pub timestamp: DateTime<Utc>,
+
+    /// Content before the step, if known.
+    pub before: Option<String>,
+
+    /// Content after the step, if known.
+    pub after: Option<String>,
+
+    /// Replacements of an Edit/MultiEdit call (empty otherwise).
+    pub replacements: Vec<Replacement>,
+}
+
+impl FileChange {
+    /// Unified diff for this step.
+    ///
+    /// With unknown `before`, a Write diffs against empty content and an
+    /// Edit shows each replacement on its own.
+    pub fn diff(&self) -> String {
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => unified_diff(before, after),
+            (None, Some(after)) => unified_diff("", after),
+            _ => self
+                .replacements
+                .iter()
+                .map(|r| unified_diff(&with_newline(&r.old), &with_newline(&r.new)))
+                .collect(),
+        }
+    }
+}
+
+// ===== FileHistory =====
+
+/// Reconstructed history of one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileHistory {
+    /// Path as written in the tool input.
+    pub path: String,
+
+    /// Steps in order.
+    pub changes: Vec<FileChange>,
+
+    /// Content at the first touch, if known. A Write to a file that was
+    /// never read counts as creating it, so the start is empty.
+    pub initial: Option<String>,
+
+    /// Reconstructed content at the end of the log, if known.
+    pub current: Option<String>,
+}
+
+impl FileHistory {
+    /// Reconstruct the history of `path` from `entries`, which must be
+    /// ordered by timestamp (all agents interleaved).
+    pub fn build<'a>(path: &str, entries: impl IntoIterator<Item = &'a LogEntry>) -> Self {
+        let mut history = FileHistory {
+            path: path.to_string(),
+            changes: Vec::new(),
+            initial: None,
+            current: None,
+        };
+        let mut touched = false;
+        let mut pending_reads: Vec<ToolUseId> = Vec::new();
+        let mut pending_changes: HashMap<ToolUseId, usize> = HashMap::new();
+
+        for entry in entries {
+            for block in content_blocks(entry) {
+                match block {
+                    ContentBlock::ToolUse(call) => {
+                        let input = call.input();
+                        if input.get("file_path").and_then(|v| v.as_str()) != Some(path) {
+                            continue;
+                        }
+                        let step = match call.name() {
+                            ToolName::Read => {
+                                // Partial reads don't reveal the whole file
+                                if input.get("offset").is_none() && input.get("limit").is_none() {
+                                    pending_reads.push(call.id().clone());
+                                }
+                                touched = true;
+                                continue;
+                            }
+                            ToolName::Write => {
+                                let content = input
+                                    .get("content")
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or_default();
+                                history.record_write(entry, content, touched)
+                            }
+                            ToolName::Edit => history.record_edit(
+                                entry,
+                                input
+                                    .as_object()
+                                    .and_then(replacement)
+                                    .into_iter()
+                                    .collect(),
+                            ),
+                            ToolName::MultiEdit => history.record_edit(
+                                entry,
+                                input
+                                    .get("edits")
+                                    .and_then(|v| v.as_array())
+                                    .map(|edits| {
+                                        edits
+                                            .iter()
+                                            .filter_map(|e| e.as_object().and_then(replacement))
+                                            .collect()
+                                    })
+                                    .unwrap_or_default(),
+                            ),
+                            _ => continue,
+                        };
+                        touched = true;
+                        pending_changes.insert(call.id().clone(), step);
+                    }
+                    ContentBlock::ToolResult {
+                        tool_use_id,
+                        content,
+                        is_error,
+                    } => {
+                        if let Some(pos) = pending_reads.iter().position(|id| id == tool_use_id) {
+                            pending_reads.swap_remove(pos);
+                            if !is_error {
+                                if let Some(observed) = parse_read_result(content) {
+                                    history.record_read(entry, observed);
+                                }
+                            }
+                        } else if let Some(step) = pending_changes.remove(tool_use_id) {
+                            if *is_error {
+                                history.mark_tool_error(step);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        history
+    }
+
+    /// Unified diff from the first touch to the end of the log, or `None`
+    /// if either end is unknown.
+    pub fn cumulative_diff(&self) -> Option<String> {
+        Some(unified_diff(self.initial.as_ref()?, self.current.as_ref()?))
+    }
+
+    /// Number of flagged steps.
+    pub fn flagged_count(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|c| c.status.is_flagged())
+            .count()
+    }
+
+    fn push(&mut self, entry: &LogEntry, change: FileChangeParts) -> usize {
+        self.changes.push(FileChange {
+            kind: change.kind,
+            status: change.status,
+            agent_id: entry.agent_id().cloned(),
+            entry_uuid: entry.uuid().clone(),
+            timestamp: entry.timestamp(),
+            before: change.before,
+            after: change.after.clone(),
+            replacements: change.replacements,
+        });
+        self.current = change.after;
+        self.changes.len() - 1
+    }
+
+    fn record_write(&mut self, entry: &LogEntry, content: &str, touched: bool) -> usize {
+        if !touched {
+            // Writing a file that was never read creates it
+            self.initial = Some(String::new());
+        }
+        self.push(
+            entry,
+            FileChangeParts {
+                kind: ChangeKind::Write,
+                status: ChangeStatus::Applied,
+                before: self.current.clone(),
+                after: Some(content.to_string()),
+                replacements: Vec::new(),
+            },
+        )
+    }
+
+    fn record_edit(&mut self, entry: &LogEntry, replacements: Vec<Replacement>) -> usize {
+        let (status, after) = edit_outcome(self.current.as_deref(), &replacements);
+        self.push(
+            entry,
+            FileChangeParts {
+                kind: ChangeKind::Edit,
+                status,
+                before: self.current.clone(),
+                after,
+                replacements,
+            },
+        )
+    }
+
+    fn record_read(&mut self, entry: &LogEntry, observed: String) {
+        match &self.current {
+            Some(current) if current.trim_end() == observed.trim_end() => {}
+            Some(_) => {
+                self.push(
+                    entry,
+                    FileChangeParts {
+                        kind: ChangeKind::External,
+                        status: ChangeStatus::Applied,
+                        before: self.current.clone(),
+                        after: Some(observed),
+                        replacements: Vec::new(),
+                    },
+                );
+            }
+            None => {
+                // First sight of the content, or recovery after an unknown edit
+                if self.changes.is_empty() {
+                    self.initial = Some(observed.clone());
+                }
+                self.current = Some(observed);
+            }
+        }
+    }
+
+    /// The tool rejected the step: restore the content it replaced and
+    /// replay the steps recorded after it (parallel calls) on that content.
+    fn mark_tool_error(&mut self, step: usize) {
+        let last_after = self.changes.last().and_then(|c| c.after.clone());
+        let mut content = self.changes[step].before.clone();
+        let change = &mut self.changes[step];
+        change.status = ChangeStatus::ToolError;
+        change.after = content.clone();
+
+        for change in &mut self.changes[step + 1..] {
+            change.before = content.clone();
+            match (change.kind, change.status) {
+                (_, ChangeStatus::ToolError) => change.after = content.clone(),
+                (ChangeKind::Edit, _) => {
+                    (change.status, change.after) =
+                        edit_outcome(content.as_deref(), &change.replacements);
+                }
+                (ChangeKind::Write | ChangeKind::External, _) => {}
+            }
+            content = change.after.clone();
+        }
+
+        // A Read after the last step already shows the real content
+        if self.current == last_after {
+            self.current = content;
+        }
+    }
+}
+
+/// Fields of a `FileChange` not taken from the entry.
+struct FileChangeParts {
+    kind: ChangeKind,
+    status: ChangeStatus,
+    before: Option<String>,
+    after: Option<String>,
+    replacements: Vec<Replacement>,
+}
+
+/// Content blocks of an entry (empty for plain text messages).
+fn content_blocks(entry: &LogEntry) -> &[ContentBlock] {
+    match entry.message().content() {
+        MessageContent::Blocks(blocks) => blocks,
+        MessageContent::Text(_) => &[],
+    }
+}
+
+/// Parse an Edit input (or a MultiEdit `edits` item).
+fn replacement(input: &serde_json::Map<String, serde_json::Value>) -> Option<Replacement> {
+    Some(Replacement {
+        old: input.get("old_string")?.as_str()?.to_string(),
+        new: input.get("new_string")?.as_str()?.to_string(),
+        all: input
+            .get("replace_all")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+    })
+}
+
+/// Status and resulting content of an Edit on `content`.
+fn edit_outcome(
+    content: Option<&str>,
+    replacements: &[Replacement],
+) -> (ChangeStatus, Option<String>) {
+    match content {
+        None => (ChangeStatus::BaseUnknown, None),
+        Some(content) => match apply_replacements(content, replacements) {
+            Some(after) => (ChangeStatus::Applied, Some(after)),
+            None => (ChangeStatus::OldStringNotFound, Some(content.to_string())),
+        },
+    }
+}
+
+/// Apply replacements in order; `None` if any `old` is missing.
+fn apply_replacements(content: &str, replacements: &[Replacement]) -> Option<String> {
+    let mut content = content.to_string();
+    for r in replacements {
+        if r.old.is_empty() || !content.contains(&r.old) {
+            return None;
+        }
+        content = if r.all {
+            content.replace(&r.old, &r.new)
+        } else {
+            content.replacen(&r.old, &r.new, 1)
+        };
+    }
+    Some(content)
+}
+
+/// Recover file content from a Read result in `cat -n` form
+/// (`     1→line` or `     1\tline`).
+///
+/// Lines must be numbered consecutively from 1; anything after the numbered
+/// block (e.g. a trailing reminder) is ignored. `None` if no line matches, or
+/// if the result may be cut short: `READ_LINE_LIMIT` lines, or a line of
+/// `READ_LINE_CHARS` characters or more.
+fn parse_read_result(result: &str) -> Option<String> {
+    let mut content = String::new();
+    let mut expected = 1usize;
+    for line in result.lines() {
+        let trimmed = line.trim_start();
+        let digits = trimmed.len()
+            - trimmed
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        let (number, rest) = trimmed.split_at(digits);
+        let Some(text) = rest.strip_prefix('→').or_else(|| rest.strip_prefix('\t')) else {
+            break;
+        };
+        if number.parse::<usize>().ok() != Some(expected) {
+            break;
+        }
+        if expected == READ_LINE_LIMIT || text.chars().count() >= READ_LINE_CHARS {
+            return None;
+        }
+        content.push_str(text);
+        content.push('\n');
+        expected += 1;
+    }
+    (expected > 1).then_some(content)
+}
+
+/// Append a newline so fragments diff as whole lines.
+fn with_newline(text: &str) -> String {
+    if text.ends_with('\n') {
+        text.to_string()
+    } else {
+        format!("{}\n", text)
+    }
+}
+
+/// Line-based unified diff without file headers.
+fn unified_diff(before: &str, after: &str) -> String {
+    TextDiff::from_lines(before, after)
+        .unified_diff()
+        .context_radius(DIFF_CONTEXT_LINES)
+        .to_string()
+}
+
+#[cfg(test)]
+#[path = "file_history_tests.rs"]
+mod tests;
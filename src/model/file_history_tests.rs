@@ -0,0 +1,376 @@
+//! Tests for file history reconstruction.
+
+use super::*;
+use crate::model::{
+    EntryMetadata, EntryType, Message, MessageContent, Role, SessionId, ToolCall, ToolUseId,
+};
+use chrono::TimeZone;
+use serde_json::{Value, json};
+
+const PATH: &str = "/src/lib.rs";
+
+fn entry(uuid: &str, secs: u32, role: Role, blocks: Vec<ContentBlock>) -> LogEntry {
+    LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        None,
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, secs).unwrap(),
+        EntryType::Assistant,
+        Message::new(role, MessageContent::Blocks(blocks)),
+        EntryMetadata::default(),
+    )
+}
+
+fn call(id: &str, tool: &str, input: Value) -> ContentBlock {
+    ContentBlock::ToolUse(ToolCall::new(
+        ToolUseId::new(id).unwrap(),
+        ToolName::parse(tool),
+        input,
+    ))
+}
+
+fn result(id: &str, content: &str, is_error: bool) -> ContentBlock {
+    ContentBlock::ToolResult {
+        tool_use_id: ToolUseId::new(id).unwrap(),
+        content: content.to_string(),
+        is_error,
+    }
+}
+
+/// A tool call entry followed by its result entry.
+fn call_with_result(
+    id: &str,
+    secs: u32,
+    tool: &str,
+    input: Value,
+    output: &str,
+    is_error: bool,
+) -> [LogEntry; 2] {
+    [
+        entry(
+            &format!("{}-call", id),
+            secs,
+            Role::Assistant,
+            vec![call(id, tool, input)],
+        ),
+        entry(
+            &format!("{}-result", id),
+            secs,
+            Role::User,
+            vec![result(id, output, is_error)],
+        ),
+    ]
+}
+
+fn edit(old: &str, new: &str) -> Value {
+    json!({"file_path": PATH, "old_string": old, "new_string": new})
+}
+
+// ===== Read results =====
+
+#[test]
+fn parse_read_result_strips_line_numbers() {
+    let result = "     1→fn main() {\n     2→}\n\n<system-reminder>x</system-reminder>";
+
+    assert_eq!(
+        parse_read_result(result),
+        Some("fn main() {\n}\n".to_string())
+    );
+    assert_eq!(parse_read_result("     1\tone"), Some("one\n".to_string()));
+}
+
+#[test]
+fn parse_read_result_rejects_unnumbered_output() {
+    assert_eq!(parse_read_result("File does not exist."), None);
+    assert_eq!(
+        parse_read_result("     5→mid-file"),
+        None,
+        "Must start at 1"
+    );
+}
+
+// ===== Reconstruction =====
+
+#[test]
+fn write_then_edits_reconstruct_content() {
+    let entries: Vec<LogEntry> = [
+        call_with_result(
+            "t1",
+            0,
+            "Write",
+            json!({"file_path": PATH, "content": "a\nb\nc\n"}),
+            "ok",
+            false,
+        ),
+        call_with_result("t2", 1, "Edit", edit("b", "B"), "ok", false),
+        call_with_result(
+            "t3",
+            2,
+            "MultiEdit",
+            json!({"file_path": PATH, "edits": [
+                {"old_string": "a", "new_string": "A"},
+                {"old_string": "c", "new_string": "C"},
+            ]}),
+            "ok",
+            false,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let history = FileHistory::build(PATH, &entries);
+
+    assert_eq!(history.changes.len(), 3);
+    assert_eq!(
+        history.initial.as_deref(),
+        Some(""),
+        "Unread file is created"
+    );
+    assert_eq!(history.current.as_deref(), Some("A\nB\nC\n"));
+    assert_eq!(history.flagged_count(), 0);
+    assert!(history.changes[1].diff().contains("-b\n+B\n"));
+    let cumulative = history.cumulative_diff().unwrap();
+    assert!(cumulative.contains("+A\n+B\n+C\n"), "got:\n{}", cumulative);
+}
+
+#[test]
+fn read_result_provides_base_for_edits() {
+    let entries: Vec<LogEntry> = [
+        call_with_result(
+            "t1",
+            0,
+            "Read",
+            json!({"file_path": PATH}),
+            "     1→let x = 1;\n     2→let y = 2;",
+            false,
+        ),
+        call_with_result("t2", 1, "Edit", edit("x = 1", "x = 10"), "ok", false),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let history = FileHistory::build(PATH, &entries);
+
+    assert_eq!(history.changes[0].status, ChangeStatus::Applied);
+    assert_eq!(
+        history.cumulative_diff().unwrap(),
+        "@@ -1,2 +1,2 @@\n-let x = 1;\n+let x = 10;\n let y = 2;\n"
+    );
+}
+
+#[test]
+fn edit_without_known_base_is_flagged_not_guessed() {
+    let entries: Vec<LogEntry> = call_with_result("t1", 0, "Edit", edit("old", "new"), "ok", false)
+        .into_iter()
+        .collect();
+
+    let history = FileHistory::build(PATH, &entries);
+
+    let change = &history.changes[0];
+    assert_eq!(change.status, ChangeStatus::BaseUnknown);
+    assert_eq!(change.after, None);
+    assert_eq!(history.cumulative_diff(), None);
+    assert!(
+        change.diff().contains("-old\n+new\n"),
+        "Replacement still shown"
+    );
+}
+
+#[test]
+fn partial_read_does_not_provide_base() {
+    let entries: Vec<LogEntry> = [
+        call_with_result(
+            "t1",
+            0,
+            "Read",
+            json!({"file_path": PATH, "offset": 1, "limit": 1}),
+            "     1→old",
+            false,
+        ),
+        call_with_result("t2", 1, "Edit", edit("old", "new"), "ok", false),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let history = FileHistory::build(PATH, &entries);
+
+    assert_eq!(history.changes[0].status, ChangeStatus::BaseUnknown);
+}
+
+#[test]
+fn capped_read_does_not_provide_base_or_external_step() {
+    let content: String = (1..=2500).map(|n| format!("line {}\n", n)).collect();
+    let numbered = |lines: usize| -> String {
+        (1..=lines)
+            .map(|n| format!("{:>6}→line {}\n", n, n))
+            .collect()
+    };
+    let long_line = format!("     1→{}", "x".repeat(READ_LINE_CHARS));
+    let entries: Vec<LogEntry> = [
+        call_with_result(
+            "t1",
+            0,
+            "Write",
+            json!({"file_path": PATH, "content": content}),
+            "ok",
+            false,
+        ),
+        call_with_result(
+            "t2",
+            1,
+            "Read",
+            json!({"file_path": PATH}),
+            &numbered(READ_LINE_LIMIT),
+            false,
+        ),
+        call_with_result(
+            "t3",
+            2,
+            "Read",
+            json!({"file_path": PATH}),
+            &long_line,
+            false,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let history = FileHistory::build(PATH, &entries);
+
+    assert_eq!(history.changes.len(), 1, "capped reads add no step");
+    assert_eq!(history.current, Some(content));
+    assert_eq!(
+        parse_read_result(&numbered(READ_LINE_LIMIT - 1)).map(|c| c.lines().count()),
+        Some(1999)
+    );
+}
+
+#[test]
+fn earlier_rejected_edit_is_undone_in_later_steps() {
+    // Both edits are called before either result arrives
+    let entries = vec![
+        entry(
+            "w",
+            0,
+            Role::Assistant,
+            vec![call(
+                "t1",
+                "Write",
+                json!({"file_path": PATH, "content": "a\nb\n"}),
+            )],
+        ),
+        entry("w-result", 0, Role::User, vec![result("t1", "ok", false)]),
+        entry(
+            "edits",
+            1,
+            Role::Assistant,
+            vec![
+                call("t2", "Edit", edit("a", "A")),
+                call("t3", "Edit", edit("b", "B")),
+            ],
+        ),
+        entry(
+            "results",
+            2,
+            Role::User,
+            vec![result("t2", "denied", true), result("t3", "ok", false)],
+        ),
+    ];
+
+    let history = FileHistory::build(PATH, &entries);
+
+    assert_eq!(history.changes[1].status, ChangeStatus::ToolError);
+    assert_eq!(history.changes[1].after.as_deref(), Some("a\nb\n"));
+    assert_eq!(history.changes[2].before.as_deref(), Some("a\nb\n"));
+    assert_eq!(history.changes[2].after.as_deref(), Some("a\nB\n"));
+    assert_eq!(history.current.as_deref(), Some("a\nB\n"));
+}
+
+#[test]
+fn missing_old_string_and_tool_errors_leave_content_unchanged() {
+    let entries: Vec<LogEntry> = [
+        call_with_result(
+            "t1",
+            0,
+            "Write",
+            json!({"file_path": PATH, "content": "one\n"}),
+            "ok",
+            false,
+        ),
+        call_with_result("t2", 1, "Edit", edit("missing", "x"), "ok", false),
+        call_with_result("t3", 2, "Edit", edit("one", "two"), "denied", true),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let history = FileHistory::build(PATH, &entries);
+
+    assert_eq!(history.changes[1].status, ChangeStatus::OldStringNotFound);
+    assert_eq!(history.changes[2].status, ChangeStatus::ToolError);
+    assert_eq!(history.current.as_deref(), Some("one\n"));
+    assert_eq!(history.flagged_count(), 2);
+}
+
+#[test]
+fn read_revealing_outside_change_adds_external_step() {
+    let entries: Vec<LogEntry> = [
+        call_with_result(
+            "t1",
+            0,
+            "Write",
+            json!({"file_path": PATH, "content": "v1\n"}),
+            "ok",
+            false,
+        ),
+        call_with_result(
+            "t2",
+            1,
+            "Read",
+            json!({"file_path": PATH}),
+            "     1→v1",
+            false,
+        ),
+        call_with_result(
+            "t3",
+            2,
+            "Read",
+            json!({"file_path": PATH}),
+            "     1→v2",
+            false,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let history = FileHistory::build(PATH, &entries);
+
+    assert_eq!(history.changes.len(), 2, "Matching read adds no step");
+    assert_eq!(history.changes[1].kind, ChangeKind::External);
+    assert_eq!(history.current.as_deref(), Some("v2\n"));
+}
+
+#[test]
+fn other_paths_are_ignored() {
+    let entries: Vec<LogEntry> = call_with_result(
+        "t1",
+        0,
+        "Write",
+        json!({"file_path": "/other.rs", "content": "x"}),
+        "ok",
+        false,
+    )
+    .into_iter()
+    .collect();
+
+    let history = FileHistory::build(PATH, &entries);
+
+    assert!(history.changes.is_empty());
+    assert_eq!(history.cumulative_diff(), None);
+}
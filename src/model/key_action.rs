@@ -59,18 +59,30 @@ pub enum KeyAction {
     NextMatch,
     /// Navigate to previous search match (FR-013). Default: N/Shift+n
     PrevMatch,
+    /// Toggle the saved search picker (`[searches]` in config.toml). Default: Ctrl+r
+    ToggleSavedSearches,
 
     // Stats
     /// Toggle visibility of statistics panel (FR-015-020). Default: s (lowercase)
     ToggleStats,
     /// Toggle visibility of session list modal (FR-002, FR-003). Default: S (uppercase)
     ToggleSessionModal,
+    /// Toggle the files-touched modal (files read/edited/written per scope). Default: F (uppercase)
+    ToggleFilesModal,
+    /// Toggle the subagent summary table (one row per subagent). Default: A (uppercase)
+    ToggleSubagentTable,
     /// Filter stats to show all agents globally (FR-020). Default: !
     FilterGlobal,
     /// Filter stats to show main agent only (FR-020). Default: @
     FilterMainAgent,
     /// Filter stats to show current subagent only (FR-020). Default: #
     FilterSubagent,
+    /// Switch the stats timeline charts between turn index and wall-clock time. Default: t
+    ToggleChartAxis,
+    /// Switch the stats panel between its pages (overview, tools). Default: v
+    NextStatsPage,
+    /// Cycle the column the stats tool table is sorted by. Default: o
+    CycleToolSort,
 
     // Auto-scroll (live mode)
     /// Toggle auto-scroll behavior when following live logs (FR-036, FR-038). Default: a
@@ -84,6 +96,24 @@ pub enum KeyAction {
     /// Toggle global line wrapping for all items (FR-039, FR-050, FR-051). Default: W/Shift+w
     ToggleGlobalWrap,
 
+    // Timing
+    /// Cycle the time gutter: off, absolute time, relative time. Default: T/Shift+t
+    CycleTimeGutter,
+
+    // Session header
+    /// Expand or collapse the session header (model, cwd, tools, ...). Default: i
+    ToggleSessionHeader,
+
+    // Continuous sessions
+    /// Switch between one session at a time and all sessions back to back (FR-076, FR-077). Default: C/Shift+c
+    ToggleContinuousSessions,
+    /// Fold the viewed session to its heading in continuous mode, or unfold it. Default: z
+    ToggleSessionFold,
+
+    // Export
+    /// Open the picker exporting the session, tab or focused entry to a Markdown file. Default: E/Shift+e
+    ToggleExportPicker,
+
     // Application
     /// Exit the application (FR-025). Default: q/Ctrl+c
     Quit,
@@ -52,9 +52,11 @@ pub struct EntryMetadata {
 
 // ===== SystemMetadata (FMT-006) =====
 
-/// System entry metadata for system:init and system:hook_response entries.
+/// System entry metadata for system:init, system:hook_response and
+/// system:compact_boundary entries.
 ///
-/// Contains session initialization information such as model, tools, agents, and skills.
+/// Contains session initialization information such as model, tools, agents, and skills,
+/// or compaction details for compact boundaries.
 /// Only present for EntryType::System entries.
 #[derive(Debug, Clone)]
 pub struct SystemMetadata {
@@ -70,6 +72,49 @@ pub struct SystemMetadata {
     pub agents: Vec<String>,
     /// Available skills for this session
     pub skills: Vec<String>,
+    /// Permission mode at session start (e.g., "default", "acceptEdits")
+    pub permission_mode: Option<String>,
+    /// Configured MCP servers and their connection status
+    pub mcp_servers: Vec<McpServer>,
+    /// Claude Code version reported at session start
+    pub version: Option<String>,
+    /// Compaction details (system:compact_boundary only)
+    pub compact: Option<CompactMetadata>,
+}
+
+impl SystemMetadata {
+    /// Subtype of entries marking a conversation compaction.
+    pub const COMPACT_BOUNDARY: &'static str = "compact_boundary";
+
+    /// Whether this entry marks a conversation compaction.
+    pub fn is_compact_boundary(&self) -> bool {
+        self.subtype == Self::COMPACT_BOUNDARY
+    }
+}
+
+/// An MCP server listed in a system:init entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct McpServer {
+    /// Server name
+    pub name: String,
+    /// Connection status (e.g., "connected", "failed")
+    pub status: Option<String>,
+}
+
+/// Compaction details of a system:compact_boundary entry.
+///
+/// Context fill restarts after a boundary: everything before it was replaced
+/// by a summary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactMetadata {
+    /// What triggered the compaction ("auto" or "manual")
+    pub trigger: Option<String>,
+    /// Context tokens just before compaction
+    pub pre_tokens: Option<u64>,
+    /// Context tokens just after compaction, when the log records them
+    pub post_tokens: Option<u64>,
+    /// Boundary text (e.g., "Conversation compacted")
+    pub summary: Option<String>,
 }
 
 // ===== ResultMetadata (FMT-007) =====
@@ -101,6 +146,7 @@ pub struct ResultMetadata {
 pub struct LogEntry {
     uuid: EntryUuid,
     parent_uuid: Option<EntryUuid>,
+    transcript_parent_uuid: Option<EntryUuid>,
     session_id: SessionId,
     agent_id: Option<AgentId>,
     timestamp: DateTime<Utc>,
@@ -130,6 +176,7 @@ impl LogEntry {
         Self {
             uuid,
             parent_uuid,
+            transcript_parent_uuid: None,
             session_id,
             agent_id,
             timestamp,
@@ -160,6 +207,7 @@ impl LogEntry {
         Self {
             uuid,
             parent_uuid,
+            transcript_parent_uuid: None,
             session_id,
             agent_id,
             timestamp,
@@ -190,6 +238,7 @@ impl LogEntry {
         Self {
             uuid,
             parent_uuid,
+            transcript_parent_uuid: None,
             session_id,
             agent_id,
             timestamp,
@@ -218,6 +267,12 @@ impl LogEntry {
         crate::parser::parse_entry(raw, 1)
     }
 
+    /// Set the `parentUuid` of a transcript entry (see `transcript_parent_uuid`).
+    pub fn with_transcript_parent_uuid(mut self, uuid: Option<EntryUuid>) -> Self {
+        self.transcript_parent_uuid = uuid;
+        self
+    }
+
     // ===== Accessors (read-only) =====
 
     /// Returns the unique identifier for this log entry.
@@ -232,6 +287,15 @@ impl LogEntry {
         self.parent_uuid.as_ref()
     }
 
+    /// Returns the `parentUuid` of a transcript entry: the entry before it in
+    /// the conversation chain, which may belong to an earlier session when
+    /// the session was resumed.
+    ///
+    /// Unlike `parent_uuid`, this is not a subagent link.
+    pub fn transcript_parent_uuid(&self) -> Option<&EntryUuid> {
+        self.transcript_parent_uuid.as_ref()
+    }
+
     /// Returns the session identifier grouping related entries together.
     ///
     /// All entries from the same Claude Code session share the same session ID.
@@ -247,10 +311,18 @@ impl LogEntry {
     }
 
     /// Returns the UTC timestamp when this entry was created.
+    ///
+    /// Entries whose log line had no timestamp report `DateTime::UNIX_EPOCH`;
+    /// use `known_timestamp` for display.
     pub fn timestamp(&self) -> DateTime<Utc> {
         self.timestamp
     }
 
+    /// Returns the timestamp, or None if the log line had none.
+    pub fn known_timestamp(&self) -> Option<DateTime<Utc>> {
+        (self.timestamp != DateTime::UNIX_EPOCH).then_some(self.timestamp)
+    }
+
     /// Returns the type of this entry (User, Assistant, or Summary).
     ///
     /// The entry type determines how this entry is visually rendered in the
@@ -282,6 +354,11 @@ impl LogEntry {
         self.system_metadata.as_ref()
     }
 
+    /// Returns the compaction details if this entry is a compact boundary.
+    pub fn compact_metadata(&self) -> Option<&CompactMetadata> {
+        self.system_metadata.as_ref()?.compact.as_ref()
+    }
+
     /// Returns the result metadata if this is a Result entry.
     ///
     /// Result metadata contains session completion information like error status,
@@ -717,6 +794,10 @@ mod tests {
             tools: vec!["Read".to_string(), "Write".to_string()],
             agents: vec!["general-purpose".to_string()],
             skills: vec!["commit".to_string()],
+            permission_mode: None,
+            mcp_servers: vec![],
+            version: None,
+            compact: None,
         };
 
         assert_eq!(metadata.subtype, "init");
@@ -737,6 +818,10 @@ mod tests {
             tools: vec![],
             agents: vec![],
             skills: vec![],
+            permission_mode: None,
+            mcp_servers: vec![],
+            version: None,
+            compact: None,
         };
 
         assert_eq!(metadata.subtype, "hook_response");
@@ -757,6 +842,10 @@ mod tests {
             tools: vec!["Read".to_string()],
             agents: vec!["general-purpose".to_string()],
             skills: vec!["commit".to_string()],
+            permission_mode: None,
+            mcp_servers: vec![],
+            version: None,
+            compact: None,
         };
 
         let entry = LogEntry::new_with_system_metadata(
@@ -2,26 +2,50 @@
 //!
 //! All types in this module are pure data with smart constructors.
 
+pub mod context_window;
 pub mod conversation_entry;
+pub mod entry_timing;
 pub mod error;
+pub mod file_activity;
+pub mod file_history;
 pub mod identifiers;
 pub mod key_action;
 pub mod log_entry;
 pub mod malformed_entry;
 pub mod message;
+pub mod session_info;
+pub mod session_links;
 pub mod stats;
+pub mod subagent_summary;
+pub mod tool_stats;
 pub mod usage;
 
 // Re-export for convenience
+pub use context_window::{
+    ContextWindows, DEFAULT_CONTEXT_WINDOW_TOKENS, LONG_CONTEXT_WINDOW_TOKENS,
+    is_long_context_model,
+};
 pub use conversation_entry::ConversationEntry;
+pub use entry_timing::{EntryTiming, EntryTimings, LONG_GAP_SECS, is_user_prompt};
 pub use error::{AppError, InputError, ParseError};
+pub use file_activity::{FileAccess, FileActivity, FileTouch, file_access};
+pub use file_history::{ChangeKind, ChangeStatus, FileChange, FileHistory, Replacement};
 pub use identifiers::{
     AgentId, EntryUuid, InvalidAgentId, InvalidSessionId, InvalidToolUseId, InvalidUuid, SessionId,
     ToolUseId,
 };
 pub use key_action::KeyAction;
-pub use log_entry::{EntryMetadata, EntryType, LogEntry, ResultMetadata, SystemMetadata};
+pub use log_entry::{
+    CompactMetadata, EntryMetadata, EntryType, LogEntry, McpServer, ResultMetadata, SystemMetadata,
+};
 pub use malformed_entry::MalformedEntry;
 pub use message::{ContentBlock, Message, MessageContent, Role, ToolCall, ToolName};
-pub use stats::{ModelPricing, PricingConfig, SessionStats, StatsFilter};
+pub use session_info::SessionInfo;
+pub use session_links::SessionLinks;
+pub use stats::{
+    ChartAxis, CostReconciliation, DEFAULT_COST_TOLERANCE_PERCENT, ModelPricing, PricingConfig,
+    SessionStats, StatsFilter, StatsPage, TimelineSample,
+};
+pub use subagent_summary::{SubagentSortColumn, SubagentStatus, SubagentSummary};
+pub use tool_stats::{PendingToolCall, ToolSortColumn, ToolStats};
 pub use usage::{ModelInfo, TokenUsage};
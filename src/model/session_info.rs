@@ -0,0 +1,101 @@
+//! Session-level environment details for the session header.
+//!
+//! The session's system:init entry is the main source (model, tools, MCP
+//! servers, permission mode, ...). Logs without one, or whose init entry omits
+//! a field, fall back to the first entry that carries it: `cwd`, `gitBranch`
+//! and `version` are on every entry, and assistant messages name their model.
+
+use crate::model::{LogEntry, McpServer, ModelInfo};
+use std::path::PathBuf;
+
+/// Subtype of the system entry that starts a session.
+const INIT_SUBTYPE: &str = "init";
+
+/// Environment a session ran in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionInfo {
+    /// Model from system:init, or the first assistant message's model.
+    pub model: Option<ModelInfo>,
+    /// Claude Code version.
+    pub version: Option<String>,
+    /// Working directory.
+    pub cwd: Option<PathBuf>,
+    /// Git branch of the working directory.
+    pub git_branch: Option<String>,
+    /// Permission mode at session start.
+    pub permission_mode: Option<String>,
+    /// Configured MCP servers.
+    pub mcp_servers: Vec<McpServer>,
+    /// Available tools.
+    pub tools: Vec<String>,
+    /// Available agents.
+    pub agents: Vec<String>,
+    /// Available skills.
+    pub skills: Vec<String>,
+    /// Whether the system:init entry has been seen.
+    seen_init: bool,
+}
+
+impl SessionInfo {
+    /// Collect session details from its entries, in log order.
+    pub fn from_entries<'a>(entries: impl IntoIterator<Item = &'a LogEntry>) -> Self {
+        let mut info = SessionInfo::default();
+        for entry in entries {
+            info.record_entry(entry);
+        }
+        info
+    }
+
+    /// Fold in the next entry of the session.
+    ///
+    /// Only the first system:init entry is used; later fields only fill gaps.
+    pub fn record_entry(&mut self, entry: &LogEntry) {
+        if let Some(init) = entry
+            .system_metadata()
+            .filter(|m| !self.seen_init && m.subtype == INIT_SUBTYPE)
+        {
+            self.seen_init = true;
+            self.model = self.model.take().or(init.model.clone().map(ModelInfo::new));
+            self.version = self.version.take().or(init.version.clone());
+            self.cwd = self.cwd.take().or(init.cwd.clone());
+            self.permission_mode = init.permission_mode.clone();
+            self.mcp_servers = init.mcp_servers.clone();
+            self.tools = init.tools.clone();
+            self.agents = init.agents.clone();
+            self.skills = init.skills.clone();
+        }
+
+        let metadata = entry.metadata();
+        self.cwd = self.cwd.take().or(metadata.cwd.clone());
+        self.git_branch = self.git_branch.take().or(metadata.git_branch.clone());
+        self.version = self.version.take().or(metadata.version.clone());
+        self.model = self.model.take().or(entry.message().model().cloned());
+    }
+
+    /// Whether nothing is known about the session's environment.
+    pub fn is_empty(&self) -> bool {
+        *self == SessionInfo::default()
+    }
+
+    /// Where the session ran: "cwd (branch)", "cwd" or "(branch)".
+    pub fn location(&self) -> Option<String> {
+        match (&self.cwd, &self.git_branch) {
+            (Some(cwd), Some(branch)) => Some(format!("{} ({})", cwd.display(), branch)),
+            (Some(cwd), None) => Some(cwd.display().to_string()),
+            (None, Some(branch)) => Some(format!("({})", branch)),
+            (None, None) => None,
+        }
+    }
+
+    /// Number of MCP servers whose status is "connected".
+    pub fn connected_mcp_servers(&self) -> usize {
+        self.mcp_servers
+            .iter()
+            .filter(|server| server.status.as_deref() == Some("connected"))
+            .count()
+    }
+}
+
+#[cfg(test)]
+#[path = "session_info_tests.rs"]
+mod tests;
@@ -0,0 +1,129 @@
+//! Tests for session info collection.
+
+use super::*;
+use crate::model::{
+    EntryMetadata, EntryType, EntryUuid, Message, MessageContent, Role, SessionId, SystemMetadata,
+};
+use chrono::{TimeZone, Utc};
+
+fn entry(uuid: &str, entry_type: EntryType, metadata: EntryMetadata) -> LogEntry {
+    LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        None,
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+        entry_type,
+        Message::new(Role::User, MessageContent::Text("hi".to_string())),
+        metadata,
+    )
+}
+
+fn system(uuid: &str, subtype: &str, model: &str) -> LogEntry {
+    LogEntry::new_with_system_metadata(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        None,
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+        EntryType::System,
+        Message::new(Role::Assistant, MessageContent::Text(String::new())),
+        EntryMetadata::default(),
+        Some(SystemMetadata {
+            subtype: subtype.to_string(),
+            cwd: Some(PathBuf::from("/work/repo")),
+            model: Some(model.to_string()),
+            tools: vec!["Read".to_string(), "Bash".to_string()],
+            agents: vec!["general-purpose".to_string()],
+            skills: vec![],
+            permission_mode: Some("acceptEdits".to_string()),
+            mcp_servers: vec![
+                McpServer {
+                    name: "github".to_string(),
+                    status: Some("connected".to_string()),
+                },
+                McpServer {
+                    name: "linear".to_string(),
+                    status: Some("failed".to_string()),
+                },
+            ],
+            version: None,
+            compact: None,
+        }),
+    )
+}
+
+#[test]
+fn init_entry_fields_are_merged_with_entry_metadata() {
+    let user = entry(
+        "u1",
+        EntryType::User,
+        EntryMetadata {
+            cwd: Some(PathBuf::from("/elsewhere")),
+            git_branch: Some("feature/header".to_string()),
+            version: Some("2.0.31".to_string()),
+            is_sidechain: false,
+        },
+    );
+    let entries = [
+        system("init-1", "init", "claude-opus-4-5-20251101"),
+        user,
+        system("init-2", "init", "claude-haiku-3-5"),
+    ];
+
+    let info = SessionInfo::from_entries(&entries);
+
+    assert_eq!(info.model.as_ref().map(|m| m.display_name()), Some("Opus"));
+    assert_eq!(info.cwd, Some(PathBuf::from("/work/repo")));
+    assert_eq!(info.git_branch.as_deref(), Some("feature/header"));
+    assert_eq!(info.version.as_deref(), Some("2.0.31"));
+    assert_eq!(info.permission_mode.as_deref(), Some("acceptEdits"));
+    assert_eq!(info.tools, vec!["Read".to_string(), "Bash".to_string()]);
+    assert_eq!(info.connected_mcp_servers(), 1);
+}
+
+#[test]
+fn session_without_init_uses_first_entry_metadata() {
+    let entries = [
+        system("hook", "hook_response", "ignored"),
+        entry(
+            "u1",
+            EntryType::User,
+            EntryMetadata {
+                cwd: Some(PathBuf::from("/work/other")),
+                ..EntryMetadata::default()
+            },
+        ),
+    ];
+
+    let info = SessionInfo::from_entries(&entries);
+
+    assert_eq!(info.cwd, Some(PathBuf::from("/work/other")));
+    assert!(info.model.is_none());
+    assert!(info.tools.is_empty());
+    assert!(!info.is_empty());
+    assert!(SessionInfo::from_entries(&[]).is_empty());
+}
+
+#[test]
+fn location_combines_cwd_and_branch() {
+    let info = |cwd: Option<&str>, branch: Option<&str>| SessionInfo {
+        cwd: cwd.map(PathBuf::from),
+        git_branch: branch.map(str::to_string),
+        ..SessionInfo::default()
+    };
+
+    assert_eq!(
+        info(Some("/work/repo"), Some("main")).location().as_deref(),
+        Some("/work/repo (main)")
+    );
+    assert_eq!(
+        info(Some("/work/repo"), None).location().as_deref(),
+        Some("/work/repo")
+    );
+    assert_eq!(
+        info(None, Some("main")).location().as_deref(),
+        Some("(main)")
+    );
+    assert_eq!(info(None, None).location(), None);
+}
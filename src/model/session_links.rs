@@ -0,0 +1,117 @@
+//! Lineage of resumed and continued sessions.
+//!
+//! `claude --resume`/`--continue` start a session that carries on from an
+//! earlier one. Two signals link them:
+//! - The session reuses an earlier session's ID (the log switched away and
+//!   came back), or
+//! - One of its entries has a `parentUuid` that belongs to an earlier session
+//!   (interactive transcripts keep the chain of entries across files).
+//!
+//! Each session has at most one parent, so the links form a forest; a chain
+//! is one tree of it.
+
+use crate::model::{EntryUuid, LogEntry, SessionId};
+use std::collections::{HashMap, HashSet};
+
+/// Parent links between the sessions of one log, by session index.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionLinks {
+    /// Index of the session each session continues, if any.
+    parents: Vec<Option<usize>>,
+}
+
+impl SessionLinks {
+    /// Detect links from each session's ID and entries, in log order.
+    pub fn detect<'a, S, E>(sessions: S) -> Self
+    where
+        S: IntoIterator<Item = (&'a SessionId, E)>,
+        E: IntoIterator<Item = &'a LogEntry>,
+    {
+        let mut parents = Vec::new();
+        let mut entry_owner: HashMap<EntryUuid, usize> = HashMap::new();
+        let mut last_with_id: HashMap<SessionId, usize> = HashMap::new();
+
+        for (index, (session_id, entries)) in sessions.into_iter().enumerate() {
+            let entries: Vec<&LogEntry> = entries.into_iter().collect();
+            let own: HashSet<&EntryUuid> = entries.iter().map(|e| e.uuid()).collect();
+
+            let parent = last_with_id.get(session_id).copied().or_else(|| {
+                entries
+                    .iter()
+                    .filter_map(|e| e.transcript_parent_uuid())
+                    .filter(|uuid| !own.contains(uuid))
+                    .find_map(|uuid| entry_owner.get(uuid).copied())
+            });
+            parents.push(parent);
+
+            for entry in &entries {
+                entry_owner.insert(entry.uuid().clone(), index);
+            }
+            last_with_id.insert(session_id.clone(), index);
+        }
+        Self { parents }
+    }
+
+    /// Number of sessions.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Whether there are no sessions.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Session that `index` continues.
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        self.parents.get(index).copied().flatten()
+    }
+
+    /// Sessions that continue `index`, in log order.
+    pub fn children(&self, index: usize) -> Vec<usize> {
+        (0..self.parents.len())
+            .filter(|&i| self.parents[i] == Some(index))
+            .collect()
+    }
+
+    /// First session of the chain `index` belongs to.
+    pub fn root(&self, index: usize) -> usize {
+        let mut current = index;
+        while let Some(parent) = self.parent(current) {
+            current = parent;
+        }
+        current
+    }
+
+    /// Number of sessions between `index` and its chain's first session.
+    pub fn depth(&self, index: usize) -> usize {
+        std::iter::successors(self.parent(index), |&i| self.parent(i)).count()
+    }
+
+    /// Every session in the chain of `index`, in log order.
+    pub fn chain(&self, index: usize) -> Vec<usize> {
+        let root = self.root(index);
+        (0..self.parents.len())
+            .filter(|&i| self.root(i) == root)
+            .collect()
+    }
+
+    /// Sessions in tree order: each chain's first session, then the sessions
+    /// continuing it (depth first), chains in log order.
+    pub fn tree_order(&self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.parents.len());
+        let mut stack: Vec<usize> = (0..self.parents.len())
+            .filter(|&i| self.parents[i].is_none())
+            .rev()
+            .collect();
+        while let Some(index) = stack.pop() {
+            order.push(index);
+            stack.extend(self.children(index).into_iter().rev());
+        }
+        order
+    }
+}
+
+#[cfg(test)]
+#[path = "session_links_tests.rs"]
+mod tests;
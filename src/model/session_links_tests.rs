@@ -0,0 +1,100 @@
+//! Tests for session lineage detection.
+
+use super::*;
+use crate::model::{EntryMetadata, EntryType, Message, MessageContent, Role};
+use chrono::{TimeZone, Utc};
+
+fn entry(session: &str, uuid: &str, parent: Option<&str>) -> LogEntry {
+    LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new(session).unwrap(),
+        None,
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+        EntryType::User,
+        Message::new(Role::User, MessageContent::Text("hi".to_string())),
+        EntryMetadata::default(),
+    )
+    .with_transcript_parent_uuid(parent.map(|p| EntryUuid::new(p).unwrap()))
+}
+
+fn detect(sessions: &[(SessionId, Vec<LogEntry>)]) -> SessionLinks {
+    SessionLinks::detect(sessions.iter().map(|(id, entries)| (id, entries.iter())))
+}
+
+fn session(id: &str, entries: Vec<LogEntry>) -> (SessionId, Vec<LogEntry>) {
+    (SessionId::new(id).unwrap(), entries)
+}
+
+#[test]
+fn parent_uuid_into_earlier_session_links_sessions() {
+    let links = detect(&[
+        session(
+            "a",
+            vec![entry("a", "a1", None), entry("a", "a2", Some("a1"))],
+        ),
+        session("b", vec![entry("b", "b1", None)]),
+        session(
+            "c",
+            vec![entry("c", "c1", Some("a2")), entry("c", "c2", Some("c1"))],
+        ),
+    ]);
+
+    assert_eq!(links.parent(0), None);
+    assert_eq!(links.parent(1), None, "unrelated session");
+    assert_eq!(links.parent(2), Some(0));
+    assert_eq!(links.children(0), vec![2]);
+    assert_eq!(links.chain(2), vec![0, 2]);
+    assert_eq!(links.chain(1), vec![1]);
+}
+
+#[test]
+fn reused_session_id_continues_latest_session_with_that_id() {
+    let links = detect(&[
+        session("a", vec![entry("a", "a1", None)]),
+        session("b", vec![entry("b", "b1", None)]),
+        session("a", vec![entry("a", "a2", None)]),
+    ]);
+
+    assert_eq!(links.parent(2), Some(0));
+    assert_eq!(links.depth(2), 1);
+}
+
+#[test]
+fn tree_order_lists_each_chain_depth_first() {
+    // 0 ← 2 ← 3, 1 alone, 0 ← 4
+    let links = detect(&[
+        session("a", vec![entry("a", "a1", None)]),
+        session("b", vec![entry("b", "b1", None)]),
+        session("c", vec![entry("c", "c1", Some("a1"))]),
+        session("d", vec![entry("d", "d1", Some("c1"))]),
+        session("e", vec![entry("e", "e1", Some("a1"))]),
+    ]);
+
+    assert_eq!(links.tree_order(), vec![0, 2, 3, 4, 1]);
+    assert_eq!(links.root(3), 0);
+    assert_eq!(links.depth(3), 2);
+    assert_eq!(links.chain(1), vec![1]);
+    assert_eq!(links.chain(4), vec![0, 2, 3, 4]);
+    assert_eq!(links.len(), 5);
+}
+
+#[test]
+fn transcript_parent_uuid_links_parsed_sessions() {
+    // First line of each transcript file; the resumed one chains onto the other
+    let first = crate::parser::parse_entry(
+        r#"{"type":"user","sessionId":"a","uuid":"a1","parentUuid":null,"timestamp":"2025-01-15T14:00:00Z","message":{"role":"user","content":"Fix the parser"}}"#,
+        1,
+    )
+    .unwrap();
+    let resumed = crate::parser::parse_entry(
+        r#"{"type":"user","sessionId":"b","uuid":"b1","parentUuid":"a1","timestamp":"2025-01-16T09:00:00Z","message":{"role":"user","content":"Carry on"}}"#,
+        1,
+    )
+    .unwrap();
+
+    assert_eq!(resumed.parent_uuid(), None, "not a subagent entry");
+    let links = detect(&[session("a", vec![first]), session("b", vec![resumed])]);
+
+    assert_eq!(links.parent(1), Some(0));
+}
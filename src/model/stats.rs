@@ -3,7 +3,12 @@
 //! This module provides aggregated statistics for sessions, including token usage,
 //! tool counts, and estimated costs based on pricing configuration.
 
-use crate::model::{AgentId, LogEntry, SessionId, TokenUsage, ToolName};
+use crate::model::file_activity::{FileActivity, FileTouch, file_access};
+use crate::model::tool_stats::{PendingToolCall, ToolStats};
+use crate::model::{
+    AgentId, ContentBlock, LogEntry, MessageContent, SessionId, TokenUsage, ToolName, ToolUseId,
+};
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
 // ===== SessionStats =====
@@ -24,10 +29,11 @@ use std::collections::HashMap;
 /// # Cost Calculation
 ///
 /// Estimated costs are calculated using `estimated_cost()` which applies pricing
-/// configuration (FR-017, FR-046, FR-047) to token counts:
+/// configuration (FR-017, FR-046, FR-047) to token counts. Usage is tracked per
+/// model at every filter scope, and each model is priced at its own rates:
 /// - Input tokens at model's input rate (FR-015)
 /// - Output tokens at model's output rate (FR-016)
-/// - Cached tokens (creation + read) at cached rate when available
+/// - Cache writes at the 5-minute or 1-hour write rate, cache reads at the read rate
 ///
 /// See `PricingConfig` and `ModelPricing` for cost rates.
 ///
@@ -49,7 +55,8 @@ pub struct SessionStats {
     /// Token usage for the main agent only (excludes subagents).
     ///
     /// Corresponds to StatsFilter::MainAgent (FR-020). Incremented only
-    /// for log entries where `agent_id` is `None`.
+    /// for log entries where `agent_id` is `None`. Like every usage total here
+    /// it includes cache tokens.
     pub main_agent_usage: TokenUsage,
 
     /// Token usage per subagent, keyed by AgentId.
@@ -70,6 +77,21 @@ pub struct SessionStats {
     /// agent usage (agent_id == None) for specific sessions.
     pub main_agent_usage_by_session: HashMap<SessionId, TokenUsage>,
 
+    /// Token usage per model across all sessions and agents, keyed by `ModelInfo::id`.
+    ///
+    /// Only entries that report a model are attributed; the remainder of
+    /// `total_usage` is priced with the fallback model (see `estimated_cost`).
+    pub model_usage: HashMap<String, TokenUsage>,
+
+    /// Per-model usage for each session (main + all subagents).
+    pub session_model_usage: HashMap<SessionId, HashMap<String, TokenUsage>>,
+
+    /// Per-model usage for each session's main agent.
+    pub main_agent_model_usage_by_session: HashMap<SessionId, HashMap<String, TokenUsage>>,
+
+    /// Per-model usage for each subagent.
+    pub subagent_model_usage: HashMap<AgentId, HashMap<String, TokenUsage>>,
+
     /// Total tool invocation counts across all agents, grouped by tool name (FR-018).
     ///
     /// Tracks how many times each tool (Read, Write, Bash, etc.) was invoked across
@@ -88,6 +110,17 @@ pub struct SessionStats {
     /// filtering to show which tools a specific subagent invoked.
     pub subagent_tool_counts: HashMap<AgentId, HashMap<ToolName, u32>>,
 
+    /// Per-session tool invocation counts (main + all subagents for each session).
+    ///
+    /// Used for StatsFilter::Session(id) filtering.
+    pub session_tool_counts: HashMap<SessionId, HashMap<ToolName, u32>>,
+
+    /// Main agent tool invocation counts by session.
+    ///
+    /// Used for StatsFilter::MainAgent(session_id) filtering. Tracks only tools
+    /// called by the main agent (agent_id == None) for specific sessions.
+    pub main_agent_tool_counts_by_session: HashMap<SessionId, HashMap<ToolName, u32>>,
+
     /// Number of unique subagents spawned during the session (FR-019).
     ///
     /// Derived from the number of unique keys in `subagent_usage`. Updated
@@ -110,6 +143,42 @@ pub struct SessionStats {
     /// `None` until a result entry is encountered. Updated to the latest result
     /// entry's cost if multiple result entries are seen.
     pub actual_cost_usd: Option<f64>,
+
+    /// Reported cost per session, from each session's latest result entry.
+    ///
+    /// Used by `filtered_cost_reconciliation` to compare against the
+    /// token-based estimate for a single session.
+    pub session_actual_cost_usd: HashMap<SessionId, f64>,
+
+    /// One sample per entry that reported usage, in recording order.
+    ///
+    /// Feeds the stats panel timeline charts (output tokens per turn,
+    /// cumulative cost, context fill). See `filtered_timeline`.
+    pub timeline: Vec<TimelineSample>,
+
+    /// Per-tool reliability metrics across all sessions and agents.
+    ///
+    /// Calls are counted from tool_use blocks; errors, latency and result
+    /// size come from tool_result blocks matched via `pending_tool_calls`.
+    pub tool_stats: HashMap<ToolName, ToolStats>,
+
+    /// Per-tool reliability metrics per session (main agent + subagents).
+    pub session_tool_stats: HashMap<SessionId, HashMap<ToolName, ToolStats>>,
+
+    /// Per-tool reliability metrics for the main agent, per session.
+    pub main_agent_tool_stats_by_session: HashMap<SessionId, HashMap<ToolName, ToolStats>>,
+
+    /// Per-tool reliability metrics per subagent.
+    pub subagent_tool_stats: HashMap<AgentId, HashMap<ToolName, ToolStats>>,
+
+    /// Tool calls still waiting for their tool_result, keyed by tool_use id.
+    pub pending_tool_calls: HashMap<ToolUseId, PendingToolCall>,
+
+    /// Files touched by file tool calls (Read, Write, Edit, Glob, ...), keyed by path.
+    ///
+    /// Each touch keeps its session and agent, so scopes are derived on demand
+    /// by `filtered_files`.
+    pub files: HashMap<String, FileActivity>,
 }
 
 impl SessionStats {
@@ -121,6 +190,9 @@ impl SessionStats {
     /// - Accumulates token usage to session_usage (per-session totals)
     /// - Routes usage to main_agent_usage or subagent_usage based on agent_id
     /// - Routes main agent usage to main_agent_usage_by_session (per-session main agent)
+    /// - Attributes usage to the entry's model at every scope (if the entry reports one)
+    /// - Appends a `TimelineSample` for entries that report usage
+    /// - Updates per-tool reliability stats (calls, and results matched to earlier calls)
     /// - Counts tool calls from the message
     /// - Updates subagent_count from unique subagents
     pub fn record_entry(&mut self, entry: &LogEntry) {
@@ -128,49 +200,81 @@ impl SessionStats {
         self.entry_count += 1;
 
         // Extract and accumulate usage if present
-        if let Some(usage) = entry.message().usage() {
+        if let Some(&usage) = entry.message().usage() {
+            let model_id = entry.message().model().map(|m| m.id().to_string());
+
             // Accumulate to total
-            self.total_usage.input_tokens += usage.input_tokens;
-            self.total_usage.output_tokens += usage.output_tokens;
-            self.total_usage.cache_creation_input_tokens += usage.cache_creation_input_tokens;
-            self.total_usage.cache_read_input_tokens += usage.cache_read_input_tokens;
+            self.total_usage += usage;
 
             // Accumulate to session usage (all agents for this session)
-            let session_usage = self
+            *self
                 .session_usage
                 .entry(entry.session_id().clone())
-                .or_default();
-            session_usage.input_tokens += usage.input_tokens;
-            session_usage.output_tokens += usage.output_tokens;
-            session_usage.cache_creation_input_tokens += usage.cache_creation_input_tokens;
-            session_usage.cache_read_input_tokens += usage.cache_read_input_tokens;
+                .or_default() += usage;
 
             // Route to main agent or subagent
             if let Some(agent_id) = entry.agent_id() {
                 // Subagent usage
-                let agent_usage = self.subagent_usage.entry(agent_id.clone()).or_default();
-                agent_usage.input_tokens += usage.input_tokens;
-                agent_usage.output_tokens += usage.output_tokens;
+                *self.subagent_usage.entry(agent_id.clone()).or_default() += usage;
             } else {
                 // Main agent usage (global)
-                self.main_agent_usage.input_tokens += usage.input_tokens;
-                self.main_agent_usage.output_tokens += usage.output_tokens;
+                self.main_agent_usage += usage;
 
                 // Main agent usage by session
-                let main_session_usage = self
+                *self
                     .main_agent_usage_by_session
                     .entry(entry.session_id().clone())
-                    .or_default();
-                main_session_usage.input_tokens += usage.input_tokens;
-                main_session_usage.output_tokens += usage.output_tokens;
+                    .or_default() += usage;
+            }
+
+            self.timeline.push(TimelineSample {
+                session_id: entry.session_id().clone(),
+                agent_id: entry.agent_id().cloned(),
+                timestamp: entry.timestamp(),
+                model_id: model_id.clone(),
+                usage,
+            });
+
+            // Per-model usage at every scope (entries without a model stay unattributed)
+            if let Some(model_id) = model_id {
+                *self.model_usage.entry(model_id.clone()).or_default() += usage;
+                *self
+                    .session_model_usage
+                    .entry(entry.session_id().clone())
+                    .or_default()
+                    .entry(model_id.clone())
+                    .or_default() += usage;
+                if let Some(agent_id) = entry.agent_id() {
+                    *self
+                        .subagent_model_usage
+                        .entry(agent_id.clone())
+                        .or_default()
+                        .entry(model_id)
+                        .or_default() += usage;
+                } else {
+                    *self
+                        .main_agent_model_usage_by_session
+                        .entry(entry.session_id().clone())
+                        .or_default()
+                        .entry(model_id)
+                        .or_default() += usage;
+                }
             }
         }
 
-        // Count tool calls (global and per-agent)
+        // Count tool calls (global, per-session and per-agent)
         for tool in entry.message().tool_calls() {
             // Global count
             *self.tool_counts.entry(tool.name().clone()).or_default() += 1;
 
+            // Per-session count
+            *self
+                .session_tool_counts
+                .entry(entry.session_id().clone())
+                .or_default()
+                .entry(tool.name().clone())
+                .or_default() += 1;
+
             // Per-agent count
             if let Some(agent_id) = entry.agent_id() {
                 // Subagent tool count
@@ -180,11 +284,76 @@ impl SessionStats {
                     .or_default();
                 *agent_tools.entry(tool.name().clone()).or_default() += 1;
             } else {
-                // Main agent tool count
+                // Main agent tool count (all sessions and per session)
                 *self
                     .main_agent_tool_counts
                     .entry(tool.name().clone())
                     .or_default() += 1;
+                *self
+                    .main_agent_tool_counts_by_session
+                    .entry(entry.session_id().clone())
+                    .or_default()
+                    .entry(tool.name().clone())
+                    .or_default() += 1;
+            }
+        }
+
+        // Files touched by file tool calls
+        for tool in entry.message().tool_calls() {
+            if let Some((path, access)) = file_access(tool) {
+                self.files
+                    .entry(path.clone())
+                    .or_insert_with(|| FileActivity::new(path))
+                    .touches
+                    .push(FileTouch {
+                        access,
+                        tool: tool.name().clone(),
+                        session_id: entry.session_id().clone(),
+                        agent_id: entry.agent_id().cloned(),
+                        entry_uuid: entry.uuid().clone(),
+                        timestamp: entry.timestamp(),
+                    });
+            }
+        }
+
+        // Per-tool reliability: count calls now, match results to earlier calls
+        for tool in entry.message().tool_calls() {
+            self.pending_tool_calls.insert(
+                tool.id().clone(),
+                PendingToolCall {
+                    name: tool.name().clone(),
+                    session_id: entry.session_id().clone(),
+                    agent_id: entry.agent_id().cloned(),
+                    timestamp: entry.timestamp(),
+                },
+            );
+            let (session_id, agent_id) = (entry.session_id(), entry.agent_id());
+            for scope in self.tool_stats_scopes_mut(session_id, agent_id, tool.name()) {
+                scope.record_call();
+            }
+        }
+        if let MessageContent::Blocks(blocks) = entry.message().content() {
+            for block in blocks {
+                let ContentBlock::ToolResult {
+                    tool_use_id,
+                    content,
+                    is_error,
+                } = block
+                else {
+                    continue;
+                };
+                let Some(call) = self.pending_tool_calls.remove(tool_use_id) else {
+                    continue;
+                };
+                let latency_ms = (entry.timestamp() - call.timestamp)
+                    .num_milliseconds()
+                    .max(0);
+                let chars = content.chars().count() as u64;
+                for scope in
+                    self.tool_stats_scopes_mut(&call.session_id, call.agent_id.as_ref(), &call.name)
+                {
+                    scope.record_result(*is_error, latency_ms as u64, chars);
+                }
             }
         }
 
@@ -194,37 +363,107 @@ impl SessionStats {
         // Extract actual cost from result entry metadata (FMT-010)
         if let Some(result_meta) = entry.result_metadata() {
             self.actual_cost_usd = Some(result_meta.total_cost_usd);
+            self.session_actual_cost_usd
+                .insert(entry.session_id().clone(), result_meta.total_cost_usd);
         }
     }
 
-    /// Calculate estimated cost in USD using the provided pricing configuration.
+    /// Calculate estimated cost in USD across all sessions and agents.
     ///
-    /// Cost includes:
-    /// - Input tokens at input rate
-    /// - Output tokens at output rate
-    /// - Cached tokens (cache_creation + cache_read) at cached rate (or input rate if not specified)
-    ///
-    /// Model pricing is determined by matching the model_id string (e.g., "opus", "sonnet").
+    /// Shorthand for `filtered_estimated_cost` with `StatsFilter::AllSessionsCombined`.
     pub fn estimated_cost(&self, pricing: &PricingConfig, model_id: Option<&str>) -> f64 {
-        let model_pricing = pricing.get(model_id.unwrap_or("opus"));
-
-        let input_cost = (self.total_usage.input_tokens as f64 / 1_000_000.0)
-            * model_pricing.input_cost_per_million;
-
-        let output_cost = (self.total_usage.output_tokens as f64 / 1_000_000.0)
-            * model_pricing.output_cost_per_million;
+        self.filtered_estimated_cost(&StatsFilter::AllSessionsCombined, pricing, model_id)
+    }
 
-        // Use cached rate if available, otherwise use standard input rate
-        let cache_rate = model_pricing
-            .cached_input_cost_per_million
-            .unwrap_or(model_pricing.input_cost_per_million);
+    /// Calculate estimated cost in USD for the given filter scope.
+    ///
+    /// Each model's usage is priced with that model's own `ModelPricing`.
+    /// Usage not attributed to any model (entries without `message.model`) is
+    /// priced with `fallback_model_id` (defaults to "opus" if None).
+    pub fn filtered_estimated_cost(
+        &self,
+        filter: &StatsFilter,
+        pricing: &PricingConfig,
+        fallback_model_id: Option<&str>,
+    ) -> f64 {
+        let by_model = self.filtered_model_usage(filter);
+
+        let attributed = by_model
+            .values()
+            .fold(TokenUsage::default(), |acc, usage| acc + *usage);
+        let unattributed = self.filtered_usage(filter).saturating_sub(&attributed);
+
+        let model_cost: f64 = by_model
+            .iter()
+            .map(|(model_id, usage)| pricing.get(model_id).cost(usage))
+            .sum();
+
+        model_cost
+            + pricing
+                .get(fallback_model_id.unwrap_or("opus"))
+                .cost(&unattributed)
+    }
+
+    /// Get the reported cost for the given filter scope.
+    ///
+    /// Result entries report cost for a whole session, so only
+    /// `AllSessionsCombined` (sum over sessions) and `Session` have a
+    /// reported cost. Agent-level filters return `None`.
+    pub fn filtered_actual_cost(&self, filter: &StatsFilter) -> Option<f64> {
+        match filter {
+            StatsFilter::AllSessionsCombined => {
+                if self.session_actual_cost_usd.is_empty() {
+                    None
+                } else {
+                    Some(self.session_actual_cost_usd.values().sum())
+                }
+            }
+            StatsFilter::Session(session_id) => {
+                self.session_actual_cost_usd.get(session_id).copied()
+            }
+            StatsFilter::MainAgent(_) | StatsFilter::Subagent(_) => None,
+        }
+    }
 
-        let cache_cost = ((self.total_usage.cache_creation_input_tokens
-            + self.total_usage.cache_read_input_tokens) as f64
-            / 1_000_000.0)
-            * cache_rate;
+    /// Compare reported and estimated cost for the given filter scope.
+    ///
+    /// Returns `None` when the scope has no reported cost (see
+    /// `filtered_actual_cost`).
+    pub fn filtered_cost_reconciliation(
+        &self,
+        filter: &StatsFilter,
+        pricing: &PricingConfig,
+        fallback_model_id: Option<&str>,
+    ) -> Option<CostReconciliation> {
+        let reported = self.filtered_actual_cost(filter)?;
+        let estimated = self.filtered_estimated_cost(filter, pricing, fallback_model_id);
+        Some(CostReconciliation::new(reported, estimated))
+    }
+
+    /// Get per-model token usage for the given filter scope.
+    ///
+    /// Keys are full model IDs (`ModelInfo::id`). Empty if no entry in scope
+    /// reported a model.
+    pub fn filtered_model_usage(&self, filter: &StatsFilter) -> &HashMap<String, TokenUsage> {
+        use std::sync::OnceLock;
+        static EMPTY: OnceLock<HashMap<String, TokenUsage>> = OnceLock::new();
+        let empty = || EMPTY.get_or_init(HashMap::new);
 
-        input_cost + output_cost + cache_cost
+        match filter {
+            StatsFilter::AllSessionsCombined => &self.model_usage,
+            StatsFilter::Session(session_id) => self
+                .session_model_usage
+                .get(session_id)
+                .unwrap_or_else(empty),
+            StatsFilter::MainAgent(session_id) => self
+                .main_agent_model_usage_by_session
+                .get(session_id)
+                .unwrap_or_else(empty),
+            StatsFilter::Subagent(agent_id) => self
+                .subagent_model_usage
+                .get(agent_id)
+                .unwrap_or_else(empty),
+        }
     }
 
     /// Get filtered token usage based on the current stats filter.
@@ -255,25 +494,223 @@ impl SessionStats {
         }
     }
 
+    /// Per-tool stats entries to update for a call or result: global,
+    /// session, and main agent or subagent.
+    fn tool_stats_scopes_mut(
+        &mut self,
+        session_id: &SessionId,
+        agent_id: Option<&AgentId>,
+        tool: &ToolName,
+    ) -> [&mut ToolStats; 3] {
+        let agent_scope = match agent_id {
+            Some(agent_id) => self
+                .subagent_tool_stats
+                .entry(agent_id.clone())
+                .or_default(),
+            None => self
+                .main_agent_tool_stats_by_session
+                .entry(session_id.clone())
+                .or_default(),
+        };
+        [
+            self.tool_stats.entry(tool.clone()).or_default(),
+            self.session_tool_stats
+                .entry(session_id.clone())
+                .or_default()
+                .entry(tool.clone())
+                .or_default(),
+            agent_scope.entry(tool.clone()).or_default(),
+        ]
+    }
+
+    /// Get per-tool reliability stats for the given filter scope.
+    ///
+    /// Empty if no tool was called in scope.
+    pub fn filtered_tool_stats(&self, filter: &StatsFilter) -> &HashMap<ToolName, ToolStats> {
+        use std::sync::OnceLock;
+        static EMPTY: OnceLock<HashMap<ToolName, ToolStats>> = OnceLock::new();
+        let empty = || EMPTY.get_or_init(HashMap::new);
+
+        match filter {
+            StatsFilter::AllSessionsCombined => &self.tool_stats,
+            StatsFilter::Session(session_id) => self
+                .session_tool_stats
+                .get(session_id)
+                .unwrap_or_else(empty),
+            StatsFilter::MainAgent(session_id) => self
+                .main_agent_tool_stats_by_session
+                .get(session_id)
+                .unwrap_or_else(empty),
+            StatsFilter::Subagent(agent_id) => {
+                self.subagent_tool_stats.get(agent_id).unwrap_or_else(empty)
+            }
+        }
+    }
+
+    /// Get timeline samples in the given filter scope, ordered by timestamp.
+    ///
+    /// Entries are recorded per conversation (main agent, then each
+    /// subagent), so samples are re-sorted to interleave agents in time.
+    pub fn filtered_timeline(&self, filter: &StatsFilter) -> Vec<&TimelineSample> {
+        let mut samples: Vec<&TimelineSample> = self
+            .timeline
+            .iter()
+            .filter(|sample| filter.includes(&sample.session_id, sample.agent_id.as_ref()))
+            .collect();
+        samples.sort_by_key(|sample| sample.timestamp);
+        samples
+    }
+
+    /// Get the files touched in the given filter scope, sorted by path.
+    ///
+    /// Each activity keeps only the touches inside the scope, ordered by
+    /// timestamp. Files with no touches in scope are omitted.
+    pub fn filtered_files(&self, filter: &StatsFilter) -> Vec<FileActivity> {
+        let mut files: Vec<FileActivity> = self
+            .files
+            .values()
+            .filter_map(|activity| {
+                let mut touches: Vec<FileTouch> = activity
+                    .touches
+                    .iter()
+                    .filter(|t| filter.includes(&t.session_id, t.agent_id.as_ref()))
+                    .cloned()
+                    .collect();
+                if touches.is_empty() {
+                    return None;
+                }
+                touches.sort_by_key(|t| t.timestamp);
+                Some(FileActivity {
+                    path: activity.path.clone(),
+                    touches,
+                })
+            })
+            .collect();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        files
+    }
+
     /// Get filtered tool counts based on the current stats filter.
     ///
     /// Returns:
     /// - `StatsFilter::AllSessionsCombined`: tool_counts (all agents)
-    /// - `StatsFilter::Session(session_id)`: TODO - session-scoped tool counts
-    /// - `StatsFilter::MainAgent(session_id)`: main_agent_tool_counts only
-    /// - `StatsFilter::Subagent(id)`: tool_counts for specific subagent, or empty if not found
+    /// - `StatsFilter::Session(session_id)`: session_tool_counts for specific session
+    /// - `StatsFilter::MainAgent(session_id)`: main_agent_tool_counts_by_session for specific session
+    /// - `StatsFilter::Subagent(id)`: tool_counts for specific subagent
+    ///
+    /// Unknown sessions and subagents yield an empty map.
     pub fn filtered_tool_counts(&self, filter: &StatsFilter) -> &HashMap<ToolName, u32> {
         use std::sync::OnceLock;
         static EMPTY: OnceLock<HashMap<ToolName, u32>> = OnceLock::new();
+        let empty = || EMPTY.get_or_init(HashMap::new);
 
         match filter {
             StatsFilter::AllSessionsCombined => &self.tool_counts,
-            StatsFilter::Session(_session_id) => todo!("Session-scoped tool counts"),
-            StatsFilter::MainAgent(_session_id) => &self.main_agent_tool_counts,
+            StatsFilter::Session(session_id) => self
+                .session_tool_counts
+                .get(session_id)
+                .unwrap_or_else(empty),
+            StatsFilter::MainAgent(session_id) => self
+                .main_agent_tool_counts_by_session
+                .get(session_id)
+                .unwrap_or_else(empty),
             StatsFilter::Subagent(agent_id) => self
                 .subagent_tool_counts
                 .get(agent_id)
-                .unwrap_or_else(|| EMPTY.get_or_init(HashMap::new)),
+                .unwrap_or_else(empty),
+        }
+    }
+}
+
+// ===== TimelineSample =====
+
+/// Token usage of a single entry, positioned in time for the timeline charts.
+#[derive(Debug, Clone)]
+pub struct TimelineSample {
+    /// Session the entry belongs to.
+    pub session_id: SessionId,
+
+    /// Subagent that produced the entry (`None` for the main agent).
+    pub agent_id: Option<AgentId>,
+
+    /// Entry timestamp.
+    pub timestamp: DateTime<Utc>,
+
+    /// Model that produced the entry, if reported.
+    pub model_id: Option<String>,
+
+    /// Usage reported by the entry.
+    pub usage: TokenUsage,
+}
+
+impl TimelineSample {
+    /// Tokens occupying the context window for this turn (input + cache).
+    pub fn context_tokens(&self) -> u64 {
+        self.usage.total_input()
+    }
+
+    /// Cost of this entry, priced by its own model or `fallback_model_id`
+    /// (defaults to "opus").
+    pub fn cost(&self, pricing: &PricingConfig, fallback_model_id: Option<&str>) -> f64 {
+        let model_id = self
+            .model_id
+            .as_deref()
+            .or(fallback_model_id)
+            .unwrap_or("opus");
+        pricing.get(model_id).cost(&self.usage)
+    }
+}
+
+// ===== ChartAxis =====
+
+/// X axis of the stats panel timeline charts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartAxis {
+    /// One step per turn (entry with usage).
+    #[default]
+    Turn,
+
+    /// Wall-clock time of each entry.
+    WallClock,
+}
+
+impl ChartAxis {
+    /// Switch to the other axis.
+    pub fn toggle(self) -> Self {
+        match self {
+            ChartAxis::Turn => ChartAxis::WallClock,
+            ChartAxis::WallClock => ChartAxis::Turn,
+        }
+    }
+
+    /// Short label for chart captions.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChartAxis::Turn => "turn",
+            ChartAxis::WallClock => "time",
+        }
+    }
+}
+
+// ===== StatsPage =====
+
+/// Page shown in the stats panel body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsPage {
+    /// Token totals, cost, charts and per-model table.
+    #[default]
+    Overview,
+
+    /// Per-tool reliability table.
+    Tools,
+}
+
+impl StatsPage {
+    /// Next page, wrapping around.
+    pub fn next(self) -> Self {
+        match self {
+            StatsPage::Overview => StatsPage::Tools,
+            StatsPage::Tools => StatsPage::Overview,
         }
     }
 }
@@ -314,6 +751,43 @@ impl StatsFilter {
         }
     }
 
+    /// Whether an entry from `session_id` / `agent_id` falls in this scope.
+    pub fn includes(&self, session_id: &SessionId, agent_id: Option<&AgentId>) -> bool {
+        match self {
+            StatsFilter::AllSessionsCombined => true,
+            StatsFilter::Session(id) => session_id == id,
+            StatsFilter::MainAgent(id) => session_id == id && agent_id.is_none(),
+            StatsFilter::Subagent(id) => agent_id == Some(id),
+        }
+    }
+
+    /// Parse a scope as given on the command line: `all`, `session:<id>`,
+    /// `main:<session-id>` or `subagent:<agent-id>`.
+    pub fn parse(scope: &str) -> Result<Self, String> {
+        let invalid = || {
+            format!(
+                "invalid scope '{}' (expected all, session:<id>, main:<session-id> or subagent:<agent-id>)",
+                scope
+            )
+        };
+        if scope == "all" {
+            return Ok(StatsFilter::AllSessionsCombined);
+        }
+        let (kind, id) = scope.split_once(':').ok_or_else(invalid)?;
+        match kind {
+            "session" => SessionId::new(id)
+                .map(StatsFilter::Session)
+                .map_err(|_| invalid()),
+            "main" => SessionId::new(id)
+                .map(StatsFilter::MainAgent)
+                .map_err(|_| invalid()),
+            "subagent" => AgentId::new(id)
+                .map(StatsFilter::Subagent)
+                .map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+
     /// Get the short label for display in status bar.
     pub fn short_label(&self) -> &'static str {
         match self {
@@ -325,8 +799,63 @@ impl StatsFilter {
     }
 }
 
+// ===== CostReconciliation =====
+
+/// Reported session cost (from result entries) next to the token-based estimate.
+///
+/// A large delta means the pricing table is stale or usage is being
+/// double counted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostReconciliation {
+    /// Cost reported by the result entry (`total_cost_usd`).
+    pub reported: f64,
+
+    /// Cost estimated from token usage and `PricingConfig`.
+    pub estimated: f64,
+}
+
+impl CostReconciliation {
+    /// Create a reconciliation from reported and estimated cost.
+    pub fn new(reported: f64, estimated: f64) -> Self {
+        Self {
+            reported,
+            estimated,
+        }
+    }
+
+    /// Estimated minus reported cost in USD (positive means we over-estimate).
+    pub fn delta(&self) -> f64 {
+        self.estimated - self.reported
+    }
+
+    /// Delta as a percentage of the reported cost.
+    ///
+    /// `None` if the reported cost is zero.
+    pub fn delta_percent(&self) -> Option<f64> {
+        if self.reported > 0.0 {
+            Some(self.delta() / self.reported * 100.0)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the estimate diverges from the reported cost by more than
+    /// `tolerance_percent`.
+    ///
+    /// With a zero reported cost, any non-zero estimate is out of tolerance.
+    pub fn exceeds_tolerance(&self, tolerance_percent: f64) -> bool {
+        match self.delta_percent() {
+            Some(percent) => percent.abs() > tolerance_percent,
+            None => self.estimated > 0.0,
+        }
+    }
+}
+
 // ===== PricingConfig =====
 
+/// Default tolerance (percent) before estimated and reported cost are flagged.
+pub const DEFAULT_COST_TOLERANCE_PERCENT: f64 = 10.0;
+
 /// Pricing configuration for cost estimation.
 ///
 /// Contains pricing for known model families (opus, sonnet, haiku) and a default fallback.
@@ -336,6 +865,7 @@ pub struct PricingConfig {
     models: HashMap<String, ModelPricing>,
     #[allow(dead_code)]
     default_pricing: ModelPricing,
+    cost_tolerance_percent: f64,
 }
 
 impl Default for PricingConfig {
@@ -345,22 +875,23 @@ impl Default for PricingConfig {
         // Claude Opus 4.5 - $5/$25 per million tokens (Jan 2026)
         models.insert(
             "opus".to_string(),
-            ModelPricing::new(5.0, 25.0).with_cache(0.5),
+            ModelPricing::new(5.0, 25.0).with_cache_rates(6.25, 10.0, 0.5),
         );
         // Claude Sonnet 4.5 - $3/$15 per million tokens
         models.insert(
             "sonnet".to_string(),
-            ModelPricing::new(3.0, 15.0).with_cache(0.3),
+            ModelPricing::new(3.0, 15.0).with_cache_rates(3.75, 6.0, 0.3),
         );
         // Claude Haiku 4.5 - $1/$5 per million tokens
         models.insert(
             "haiku".to_string(),
-            ModelPricing::new(1.0, 5.0).with_cache(0.1),
+            ModelPricing::new(1.0, 5.0).with_cache_rates(1.25, 2.0, 0.1),
         );
 
         Self {
             models,
             default_pricing: ModelPricing::new(5.0, 25.0), // Opus as fallback
+            cost_tolerance_percent: DEFAULT_COST_TOLERANCE_PERCENT,
         }
     }
 }
@@ -390,30 +921,55 @@ impl PricingConfig {
 
         &self.default_pricing
     }
+
+    /// Allowed divergence (percent) between estimated and reported cost.
+    pub fn cost_tolerance_percent(&self) -> f64 {
+        self.cost_tolerance_percent
+    }
+
+    /// Set the allowed divergence (percent) between estimated and reported cost.
+    pub fn with_cost_tolerance_percent(mut self, tolerance_percent: f64) -> Self {
+        self.cost_tolerance_percent = tolerance_percent;
+        self
+    }
 }
 
 // ===== ModelPricing =====
 
+/// Cache write (5-minute TTL) rate as a multiple of the input rate, used when unset.
+pub const CACHE_WRITE_5M_MULTIPLIER: f64 = 1.25;
+
+/// Cache write (1-hour TTL) rate as a multiple of the input rate, used when unset.
+pub const CACHE_WRITE_1H_MULTIPLIER: f64 = 2.0;
+
+/// Cache read rate as a multiple of the input rate, used when unset.
+pub const CACHE_READ_MULTIPLIER: f64 = 0.1;
+
 /// Pricing for a specific model (per million tokens, in USD).
 ///
 /// Used by `SessionStats::estimated_cost` to calculate session costs based on
 /// token usage (FR-017). Pricing is applied to token counts from `TokenUsage`:
 /// - `input_cost_per_million` applies to `input_tokens`
 /// - `output_cost_per_million` applies to `output_tokens`
-/// - `cached_input_cost_per_million` applies to `cache_creation_input_tokens`
-///   and `cache_read_input_tokens` (falls back to `input_cost_per_million` if None)
+/// - `cache_write_5m_cost_per_million` applies to `ephemeral_5m_input_tokens`, plus any
+///   `cache_creation_input_tokens` without a TTL breakdown
+/// - `cache_write_1h_cost_per_million` applies to `ephemeral_1h_input_tokens`
+/// - `cache_read_cost_per_million` applies to `cache_read_input_tokens`
+///
+/// Unset cache rates fall back to the standard multiples of the input rate
+/// (1.25x for 5m writes, 2x for 1h writes, 0.1x for reads).
 ///
 /// # Default Pricing (FR-046)
 ///
-/// Hardcoded defaults in `PricingConfig::default()` (Jan 2026 pricing):
-/// - **Claude Opus 4.5**: $5 input / $25 output / $0.50 cached (per million)
-/// - **Claude Sonnet 4.5**: $3 input / $15 output / $0.30 cached (per million)
-/// - **Claude Haiku 4.5**: $1 input / $5 output / $0.10 cached (per million)
+/// Hardcoded defaults in `PricingConfig::default()` (Jan 2026 pricing, per million):
+/// - **Claude Opus 4.5**: $5 input / $25 output / $6.25 5m write / $10 1h write / $0.50 read
+/// - **Claude Sonnet 4.5**: $3 input / $15 output / $3.75 5m write / $6 1h write / $0.30 read
+/// - **Claude Haiku 4.5**: $1 input / $5 output / $1.25 5m write / $2 1h write / $0.10 read
 ///
 /// # Configuration (FR-047)
 ///
-/// Pricing MAY be overridden via configuration file, though this is currently
-/// not implemented. Default pricing is always available as fallback.
+/// Pricing may be overridden per model via the `[pricing]` config section.
+/// Default pricing is always available as fallback.
 #[derive(Debug, Clone, Copy)]
 pub struct ModelPricing {
     /// Cost per million input tokens in USD (FR-015, FR-017).
@@ -428,32 +984,83 @@ pub struct ModelPricing {
     /// by the model in responses.
     pub output_cost_per_million: f64,
 
-    /// Optional cost per million cached input tokens in USD (FR-017).
+    /// Optional cost per million tokens written to the 5-minute prompt cache.
+    ///
+    /// Falls back to `CACHE_WRITE_5M_MULTIPLIER` x input rate when None.
+    pub cache_write_5m_cost_per_million: Option<f64>,
+
+    /// Optional cost per million tokens written to the 1-hour prompt cache.
     ///
-    /// Applied to both `TokenUsage::cache_creation_input_tokens` and
-    /// `TokenUsage::cache_read_input_tokens`. When None, falls back to
-    /// `input_cost_per_million` for cached token cost calculation.
+    /// Falls back to `CACHE_WRITE_1H_MULTIPLIER` x input rate when None.
+    pub cache_write_1h_cost_per_million: Option<f64>,
+
+    /// Optional cost per million tokens read from the prompt cache.
     ///
-    /// Cached input is typically cheaper than standard input (e.g., 10x reduction
-    /// for Opus: $1.50 vs $15.00 per million).
-    pub cached_input_cost_per_million: Option<f64>,
+    /// Falls back to `CACHE_READ_MULTIPLIER` x input rate when None.
+    pub cache_read_cost_per_million: Option<f64>,
 }
 
 impl ModelPricing {
     /// Create new model pricing with input and output costs.
+    ///
+    /// Cache rates default to the standard multiples of the input rate.
     pub const fn new(input: f64, output: f64) -> Self {
         Self {
             input_cost_per_million: input,
             output_cost_per_million: output,
-            cached_input_cost_per_million: None,
+            cache_write_5m_cost_per_million: None,
+            cache_write_1h_cost_per_million: None,
+            cache_read_cost_per_million: None,
         }
     }
 
-    /// Add cached input pricing.
-    pub const fn with_cache(mut self, cached: f64) -> Self {
-        self.cached_input_cost_per_million = Some(cached);
+    /// Set explicit cache write (5m, 1h) and cache read rates.
+    pub const fn with_cache_rates(mut self, write_5m: f64, write_1h: f64, read: f64) -> Self {
+        self.cache_write_5m_cost_per_million = Some(write_5m);
+        self.cache_write_1h_cost_per_million = Some(write_1h);
+        self.cache_read_cost_per_million = Some(read);
         self
     }
+
+    /// Effective cost per million 5-minute cache write tokens.
+    pub fn cache_write_5m_rate(&self) -> f64 {
+        self.cache_write_5m_cost_per_million
+            .unwrap_or(self.input_cost_per_million * CACHE_WRITE_5M_MULTIPLIER)
+    }
+
+    /// Effective cost per million 1-hour cache write tokens.
+    pub fn cache_write_1h_rate(&self) -> f64 {
+        self.cache_write_1h_cost_per_million
+            .unwrap_or(self.input_cost_per_million * CACHE_WRITE_1H_MULTIPLIER)
+    }
+
+    /// Effective cost per million cache read tokens.
+    pub fn cache_read_rate(&self) -> f64 {
+        self.cache_read_cost_per_million
+            .unwrap_or(self.input_cost_per_million * CACHE_READ_MULTIPLIER)
+    }
+
+    /// Cost in USD of the given token usage at this model's rates.
+    ///
+    /// Cache writes are split by TTL using the ephemeral breakdown. Writes not
+    /// covered by the breakdown (older logs report only the total) are billed
+    /// at the 5-minute rate, the API default.
+    pub fn cost(&self, usage: &TokenUsage) -> f64 {
+        const PER_MILLION: f64 = 1_000_000.0;
+
+        let broken_down = usage.ephemeral_5m_input_tokens + usage.ephemeral_1h_input_tokens;
+        let write_5m = usage.ephemeral_5m_input_tokens
+            + usage
+                .cache_creation_input_tokens
+                .saturating_sub(broken_down);
+        let write_1h = usage.ephemeral_1h_input_tokens;
+
+        (usage.input_tokens as f64 / PER_MILLION) * self.input_cost_per_million
+            + (usage.output_tokens as f64 / PER_MILLION) * self.output_cost_per_million
+            + (write_5m as f64 / PER_MILLION) * self.cache_write_5m_rate()
+            + (write_1h as f64 / PER_MILLION) * self.cache_write_1h_rate()
+            + (usage.cache_read_input_tokens as f64 / PER_MILLION) * self.cache_read_rate()
+    }
 }
 
 // ===== Config Conversions =====
@@ -462,7 +1069,10 @@ impl ModelPricing {
 impl From<crate::config::PricingEntry> for ModelPricing {
     fn from(entry: crate::config::PricingEntry) -> Self {
         let mut pricing = ModelPricing::new(entry.input, entry.output);
-        pricing.cached_input_cost_per_million = entry.cached_input;
+        pricing.cache_write_5m_cost_per_million = entry.cache_write_5m;
+        pricing.cache_write_1h_cost_per_million = entry.cache_write_1h;
+        // `cached_input` is the legacy name for the cache read rate
+        pricing.cache_read_cost_per_million = entry.cache_read.or(entry.cached_input);
         pricing
     }
 }
@@ -486,6 +1096,9 @@ impl From<crate::config::PricingConfigSection> for PricingConfig {
         Self {
             models,
             default_pricing,
+            cost_tolerance_percent: section
+                .cost_tolerance_percent
+                .unwrap_or(DEFAULT_COST_TOLERANCE_PERCENT),
         }
     }
 }
@@ -687,6 +1300,43 @@ mod tests {
         assert_eq!(agent_usage.output_tokens, 125);
     }
 
+    #[test]
+    fn record_entry_counts_cache_tokens_in_agent_usage() {
+        let mut stats = SessionStats::default();
+        let usage = TokenUsage {
+            input_tokens: 10,
+            output_tokens: 20,
+            cache_creation_input_tokens: 300,
+            cache_read_input_tokens: 4000,
+            ephemeral_5m_input_tokens: 300,
+            ephemeral_1h_input_tokens: 0,
+        };
+        stats.record_entry(&make_log_entry(
+            "e1",
+            "s1",
+            None,
+            make_message_with_usage(usage),
+        ));
+        stats.record_entry(&make_log_entry(
+            "e2",
+            "s1",
+            Some("agent-abc"),
+            make_message_with_usage(usage),
+        ));
+
+        // Cache tokens count at every scope, so agent totals add up to the total
+        let main = stats.main_agent_usage;
+        let by_session = stats.main_agent_usage_by_session[&make_session_id("s1")];
+        let subagent = stats.subagent_usage[&make_agent_id("agent-abc")];
+        for scoped in [main, by_session, subagent] {
+            assert_eq!(scoped.cache_creation_input_tokens, 300);
+            assert_eq!(scoped.cache_read_input_tokens, 4000);
+            assert_eq!(scoped.ephemeral_5m_input_tokens, 300);
+            assert_eq!(scoped.total(), 4330);
+        }
+        assert_eq!(stats.total_usage.total(), main.total() + subagent.total());
+    }
+
     #[test]
     fn record_entry_counts_tool_calls() {
         let mut stats = SessionStats::default();
@@ -822,8 +1472,9 @@ mod tests {
 
         let cost = stats.estimated_cost(&pricing, Some("opus"));
 
-        // Opus 4.5: $0.5 per million cached tokens, total 1M cached = $0.5
-        assert_eq!(cost, 0.5);
+        // Opus 4.5: 500k writes (no TTL breakdown -> 5m rate $6.25) = $3.125
+        //           500k reads at $0.50 = $0.25
+        assert_eq!(cost, 3.375);
     }
 
     #[test]
@@ -844,8 +1495,8 @@ mod tests {
 
         let cost = stats.estimated_cost(&pricing, Some("opus"));
 
-        // $5 (input) + $25 (output) + $0.5 (cached) = $30.5
-        assert_eq!(cost, 30.5);
+        // $5 (input) + $25 (output) + $3.125 (cache writes) + $0.25 (cache reads)
+        assert_eq!(cost, 33.375);
     }
 
     #[test]
@@ -945,7 +1596,9 @@ mod tests {
 
         assert_eq!(model_pricing.input_cost_per_million, 5.0);
         assert_eq!(model_pricing.output_cost_per_million, 25.0);
-        assert_eq!(model_pricing.cached_input_cost_per_million, Some(0.5));
+        assert_eq!(model_pricing.cache_write_5m_cost_per_million, Some(6.25));
+        assert_eq!(model_pricing.cache_write_1h_cost_per_million, Some(10.0));
+        assert_eq!(model_pricing.cache_read_cost_per_million, Some(0.5));
     }
 
     #[test]
@@ -955,7 +1608,9 @@ mod tests {
 
         assert_eq!(model_pricing.input_cost_per_million, 3.0);
         assert_eq!(model_pricing.output_cost_per_million, 15.0);
-        assert_eq!(model_pricing.cached_input_cost_per_million, Some(0.3));
+        assert_eq!(model_pricing.cache_write_5m_cost_per_million, Some(3.75));
+        assert_eq!(model_pricing.cache_write_1h_cost_per_million, Some(6.0));
+        assert_eq!(model_pricing.cache_read_cost_per_million, Some(0.3));
     }
 
     #[test]
@@ -965,7 +1620,9 @@ mod tests {
 
         assert_eq!(model_pricing.input_cost_per_million, 1.0);
         assert_eq!(model_pricing.output_cost_per_million, 5.0);
-        assert_eq!(model_pricing.cached_input_cost_per_million, Some(0.1));
+        assert_eq!(model_pricing.cache_write_5m_cost_per_million, Some(1.25));
+        assert_eq!(model_pricing.cache_write_1h_cost_per_million, Some(2.0));
+        assert_eq!(model_pricing.cache_read_cost_per_million, Some(0.1));
     }
 
     #[test]
@@ -1114,6 +1771,29 @@ mod tests {
         assert_eq!(filter.short_label(), "Sub");
     }
 
+    #[test]
+    fn stats_filter_parses_command_line_scopes() {
+        assert_eq!(
+            StatsFilter::parse("all"),
+            Ok(StatsFilter::AllSessionsCombined)
+        );
+        assert_eq!(
+            StatsFilter::parse("session:abc"),
+            Ok(StatsFilter::Session(make_session_id("abc")))
+        );
+        assert_eq!(
+            StatsFilter::parse("main:abc"),
+            Ok(StatsFilter::MainAgent(make_session_id("abc")))
+        );
+        assert_eq!(
+            StatsFilter::parse("subagent:agent-1"),
+            Ok(StatsFilter::Subagent(make_agent_id("agent-1")))
+        );
+        assert!(StatsFilter::parse("session:").is_err());
+        assert!(StatsFilter::parse("agent:x").is_err());
+        assert!(StatsFilter::parse("everything").is_err());
+    }
+
     #[test]
     fn stats_filter_default_is_all_sessions_combined() {
         let filter = StatsFilter::default();
@@ -1128,16 +1808,81 @@ mod tests {
 
         assert_eq!(pricing.input_cost_per_million, 10.0);
         assert_eq!(pricing.output_cost_per_million, 50.0);
-        assert_eq!(pricing.cached_input_cost_per_million, None);
+        assert_eq!(pricing.cache_write_5m_cost_per_million, None);
+        assert_eq!(pricing.cache_write_1h_cost_per_million, None);
+        assert_eq!(pricing.cache_read_cost_per_million, None);
+    }
+
+    #[test]
+    fn model_pricing_unset_cache_rates_use_standard_multipliers() {
+        let pricing = ModelPricing::new(10.0, 50.0);
+
+        assert_eq!(pricing.cache_write_5m_rate(), 12.5);
+        assert_eq!(pricing.cache_write_1h_rate(), 20.0);
+        assert_eq!(pricing.cache_read_rate(), 1.0);
     }
 
     #[test]
-    fn model_pricing_with_cache_adds_cached_cost() {
-        let pricing = ModelPricing::new(10.0, 50.0).with_cache(1.0);
+    fn model_pricing_with_cache_rates_sets_all_rates() {
+        let pricing = ModelPricing::new(10.0, 50.0).with_cache_rates(12.0, 19.0, 0.9);
 
         assert_eq!(pricing.input_cost_per_million, 10.0);
         assert_eq!(pricing.output_cost_per_million, 50.0);
-        assert_eq!(pricing.cached_input_cost_per_million, Some(1.0));
+        assert_eq!(pricing.cache_write_5m_rate(), 12.0);
+        assert_eq!(pricing.cache_write_1h_rate(), 19.0);
+        assert_eq!(pricing.cache_read_rate(), 0.9);
+    }
+
+    #[test]
+    fn model_pricing_cost_splits_cache_writes_by_ttl() {
+        let pricing = ModelPricing::new(5.0, 25.0).with_cache_rates(6.25, 10.0, 0.5);
+        let usage = TokenUsage {
+            cache_creation_input_tokens: 3_000_000,
+            ephemeral_5m_input_tokens: 1_000_000,
+            ephemeral_1h_input_tokens: 1_000_000,
+            cache_read_input_tokens: 2_000_000,
+            ..Default::default()
+        };
+
+        // 5m: 1M + 1M without breakdown at $6.25 = $12.50
+        // 1h: 1M at $10 = $10
+        // read: 2M at $0.50 = $1
+        assert_eq!(pricing.cost(&usage), 23.5);
+    }
+
+    #[test]
+    fn model_pricing_from_config_entry_maps_cache_rates() {
+        let entry = crate::config::PricingEntry {
+            input: 4.0,
+            output: 20.0,
+            cached_input: None,
+            cache_write_5m: Some(5.0),
+            cache_write_1h: Some(8.0),
+            cache_read: Some(0.4),
+        };
+
+        let pricing = ModelPricing::from(entry);
+
+        assert_eq!(pricing.cache_write_5m_rate(), 5.0);
+        assert_eq!(pricing.cache_write_1h_rate(), 8.0);
+        assert_eq!(pricing.cache_read_rate(), 0.4);
+    }
+
+    #[test]
+    fn model_pricing_from_config_entry_treats_cached_input_as_read_rate() {
+        let entry = crate::config::PricingEntry {
+            input: 4.0,
+            output: 20.0,
+            cached_input: Some(0.4),
+            cache_write_5m: None,
+            cache_write_1h: None,
+            cache_read: None,
+        };
+
+        let pricing = ModelPricing::from(entry);
+
+        assert_eq!(pricing.cache_read_rate(), 0.4);
+        assert_eq!(pricing.cache_write_5m_rate(), 5.0);
     }
 
     // ===== SessionStats::filtered_usage Tests =====
@@ -1463,14 +2208,17 @@ mod tests {
         main_counts.insert(ToolName::Read, 6);
         main_counts.insert(ToolName::Write, 4);
 
+        let session_id = make_session_id("test-session");
+        let mut main_counts_by_session = HashMap::new();
+        main_counts_by_session.insert(session_id.clone(), main_counts);
+
         let stats = SessionStats {
             tool_counts: global_counts,
-            main_agent_tool_counts: main_counts,
+            main_agent_tool_counts_by_session: main_counts_by_session,
 
             ..Default::default()
         };
 
-        let session_id = make_session_id("test-session");
         let filter = StatsFilter::MainAgent(session_id);
         let result = stats.filtered_tool_counts(&filter);
 
@@ -1536,20 +2284,132 @@ mod tests {
         assert!(result.is_empty());
     }
 
-    // ===== FMT-010: Result Entry Cost Tracking Tests =====
-
     #[test]
-    fn record_entry_extracts_cost_from_result_entry() {
-        // RED TEST: SessionStats should extract total_cost_usd from Result entry's ResultMetadata
-        use crate::model::{EntryType, ResultMetadata};
-
+    fn record_entry_tracks_tool_counts_per_session() {
         let mut stats = SessionStats::default();
+        let entries = [
+            make_log_entry(
+                "e1",
+                "s1",
+                None,
+                make_message_with_tool_calls(vec![ToolName::Read, ToolName::Read]),
+            ),
+            make_log_entry(
+                "e2",
+                "s1",
+                Some("agent-1"),
+                make_message_with_tool_calls(vec![ToolName::Bash]),
+            ),
+            make_log_entry(
+                "e3",
+                "s2",
+                None,
+                make_message_with_tool_calls(vec![ToolName::Write]),
+            ),
+            make_log_entry(
+                "e4",
+                "s2",
+                Some("agent-2"),
+                make_message_with_tool_calls(vec![ToolName::Read]),
+            ),
+        ];
+        for entry in &entries {
+            stats.record_entry(entry);
+        }
 
-        // Create a Result entry with total_cost_usd = 1.5
-        let result_metadata = ResultMetadata {
-            is_error: false,
-            duration_ms: 100000,
-            num_turns: 10,
+        let s1 = stats.filtered_tool_counts(&StatsFilter::Session(make_session_id("s1")));
+        assert_eq!(s1.get(&ToolName::Read), Some(&2));
+        assert_eq!(s1.get(&ToolName::Bash), Some(&1));
+        assert_eq!(s1.get(&ToolName::Write), None);
+
+        let s2 = stats.filtered_tool_counts(&StatsFilter::Session(make_session_id("s2")));
+        assert_eq!(s2.get(&ToolName::Read), Some(&1));
+        assert_eq!(s2.get(&ToolName::Write), Some(&1));
+        assert_eq!(s2.get(&ToolName::Bash), None);
+
+        // Global counts still combine both sessions
+        assert_eq!(stats.tool_counts.get(&ToolName::Read), Some(&3));
+    }
+
+    #[test]
+    fn record_entry_tracks_main_agent_tool_counts_per_session() {
+        let mut stats = SessionStats::default();
+        let entries = [
+            make_log_entry(
+                "e1",
+                "s1",
+                None,
+                make_message_with_tool_calls(vec![ToolName::Read]),
+            ),
+            make_log_entry(
+                "e2",
+                "s1",
+                Some("agent-1"),
+                make_message_with_tool_calls(vec![ToolName::Bash]),
+            ),
+            make_log_entry(
+                "e3",
+                "s2",
+                None,
+                make_message_with_tool_calls(vec![ToolName::Write, ToolName::Read]),
+            ),
+        ];
+        for entry in &entries {
+            stats.record_entry(entry);
+        }
+
+        let s1_main = stats.filtered_tool_counts(&StatsFilter::MainAgent(make_session_id("s1")));
+        assert_eq!(s1_main.get(&ToolName::Read), Some(&1));
+        assert_eq!(s1_main.get(&ToolName::Bash), None, "Subagent tool excluded");
+        assert_eq!(
+            s1_main.get(&ToolName::Write),
+            None,
+            "Other session excluded"
+        );
+
+        let s2_main = stats.filtered_tool_counts(&StatsFilter::MainAgent(make_session_id("s2")));
+        assert_eq!(s2_main.get(&ToolName::Read), Some(&1));
+        assert_eq!(s2_main.get(&ToolName::Write), Some(&1));
+    }
+
+    #[test]
+    fn filtered_tool_counts_returns_empty_for_unknown_session() {
+        let mut stats = SessionStats::default();
+        stats.record_entry(&make_log_entry(
+            "e1",
+            "s1",
+            None,
+            make_message_with_tool_calls(vec![ToolName::Read]),
+        ));
+
+        let missing = make_session_id("missing");
+
+        assert!(
+            stats
+                .filtered_tool_counts(&StatsFilter::Session(missing.clone()))
+                .is_empty()
+        );
+        assert!(
+            stats
+                .filtered_tool_counts(&StatsFilter::MainAgent(missing))
+                .is_empty()
+        );
+    }
+
+    // ===== FMT-010: Result Entry Cost Tracking Tests =====
+
+    #[test]
+    fn record_entry_extracts_cost_from_result_entry() {
+        // RED TEST: SessionStats should extract total_cost_usd from Result entry's ResultMetadata
+        use crate::model::{EntryType, ResultMetadata};
+
+        let mut stats = SessionStats::default();
+
+        // Create a Result entry with total_cost_usd = 1.5
+        let result_metadata = ResultMetadata {
+            is_error: false,
+            duration_ms: 100000,
+            num_turns: 10,
             total_cost_usd: 1.5,
             result_text: "Session complete".to_string(),
         };
@@ -1663,6 +2523,220 @@ mod tests {
         );
     }
 
+    // ===== Timeline Tests =====
+
+    #[test]
+    fn record_entry_appends_timeline_sample_for_usage() {
+        let mut stats = SessionStats::default();
+        let usage = TokenUsage {
+            input_tokens: 100,
+            output_tokens: 20,
+            cache_read_input_tokens: 50,
+            ..Default::default()
+        };
+        stats.record_entry(&make_log_entry(
+            "e1",
+            "s1",
+            None,
+            make_message_with_usage(usage),
+        ));
+        stats.record_entry(&make_result_entry("r1", "s1", 1.0));
+
+        assert_eq!(
+            stats.timeline.len(),
+            1,
+            "Only entries with usage are sampled"
+        );
+        assert_eq!(stats.timeline[0].usage.output_tokens, 20);
+        assert_eq!(stats.timeline[0].context_tokens(), 150);
+    }
+
+    #[test]
+    fn filtered_timeline_scopes_by_agent_and_sorts_by_time() {
+        let mut stats = SessionStats::default();
+        let usage = TokenUsage {
+            output_tokens: 1,
+            ..Default::default()
+        };
+        let start = Utc::now();
+        let at = |uuid: &str, agent: Option<&str>, offset_secs: i64| {
+            LogEntry::new(
+                make_uuid(uuid),
+                None,
+                make_session_id("s1"),
+                agent.map(make_agent_id),
+                start + chrono::Duration::seconds(offset_secs),
+                EntryType::Assistant,
+                make_message_with_usage(usage),
+                EntryMetadata::default(),
+            )
+        };
+        // Main agent recorded first but happened later
+        stats.record_entry(&at("e1", None, 10));
+        stats.record_entry(&at("e2", Some("agent-1"), 0));
+
+        let all = stats.filtered_timeline(&StatsFilter::Session(make_session_id("s1")));
+        let main_only = stats.filtered_timeline(&StatsFilter::MainAgent(make_session_id("s1")));
+        let sub_only = stats.filtered_timeline(&StatsFilter::Subagent(make_agent_id("agent-1")));
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(
+            all[0].agent_id,
+            Some(make_agent_id("agent-1")),
+            "Earliest first"
+        );
+        assert_eq!(main_only.len(), 1);
+        assert_eq!(main_only[0].agent_id, None);
+        assert_eq!(sub_only.len(), 1);
+    }
+
+    #[test]
+    fn timeline_sample_cost_prefers_its_own_model() {
+        let sample = TimelineSample {
+            session_id: make_session_id("s1"),
+            agent_id: None,
+            timestamp: Utc::now(),
+            model_id: Some("claude-haiku-4-5".to_string()),
+            usage: TokenUsage {
+                output_tokens: 1_000_000,
+                ..Default::default()
+            },
+        };
+
+        // Haiku output: $5/M (fallback opus would be $25)
+        assert_eq!(sample.cost(&PricingConfig::default(), Some("opus")), 5.0);
+    }
+
+    #[test]
+    fn chart_axis_toggles_between_turn_and_wall_clock() {
+        assert_eq!(ChartAxis::default(), ChartAxis::Turn);
+        assert_eq!(ChartAxis::Turn.toggle(), ChartAxis::WallClock);
+        assert_eq!(ChartAxis::WallClock.toggle(), ChartAxis::Turn);
+    }
+
+    // ===== Cost Reconciliation Tests =====
+
+    fn make_result_entry(uuid: &str, session: &str, cost: f64) -> LogEntry {
+        use crate::model::ResultMetadata;
+
+        LogEntry::new_with_result_metadata(
+            make_uuid(uuid),
+            None,
+            make_session_id(session),
+            None,
+            Utc::now(),
+            EntryType::Result,
+            Message::new(Role::Assistant, MessageContent::Text("".to_string())),
+            EntryMetadata::default(),
+            Some(ResultMetadata {
+                is_error: false,
+                duration_ms: 1000,
+                num_turns: 1,
+                total_cost_usd: cost,
+                result_text: "Done".to_string(),
+            }),
+        )
+    }
+
+    #[test]
+    fn filtered_actual_cost_tracks_each_session() {
+        let mut stats = SessionStats::default();
+        stats.record_entry(&make_result_entry("r1", "s1", 1.0));
+        stats.record_entry(&make_result_entry("r2", "s2", 2.5));
+
+        assert_eq!(
+            stats.filtered_actual_cost(&StatsFilter::Session(make_session_id("s1"))),
+            Some(1.0)
+        );
+        assert_eq!(
+            stats.filtered_actual_cost(&StatsFilter::AllSessionsCombined),
+            Some(3.5)
+        );
+        assert_eq!(
+            stats.filtered_actual_cost(&StatsFilter::MainAgent(make_session_id("s1"))),
+            None,
+            "Reported cost covers the whole session, not a single agent"
+        );
+    }
+
+    #[test]
+    fn filtered_cost_reconciliation_compares_reported_and_estimated() {
+        let mut stats = SessionStats::default();
+        let usage = TokenUsage {
+            input_tokens: 1_000_000,
+            ..Default::default()
+        };
+        stats.record_entry(&make_log_entry(
+            "e1",
+            "s1",
+            None,
+            make_message_with_usage(usage),
+        ));
+        stats.record_entry(&make_result_entry("r1", "s1", 4.0));
+
+        let reconciliation = stats
+            .filtered_cost_reconciliation(
+                &StatsFilter::Session(make_session_id("s1")),
+                &PricingConfig::default(),
+                Some("opus"),
+            )
+            .expect("Session with a result entry should reconcile");
+
+        assert_eq!(reconciliation, CostReconciliation::new(4.0, 5.0));
+    }
+
+    #[test]
+    fn filtered_cost_reconciliation_is_none_without_result_entry() {
+        let stats = SessionStats::default();
+
+        let reconciliation = stats.filtered_cost_reconciliation(
+            &StatsFilter::AllSessionsCombined,
+            &PricingConfig::default(),
+            None,
+        );
+
+        assert_eq!(reconciliation, None);
+    }
+
+    #[test]
+    fn cost_reconciliation_reports_delta_and_percent() {
+        let reconciliation = CostReconciliation::new(2.0, 1.5);
+
+        assert_eq!(reconciliation.delta(), -0.5);
+        assert_eq!(reconciliation.delta_percent(), Some(-25.0));
+    }
+
+    #[test]
+    fn cost_reconciliation_exceeds_tolerance_in_either_direction() {
+        assert!(!CostReconciliation::new(2.0, 2.1).exceeds_tolerance(10.0));
+        assert!(CostReconciliation::new(2.0, 2.5).exceeds_tolerance(10.0));
+        assert!(CostReconciliation::new(2.0, 1.5).exceeds_tolerance(10.0));
+    }
+
+    #[test]
+    fn cost_reconciliation_with_zero_reported_cost() {
+        let reconciliation = CostReconciliation::new(0.0, 0.25);
+
+        assert_eq!(reconciliation.delta_percent(), None);
+        assert!(reconciliation.exceeds_tolerance(10.0));
+        assert!(!CostReconciliation::new(0.0, 0.0).exceeds_tolerance(10.0));
+    }
+
+    #[test]
+    fn pricing_config_cost_tolerance_defaults_and_reads_config() {
+        assert_eq!(
+            PricingConfig::default().cost_tolerance_percent(),
+            DEFAULT_COST_TOLERANCE_PERCENT
+        );
+
+        let section = crate::config::PricingConfigSection {
+            models: HashMap::new(),
+            default: None,
+            cost_tolerance_percent: Some(2.5),
+        };
+        assert_eq!(PricingConfig::from(section).cost_tolerance_percent(), 2.5);
+    }
+
     // ===== Session-Scoped Statistics Tests =====
 
     #[test]
@@ -2104,4 +3178,355 @@ mod tests {
         assert_eq!(session_usage.input_tokens, sum_input);
         assert_eq!(session_usage.output_tokens, sum_output);
     }
+
+    // ===== Per-model usage and cost =====
+
+    fn make_message_with_model(model: &str, input: u64, output: u64) -> Message {
+        make_message_with_usage(TokenUsage {
+            input_tokens: input,
+            output_tokens: output,
+            ..Default::default()
+        })
+        .with_model(crate::model::ModelInfo::new(model))
+    }
+
+    #[test]
+    fn record_entry_attributes_usage_to_model_at_every_scope() {
+        let mut stats = SessionStats::default();
+        stats.record_entry(&make_log_entry(
+            "e1",
+            "s1",
+            None,
+            make_message_with_model("claude-opus-4-5-20251101", 100, 10),
+        ));
+        stats.record_entry(&make_log_entry(
+            "e2",
+            "s1",
+            Some("agent-1"),
+            make_message_with_model("claude-haiku-4-5-20251001", 200, 20),
+        ));
+
+        let session = make_session_id("s1");
+        let global = stats.filtered_model_usage(&StatsFilter::AllSessionsCombined);
+        assert_eq!(global.len(), 2);
+        assert_eq!(global["claude-opus-4-5-20251101"].input_tokens, 100);
+        assert_eq!(global["claude-haiku-4-5-20251001"].input_tokens, 200);
+
+        let session_models = stats.filtered_model_usage(&StatsFilter::Session(session.clone()));
+        assert_eq!(session_models.len(), 2);
+
+        let main_models = stats.filtered_model_usage(&StatsFilter::MainAgent(session));
+        assert_eq!(main_models.len(), 1);
+        assert!(main_models.contains_key("claude-opus-4-5-20251101"));
+
+        let sub_models =
+            stats.filtered_model_usage(&StatsFilter::Subagent(make_agent_id("agent-1")));
+        assert_eq!(sub_models.len(), 1);
+        assert_eq!(sub_models["claude-haiku-4-5-20251001"].output_tokens, 20);
+    }
+
+    #[test]
+    fn filtered_model_usage_is_empty_for_unknown_scope() {
+        let stats = SessionStats::default();
+
+        assert!(
+            stats
+                .filtered_model_usage(&StatsFilter::Subagent(make_agent_id("nope")))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn estimated_cost_prices_each_model_at_its_own_rate() {
+        let mut stats = SessionStats::default();
+        // Opus main agent: 1M in + 1M out = $5 + $25
+        stats.record_entry(&make_log_entry(
+            "e1",
+            "s1",
+            None,
+            make_message_with_model("claude-opus-4-5-20251101", 1_000_000, 1_000_000),
+        ));
+        // Haiku subagent: 1M in + 1M out = $1 + $5
+        stats.record_entry(&make_log_entry(
+            "e2",
+            "s1",
+            Some("agent-1"),
+            make_message_with_model("claude-haiku-4-5-20251001", 1_000_000, 1_000_000),
+        ));
+        let pricing = PricingConfig::default();
+
+        // Fallback model is irrelevant when all usage is attributed
+        assert_eq!(stats.estimated_cost(&pricing, Some("opus")), 36.0);
+        assert_eq!(stats.estimated_cost(&pricing, Some("haiku")), 36.0);
+        assert_eq!(
+            stats.filtered_estimated_cost(
+                &StatsFilter::Subagent(make_agent_id("agent-1")),
+                &pricing,
+                Some("opus"),
+            ),
+            6.0
+        );
+    }
+
+    #[test]
+    fn estimated_cost_prices_unattributed_usage_with_fallback_model() {
+        let mut stats = SessionStats::default();
+        // Sonnet with model: 1M in = $3
+        stats.record_entry(&make_log_entry(
+            "e1",
+            "s1",
+            None,
+            make_message_with_model("claude-sonnet-4-5", 1_000_000, 0),
+        ));
+        // No model reported: 1M in, priced with fallback (haiku = $1)
+        stats.record_entry(&make_log_entry(
+            "e2",
+            "s1",
+            None,
+            make_message_with_usage(TokenUsage {
+                input_tokens: 1_000_000,
+                ..Default::default()
+            }),
+        ));
+
+        let cost = stats.estimated_cost(&PricingConfig::default(), Some("haiku"));
+
+        assert_eq!(cost, 4.0);
+    }
+
+    // ===== Tool Reliability Tests =====
+
+    fn make_entry_at(
+        uuid: &str,
+        agent_id: Option<&str>,
+        offset_ms: i64,
+        role: Role,
+        blocks: Vec<ContentBlock>,
+    ) -> LogEntry {
+        let start = chrono::DateTime::parse_from_rfc3339("2025-01-15T14:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        LogEntry::new(
+            make_uuid(uuid),
+            None,
+            make_session_id("s1"),
+            agent_id.map(make_agent_id),
+            start + chrono::Duration::milliseconds(offset_ms),
+            EntryType::Assistant,
+            Message::new(role, MessageContent::Blocks(blocks)),
+            EntryMetadata::default(),
+        )
+    }
+
+    fn tool_use(id: &str, name: ToolName) -> ContentBlock {
+        ContentBlock::ToolUse(ToolCall::new(
+            make_tool_use_id(id),
+            name,
+            serde_json::json!({}),
+        ))
+    }
+
+    fn tool_result(id: &str, content: &str, is_error: bool) -> ContentBlock {
+        ContentBlock::ToolResult {
+            tool_use_id: make_tool_use_id(id),
+            content: content.to_string(),
+            is_error,
+        }
+    }
+
+    #[test]
+    fn record_entry_matches_tool_results_to_calls() {
+        let mut stats = SessionStats::default();
+        stats.record_entry(&make_entry_at(
+            "e1",
+            None,
+            0,
+            Role::Assistant,
+            vec![
+                tool_use("t1", ToolName::Bash),
+                tool_use("t2", ToolName::Bash),
+            ],
+        ));
+        stats.record_entry(&make_entry_at(
+            "e2",
+            None,
+            1_500,
+            Role::User,
+            vec![
+                tool_result("t1", "12345678", true),
+                tool_result("t2", "1234", false),
+            ],
+        ));
+
+        let bash = &stats.tool_stats[&ToolName::Bash];
+        assert_eq!(bash.calls, 2);
+        assert_eq!(bash.results, 2);
+        assert_eq!(bash.errors, 1);
+        assert_eq!(bash.latencies_ms, vec![1_500, 1_500]);
+        assert_eq!(bash.avg_result_chars(), Some(6));
+        assert!(
+            stats.pending_tool_calls.is_empty(),
+            "Matched calls are no longer pending"
+        );
+    }
+
+    #[test]
+    fn unmatched_tool_results_are_ignored() {
+        let mut stats = SessionStats::default();
+        stats.record_entry(&make_entry_at(
+            "e1",
+            None,
+            0,
+            Role::User,
+            vec![tool_result("unknown", "output", true)],
+        ));
+
+        assert!(stats.tool_stats.is_empty());
+    }
+
+    #[test]
+    fn filtered_tool_stats_covers_every_scope() {
+        let mut stats = SessionStats::default();
+        stats.record_entry(&make_entry_at(
+            "e1",
+            None,
+            0,
+            Role::Assistant,
+            vec![tool_use("t1", ToolName::Read)],
+        ));
+        stats.record_entry(&make_entry_at(
+            "e2",
+            Some("agent-1"),
+            0,
+            Role::Assistant,
+            vec![tool_use("t2", ToolName::Grep)],
+        ));
+        stats.record_entry(&make_entry_at(
+            "e3",
+            Some("agent-1"),
+            200,
+            Role::User,
+            vec![tool_result("t2", "match", false)],
+        ));
+
+        let session = StatsFilter::Session(make_session_id("s1"));
+        let main = StatsFilter::MainAgent(make_session_id("s1"));
+        let sub = StatsFilter::Subagent(make_agent_id("agent-1"));
+
+        assert_eq!(
+            stats
+                .filtered_tool_stats(&StatsFilter::AllSessionsCombined)
+                .len(),
+            2
+        );
+        assert_eq!(stats.filtered_tool_stats(&session).len(), 2);
+        assert!(
+            stats
+                .filtered_tool_stats(&main)
+                .contains_key(&ToolName::Read)
+        );
+        assert!(
+            !stats
+                .filtered_tool_stats(&main)
+                .contains_key(&ToolName::Grep)
+        );
+        assert_eq!(
+            stats.filtered_tool_stats(&sub)[&ToolName::Grep].latencies_ms,
+            vec![200]
+        );
+        assert!(
+            stats
+                .filtered_tool_stats(&StatsFilter::Subagent(make_agent_id("other")))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn stats_page_cycles() {
+        assert_eq!(StatsPage::default(), StatsPage::Overview);
+        assert_eq!(StatsPage::Overview.next(), StatsPage::Tools);
+        assert_eq!(StatsPage::Tools.next(), StatsPage::Overview);
+    }
+
+    // ===== File Activity Tests =====
+
+    fn file_call(id: &str, name: ToolName, path: &str) -> ContentBlock {
+        ContentBlock::ToolUse(ToolCall::new(
+            make_tool_use_id(id),
+            name,
+            serde_json::json!({ "file_path": path }),
+        ))
+    }
+
+    #[test]
+    fn record_entry_collects_file_touches() {
+        let mut stats = SessionStats::default();
+        stats.record_entry(&make_entry_at(
+            "e1",
+            None,
+            0,
+            Role::Assistant,
+            vec![
+                file_call("t1", ToolName::Read, "/src/lib.rs"),
+                file_call("t2", ToolName::Edit, "/src/lib.rs"),
+                tool_use("t3", ToolName::Bash),
+            ],
+        ));
+        stats.record_entry(&make_entry_at(
+            "e2",
+            Some("agent-1"),
+            1_000,
+            Role::Assistant,
+            vec![file_call("t4", ToolName::Write, "/src/new.rs")],
+        ));
+
+        let files = stats.filtered_files(&StatsFilter::AllSessionsCombined);
+
+        assert_eq!(files.len(), 2, "Bash call is not a file touch");
+        assert_eq!(files[0].path, "/src/lib.rs");
+        assert_eq!((files[0].reads(), files[0].edits()), (1, 1));
+        assert_eq!(files[0].touches[0].entry_uuid, make_uuid("e1"));
+        assert_eq!(files[1].path, "/src/new.rs");
+        assert_eq!(files[1].writes(), 1);
+        assert_eq!(files[1].agents(), vec![Some(&make_agent_id("agent-1"))]);
+    }
+
+    #[test]
+    fn filtered_files_keeps_only_touches_in_scope() {
+        let mut stats = SessionStats::default();
+        stats.record_entry(&make_entry_at(
+            "e1",
+            Some("agent-1"),
+            5_000,
+            Role::Assistant,
+            vec![file_call("t1", ToolName::Edit, "/a.rs")],
+        ));
+        stats.record_entry(&make_entry_at(
+            "e2",
+            None,
+            0,
+            Role::Assistant,
+            vec![
+                file_call("t2", ToolName::Read, "/a.rs"),
+                file_call("t3", ToolName::Read, "/b.rs"),
+            ],
+        ));
+
+        let all = stats.filtered_files(&StatsFilter::Session(make_session_id("s1")));
+        let main = stats.filtered_files(&StatsFilter::MainAgent(make_session_id("s1")));
+        let sub = stats.filtered_files(&StatsFilter::Subagent(make_agent_id("agent-1")));
+        let other = stats.filtered_files(&StatsFilter::Session(make_session_id("s2")));
+
+        assert_eq!(all[0].touches.len(), 2);
+        assert_eq!(
+            all[0].touches[0].access,
+            crate::model::FileAccess::Read,
+            "Touches ordered by timestamp"
+        );
+        assert_eq!(main.len(), 2);
+        assert_eq!(main[0].edits(), 0);
+        assert_eq!(sub.len(), 1);
+        assert_eq!(sub[0].path, "/a.rs");
+        assert!(other.is_empty());
+    }
 }
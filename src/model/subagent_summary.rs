@@ -0,0 +1,334 @@
+//! One-row-per-subagent summaries for the subagent table.
+//!
+//! Subagent entries carry the spawning Task call's `tool_use_id` as their
+//! `AgentId`, so the Task call provides the label (`description`) and
+//! `subagent_type`, and its tool_result provides the final status. Usage,
+//! cost and tool counts come from `SessionStats`.
+
+use crate::model::{
+    AgentId, ContentBlock, LogEntry, MessageContent, ModelInfo, PricingConfig, SessionStats,
+    StatsFilter, TokenUsage, ToolName,
+};
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+// ===== SubagentStatus =====
+
+/// Final status of a subagent, from its Task call's tool_result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SubagentStatus {
+    /// Task tool_result with `is_error: true`.
+    Failed,
+    /// Task call without a tool_result yet.
+    Running,
+    /// Task tool_result with `is_error: false`.
+    Succeeded,
+    /// No Task call with this agent's ID in the log.
+    Unknown,
+}
+
+impl SubagentStatus {
+    /// Short label for display.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SubagentStatus::Failed => "failed",
+            SubagentStatus::Running => "running",
+            SubagentStatus::Succeeded => "ok",
+            SubagentStatus::Unknown => "-",
+        }
+    }
+}
+
+// ===== SubagentSummary =====
+
+/// Everything the subagent table shows for one subagent.
+#[derive(Debug, Clone)]
+pub struct SubagentSummary {
+    /// Subagent conversation (the Task call's `tool_use_id`).
+    pub agent_id: AgentId,
+
+    /// Task `description` input.
+    pub description: Option<String>,
+
+    /// Task `subagent_type` input.
+    pub subagent_type: Option<String>,
+
+    /// First model reported by the subagent's entries.
+    pub model: Option<ModelInfo>,
+
+    /// Timestamp of the subagent's first entry.
+    pub start: Option<DateTime<Utc>>,
+
+    /// Timestamp of the subagent's last entry.
+    pub end: Option<DateTime<Utc>>,
+
+    /// Number of subagent entries.
+    pub entries: usize,
+
+    /// Token usage of the subagent.
+    pub usage: TokenUsage,
+
+    /// Estimated cost in USD (see `SessionStats::filtered_estimated_cost`).
+    pub estimated_cost: f64,
+
+    /// Tool invocation counts of the subagent.
+    pub tool_counts: HashMap<ToolName, u32>,
+
+    /// Final status.
+    pub status: SubagentStatus,
+}
+
+impl SubagentSummary {
+    /// Summarize every subagent with entries in `entries`, in order of first entry.
+    ///
+    /// `entries` should be every entry of one session (main agent included,
+    /// for the Task calls and results) ordered by timestamp.
+    pub fn collect(
+        entries: &[&LogEntry],
+        stats: &SessionStats,
+        pricing: &PricingConfig,
+        fallback_model_id: Option<&str>,
+    ) -> Vec<SubagentSummary> {
+        let mut summaries: Vec<SubagentSummary> = Vec::new();
+        let mut index: HashMap<AgentId, usize> = HashMap::new();
+        let mut tasks: HashMap<String, (Option<String>, Option<String>)> = HashMap::new();
+        let mut results: HashMap<String, bool> = HashMap::new();
+
+        for entry in entries {
+            let blocks = match entry.message().content() {
+                MessageContent::Blocks(blocks) => blocks.as_slice(),
+                MessageContent::Text(_) => &[],
+            };
+            for block in blocks {
+                match block {
+                    ContentBlock::ToolUse(call) if *call.name() == ToolName::Task => {
+                        let field = |name: &str| {
+                            call.input()
+                                .get(name)
+                                .and_then(|v| v.as_str())
+                                .map(str::to_string)
+                        };
+                        tasks.insert(
+                            call.id().as_str().to_string(),
+                            (field("description"), field("subagent_type")),
+                        );
+                    }
+                    ContentBlock::ToolResult {
+                        tool_use_id,
+                        is_error,
+                        ..
+                    } => {
+                        results.insert(tool_use_id.as_str().to_string(), *is_error);
+                    }
+                    _ => {}
+                }
+            }
+
+            let Some(agent_id) = entry.agent_id() else {
+                continue;
+            };
+            let i = *index.entry(agent_id.clone()).or_insert_with(|| {
+                summaries.push(SubagentSummary::new(
+                    agent_id.clone(),
+                    stats,
+                    pricing,
+                    fallback_model_id,
+                ));
+                summaries.len() - 1
+            });
+            let summary = &mut summaries[i];
+            summary.entries += 1;
+            if let Some(timestamp) = entry.known_timestamp() {
+                summary.start = summary.start.or(Some(timestamp));
+                summary.end = Some(timestamp);
+            }
+            if summary.model.is_none() {
+                summary.model = entry.message().model().cloned();
+            }
+        }
+
+        for summary in &mut summaries {
+            let id = summary.agent_id.as_str();
+            if let Some((description, subagent_type)) = tasks.remove(id) {
+                summary.description = description;
+                summary.subagent_type = subagent_type;
+                summary.status = match results.get(id) {
+                    Some(true) => SubagentStatus::Failed,
+                    Some(false) => SubagentStatus::Succeeded,
+                    None => SubagentStatus::Running,
+                };
+            }
+        }
+        summaries
+    }
+
+    fn new(
+        agent_id: AgentId,
+        stats: &SessionStats,
+        pricing: &PricingConfig,
+        fallback_model_id: Option<&str>,
+    ) -> Self {
+        let filter = StatsFilter::Subagent(agent_id.clone());
+        Self {
+            description: None,
+            subagent_type: None,
+            model: None,
+            start: None,
+            end: None,
+            entries: 0,
+            usage: stats.filtered_usage(&filter),
+            estimated_cost: stats.filtered_estimated_cost(&filter, pricing, fallback_model_id),
+            tool_counts: stats.filtered_tool_counts(&filter).clone(),
+            status: SubagentStatus::Unknown,
+            agent_id,
+        }
+    }
+
+    /// Task description, or the agent ID when the Task call is not in the log.
+    pub fn label(&self) -> &str {
+        self.description
+            .as_deref()
+            .unwrap_or(self.agent_id.as_str())
+    }
+
+    /// Time from first to last entry.
+    pub fn duration(&self) -> Option<chrono::Duration> {
+        Some(self.end? - self.start?)
+    }
+
+    /// Total tool invocations.
+    pub fn tool_calls(&self) -> u32 {
+        self.tool_counts.values().sum()
+    }
+}
+
+// ===== SubagentSortColumn =====
+
+/// Column the subagent table is sorted by.
+///
+/// Text and time columns sort ascending, amounts sort descending; the table
+/// can reverse either.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubagentSortColumn {
+    /// Label (Task description).
+    Label,
+    /// Subagent type.
+    Type,
+    /// Model display name.
+    Model,
+    /// Start time.
+    #[default]
+    Start,
+    /// End time.
+    End,
+    /// Duration.
+    Duration,
+    /// Entry count.
+    Entries,
+    /// Total tokens.
+    Tokens,
+    /// Estimated cost.
+    Cost,
+    /// Tool invocation count.
+    Tools,
+    /// Final status (failed first).
+    Status,
+}
+
+impl SubagentSortColumn {
+    /// Every column in display order.
+    pub const ALL: [SubagentSortColumn; 11] = [
+        SubagentSortColumn::Label,
+        SubagentSortColumn::Type,
+        SubagentSortColumn::Model,
+        SubagentSortColumn::Start,
+        SubagentSortColumn::End,
+        SubagentSortColumn::Duration,
+        SubagentSortColumn::Entries,
+        SubagentSortColumn::Tokens,
+        SubagentSortColumn::Cost,
+        SubagentSortColumn::Tools,
+        SubagentSortColumn::Status,
+    ];
+
+    /// Next column in display order, wrapping around.
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|c| *c == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    /// Previous column in display order, wrapping around.
+    pub fn prev(self) -> Self {
+        let i = Self::ALL.iter().position(|c| *c == self).unwrap_or(0);
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Column header.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SubagentSortColumn::Label => "Label",
+            SubagentSortColumn::Type => "Type",
+            SubagentSortColumn::Model => "Model",
+            SubagentSortColumn::Start => "Start",
+            SubagentSortColumn::End => "End",
+            SubagentSortColumn::Duration => "Dur",
+            SubagentSortColumn::Entries => "Entries",
+            SubagentSortColumn::Tokens => "Tokens",
+            SubagentSortColumn::Cost => "Cost",
+            SubagentSortColumn::Tools => "Tools",
+            SubagentSortColumn::Status => "Status",
+        }
+    }
+
+    /// Whether the default direction is descending (amounts) rather than
+    /// ascending (text, times and status).
+    pub fn is_descending(&self) -> bool {
+        matches!(
+            self,
+            SubagentSortColumn::Duration
+                | SubagentSortColumn::Entries
+                | SubagentSortColumn::Tokens
+                | SubagentSortColumn::Cost
+                | SubagentSortColumn::Tools
+        )
+    }
+
+    /// Compare two summaries in this column's default direction.
+    ///
+    /// Ties are broken by start time, then agent ID.
+    pub fn compare(&self, a: &SubagentSummary, b: &SubagentSummary) -> Ordering {
+        let text = |s: &SubagentSummary| -> String {
+            match self {
+                SubagentSortColumn::Label => s.label().to_lowercase(),
+                SubagentSortColumn::Type => s.subagent_type.clone().unwrap_or_default(),
+                SubagentSortColumn::Model => s
+                    .model
+                    .as_ref()
+                    .map(|m| m.display_name().to_string())
+                    .unwrap_or_default(),
+                _ => String::new(),
+            }
+        };
+        let primary = match self {
+            SubagentSortColumn::Label | SubagentSortColumn::Type | SubagentSortColumn::Model => {
+                text(a).cmp(&text(b))
+            }
+            SubagentSortColumn::Start => a.start.cmp(&b.start),
+            SubagentSortColumn::End => a.end.cmp(&b.end),
+            SubagentSortColumn::Duration => b.duration().cmp(&a.duration()),
+            SubagentSortColumn::Entries => b.entries.cmp(&a.entries),
+            SubagentSortColumn::Tokens => b.usage.total().cmp(&a.usage.total()),
+            SubagentSortColumn::Cost => b.estimated_cost.total_cmp(&a.estimated_cost),
+            SubagentSortColumn::Tools => b.tool_calls().cmp(&a.tool_calls()),
+            SubagentSortColumn::Status => a.status.cmp(&b.status),
+        };
+        primary
+            .then_with(|| a.start.cmp(&b.start))
+            .then_with(|| a.agent_id.as_str().cmp(b.agent_id.as_str()))
+    }
+}
+
+#[cfg(test)]
+#[path = "subagent_summary_tests.rs"]
+mod tests;
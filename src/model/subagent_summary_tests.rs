@@ -0,0 +1,167 @@
+//! Tests for subagent summaries.
+
+use super::*;
+use crate::model::{
+    EntryMetadata, EntryType, EntryUuid, Message, Role, SessionId, ToolCall, ToolUseId,
+};
+use chrono::TimeZone;
+use serde_json::json;
+
+fn entry(uuid: &str, agent: Option<&str>, secs: u32, message: Message) -> LogEntry {
+    LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        agent.map(|a| AgentId::new(a).unwrap()),
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, secs).unwrap(),
+        EntryType::Assistant,
+        message,
+        EntryMetadata::default(),
+    )
+}
+
+fn blocks(role: Role, blocks: Vec<ContentBlock>) -> Message {
+    Message::new(role, MessageContent::Blocks(blocks))
+}
+
+fn task_call(id: &str, description: &str) -> Message {
+    blocks(
+        Role::Assistant,
+        vec![ContentBlock::ToolUse(ToolCall::new(
+            ToolUseId::new(id).unwrap(),
+            ToolName::Task,
+            json!({"description": description, "subagent_type": "Explore", "prompt": "go"}),
+        ))],
+    )
+}
+
+fn task_result(id: &str, is_error: bool) -> Message {
+    blocks(
+        Role::User,
+        vec![ContentBlock::ToolResult {
+            tool_use_id: ToolUseId::new(id).unwrap(),
+            content: "done".to_string(),
+            is_error,
+        }],
+    )
+}
+
+fn text(role: Role) -> Message {
+    Message::new(role, MessageContent::Text("hi".to_string()))
+}
+
+fn bash_call(id: &str) -> Message {
+    blocks(
+        Role::Assistant,
+        vec![ContentBlock::ToolUse(ToolCall::new(
+            ToolUseId::new(id).unwrap(),
+            ToolName::Bash,
+            json!({"command": "ls"}),
+        ))],
+    )
+}
+
+fn summarize(entries: &[LogEntry]) -> Vec<SubagentSummary> {
+    let mut stats = SessionStats::default();
+    for entry in entries {
+        stats.record_entry(entry);
+    }
+    let refs: Vec<&LogEntry> = entries.iter().collect();
+    SubagentSummary::collect(&refs, &stats, &PricingConfig::default(), None)
+}
+
+/// Two Task subagents (`toolu_a` succeeded, `toolu_b` failed) and one still running.
+fn session() -> Vec<LogEntry> {
+    let usage = TokenUsage {
+        input_tokens: 100,
+        output_tokens: 50,
+        ..Default::default()
+    };
+    vec![
+        entry("m1", None, 0, task_call("toolu_a", "Find tests")),
+        entry(
+            "a1",
+            Some("toolu_a"),
+            1,
+            text(Role::Assistant)
+                .with_usage(usage)
+                .with_model(ModelInfo::new("claude-haiku-4-5")),
+        ),
+        entry("a2", Some("toolu_a"), 4, bash_call("b1")),
+        entry("m2", None, 5, task_result("toolu_a", false)),
+        entry("m3", None, 6, task_call("toolu_b", "Fix lint")),
+        entry("b1", Some("toolu_b"), 7, text(Role::Assistant)),
+        entry("m4", None, 8, task_result("toolu_b", true)),
+        entry("m5", None, 9, task_call("toolu_c", "Write docs")),
+        entry("c1", Some("toolu_c"), 10, text(Role::Assistant)),
+    ]
+}
+
+#[test]
+fn collect_reads_task_inputs_and_subagent_entries() {
+    let summaries = summarize(&session());
+
+    assert_eq!(summaries.len(), 3);
+    let a = &summaries[0];
+    assert_eq!(a.agent_id.as_str(), "toolu_a");
+    assert_eq!(a.label(), "Find tests");
+    assert_eq!(a.subagent_type.as_deref(), Some("Explore"));
+    assert_eq!(a.model.as_ref().map(|m| m.display_name()), Some("Haiku"));
+    assert_eq!(a.entries, 2);
+    assert_eq!(a.duration(), Some(chrono::Duration::seconds(3)));
+    assert_eq!(a.usage.total(), 150);
+    assert!(a.estimated_cost > 0.0);
+    assert_eq!(a.tool_calls(), 1);
+    assert_eq!(a.tool_counts.get(&ToolName::Bash), Some(&1));
+}
+
+#[test]
+fn collect_takes_status_from_task_result() {
+    let statuses: Vec<SubagentStatus> = summarize(&session()).iter().map(|s| s.status).collect();
+
+    assert_eq!(
+        statuses,
+        vec![
+            SubagentStatus::Succeeded,
+            SubagentStatus::Failed,
+            SubagentStatus::Running
+        ]
+    );
+}
+
+#[test]
+fn subagent_without_task_call_falls_back_to_agent_id() {
+    let summaries = summarize(&[entry("x1", Some("agent-x"), 0, text(Role::Assistant))]);
+
+    assert_eq!(summaries[0].label(), "agent-x");
+    assert_eq!(summaries[0].subagent_type, None);
+    assert_eq!(summaries[0].status, SubagentStatus::Unknown);
+}
+
+#[test]
+fn sort_columns_use_natural_direction() {
+    let mut summaries = summarize(&session());
+
+    summaries.sort_by(|a, b| SubagentSortColumn::Label.compare(a, b));
+    assert_eq!(summaries[0].label(), "Find tests", "Text sorts ascending");
+
+    summaries.sort_by(|a, b| SubagentSortColumn::Entries.compare(a, b));
+    assert_eq!(
+        summaries[0].label(),
+        "Find tests",
+        "Amounts sort descending"
+    );
+
+    summaries.sort_by(|a, b| SubagentSortColumn::Status.compare(a, b));
+    assert_eq!(summaries[0].label(), "Fix lint", "Failures first");
+}
+
+#[test]
+fn sort_column_cycles_through_all_columns() {
+    let mut column = SubagentSortColumn::Label;
+    for _ in 0..SubagentSortColumn::ALL.len() {
+        column = column.next();
+    }
+    assert_eq!(column, SubagentSortColumn::Label);
+    assert_eq!(SubagentSortColumn::Label.prev(), SubagentSortColumn::Status);
+}
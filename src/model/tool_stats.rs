@@ -0,0 +1,236 @@
+//! Per-tool reliability statistics.
+//!
+//! Tool calls are matched to their results by `ToolUseId` to derive error
+//! rates, latency (tool_use timestamp to tool_result timestamp) and result
+//! sizes. Aggregation per filter scope lives in `SessionStats`.
+
+use crate::model::{AgentId, SessionId, ToolName};
+use chrono::{DateTime, Utc};
+
+/// Characters per token used to estimate result size in tokens.
+pub const CHARS_PER_TOKEN: u64 = 4;
+
+// ===== ToolStats =====
+
+/// Aggregated reliability metrics for one tool.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolStats {
+    /// Number of tool_use blocks.
+    pub calls: u32,
+
+    /// Number of tool_result blocks matched to a call.
+    pub results: u32,
+
+    /// Number of matched results with `is_error: true`.
+    pub errors: u32,
+
+    /// Latency of each matched result in milliseconds, in recording order.
+    pub latencies_ms: Vec<u64>,
+
+    /// Total characters across all matched result contents.
+    pub result_chars: u64,
+}
+
+impl ToolStats {
+    /// Record a tool_use block.
+    pub fn record_call(&mut self) {
+        self.calls += 1;
+    }
+
+    /// Record a tool_result matched to one of this tool's calls.
+    pub fn record_result(&mut self, is_error: bool, latency_ms: u64, chars: u64) {
+        self.results += 1;
+        if is_error {
+            self.errors += 1;
+        }
+        self.latencies_ms.push(latency_ms);
+        self.result_chars += chars;
+    }
+
+    /// Fraction of completed calls that failed (0.0-1.0).
+    ///
+    /// Calls still waiting for a result are not counted. `None` if no
+    /// result has been seen.
+    pub fn error_rate(&self) -> Option<f64> {
+        (self.results > 0).then(|| self.errors as f64 / self.results as f64)
+    }
+
+    /// Median latency in milliseconds.
+    pub fn median_latency_ms(&self) -> Option<u64> {
+        self.latency_percentile(50.0)
+    }
+
+    /// 95th percentile latency in milliseconds.
+    pub fn p95_latency_ms(&self) -> Option<u64> {
+        self.latency_percentile(95.0)
+    }
+
+    /// Nearest-rank percentile of the recorded latencies.
+    fn latency_percentile(&self, percentile: f64) -> Option<u64> {
+        if self.latencies_ms.is_empty() {
+            return None;
+        }
+        let mut sorted = self.latencies_ms.clone();
+        sorted.sort_unstable();
+        let rank = ((percentile / 100.0) * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.clamp(1, sorted.len()) - 1])
+    }
+
+    /// Average result size in characters.
+    pub fn avg_result_chars(&self) -> Option<u64> {
+        (self.results > 0).then(|| self.result_chars / self.results as u64)
+    }
+
+    /// Average result size in estimated tokens (chars / `CHARS_PER_TOKEN`).
+    pub fn avg_result_tokens(&self) -> Option<u64> {
+        self.avg_result_chars().map(|chars| chars / CHARS_PER_TOKEN)
+    }
+}
+
+// ===== PendingToolCall =====
+
+/// A tool call waiting for its tool_result.
+#[derive(Debug, Clone)]
+pub struct PendingToolCall {
+    /// Tool that was invoked.
+    pub name: ToolName,
+
+    /// Session of the calling entry.
+    pub session_id: SessionId,
+
+    /// Subagent of the calling entry (`None` for the main agent).
+    pub agent_id: Option<AgentId>,
+
+    /// Timestamp of the calling entry.
+    pub timestamp: DateTime<Utc>,
+}
+
+// ===== ToolSortColumn =====
+
+/// Column the stats panel tool table is sorted by (always descending).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToolSortColumn {
+    /// Number of calls.
+    #[default]
+    Calls,
+    /// Number of errors.
+    Errors,
+    /// Error rate.
+    ErrorRate,
+    /// Median latency.
+    MedianLatency,
+    /// 95th percentile latency.
+    P95Latency,
+    /// Average result size.
+    ResultSize,
+}
+
+impl ToolSortColumn {
+    /// Next column in display order, wrapping around.
+    pub fn next(self) -> Self {
+        match self {
+            ToolSortColumn::Calls => ToolSortColumn::Errors,
+            ToolSortColumn::Errors => ToolSortColumn::ErrorRate,
+            ToolSortColumn::ErrorRate => ToolSortColumn::MedianLatency,
+            ToolSortColumn::MedianLatency => ToolSortColumn::P95Latency,
+            ToolSortColumn::P95Latency => ToolSortColumn::ResultSize,
+            ToolSortColumn::ResultSize => ToolSortColumn::Calls,
+        }
+    }
+
+    /// Sort key for `stats` under this column (missing values sort last).
+    pub fn key(&self, stats: &ToolStats) -> f64 {
+        let value = match self {
+            ToolSortColumn::Calls => Some(stats.calls as f64),
+            ToolSortColumn::Errors => Some(stats.errors as f64),
+            ToolSortColumn::ErrorRate => stats.error_rate(),
+            ToolSortColumn::MedianLatency => stats.median_latency_ms().map(|ms| ms as f64),
+            ToolSortColumn::P95Latency => stats.p95_latency_ms().map(|ms| ms as f64),
+            ToolSortColumn::ResultSize => stats.avg_result_chars().map(|chars| chars as f64),
+        };
+        value.unwrap_or(-1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats_with_latencies(latencies: &[u64]) -> ToolStats {
+        let mut stats = ToolStats::default();
+        for &ms in latencies {
+            stats.record_call();
+            stats.record_result(false, ms, 0);
+        }
+        stats
+    }
+
+    #[test]
+    fn record_result_counts_errors_and_chars() {
+        let mut stats = ToolStats::default();
+        stats.record_call();
+        stats.record_call();
+        stats.record_call();
+        stats.record_result(true, 100, 40);
+        stats.record_result(false, 300, 80);
+
+        assert_eq!(stats.calls, 3);
+        assert_eq!(stats.results, 2);
+        assert_eq!(stats.errors, 1);
+        assert_eq!(stats.error_rate(), Some(0.5), "Pending call not counted");
+        assert_eq!(stats.avg_result_chars(), Some(60));
+        assert_eq!(stats.avg_result_tokens(), Some(15));
+    }
+
+    #[test]
+    fn empty_stats_have_no_rates() {
+        let stats = ToolStats::default();
+
+        assert_eq!(stats.error_rate(), None);
+        assert_eq!(stats.median_latency_ms(), None);
+        assert_eq!(stats.p95_latency_ms(), None);
+        assert_eq!(stats.avg_result_chars(), None);
+    }
+
+    #[test]
+    fn latency_percentiles_use_nearest_rank() {
+        let stats = stats_with_latencies(&[500, 100, 400, 200, 300]);
+
+        assert_eq!(stats.median_latency_ms(), Some(300));
+        assert_eq!(stats.p95_latency_ms(), Some(500));
+    }
+
+    #[test]
+    fn p95_picks_tail_of_large_sample() {
+        let latencies: Vec<u64> = (1..=100).collect();
+        let stats = stats_with_latencies(&latencies);
+
+        assert_eq!(stats.median_latency_ms(), Some(50));
+        assert_eq!(stats.p95_latency_ms(), Some(95));
+    }
+
+    #[test]
+    fn sort_column_cycles_through_all_columns() {
+        let mut column = ToolSortColumn::default();
+        for _ in 0..6 {
+            column = column.next();
+        }
+
+        assert_eq!(column, ToolSortColumn::Calls);
+        assert_eq!(ToolSortColumn::Calls.next(), ToolSortColumn::Errors);
+    }
+
+    #[test]
+    fn sort_key_puts_missing_values_last() {
+        let no_results = ToolStats {
+            calls: 1,
+            ..Default::default()
+        };
+        let with_results = stats_with_latencies(&[10]);
+
+        assert!(
+            ToolSortColumn::MedianLatency.key(&with_results)
+                > ToolSortColumn::MedianLatency.key(&no_results)
+        );
+    }
+}
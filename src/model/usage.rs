@@ -17,7 +17,7 @@ pub const MODEL_SONNET: &str = "Sonnet";
 pub const MODEL_HAIKU: &str = "Haiku";
 
 /// Model information from the assistant message.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ModelInfo {
     model_id: ModelId,
 }
@@ -81,7 +81,7 @@ impl ModelInfo {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct ModelId(String);
 
 /// Token usage statistics from a single message.
@@ -183,6 +183,49 @@ impl TokenUsage {
     pub fn total(&self) -> u64 {
         self.total_input() + self.output_tokens
     }
+
+    /// Component-wise subtraction, clamping each field at zero.
+    ///
+    /// Used to find the part of an aggregate not covered by a breakdown
+    /// (e.g. usage not attributed to any model).
+    pub fn saturating_sub(&self, other: &TokenUsage) -> TokenUsage {
+        TokenUsage {
+            input_tokens: self.input_tokens.saturating_sub(other.input_tokens),
+            output_tokens: self.output_tokens.saturating_sub(other.output_tokens),
+            cache_creation_input_tokens: self
+                .cache_creation_input_tokens
+                .saturating_sub(other.cache_creation_input_tokens),
+            cache_read_input_tokens: self
+                .cache_read_input_tokens
+                .saturating_sub(other.cache_read_input_tokens),
+            ephemeral_5m_input_tokens: self
+                .ephemeral_5m_input_tokens
+                .saturating_sub(other.ephemeral_5m_input_tokens),
+            ephemeral_1h_input_tokens: self
+                .ephemeral_1h_input_tokens
+                .saturating_sub(other.ephemeral_1h_input_tokens),
+        }
+    }
+}
+
+impl std::ops::Add for TokenUsage {
+    type Output = TokenUsage;
+
+    fn add(mut self, rhs: TokenUsage) -> TokenUsage {
+        self += rhs;
+        self
+    }
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, rhs: TokenUsage) {
+        self.input_tokens += rhs.input_tokens;
+        self.output_tokens += rhs.output_tokens;
+        self.cache_creation_input_tokens += rhs.cache_creation_input_tokens;
+        self.cache_read_input_tokens += rhs.cache_read_input_tokens;
+        self.ephemeral_5m_input_tokens += rhs.ephemeral_5m_input_tokens;
+        self.ephemeral_1h_input_tokens += rhs.ephemeral_1h_input_tokens;
+    }
 }
 
 #[cfg(test)]
@@ -245,6 +288,42 @@ mod tests {
         assert_eq!(usage.total(), 200);
     }
 
+    #[test]
+    fn test_token_usage_add_is_component_wise() {
+        let a = TokenUsage {
+            input_tokens: 1,
+            output_tokens: 2,
+            cache_creation_input_tokens: 3,
+            cache_read_input_tokens: 4,
+            ephemeral_5m_input_tokens: 5,
+            ephemeral_1h_input_tokens: 6,
+        };
+        let sum = a + a;
+        assert_eq!(sum.input_tokens, 2);
+        assert_eq!(sum.output_tokens, 4);
+        assert_eq!(sum.cache_creation_input_tokens, 6);
+        assert_eq!(sum.cache_read_input_tokens, 8);
+        assert_eq!(sum.ephemeral_5m_input_tokens, 10);
+        assert_eq!(sum.ephemeral_1h_input_tokens, 12);
+    }
+
+    #[test]
+    fn test_token_usage_saturating_sub_clamps_at_zero() {
+        let big = TokenUsage {
+            input_tokens: 10,
+            output_tokens: 10,
+            ..Default::default()
+        };
+        let small = TokenUsage {
+            input_tokens: 3,
+            output_tokens: 20,
+            ..Default::default()
+        };
+        let diff = big.saturating_sub(&small);
+        assert_eq!(diff.input_tokens, 7);
+        assert_eq!(diff.output_tokens, 0);
+    }
+
     #[test]
     fn test_token_usage_default() {
         let usage = TokenUsage::default();
@@ -4,13 +4,14 @@
 //! into validated LogEntry structs.
 
 use crate::model::{
-    AgentId, ContentBlock, EntryMetadata, EntryType, EntryUuid, LogEntry, MalformedEntry, Message,
-    MessageContent, ModelInfo, ParseError, ResultMetadata, Role, SessionId, SystemMetadata,
-    TokenUsage, ToolCall, ToolName, ToolUseId,
+    AgentId, CompactMetadata, ContentBlock, EntryMetadata, EntryType, EntryUuid, LogEntry,
+    MalformedEntry, McpServer, Message, MessageContent, ModelInfo, ParseError, ResultMetadata,
+    Role, SessionId, SystemMetadata, TokenUsage, ToolCall, ToolName, ToolUseId,
 };
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 // Entry type string constants
 const ENTRY_TYPE_USER: &str = "user";
@@ -26,6 +27,79 @@ const ROLE_ASSISTANT: &str = "assistant";
 // Session ID constants
 pub(crate) const UNKNOWN_SESSION_ID: &str = "unknown-session";
 
+/// Entry `type` values the parser accepts.
+pub(crate) const ENTRY_TYPES: &[&str] = &[
+    ENTRY_TYPE_USER,
+    ENTRY_TYPE_ASSISTANT,
+    ENTRY_TYPE_SUMMARY,
+    ENTRY_TYPE_SYSTEM,
+    ENTRY_TYPE_RESULT,
+];
+
+/// Content block `type` values the parser accepts (see `RawContentBlock`).
+pub(crate) const CONTENT_BLOCK_TYPES: &[&str] = &["text", "tool_use", "tool_result", "thinking"];
+
+/// Top-level fields read into `RawLogEntry`, including aliases.
+pub(crate) const ENTRY_FIELDS: &[&str] = &[
+    "type",
+    "message",
+    "session_id",
+    "sessionId",
+    "uuid",
+    "parent_tool_use_id",
+    "parentUuid",
+    "agentId",
+    "timestamp",
+    "cwd",
+    "gitBranch",
+    "version",
+    "isSidechain",
+    "subtype",
+    "model",
+    "tools",
+    "agents",
+    "skills",
+    "permissionMode",
+    "permission_mode",
+    "mcp_servers",
+    "claude_code_version",
+    "compactMetadata",
+    "compact_metadata",
+    "content",
+    "is_error",
+    "duration_ms",
+    "num_turns",
+    "total_cost_usd",
+    "result",
+];
+
+/// Top-level fields Claude Code writes that cclv does not read.
+pub(crate) const IGNORED_ENTRY_FIELDS: &[&str] = &[
+    // stream-json
+    "tool_use_result",
+    "isSynthetic",
+    "apiKeySource",
+    "slash_commands",
+    "output_style",
+    "plugins",
+    "hook_name",
+    "hook_event",
+    "stdout",
+    "stderr",
+    "exit_code",
+    "duration_api_ms",
+    "usage",
+    "modelUsage",
+    "permission_denials",
+    // Transcript files
+    "userType",
+    "requestId",
+    "isMeta",
+    "toolUseResult",
+    "leafUuid",
+    "summary",
+];
+
 /// Raw JSON structure for deserializing log entries.
 #[derive(Debug, Deserialize)]
 struct RawLogEntry {
@@ -35,9 +109,14 @@ struct RawLogEntry {
     message: Option<RawMessage>,
     #[serde(default)]
     session_id: Option<String>,
+    /// Transcript files spell the session ID `sessionId`
+    #[serde(default, rename = "sessionId")]
+    transcript_session_id: Option<String>,
     uuid: String,
     #[serde(default)]
     parent_tool_use_id: Option<String>,
+    #[serde(default, rename = "parentUuid")]
+    transcript_parent_uuid: Option<String>,
     #[serde(default, rename = "agentId")]
     agent_id: Option<String>,
     #[serde(default)]
@@ -61,6 +140,16 @@ struct RawLogEntry {
     agents: Option<Vec<String>>,
     #[serde(default)]
     skills: Option<Vec<String>>,
+    #[serde(default, rename = "permissionMode", alias = "permission_mode")]
+    permission_mode: Option<String>,
+    #[serde(default)]
+    mcp_servers: Option<Vec<RawMcpServer>>,
+    #[serde(default)]
+    claude_code_version: Option<String>,
+    #[serde(default, rename = "compactMetadata", alias = "compact_metadata")]
+    compact_metadata: Option<RawCompactMetadata>,
+    #[serde(default)]
+    content: Option<serde_json::Value>,
     // Result entry fields (FMT-007)
     #[serde(default)]
     is_error: Option<bool>,
@@ -74,6 +163,23 @@ struct RawLogEntry {
     result: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct RawMcpServer {
+    name: String,
+    #[serde(default)]
+    status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawCompactMetadata {
+    #[serde(default)]
+    trigger: Option<String>,
+    #[serde(default, rename = "preTokens", alias = "pre_tokens")]
+    pre_tokens: Option<u64>,
+    #[serde(default, rename = "postTokens", alias = "post_tokens")]
+    post_tokens: Option<u64>,
+}
+
 #[derive(Debug, Deserialize)]
 struct RawMessage {
     role: String,
@@ -141,7 +247,7 @@ struct RawTokenUsage {
 #[derive(Debug, Clone)]
 pub enum ParseResult {
     /// Successfully parsed a valid log entry.
-    Valid(Box<LogEntry>),
+    Valid(Arc<LogEntry>),
     /// Encountered a malformed line that could not be parsed.
     Malformed(MalformedEntry),
 }
@@ -161,7 +267,7 @@ pub enum ParseResult {
 pub fn parse_entry_graceful(raw: &str, line_number: usize) -> ParseResult {
     // Attempt to parse the entry
     match parse_entry(raw, line_number) {
-        Ok(entry) => ParseResult::Valid(Box::new(entry)),
+        Ok(entry) => ParseResult::Valid(Arc::new(entry)),
         Err(parse_error) => {
             // Parsing failed - create a malformed entry
             // Try to extract session_id if the JSON is partially parseable
@@ -240,8 +346,25 @@ pub fn parse_entry(raw: &str, line_number: usize) -> Result<LogEntry, ParseError
         })
         .transpose()?;
 
+    // parentUuid chains transcript entries, across files for resumed sessions
+    let transcript_parent_uuid = raw_entry
+        .transcript_parent_uuid
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            EntryUuid::new(s).map_err(|_| ParseError::MissingField {
+                line: line_number,
+                field: "parentUuid",
+            })
+        })
+        .transpose()?;
+
     // Validate and construct session ID (use unknown as fallback)
-    let session_id = match &raw_entry.session_id {
+    let session_id = match raw_entry
+        .session_id
+        .as_ref()
+        .or(raw_entry.transcript_session_id.as_ref())
+    {
         Some(id) if !id.is_empty() => {
             SessionId::new(id.as_str()).map_err(|_| ParseError::MissingField {
                 line: line_number,
@@ -328,7 +451,8 @@ pub fn parse_entry(raw: &str, line_number: usize) -> Result<LogEntry, ParseError
             message,
             metadata,
             result_metadata,
-        ))
+        )
+        .with_transcript_parent_uuid(transcript_parent_uuid))
     } else {
         Ok(LogEntry::new_with_system_metadata(
             uuid,
@@ -340,7 +464,8 @@ pub fn parse_entry(raw: &str, line_number: usize) -> Result<LogEntry, ParseError
             message,
             metadata,
             system_metadata,
-        ))
+        )
+        .with_transcript_parent_uuid(transcript_parent_uuid))
     }
 }
 
@@ -358,12 +483,29 @@ fn parse_entry_type(type_str: &str) -> Option<EntryType> {
 
 /// Parse system metadata from a RawLogEntry for System entries.
 ///
-/// Extracts subtype, cwd, model, tools, agents, and skills fields.
+/// Extracts subtype, cwd, model, tools, agents, skills, permission mode,
+/// MCP servers and version fields, plus compaction details for compact
+/// boundaries.
 /// Returns None if subtype is missing (required for SystemMetadata).
 fn parse_system_metadata(raw: &RawLogEntry) -> Option<SystemMetadata> {
     // Subtype is required for system metadata
     let subtype = raw.subtype.as_ref()?.clone();
 
+    // Compact boundaries always get details, even if the log omits them
+    let compact = (subtype == SystemMetadata::COMPACT_BOUNDARY).then(|| {
+        let raw_compact = raw.compact_metadata.as_ref();
+        CompactMetadata {
+            trigger: raw_compact.and_then(|c| c.trigger.clone()),
+            pre_tokens: raw_compact.and_then(|c| c.pre_tokens),
+            post_tokens: raw_compact.and_then(|c| c.post_tokens),
+            summary: raw
+                .content
+                .as_ref()
+                .and_then(|c| c.as_str())
+                .map(str::to_string),
+        }
+    });
+
     Some(SystemMetadata {
         subtype,
         cwd: raw.cwd.as_ref().map(PathBuf::from),
@@ -371,6 +513,18 @@ fn parse_system_metadata(raw: &RawLogEntry) -> Option<SystemMetadata> {
         tools: raw.tools.clone().unwrap_or_default(),
         agents: raw.agents.clone().unwrap_or_default(),
         skills: raw.skills.clone().unwrap_or_default(),
+        permission_mode: raw.permission_mode.clone(),
+        mcp_servers: raw
+            .mcp_servers
+            .iter()
+            .flatten()
+            .map(|server| McpServer {
+                name: server.name.clone(),
+                status: server.status.clone(),
+            })
+            .collect(),
+        version: raw.claude_code_version.clone(),
+        compact,
     })
 }
 
@@ -714,6 +868,11 @@ mod tests {
         let entry = result.unwrap();
         // Should use epoch fallback when timestamp is missing
         assert_eq!(entry.timestamp(), DateTime::UNIX_EPOCH);
+        assert_eq!(
+            entry.known_timestamp(),
+            None,
+            "Missing timestamp is unknown"
+        );
     }
 
     #[test]
@@ -1367,6 +1526,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_entry_system_init_with_session_settings() {
+        let raw = r#"{"type":"system","subtype":"init","uuid":"sys-004","session_id":"test-session","permissionMode":"acceptEdits","claude_code_version":"2.0.31","mcp_servers":[{"name":"github","status":"connected"},{"name":"linear","status":"failed"}]}"#;
+
+        let entry = parse_entry(raw, 1).expect("Should parse system:init entry");
+        let sys_meta = entry
+            .system_metadata()
+            .expect("System entry should have system_metadata");
+
+        assert_eq!(sys_meta.permission_mode.as_deref(), Some("acceptEdits"));
+        assert_eq!(sys_meta.version.as_deref(), Some("2.0.31"));
+        assert_eq!(
+            sys_meta.mcp_servers,
+            vec![
+                McpServer {
+                    name: "github".to_string(),
+                    status: Some("connected".to_string()),
+                },
+                McpServer {
+                    name: "linear".to_string(),
+                    status: Some("failed".to_string()),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn parse_entry_system_hook_response() {
         // System:hook_response entry (minimal metadata)
@@ -1388,6 +1573,48 @@ mod tests {
         assert!(sys_meta.skills.is_empty());
     }
 
+    #[test]
+    fn parse_entry_compact_boundary_with_metadata() {
+        let raw = r#"{"type":"system","subtype":"compact_boundary","content":"Conversation compacted","compactMetadata":{"trigger":"auto","preTokens":155123},"uuid":"sys-003","session_id":"test-session"}"#;
+
+        let entry = parse_entry(raw, 1).expect("Should parse compact boundary");
+        let sys_meta = entry
+            .system_metadata()
+            .expect("Should have system_metadata");
+        assert!(sys_meta.is_compact_boundary());
+
+        let compact = entry
+            .compact_metadata()
+            .expect("Should have compact metadata");
+        assert_eq!(compact.trigger.as_deref(), Some("auto"));
+        assert_eq!(compact.pre_tokens, Some(155_123));
+        assert_eq!(compact.post_tokens, None);
+        assert_eq!(compact.summary.as_deref(), Some("Conversation compacted"));
+    }
+
+    #[test]
+    fn parse_entry_compact_boundary_accepts_snake_case_metadata() {
+        // stream-json output uses snake_case keys
+        let raw = r#"{"type":"system","subtype":"compact_boundary","compact_metadata":{"trigger":"manual","pre_tokens":90000,"post_tokens":12000},"uuid":"sys-004","session_id":"test-session"}"#;
+
+        let entry = parse_entry(raw, 1).expect("Should parse compact boundary");
+        let compact = entry
+            .compact_metadata()
+            .expect("Should have compact metadata");
+        assert_eq!(compact.trigger.as_deref(), Some("manual"));
+        assert_eq!(compact.pre_tokens, Some(90_000));
+        assert_eq!(compact.post_tokens, Some(12_000));
+    }
+
+    #[test]
+    fn parse_entry_other_system_subtypes_have_no_compact_metadata() {
+        let raw =
+            r#"{"type":"system","subtype":"init","uuid":"sys-005","session_id":"test-session"}"#;
+
+        let entry = parse_entry(raw, 1).expect("Should parse system:init");
+        assert!(entry.compact_metadata().is_none());
+    }
+
     #[test]
     fn parse_entry_non_system_has_no_system_metadata() {
         // User entry should not have system_metadata even if fields are present
@@ -7,6 +7,7 @@
 
 use crate::model::LogEntry;
 use crate::model::error::InputError;
+use std::io::Read;
 use std::path::PathBuf;
 
 pub mod file;
@@ -47,6 +48,22 @@ impl InputSource {
         }
     }
 
+    /// Read every entry, waiting for stdin to reach EOF.
+    ///
+    /// Used by the headless subcommands, which work on the complete log.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InputError` for I/O errors.
+    pub fn read_all(mut self) -> Result<Vec<LogEntry>, InputError> {
+        let mut entries = self.poll()?;
+        while self.is_live() {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            entries.extend(self.poll()?);
+        }
+        Ok(entries)
+    }
+
     /// Check if the source is still live (can receive more data).
     ///
     /// # Behavior:
@@ -83,6 +100,63 @@ pub fn detect_input_source(file: Option<PathBuf>) -> Result<InputSource, InputEr
     }
 }
 
+/// Read the raw lines of a log, from stdin if no file is given.
+///
+/// Used by headless subcommands that report JSONL line numbers; line `n`
+/// of the log is element `n - 1`. Lines are decoded lossily and lose their
+/// `\n` or `\r\n` terminator; see `read_raw_lines` for the bytes as read.
+///
+/// # Errors
+///
+/// Returns `InputError::NoInput` if no file is provided and stdin is not piped.
+/// Returns `InputError::FileNotFound` if file does not exist.
+/// Returns `InputError::Io` for I/O errors during reading.
+pub fn read_lines(file: Option<PathBuf>) -> Result<Vec<String>, InputError> {
+    Ok(read_raw_lines(file)?
+        .iter()
+        .map(|line| {
+            let line = line.strip_suffix(b"\n").map_or(line.as_slice(), |line| {
+                line.strip_suffix(b"\r").unwrap_or(line)
+            });
+            String::from_utf8_lossy(line).into_owned()
+        })
+        .collect())
+}
+
+/// Read the lines of a log as bytes, each with its terminator, from stdin
+/// if no file is given.
+///
+/// Concatenating the lines gives the input back byte for byte, whatever its
+/// line endings or encoding.
+///
+/// # Errors
+///
+/// Returns `InputError::NoInput` if no file is provided and stdin is not piped.
+/// Returns `InputError::FileNotFound` if file does not exist.
+/// Returns `InputError::Io` for I/O errors during reading.
+pub fn read_raw_lines(file: Option<PathBuf>) -> Result<Vec<Vec<u8>>, InputError> {
+    let bytes = match file {
+        Some(path) => {
+            if !path.exists() {
+                return Err(InputError::FileNotFound { path });
+            }
+            std::fs::read(path)?
+        }
+        None => {
+            if StdinSource::is_tty() {
+                return Err(InputError::NoInput);
+            }
+            let mut bytes = Vec::new();
+            std::io::stdin().read_to_end(&mut bytes)?;
+            bytes
+        }
+    };
+    Ok(bytes
+        .split_inclusive(|&byte| byte == b'\n')
+        .map(<[u8]>::to_vec)
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,6 +193,19 @@ mod tests {
         assert_eq!(result[1].uuid().as_str(), "u2");
     }
 
+    #[test]
+    fn read_all_waits_for_stdin_eof() {
+        let content = r#"{"type":"user","message":{"role":"user","content":"First"},"sessionId":"s1","uuid":"u1","timestamp":"2025-12-27T10:00:00Z"}
+{"type":"assistant","message":{"role":"assistant","content":"Second"},"sessionId":"s1","uuid":"u2","timestamp":"2025-12-27T10:00:01Z"}
+"#;
+        let source = InputSource::Stdin(StdinSource::from_reader(std::io::Cursor::new(content)));
+
+        let entries = source.read_all().unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].uuid().as_str(), "u2");
+    }
+
     #[test]
     fn poll_returns_empty_vec_on_subsequent_calls_for_file() {
         use std::fs;
@@ -281,6 +368,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn read_lines_keeps_every_line_including_blank_and_malformed() {
+        let test_file = std::env::temp_dir().join("read_lines_test.jsonl");
+        std::fs::write(&test_file, b"{\"a\":1}\n\nnot json\r\n\xff").unwrap();
+
+        let lines = read_lines(Some(test_file.clone()));
+        let raw_lines = read_raw_lines(Some(test_file.clone()));
+        let _ = std::fs::remove_file(&test_file);
+
+        assert_eq!(
+            lines.unwrap(),
+            vec!["{\"a\":1}", "", "not json", "\u{fffd}"]
+        );
+        assert_eq!(
+            raw_lines.unwrap().concat(),
+            b"{\"a\":1}\n\nnot json\r\n\xff",
+            "raw lines keep terminators and bytes"
+        );
+        assert!(matches!(
+            read_lines(Some(test_file)),
+            Err(InputError::FileNotFound { .. })
+        ));
+    }
+
     #[test]
     fn detect_returns_no_input_when_no_file_and_stdin_is_tty() {
         // Note: This test assumes stdin IS a TTY in the test environment
@@ -95,7 +95,7 @@ impl StdinSource {
     /// Check if stdin is a TTY (interactive terminal).
     ///
     /// Used internally to detect piped vs interactive stdin.
-    fn is_tty() -> bool {
+    pub(crate) fn is_tty() -> bool {
         std::io::stdin().is_terminal()
     }
 }
@@ -6,6 +6,9 @@
 use crate::model::{AgentId, SessionId, StatsFilter};
 use crate::state::SearchState;
 use crate::view_state::log::LogViewState;
+use crate::view_state::scroll::ScrollPosition;
+use crate::view_state::session::SESSION_HEADING_HEIGHT;
+use crate::view_state::types::{EntryIndex, LineOffset};
 
 // ===== ConversationSelection =====
 
@@ -127,6 +130,15 @@ pub struct AppState {
     /// Toggled by user action (FR-019).
     pub stats_visible: bool,
 
+    /// X axis of the stats panel timeline charts (turn index or wall-clock time).
+    pub stats_chart_axis: crate::model::ChartAxis,
+
+    /// Page shown in the stats panel body (overview or tool table).
+    pub stats_page: crate::model::StatsPage,
+
+    /// Column the stats panel tool table is sorted by.
+    pub tool_sort: crate::model::ToolSortColumn,
+
     /// Whether the help overlay is currently visible.
     /// Toggled by user action to show keyboard shortcuts.
     pub help_visible: bool,
@@ -151,6 +163,13 @@ pub struct AppState {
     /// Default is `Wrap` when config is unset (FR-039).
     pub global_wrap: WrapMode,
 
+    /// Time gutter shown left of conversation entries (`T` cycles it).
+    pub time_gutter: TimeGutter,
+
+    /// Whether the session header shows full details (`i` toggles it).
+    /// Collapsed, it is a single summary line.
+    pub session_header_expanded: bool,
+
     /// Input mode for LIVE indicator display (FR-042b).
     /// Indicates whether reading from static file, actively streaming, or EOF.
     pub input_mode: InputMode,
@@ -160,11 +179,6 @@ pub struct AppState {
     /// `true` means indicator is visible (green), `false` means hidden.
     pub blink_on: bool,
 
-    /// Maximum context window size in tokens (cclv-5ur.32).
-    /// Used for token divider percentage calculation.
-    /// Default: 200,000 tokens (Claude Opus 4.5 context window).
-    pub max_context_tokens: u64,
-
     /// Pricing configuration for cost estimation (cclv-5ur.32).
     /// Used by token divider to show estimated costs.
     pub pricing: crate::model::PricingConfig,
@@ -172,9 +186,34 @@ pub struct AppState {
     /// Session list modal state.
     pub session_modal: crate::state::SessionModalState,
 
+    /// Files-touched modal state.
+    pub files_modal: crate::state::FilesModalState,
+
+    /// Subagent table modal state.
+    pub subagent_table: crate::state::SubagentTableState,
+
+    /// Saved search picker state.
+    pub saved_search_picker: crate::state::SavedSearchPickerState,
+
+    /// Export picker state.
+    pub export_picker: crate::state::ExportPickerState,
+
+    /// Named searches from the `[searches]` config table, sorted by name.
+    pub saved_searches: Vec<crate::state::SavedSearch>,
+
+    /// Previously submitted queries, recalled with Up/Down while typing.
+    pub search_history: crate::state::SearchHistory,
+
     /// Which session is currently being viewed.
+    ///
+    /// In continuous mode this follows the session at the top of the viewport
+    /// (see `LogViewState::active_session`).
     pub viewed_session: crate::state::ViewedSession,
 
+    /// Whether the main tab shows every session back to back (FR-076, FR-077)
+    /// instead of one session at a time.
+    pub continuous_sessions: bool,
+
     /// Per-session scroll positions (FR-010).
     /// Tracks scroll offset for each visited session.
     /// Key absence = unvisited (first visit shows top).
@@ -198,17 +237,28 @@ impl AppState {
             search: SearchState::Inactive,
             stats_filter: StatsFilter::AllSessionsCombined, // TODO: Should be session-aware
             stats_visible: false,
+            stats_chart_axis: crate::model::ChartAxis::default(),
+            stats_page: crate::model::StatsPage::default(),
+            tool_sort: crate::model::ToolSortColumn::default(),
             help_visible: false,
             help_scroll_offset: 0,
             live_mode: false,
             auto_scroll: true,
             global_wrap: WrapMode::default(),
+            time_gutter: TimeGutter::default(),
+            session_header_expanded: false,
             input_mode: InputMode::default(),
             blink_on: true, // Start with indicator visible
-            max_context_tokens: 200_000,
             pricing: crate::model::PricingConfig::default(),
             session_modal: crate::state::SessionModalState::new(),
+            files_modal: crate::state::FilesModalState::new(),
+            subagent_table: crate::state::SubagentTableState::new(),
+            saved_search_picker: crate::state::SavedSearchPickerState::new(),
+            export_picker: crate::state::ExportPickerState::new(),
+            saved_searches: Vec::new(),
+            search_history: crate::state::SearchHistory::new(),
             viewed_session: crate::state::ViewedSession::default(), // ViewedSession::Latest
+            continuous_sessions: false,
             session_scroll_states: crate::state::SessionScrollStates::new(),
         }
     }
@@ -283,6 +333,11 @@ impl AppState {
             self.log_view.add_entry(entry, agent_id);
         }
 
+        // Modal lists include the new entries
+        self.session_modal.invalidate_rows();
+        self.files_modal.invalidate();
+        self.subagent_table.invalidate_rows();
+
         // Synchronize stats filter with current session after adding entries
         self.sync_stats_filter();
     }
@@ -660,6 +715,140 @@ impl AppState {
         };
     }
 
+    /// Cycle the time gutter: Off → Absolute → Relative → Off.
+    ///
+    /// Conversations must be relaid out with the new `TimeGutter::width`.
+    pub fn cycle_time_gutter(&mut self) {
+        self.time_gutter = self.time_gutter.next();
+    }
+
+    /// Switch between one-session-at-a-time and continuous display.
+    ///
+    /// Entering continuous mode keeps the viewed session's scroll position.
+    pub fn toggle_continuous_sessions(&mut self) {
+        self.continuous_sessions = !self.continuous_sessions;
+        self.log_view.set_chain(None);
+        if !self.continuous_sessions {
+            return;
+        }
+        self.log_view.layout_continuous();
+        let session_count = self.log_view.session_count();
+        let line = self
+            .viewed_session
+            .effective_index(session_count)
+            .and_then(|idx| self.log_view.get_session(idx.get()))
+            .map(|session| match session.main().approximate_scroll_line() {
+                // At the top: show the session's heading too
+                0 => session.start_line(),
+                line => session.start_line() + SESSION_HEADING_HEIGHT + line,
+            })
+            .unwrap_or(0);
+        self.log_view.set_continuous_scroll(line);
+    }
+
+    /// Show the whole chain of resumed sessions that session `index` belongs
+    /// to, back to back, starting at that session.
+    pub fn view_chain(&mut self, index: usize) {
+        let session_count = self.log_view.session_count();
+        let Some(viewed) = crate::state::ViewedSession::pinned(index, session_count) else {
+            return;
+        };
+        let chain = self.log_view.session_links().chain(index);
+        self.log_view.set_chain(Some(chain));
+        self.continuous_sessions = true;
+        self.selected_conversation = ConversationSelection::Main;
+        self.viewed_session = if viewed.is_last(session_count) {
+            crate::state::ViewedSession::Latest
+        } else {
+            viewed
+        };
+        if let Some(session_id) = self
+            .log_view
+            .get_session(index)
+            .map(|s| s.session_id().clone())
+        {
+            self.on_session_change(session_id);
+        }
+        self.scroll_to_viewed_session();
+    }
+
+    /// Scroll the continuous view to the viewed session's heading.
+    pub fn scroll_to_viewed_session(&mut self) {
+        self.log_view.layout_continuous();
+        let session_count = self.log_view.session_count();
+        let start_line = self
+            .viewed_session
+            .effective_index(session_count)
+            .and_then(|idx| self.log_view.get_session(idx.get()))
+            .map_or(0, |session| session.start_line());
+        self.log_view.set_continuous_scroll(start_line);
+    }
+
+    /// Fold the viewed session to its heading in continuous mode, or unfold it.
+    ///
+    /// Folding scrolls to the session's heading so it stays in view.
+    pub fn toggle_session_fold(&mut self) {
+        let session_count = self.log_view.session_count();
+        let Some(idx) = self.viewed_session.effective_index(session_count) else {
+            return;
+        };
+        let Some(session) = self.log_view.get_session_mut(idx.get()) else {
+            return;
+        };
+        let folded = !session.is_folded();
+        session.set_folded(folded);
+        self.log_view.layout_continuous();
+        if folded {
+            self.scroll_to_viewed_session();
+        }
+    }
+
+    /// Make the session at the continuous scroll position the viewed session,
+    /// and scroll its main conversation to the same line.
+    ///
+    /// Keeps tabs, stats and the session header in step with the continuous
+    /// view. Session start lines must be current (`layout_continuous`).
+    pub fn sync_continuous_session(&mut self) {
+        let line = self.log_view.continuous_scroll();
+        let session_count = self.log_view.session_count();
+        let Some(active) = self.log_view.active_session_index(line) else {
+            return;
+        };
+        let Some(viewed) = crate::state::ViewedSession::pinned(active, session_count) else {
+            return;
+        };
+        let viewed = if viewed.is_last(session_count) {
+            crate::state::ViewedSession::Latest
+        } else {
+            viewed
+        };
+        if viewed != self.viewed_session {
+            self.viewed_session = viewed;
+            if let Some(session_id) = self
+                .log_view
+                .get_session(active)
+                .map(|s| s.session_id().clone())
+            {
+                self.on_session_change(session_id);
+            }
+        }
+        if let Some(session) = self.log_view.get_session_mut(active) {
+            let local = line.saturating_sub(session.start_line() + SESSION_HEADING_HEIGHT);
+            let main = session.main_mut();
+            main.set_scroll(ScrollPosition::AtLine(LineOffset::new(local)));
+
+            // Focus the entry at the top of the viewport, as scrolling does
+            let focused = (0..main.len())
+                .map(EntryIndex::new)
+                .take_while(|&idx| {
+                    main.entry_cumulative_y(idx)
+                        .is_some_and(|y| y.get() <= local)
+                })
+                .last();
+            main.set_focused_message(focused);
+        }
+    }
+
     /// Check if live tailing should be active (cclv-463.4.1).
     ///
     /// Live tailing is enabled when BOTH conditions are met:
@@ -858,6 +1047,40 @@ pub enum WrapMode {
     NoWrap,
 }
 
+/// Time gutter mode for conversation entries.
+///
+/// The gutter shows each entry's time, the gap since the previous entry and,
+/// on user prompts, the latency of the turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeGutter {
+    /// No gutter.
+    #[default]
+    Off,
+    /// Wall-clock time of day (`14:03:12`).
+    Absolute,
+    /// Time since the conversation started (`+2:05`).
+    Relative,
+}
+
+impl TimeGutter {
+    /// Next mode in the `T` key cycle.
+    pub fn next(self) -> Self {
+        match self {
+            TimeGutter::Off => TimeGutter::Absolute,
+            TimeGutter::Absolute => TimeGutter::Relative,
+            TimeGutter::Relative => TimeGutter::Off,
+        }
+    }
+
+    /// Columns the gutter takes from the conversation pane.
+    pub fn width(self) -> u16 {
+        match self {
+            TimeGutter::Off => 0,
+            TimeGutter::Absolute | TimeGutter::Relative => 18,
+        }
+    }
+}
+
 /// Wrap context captures both the wrap mode and whether it's from an explicit per-entry override.
 ///
 /// This type allows render logic to distinguish between:
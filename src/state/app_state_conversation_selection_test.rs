@@ -5,6 +5,7 @@
 
 use super::*;
 use crate::model::{AgentId, ConversationEntry};
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -13,9 +14,9 @@ fn state_with_subagents() -> AppState {
     let mut state = AppState::new();
 
     // Add entries to create main and two subagents
-    let main_entry = ConversationEntry::Valid(Box::new(create_test_log_entry(None)));
-    let agent_a_entry = ConversationEntry::Valid(Box::new(create_test_log_entry(Some("agent-a"))));
-    let agent_b_entry = ConversationEntry::Valid(Box::new(create_test_log_entry(Some("agent-b"))));
+    let main_entry = ConversationEntry::Valid(Arc::new(create_test_log_entry(None)));
+    let agent_a_entry = ConversationEntry::Valid(Arc::new(create_test_log_entry(Some("agent-a"))));
+    let agent_b_entry = ConversationEntry::Valid(Arc::new(create_test_log_entry(Some("agent-b"))));
 
     state.add_entries(vec![main_entry, agent_a_entry, agent_b_entry]);
     state
@@ -244,7 +245,7 @@ fn create_test_state_with_sessions(session_count: usize) -> AppState {
             ),
             EntryMetadata::default(),
         );
-        state.add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+        state.add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
     }
 
     state
@@ -493,7 +494,7 @@ fn selection_stable_when_subagent_added() {
     state.selected_conversation = ConversationSelection::Subagent(agent_b.clone());
 
     // Add a new subagent agent-c
-    let agent_c_entry = ConversationEntry::Valid(Box::new(create_test_log_entry(Some("agent-c"))));
+    let agent_c_entry = ConversationEntry::Valid(Arc::new(create_test_log_entry(Some("agent-c"))));
     state.add_entries(vec![agent_c_entry]);
 
     // Selection should still be agent-b (unchanged)
@@ -514,7 +515,7 @@ fn tab_index_changes_when_earlier_subagent_added() {
 
     // Add agent-a1 (alphabetically between agent-a and agent-b)
     let agent_a1_entry =
-        ConversationEntry::Valid(Box::new(create_test_log_entry(Some("agent-a1"))));
+        ConversationEntry::Valid(Arc::new(create_test_log_entry(Some("agent-a1"))));
     state.add_entries(vec![agent_a1_entry]);
 
     // Selection is still agent-b (stable identity)
@@ -8,6 +8,7 @@ use crate::model::{
 };
 use crate::state::{AppState, ConversationSelection};
 use chrono::Utc;
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -26,7 +27,7 @@ fn create_main_entry(uuid_str: &str, text: &str) -> ConversationEntry {
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(entry))
+    ConversationEntry::Valid(Arc::new(entry))
 }
 
 /// Create a test conversation entry for a subagent.
@@ -45,7 +46,7 @@ fn create_subagent_entry(uuid_str: &str, agent_id_str: &str, text: &str) -> Conv
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(entry))
+    ConversationEntry::Valid(Arc::new(entry))
 }
 
 // ===== Tests for selected_conversation_view() =====
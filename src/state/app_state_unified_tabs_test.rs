@@ -27,7 +27,7 @@ fn create_session_with_main_and_subagents(num_subagents: usize) -> Vec<Conversat
         Message::new(Role::User, MessageContent::Text("Main message".to_string())),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(main_entry)));
+    entries.push(ConversationEntry::Valid(Arc::new(main_entry)));
 
     // Add subagent entries
     for i in 1..=num_subagents {
@@ -44,7 +44,7 @@ fn create_session_with_main_and_subagents(num_subagents: usize) -> Vec<Conversat
             ),
             EntryMetadata::default(),
         );
-        entries.push(ConversationEntry::Valid(Box::new(subagent_entry)));
+        entries.push(ConversationEntry::Valid(Arc::new(subagent_entry)));
     }
 
     entries
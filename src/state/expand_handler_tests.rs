@@ -6,6 +6,7 @@ use crate::model::{
     Role, SessionId,
 };
 use crate::view_state::types::EntryIndex;
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -36,7 +37,7 @@ fn make_valid_entry(uuid: &str) -> ConversationEntry {
         make_message("Test message"),
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 // ===== Handler Integration Tests =====
@@ -0,0 +1,182 @@
+//! State and keyboard handling for the export picker.
+//!
+//! The picker chooses what to export (see `ExportScope`); the event loop
+//! writes the document built by `export_document` to the working directory
+//! and reports the outcome with `ExportPickerState::set_status`.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::export::ExportScope;
+use crate::export::markdown;
+use crate::state::{AppState, ConversationSelection};
+
+/// State for the export picker overlay.
+#[derive(Debug, Clone, Default)]
+pub struct ExportPickerState {
+    /// Whether the picker is visible.
+    visible: bool,
+
+    /// Currently selected row (index into `ExportScope::ALL`).
+    selected_index: usize,
+
+    /// Outcome of the last export, shown until the picker closes.
+    status: Option<String>,
+}
+
+impl ExportPickerState {
+    /// Create new picker state (closed).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if picker is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open the picker with the first scope selected.
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.selected_index = 0;
+        self.status = None;
+    }
+
+    /// Close the picker.
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// Toggle picker visibility.
+    pub fn toggle(&mut self) {
+        if self.visible {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Currently selected index.
+    pub fn selected_index(&self) -> usize {
+        self.selected_index
+    }
+
+    /// Currently selected scope.
+    pub fn selected_scope(&self) -> ExportScope {
+        ExportScope::ALL[self.selected_index]
+    }
+
+    /// Outcome of the last export.
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Report the outcome of an export.
+    pub fn set_status(&mut self, status: impl Into<String>) {
+        self.status = Some(status.into());
+    }
+}
+
+/// Result of routing a key through the export picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportPickerOutcome {
+    /// Picker not visible; key was not handled.
+    Ignored,
+    /// Key was consumed by the picker.
+    Consumed,
+    /// A scope was picked; the caller should write `export_document` for it.
+    Export(ExportScope),
+}
+
+/// Handle keyboard input when the export picker is visible.
+///
+/// # Key Bindings
+/// - Up/k: Select previous scope (clamps at 0)
+/// - Down/j: Select next scope (clamps at last)
+/// - Enter: Export the selected scope (the picker stays open with the outcome)
+/// - Esc/E: Close
+///
+/// All other keys are swallowed while the picker is open.
+pub fn handle_export_picker_key(state: &mut AppState, key: KeyEvent) -> ExportPickerOutcome {
+    if !state.export_picker.is_visible() {
+        return ExportPickerOutcome::Ignored;
+    }
+
+    let picker = &mut state.export_picker;
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('E') => picker.close(),
+        KeyCode::Up | KeyCode::Char('k') => {
+            picker.selected_index = picker.selected_index.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            picker.selected_index = (picker.selected_index + 1).min(ExportScope::ALL.len() - 1);
+        }
+        KeyCode::Enter => return ExportPickerOutcome::Export(picker.selected_scope()),
+        _ => {}
+    }
+
+    ExportPickerOutcome::Consumed
+}
+
+/// An exported document and the file name it is saved under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportDocument {
+    /// File name, e.g. `cclv-5788beec-session.md`; tab and entry exports
+    /// also name the subagent and entry (`cclv-5788beec-agent-1-entry-3.md`),
+    /// so exporting another tab or entry does not overwrite the last one.
+    pub file_name: String,
+    /// Document contents.
+    pub contents: String,
+}
+
+/// Build the Markdown document for `scope` from the viewed session and
+/// selected tab.
+///
+/// # Errors
+///
+/// Returns a message for the status line when there is nothing to export.
+pub fn export_document(state: &AppState, scope: ExportScope) -> Result<ExportDocument, String> {
+    let session_count = state.log_view().session_count();
+    let session = state
+        .viewed_session
+        .effective_index(session_count)
+        .and_then(|index| state.log_view().get_session(index.get()))
+        .ok_or("No session to export")?;
+    let agent_id = match &state.selected_conversation {
+        ConversationSelection::Main => None,
+        ConversationSelection::Subagent(agent_id) => Some(agent_id),
+    };
+
+    let session_id: String = session.session_id().as_str().chars().take(8).collect();
+    let mut file_name = format!("cclv-{}", session_id);
+    if let (ExportScope::Tab | ExportScope::Entry, Some(agent_id)) = (scope, agent_id) {
+        file_name.push_str(&format!("-{}", agent_id));
+    }
+    file_name.push_str(&format!("-{}", scope.file_suffix()));
+
+    let contents = match scope {
+        ExportScope::Session => markdown::session_markdown(session, &state.pricing),
+        ExportScope::Tab => markdown::conversation_markdown(session, agent_id, &state.pricing),
+        ExportScope::Entry => {
+            let conversation = match agent_id {
+                None => Some(session.main()),
+                Some(agent_id) => session.get_subagent(agent_id),
+            };
+            let index = conversation.and_then(|c| c.focused_message());
+            let entry = conversation
+                .and_then(|c| c.focused_entry())
+                .and_then(|e| e.entry().as_valid());
+            let (index, entry) = index.zip(entry).ok_or("No focused entry to export")?;
+            file_name.push_str(&format!("-{}", index.get() + 1));
+            markdown::entry_markdown(session, entry, &state.pricing)
+        }
+    };
+
+    Ok(ExportDocument {
+        file_name: format!("{}.md", file_name),
+        contents,
+    })
+}
+
+#[cfg(test)]
+#[path = "export_picker_tests.rs"]
+mod tests;
@@ -0,0 +1,133 @@
+//! Tests for the export picker.
+
+use super::*;
+use crate::model::{
+    AgentId, ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry, Message,
+    MessageContent, Role, SessionId,
+};
+use crate::view_state::types::EntryIndex;
+use chrono::{TimeZone, Utc};
+use crossterm::event::KeyModifiers;
+use std::sync::Arc;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+fn entry(uuid: &str, agent: Option<&str>, text: &str) -> ConversationEntry {
+    ConversationEntry::Valid(Arc::new(LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("5788beec-e7de").unwrap(),
+        agent.map(|a| AgentId::new(a).unwrap()),
+        Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+        EntryType::User,
+        Message::new(Role::User, MessageContent::Text(text.to_string())),
+        EntryMetadata::default(),
+    )))
+}
+
+fn state_with_session() -> AppState {
+    let mut state = AppState::new();
+    state.add_entries(vec![
+        entry("u1", None, "Main prompt"),
+        entry("u2", None, "Second prompt"),
+        entry("s1", Some("agent-1"), "Subagent prompt"),
+    ]);
+    state
+}
+
+#[test]
+fn keys_are_ignored_when_picker_closed() {
+    let mut state = state_with_session();
+
+    let outcome = handle_export_picker_key(&mut state, key(KeyCode::Enter));
+
+    assert_eq!(outcome, ExportPickerOutcome::Ignored);
+}
+
+#[test]
+fn enter_exports_selected_scope_and_keeps_picker_open() {
+    let mut state = state_with_session();
+    state.export_picker.open();
+
+    handle_export_picker_key(&mut state, key(KeyCode::Down));
+    let outcome = handle_export_picker_key(&mut state, key(KeyCode::Enter));
+
+    assert_eq!(outcome, ExportPickerOutcome::Export(ExportScope::Tab));
+    assert!(state.export_picker.is_visible());
+
+    for _ in 0..5 {
+        handle_export_picker_key(&mut state, key(KeyCode::Char('j')));
+    }
+    assert_eq!(state.export_picker.selected_scope(), ExportScope::Entry);
+
+    handle_export_picker_key(&mut state, key(KeyCode::Esc));
+    assert!(!state.export_picker.is_visible());
+}
+
+#[test]
+fn reopening_clears_status() {
+    let mut state = AppState::new();
+    state.export_picker.open();
+    state.export_picker.set_status("Wrote cclv-x-session.md");
+
+    state.export_picker.toggle();
+    state.export_picker.toggle();
+
+    assert_eq!(state.export_picker.status(), None);
+}
+
+#[test]
+fn session_document_is_named_after_session() {
+    let state = state_with_session();
+
+    let document = export_document(&state, ExportScope::Session).unwrap();
+
+    assert_eq!(document.file_name, "cclv-5788beec-session.md");
+    assert!(document.contents.contains("Main prompt"));
+    assert!(document.contents.contains("## Subagent: agent-1"));
+}
+
+#[test]
+fn tab_document_follows_selected_conversation() {
+    let mut state = state_with_session();
+    state.selected_conversation = ConversationSelection::Subagent(AgentId::new("agent-1").unwrap());
+
+    let document = export_document(&state, ExportScope::Tab).unwrap();
+
+    assert_eq!(document.file_name, "cclv-5788beec-agent-1-tab.md");
+    assert!(document.contents.starts_with("# Subagent: agent-1"));
+    assert!(!document.contents.contains("Main prompt"));
+}
+
+#[test]
+fn entry_document_needs_focused_entry() {
+    let mut state = state_with_session();
+
+    assert_eq!(
+        export_document(&state, ExportScope::Entry),
+        Err("No focused entry to export".to_string())
+    );
+
+    state
+        .log_view_mut()
+        .current_session_mut()
+        .unwrap()
+        .main_mut()
+        .set_focused_message(Some(EntryIndex::new(1)));
+    let document = export_document(&state, ExportScope::Entry).unwrap();
+
+    assert_eq!(document.file_name, "cclv-5788beec-entry-2.md");
+    assert_eq!(document.contents, "# User · 14:00:00\n\nSecond prompt\n");
+}
+
+#[test]
+fn nothing_to_export_without_sessions() {
+    let state = AppState::new();
+
+    assert_eq!(
+        export_document(&state, ExportScope::Session),
+        Err("No session to export".to_string())
+    );
+}
@@ -0,0 +1,329 @@
+//! State and keyboard handling for the files-touched modal.
+//!
+//! Lists the files touched by file tool calls in the viewed session, scoped
+//! by `AppState::stats_filter`. Opening a file lists the calls that touched
+//! it or its reconstructed change history; confirming a call or change jumps
+//! to its entry. The file list is built when the modal opens and the history
+//! when its page opens, and both are kept until the log changes (see
+//! `refresh_files_modal`).
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::model::{AgentId, EntryUuid, FileActivity, FileHistory};
+use crate::state::AppState;
+use crate::state::match_navigation_handler::jump_to_entry;
+
+/// Page shown by the files-touched modal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilesModalPage {
+    /// All files in scope.
+    #[default]
+    Files,
+    /// Calls that touched the selected file.
+    Calls,
+    /// Reconstructed change history of the selected file.
+    History,
+}
+
+/// Lines scrolled per page in the history diff.
+const DIFF_PAGE_LINES: u16 = 10;
+
+/// State for the files-touched modal.
+#[derive(Debug, Clone, Default)]
+pub struct FilesModalState {
+    /// Whether the modal is visible.
+    visible: bool,
+
+    /// Current page.
+    page: FilesModalPage,
+
+    /// Selected row in the file list (0-indexed).
+    selected_file: usize,
+
+    /// Selected row in the call list (0-indexed).
+    /// Only meaningful on `FilesModalPage::Calls`.
+    selected_call: usize,
+
+    /// Selected history step (0 = cumulative diff, N = Nth change).
+    /// Only meaningful on `FilesModalPage::History`.
+    selected_step: usize,
+
+    /// Scroll offset of the history diff in lines.
+    diff_scroll: u16,
+
+    /// Files in scope; `None` until built by `refresh_files_modal`.
+    files: Option<Vec<FileActivity>>,
+
+    /// History of the selected file, built by `refresh_files_modal` once the
+    /// History page is open.
+    history: Option<FileHistory>,
+}
+
+impl FilesModalState {
+    /// Create new modal state (closed).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if modal is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open the modal on the file list with the first file selected.
+    pub fn open(&mut self) {
+        *self = Self {
+            visible: true,
+            ..Self::default()
+        };
+    }
+
+    /// Close the modal.
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// Toggle modal visibility.
+    pub fn toggle(&mut self) {
+        if self.visible {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Current page.
+    pub fn page(&self) -> FilesModalPage {
+        self.page
+    }
+
+    /// Selected row in the file list.
+    pub fn selected_file(&self) -> usize {
+        self.selected_file
+    }
+
+    /// Selected row in the call list.
+    pub fn selected_call(&self) -> usize {
+        self.selected_call
+    }
+
+    /// Selected history step (0 = cumulative diff).
+    pub fn selected_step(&self) -> usize {
+        self.selected_step
+    }
+
+    /// Scroll offset of the history diff.
+    pub fn diff_scroll(&self) -> u16 {
+        self.diff_scroll
+    }
+
+    /// Show the call list of the selected file.
+    pub fn show_calls(&mut self) {
+        self.page = FilesModalPage::Calls;
+        self.selected_call = 0;
+    }
+
+    /// Show the change history of the selected file, starting at the cumulative diff.
+    pub fn show_history(&mut self) {
+        self.page = FilesModalPage::History;
+        self.selected_step = 0;
+        self.diff_scroll = 0;
+        self.history = None;
+    }
+
+    /// Files in scope (empty until built by `refresh_files_modal`).
+    pub fn files(&self) -> &[FileActivity] {
+        self.files.as_deref().unwrap_or(&[])
+    }
+
+    /// History of the selected file, on the History page once built by
+    /// `refresh_files_modal`.
+    pub fn history(&self) -> Option<&FileHistory> {
+        self.history
+            .as_ref()
+            .filter(|_| self.page == FilesModalPage::History)
+    }
+
+    /// Mark the file list and history for rebuilding, e.g. after entries
+    /// were added.
+    pub fn invalidate(&mut self) {
+        self.files = None;
+        self.history = None;
+    }
+
+    /// Return to the file list, keeping the file selection.
+    pub fn show_files(&mut self) {
+        self.page = FilesModalPage::Files;
+    }
+
+    /// Move the active list's selection up, clamping at 0.
+    pub fn select_prev(&mut self) {
+        let selected = self.active_selection_mut();
+        *selected = selected.saturating_sub(1);
+        self.diff_scroll = 0;
+    }
+
+    /// Move the active list's selection down, clamping at the last row.
+    pub fn select_next(&mut self, count: usize) {
+        if count > 0 {
+            let selected = self.active_selection_mut();
+            *selected = (*selected + 1).min(count - 1);
+            self.diff_scroll = 0;
+        }
+    }
+
+    /// Jump to the first row of the active list.
+    pub fn select_first(&mut self) {
+        *self.active_selection_mut() = 0;
+        self.diff_scroll = 0;
+    }
+
+    /// Jump to the last row of the active list.
+    pub fn select_last(&mut self, count: usize) {
+        *self.active_selection_mut() = count.saturating_sub(1);
+        self.diff_scroll = 0;
+    }
+
+    /// Scroll the history diff down one page.
+    pub fn scroll_diff_down(&mut self) {
+        self.diff_scroll = self.diff_scroll.saturating_add(DIFF_PAGE_LINES);
+    }
+
+    /// Scroll the history diff up one page.
+    pub fn scroll_diff_up(&mut self) {
+        self.diff_scroll = self.diff_scroll.saturating_sub(DIFF_PAGE_LINES);
+    }
+
+    fn active_selection_mut(&mut self) -> &mut usize {
+        match self.page {
+            FilesModalPage::Files => &mut self.selected_file,
+            FilesModalPage::Calls => &mut self.selected_call,
+            FilesModalPage::History => &mut self.selected_step,
+        }
+    }
+}
+
+/// Files touched in the viewed session within the current stats filter.
+pub fn files_in_scope(state: &AppState) -> Vec<FileActivity> {
+    if state.log_view().session_count() == 0 {
+        return Vec::new();
+    }
+    state
+        .session_view()
+        .stats()
+        .filtered_files(&state.stats_filter)
+}
+
+/// Build the modal's file list, and on the History page the selected file's
+/// history, if the modal is open and they are missing or stale.
+///
+/// The history uses every entry of the viewed session regardless of the
+/// stats filter, since all agents write to the same files. Both are built
+/// once rather than on every key and frame, as the history diffs file
+/// contents.
+pub fn refresh_files_modal(state: &mut AppState) {
+    if !state.files_modal.is_visible() {
+        return;
+    }
+    if state.files_modal.files.is_none() {
+        let files = files_in_scope(state);
+        state.files_modal.files = Some(files);
+    }
+    if state.files_modal.page == FilesModalPage::History && state.files_modal.history.is_none() {
+        let history = state
+            .files_modal
+            .files()
+            .get(state.files_modal.selected_file)
+            .map(|file| FileHistory::build(&file.path, state.session_view().log_entries_by_time()));
+        state.files_modal.history = history;
+    }
+}
+
+/// Handle keyboard input when the files modal is visible.
+///
+/// Returns `true` if the key was consumed by the modal, `false` otherwise.
+///
+/// # Key Bindings
+/// - Up/k, Down/j: Select previous/next row (clamps at bounds)
+/// - Home/g, End/G: Jump to first/last row
+/// - Enter: Open the selected file's calls; on a call or history step,
+///   jump to it and close
+/// - d: Open the selected file's change history
+/// - Ctrl+d/PageDown, Ctrl+u/PageUp: Scroll the history diff
+/// - Esc/Backspace/h: Back to the file list (closes from the file list)
+/// - F: Close
+///
+/// All other keys are swallowed while the modal is open.
+pub fn handle_files_modal_key(state: &mut AppState, key: KeyEvent) -> bool {
+    if !state.files_modal.is_visible() {
+        return false;
+    }
+
+    refresh_files_modal(state);
+    let modal = &state.files_modal;
+    let selected_file = modal.files().get(modal.selected_file());
+    let count = match modal.page() {
+        FilesModalPage::Files => modal.files().len(),
+        FilesModalPage::Calls => selected_file.map_or(0, |file| file.touches.len()),
+        // Step 0 is the cumulative diff
+        FilesModalPage::History => modal.history().map_or(0, |h| h.changes.len() + 1),
+    };
+    // Entry the confirmed call or change jumps to
+    let target = match modal.page() {
+        FilesModalPage::Files => None,
+        FilesModalPage::Calls => selected_file
+            .and_then(|file| file.touches.get(modal.selected_call()))
+            .map(|touch| (touch.agent_id.clone(), touch.entry_uuid.clone())),
+        FilesModalPage::History => modal
+            .history()
+            .and_then(|h| h.changes.get(modal.selected_step().checked_sub(1)?))
+            .map(|change| (change.agent_id.clone(), change.entry_uuid.clone())),
+    };
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+    match key.code {
+        KeyCode::Char('F') => state.files_modal.close(),
+        KeyCode::Esc | KeyCode::Backspace | KeyCode::Char('h') => {
+            if state.files_modal.page() == FilesModalPage::Files {
+                state.files_modal.close();
+            } else {
+                state.files_modal.show_files();
+            }
+        }
+        KeyCode::PageDown => state.files_modal.scroll_diff_down(),
+        KeyCode::Char('d') if ctrl => state.files_modal.scroll_diff_down(),
+        KeyCode::PageUp => state.files_modal.scroll_diff_up(),
+        KeyCode::Char('u') if ctrl => state.files_modal.scroll_diff_up(),
+        KeyCode::Up | KeyCode::Char('k') => state.files_modal.select_prev(),
+        KeyCode::Down | KeyCode::Char('j') => state.files_modal.select_next(count),
+        KeyCode::Home | KeyCode::Char('g') => state.files_modal.select_first(),
+        KeyCode::End => state.files_modal.select_last(count),
+        KeyCode::Char('G') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            state.files_modal.select_last(count)
+        }
+        KeyCode::Char('d') if state.files_modal.page() == FilesModalPage::Files && count > 0 => {
+            state.files_modal.show_history();
+            refresh_files_modal(state);
+        }
+        KeyCode::Enter => match state.files_modal.page() {
+            FilesModalPage::Files if count > 0 => state.files_modal.show_calls(),
+            FilesModalPage::Files => {}
+            FilesModalPage::Calls | FilesModalPage::History => jump_and_close(state, target),
+        },
+        _ => {}
+    }
+
+    true
+}
+
+/// Jump to the entry (if any) and close the modal.
+fn jump_and_close(state: &mut AppState, target: Option<(Option<AgentId>, EntryUuid)>) {
+    if let Some((agent_id, entry_uuid)) = target {
+        jump_to_entry(state, &agent_id, &entry_uuid);
+        state.files_modal.close();
+    }
+}
+
+#[cfg(test)]
+#[path = "files_modal_tests.rs"]
+mod tests;
@@ -0,0 +1,209 @@
+//! Tests for the files-touched modal.
+
+use super::*;
+use crate::model::{
+    AgentId, ContentBlock, ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry,
+    Message, MessageContent, Role, SessionId, ToolCall, ToolName, ToolUseId,
+};
+use crate::state::{ConversationSelection, FocusPane};
+use std::sync::Arc;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+fn file_entry(uuid: &str, agent: Option<&str>, calls: &[(&str, &str)]) -> ConversationEntry {
+    let blocks = calls
+        .iter()
+        .enumerate()
+        .map(|(i, (tool, path))| {
+            ContentBlock::ToolUse(ToolCall::new(
+                ToolUseId::new(format!("{}-{}", uuid, i)).unwrap(),
+                ToolName::parse(tool),
+                serde_json::json!({ "file_path": path }),
+            ))
+        })
+        .collect();
+    ConversationEntry::Valid(Arc::new(LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        agent.map(|a| AgentId::new(a).unwrap()),
+        chrono::Utc::now(),
+        EntryType::Assistant,
+        Message::new(Role::Assistant, MessageContent::Blocks(blocks)),
+        EntryMetadata::default(),
+    )))
+}
+
+/// `/a.rs` read by main (e1) and edited by agent-1 (e2); `/b.rs` written by main (e3).
+fn state_with_files() -> AppState {
+    let mut state = AppState::new();
+    state.add_entries(vec![
+        file_entry("e1", None, &[("Read", "/a.rs")]),
+        file_entry("e2", Some("agent-1"), &[("Edit", "/a.rs")]),
+        file_entry("e3", None, &[("Write", "/b.rs")]),
+    ]);
+    state.stats_filter = crate::model::StatsFilter::AllSessionsCombined;
+    state
+}
+
+#[test]
+fn keys_are_ignored_when_modal_closed() {
+    let mut state = state_with_files();
+
+    assert!(!handle_files_modal_key(&mut state, key(KeyCode::Enter)));
+}
+
+#[test]
+fn files_in_scope_follows_stats_filter() {
+    let mut state = state_with_files();
+
+    assert_eq!(files_in_scope(&state).len(), 2);
+
+    state.stats_filter = crate::model::StatsFilter::Subagent(AgentId::new("agent-1").unwrap());
+    let files = files_in_scope(&state);
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path, "/a.rs");
+}
+
+#[test]
+fn files_in_scope_is_empty_without_sessions() {
+    assert!(files_in_scope(&AppState::new()).is_empty());
+}
+
+#[test]
+fn file_list_is_kept_until_entries_arrive() {
+    let mut state = state_with_files();
+    state.files_modal.open();
+    handle_files_modal_key(&mut state, key(KeyCode::Down));
+    assert_eq!(state.files_modal.files().len(), 2);
+
+    state.add_entries(vec![file_entry("e4", None, &[("Read", "/c.rs")])]);
+    assert!(state.files_modal.files().is_empty());
+
+    handle_files_modal_key(&mut state, key(KeyCode::Down));
+    assert_eq!(state.files_modal.files().len(), 3);
+}
+
+#[test]
+fn navigation_clamps_to_file_count() {
+    let mut state = state_with_files();
+    state.files_modal.open();
+
+    for _ in 0..5 {
+        handle_files_modal_key(&mut state, key(KeyCode::Char('j')));
+    }
+    assert_eq!(state.files_modal.selected_file(), 1);
+
+    handle_files_modal_key(&mut state, key(KeyCode::Home));
+    assert_eq!(state.files_modal.selected_file(), 0);
+}
+
+#[test]
+fn enter_opens_calls_and_esc_returns_to_files() {
+    let mut state = state_with_files();
+    state.files_modal.open();
+
+    handle_files_modal_key(&mut state, key(KeyCode::Enter));
+    assert_eq!(state.files_modal.page(), FilesModalPage::Calls);
+
+    handle_files_modal_key(&mut state, key(KeyCode::Down));
+    handle_files_modal_key(&mut state, key(KeyCode::Down));
+    assert_eq!(
+        state.files_modal.selected_call(),
+        1,
+        "Two calls touch /a.rs"
+    );
+
+    handle_files_modal_key(&mut state, key(KeyCode::Esc));
+    assert_eq!(state.files_modal.page(), FilesModalPage::Files);
+    assert!(state.files_modal.is_visible());
+
+    handle_files_modal_key(&mut state, key(KeyCode::Esc));
+    assert!(!state.files_modal.is_visible());
+}
+
+#[test]
+fn enter_on_call_jumps_to_its_conversation() {
+    let mut state = state_with_files();
+    state.files_modal.open();
+
+    handle_files_modal_key(&mut state, key(KeyCode::Enter));
+    handle_files_modal_key(&mut state, key(KeyCode::Down));
+    handle_files_modal_key(&mut state, key(KeyCode::Enter));
+
+    assert!(!state.files_modal.is_visible());
+    assert_eq!(state.focus, FocusPane::Subagent);
+    assert_eq!(
+        state.selected_conversation,
+        ConversationSelection::Subagent(AgentId::new("agent-1").unwrap())
+    );
+}
+
+#[test]
+fn uppercase_f_closes_from_call_list() {
+    let mut state = state_with_files();
+    state.files_modal.open();
+    handle_files_modal_key(&mut state, key(KeyCode::Enter));
+
+    handle_files_modal_key(&mut state, key(KeyCode::Char('F')));
+
+    assert!(!state.files_modal.is_visible());
+}
+
+#[test]
+fn d_opens_history_with_cumulative_step_first() {
+    let mut state = state_with_files();
+    state.files_modal.open();
+
+    handle_files_modal_key(&mut state, key(KeyCode::Char('d')));
+
+    assert_eq!(state.files_modal.page(), FilesModalPage::History);
+    assert_eq!(state.files_modal.selected_step(), 0);
+    let history = state.files_modal.history().expect("history");
+    assert_eq!(history.path, "/a.rs");
+    assert_eq!(history.changes.len(), 1, "Only the Edit changes /a.rs");
+}
+
+#[test]
+fn history_steps_clamp_and_enter_jumps_to_change() {
+    let mut state = state_with_files();
+    state.files_modal.open();
+    handle_files_modal_key(&mut state, key(KeyCode::Char('d')));
+
+    // Enter on the cumulative step does nothing
+    handle_files_modal_key(&mut state, key(KeyCode::Enter));
+    assert!(state.files_modal.is_visible());
+
+    for _ in 0..3 {
+        handle_files_modal_key(&mut state, key(KeyCode::Down));
+    }
+    assert_eq!(state.files_modal.selected_step(), 1);
+    assert!(state.files_modal.history().is_some(), "built once per page");
+
+    handle_files_modal_key(&mut state, key(KeyCode::Enter));
+    assert!(!state.files_modal.is_visible());
+    assert_eq!(
+        state.selected_conversation,
+        ConversationSelection::Subagent(AgentId::new("agent-1").unwrap())
+    );
+}
+
+#[test]
+fn ctrl_d_scrolls_diff_and_step_change_resets_it() {
+    let mut state = state_with_files();
+    state.files_modal.open();
+    handle_files_modal_key(&mut state, key(KeyCode::Char('d')));
+
+    handle_files_modal_key(
+        &mut state,
+        KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+    );
+    assert_eq!(state.files_modal.diff_scroll(), 10);
+    assert_eq!(state.files_modal.page(), FilesModalPage::History);
+
+    handle_files_modal_key(&mut state, key(KeyCode::Down));
+    assert_eq!(state.files_modal.diff_scroll(), 0);
+}
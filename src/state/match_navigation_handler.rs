@@ -24,6 +24,7 @@ pub fn next_match(state: &mut AppState) {
         query,
         matches,
         current_match,
+        progress,
     } = &state.search
     {
         // Cannot navigate if no matches
@@ -43,16 +44,18 @@ pub fn next_match(state: &mut AppState) {
         let target_entry_uuid = matches[next_index].entry_uuid.clone();
         let query = query.clone();
         let matches = matches.clone();
+        let progress = *progress;
 
         // Update search state with new current_match
         state.search = SearchState::Active {
             query,
             matches,
             current_match: next_index,
+            progress,
         };
 
         // Switch focus/tab to match location and scroll to match
-        switch_to_match_location(state, &target_agent_id, &target_entry_uuid);
+        jump_to_entry(state, &target_agent_id, &target_entry_uuid);
     }
 }
 
@@ -70,6 +73,7 @@ pub fn prev_match(state: &mut AppState) {
         query,
         matches,
         current_match,
+        progress,
     } = &state.search
     {
         // Cannot navigate if no matches
@@ -89,26 +93,30 @@ pub fn prev_match(state: &mut AppState) {
         let target_entry_uuid = matches[prev_index].entry_uuid.clone();
         let query = query.clone();
         let matches = matches.clone();
+        let progress = *progress;
 
         // Update search state with new current_match
         state.search = SearchState::Active {
             query,
             matches,
             current_match: prev_index,
+            progress,
         };
 
         // Switch focus/tab to match location and scroll to match
-        switch_to_match_location(state, &target_agent_id, &target_entry_uuid);
+        jump_to_entry(state, &target_agent_id, &target_entry_uuid);
     }
 }
 
 // ===== Helper Functions =====
 
-/// Switch focus and tab to the correct location for a search match.
+/// Switch focus and tab to the conversation containing an entry and scroll to it.
 /// If agent_id is None, switches to Main pane.
 /// If agent_id is Some, switches to Subagent pane and selects the correct tab.
-/// Also scrolls the conversation to show the match entry (US5/FR-013).
-fn switch_to_match_location(
+/// Also scrolls the conversation to show the entry (US5/FR-013).
+///
+/// Used for search matches and file touches.
+pub(crate) fn jump_to_entry(
     state: &mut AppState,
     agent_id: &Option<AgentId>,
     entry_uuid: &EntryUuid,
@@ -5,7 +5,8 @@
 
 use super::*;
 use crate::model::{AgentId, EntryUuid, SessionId};
-use crate::state::{ConversationSelection, FocusPane, SearchQuery, SearchState};
+use crate::state::{ConversationSelection, FocusPane, SearchProgress, SearchQuery, SearchState};
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -85,6 +86,7 @@ fn next_match_increments_current_match() {
             make_search_match(None, "uuid-3"),
         ],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
 
     next_match(&mut state);
@@ -110,6 +112,7 @@ fn next_match_wraps_from_last_to_first() {
             make_search_match(None, "uuid-3"),
         ],
         current_match: 2, // Last match (index 2)
+        progress: SearchProgress::Complete,
     };
 
     next_match(&mut state);
@@ -131,6 +134,7 @@ fn next_match_with_single_match_stays_at_zero() {
         query,
         matches: vec![make_search_match(None, "uuid-1")],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
 
     next_match(&mut state);
@@ -154,6 +158,7 @@ fn next_match_switches_to_main_pane_when_match_in_main_agent() {
             make_search_match(None, "uuid-1"), // Main agent (agent_id = None)
         ],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     state.focus = FocusPane::Stats; // Start in different pane
 
@@ -186,7 +191,7 @@ fn next_match_switches_to_subagent_pane_when_match_in_subagent() {
         Message::new(Role::User, MessageContent::Text("test".to_string())),
         EntryMetadata::default(),
     );
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
 
     let mut state = AppState::new();
     state.add_entries(entries);
@@ -198,6 +203,7 @@ fn next_match_switches_to_subagent_pane_when_match_in_subagent() {
             make_search_match(Some(agent_id.clone()), "entry-1"), // Subagent
         ],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     state.focus = FocusPane::Main; // Start in Main pane
 
@@ -233,7 +239,7 @@ fn next_match_selects_correct_subagent_tab() {
             Message::new(Role::User, MessageContent::Text("test".to_string())),
             EntryMetadata::default(),
         );
-        entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+        entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
     }
 
     let mut state = AppState::new();
@@ -246,6 +252,7 @@ fn next_match_selects_correct_subagent_tab() {
             make_search_match(Some(agent2.clone()), "entry-1"), // Second subagent
         ],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     state.focus = FocusPane::Main;
     state.selected_conversation = ConversationSelection::Main; // Start at main conversation
@@ -326,6 +333,7 @@ fn prev_match_decrements_current_match() {
             make_search_match(None, "uuid-3"),
         ],
         current_match: 2, // Start at third match
+        progress: SearchProgress::Complete,
     };
 
     prev_match(&mut state);
@@ -351,6 +359,7 @@ fn prev_match_wraps_from_first_to_last() {
             make_search_match(None, "uuid-3"),
         ],
         current_match: 0, // First match
+        progress: SearchProgress::Complete,
     };
 
     prev_match(&mut state);
@@ -372,6 +381,7 @@ fn prev_match_with_single_match_stays_at_zero() {
         query,
         matches: vec![make_search_match(None, "uuid-1")],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
 
     prev_match(&mut state);
@@ -395,6 +405,7 @@ fn prev_match_switches_to_main_pane_when_match_in_main_agent() {
             make_search_match(None, "uuid-1"), // Main agent (agent_id = None)
         ],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     state.focus = FocusPane::Stats; // Start in different pane
 
@@ -427,7 +438,7 @@ fn prev_match_switches_to_subagent_pane_when_match_in_subagent() {
         Message::new(Role::User, MessageContent::Text("test".to_string())),
         EntryMetadata::default(),
     );
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
 
     let mut state = AppState::new();
     state.add_entries(entries);
@@ -439,6 +450,7 @@ fn prev_match_switches_to_subagent_pane_when_match_in_subagent() {
             make_search_match(Some(agent_id.clone()), "entry-1"), // Subagent
         ],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     state.focus = FocusPane::Main; // Start in Main pane
 
@@ -475,7 +487,7 @@ fn next_match_scrolls_to_match_entry_in_main_conversation() {
             Message::new(Role::User, MessageContent::Text(format!("message {}", i))),
             EntryMetadata::default(),
         );
-        entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+        entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
     }
 
     let mut state = AppState::new();
@@ -502,6 +514,7 @@ fn next_match_scrolls_to_match_entry_in_main_conversation() {
             make_search_match(None, "entry-3"), // Main agent, entry 3
         ],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     state.focus = FocusPane::Main;
 
@@ -545,7 +558,7 @@ fn prev_match_scrolls_to_match_entry_in_main_conversation() {
             Message::new(Role::User, MessageContent::Text(format!("message {}", i))),
             EntryMetadata::default(),
         );
-        entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+        entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
     }
 
     let mut state = AppState::new();
@@ -573,6 +586,7 @@ fn prev_match_scrolls_to_match_entry_in_main_conversation() {
             make_search_match(None, "entry-4"),
         ],
         current_match: 1, // Start at second match (entry 4)
+        progress: SearchProgress::Complete,
     };
     state.focus = FocusPane::Main;
 
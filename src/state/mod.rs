@@ -4,14 +4,19 @@
 
 pub mod app_state;
 pub mod expand_handler;
+pub mod export_picker;
+pub mod files_modal;
 pub mod match_navigation_handler;
 pub mod mouse_handler;
+pub mod saved_search_picker;
 pub mod scroll_handler;
 pub mod search;
+pub mod search_history;
 pub mod search_input_handler;
 pub mod session_modal;
 pub mod session_modal_handler;
 pub mod session_scroll;
+pub mod subagent_table;
 pub mod tab_handler;
 pub mod viewed_session;
 pub mod wrap_handler;
@@ -20,22 +25,41 @@ pub mod wrap_handler;
 mod session_modal_integration_test;
 
 // Re-export for convenience
-pub use app_state::{AppState, ConversationSelection, FocusPane, InputMode, WrapContext, WrapMode};
+pub use app_state::{
+    AppState, ConversationSelection, FocusPane, InputMode, TimeGutter, WrapContext, WrapMode,
+};
 pub use expand_handler::handle_expand_action;
+pub use export_picker::{
+    ExportDocument, ExportPickerOutcome, ExportPickerState, export_document,
+    handle_export_picker_key,
+};
+pub use files_modal::{
+    FilesModalPage, FilesModalState, files_in_scope, handle_files_modal_key, refresh_files_modal,
+};
 pub use match_navigation_handler::{next_match, prev_match};
 pub use mouse_handler::{
     EntryClickResult, TabClickResult, detect_entry_click, detect_tab_click, handle_entry_click,
     handle_mouse_click, handle_mouse_scroll,
 };
+pub use saved_search_picker::{
+    SavedSearchPickerOutcome, SavedSearchPickerState, handle_saved_search_picker_key,
+};
 pub use scroll_handler::handle_scroll_action;
-pub use search::{SearchMatch, SearchQuery, SearchState, agent_ids_with_matches, execute_search};
+pub use search::{
+    SearchDocument, SearchMatch, SearchProgress, SearchQuery, SearchState, agent_ids_with_matches,
+    execute_search,
+};
+pub use search_history::{MAX_SEARCH_HISTORY, SavedSearch, SearchHistory};
 pub use search_input_handler::{
     activate_search_input, cancel_search, handle_backspace, handle_char_input, handle_cursor_left,
-    handle_cursor_right, submit_search,
+    handle_cursor_right, recall_next_query, recall_previous_query, submit_search,
 };
-pub use session_modal::SessionModalState;
+pub use session_modal::{SessionModalState, refresh_session_rows, sorted_sessions};
 pub use session_modal_handler::handle_session_modal_key;
 pub use session_scroll::{ScrollState, SessionScrollExt, SessionScrollStates};
+pub use subagent_table::{
+    SubagentTableState, handle_subagent_table_key, refresh_subagent_rows, sorted_subagents,
+};
 pub use tab_handler::handle_tab_action;
 pub use viewed_session::ViewedSession;
 pub use wrap_handler::handle_toggle_wrap;
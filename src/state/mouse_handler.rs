@@ -26,6 +26,13 @@ pub enum EntryClickResult {
     MainPaneEntry(usize),
     /// Click was on a subagent pane entry at index
     SubagentPaneEntry(usize),
+    /// Click was on a main conversation entry in the continuous view
+    ContinuousEntry {
+        /// Session the entry belongs to
+        session: usize,
+        /// Entry index in the session's main conversation
+        entry: usize,
+    },
     /// Click was outside any entry
     NoEntry,
 }
@@ -148,6 +155,8 @@ pub fn detect_tab_click(
 /// - Accounts for scroll offset and entry heights via hit_test
 /// - Inner area has 1px border on each side
 /// - Returns MainPaneEntry when main tab selected, SubagentPaneEntry when subagent tab selected
+/// - In continuous mode on the main tab, returns ContinuousEntry (see
+///   `detect_continuous_entry_click`)
 pub fn detect_entry_click(
     click_x: u16,
     click_y: u16,
@@ -177,6 +186,15 @@ pub fn detect_entry_click(
         return EntryClickResult::NoEntry;
     }
 
+    if state.continuous_sessions && state.selected_agent_id().is_none() {
+        return detect_continuous_entry_click(
+            click_y - inner_y,
+            click_x - inner_x,
+            inner_height as usize,
+            state,
+        );
+    }
+
     // Use central routing to get the selected conversation view
     let conv_view = match state.selected_conversation_view() {
         Some(view) => view,
@@ -212,6 +230,53 @@ pub fn detect_entry_click(
     }
 }
 
+/// Map a click in the continuous view to the entry under it.
+///
+/// Lines are counted from the clamped `continuous_scroll` the way
+/// `ContinuousView` lays sessions out: each shown session is its heading
+/// followed, unless folded, by its main conversation. Clicks on headings
+/// hit no entry.
+fn detect_continuous_entry_click(
+    viewport_y: u16,
+    viewport_x: u16,
+    viewport_height: usize,
+    state: &AppState,
+) -> EntryClickResult {
+    use crate::view_state::hit_test::HitTestResult;
+    use crate::view_state::session::SESSION_HEADING_HEIGHT;
+    use crate::view_state::types::LineOffset;
+
+    let log_view = state.log_view();
+    let max_offset = log_view.continuous_height().saturating_sub(viewport_height);
+    let line = log_view.continuous_scroll().min(max_offset) + viewport_y as usize;
+
+    let mut session_y = 0;
+    for (index, session) in log_view.sessions().enumerate() {
+        if !log_view.is_shown(index) {
+            continue;
+        }
+        let session_height = session.continuous_height();
+        if line >= session_y + session_height {
+            session_y += session_height;
+            continue;
+        }
+        let Some(local) = (line - session_y).checked_sub(SESSION_HEADING_HEIGHT) else {
+            return EntryClickResult::NoEntry;
+        };
+        return match session
+            .main()
+            .hit_test(0, viewport_x, LineOffset::new(local))
+        {
+            HitTestResult::Hit { entry_index, .. } => EntryClickResult::ContinuousEntry {
+                session: index,
+                entry: entry_index.get(),
+            },
+            HitTestResult::Miss => EntryClickResult::NoEntry,
+        };
+    }
+    EntryClickResult::NoEntry
+}
+
 /// Handle an entry click event and toggle expand/collapse.
 ///
 /// # Arguments
@@ -225,6 +290,7 @@ pub fn detect_entry_click(
 /// - If entry was clicked, toggles expansion state via ConversationViewState
 /// - Main pane entries toggle via main ConversationViewState
 /// - Subagent pane entries toggle via selected subagent's ConversationViewState
+/// - Continuous view entries toggle in their session's main conversation
 /// - If click was outside entries, state is unchanged
 /// - Uses HeightIndex-aware toggle_entry_expanded for O(log n) updates
 pub fn handle_entry_click(
@@ -275,6 +341,15 @@ pub fn handle_entry_click(
                 }
             }
         }
+        EntryClickResult::ContinuousEntry { session, entry } => {
+            if let Some(session_view) = state.log_view_mut().get_session_mut(session) {
+                session_view
+                    .main_mut()
+                    .toggle_entry_expanded(entry, &search_state);
+            }
+            // Later sessions move with the toggled entry's height
+            state.log_view_mut().layout_continuous();
+        }
         EntryClickResult::NoEntry => {}
     }
 }
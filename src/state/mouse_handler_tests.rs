@@ -8,6 +8,7 @@ use crate::model::{
 use crate::state::{AppState, ConversationSelection};
 use chrono::Utc;
 use ratatui::layout::Rect;
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -35,7 +36,7 @@ fn make_main_entry() -> ConversationEntry {
         EntryMetadata::default(),
     );
 
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 fn make_subagent_entry(agent_id: &str) -> ConversationEntry {
@@ -53,7 +54,7 @@ fn make_subagent_entry(agent_id: &str) -> ConversationEntry {
         EntryMetadata::default(),
     );
 
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 fn create_app_state_with_tabs(agent_ids: Vec<&str>) -> AppState {
@@ -485,7 +486,7 @@ fn detect_entry_click_detects_main_pane_first_entry() {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(log_entry)));
+    entries.push(ConversationEntry::Valid(Arc::new(log_entry)));
 
     let mut state = AppState::new();
     state.add_entries(entries);
@@ -542,7 +543,7 @@ fn detect_entry_click_accounts_for_border() {
         Message::new(Role::User, MessageContent::Text("Test".to_string())),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(log_entry)));
+    entries.push(ConversationEntry::Valid(Arc::new(log_entry)));
 
     let mut state = AppState::new();
     state.add_entries(entries);
@@ -601,7 +602,7 @@ fn detect_entry_click_maps_different_y_positions_to_different_entries() {
             Message::new(Role::User, MessageContent::Text(format!("Message {}", i))),
             EntryMetadata::default(),
         );
-        entries.push(ConversationEntry::Valid(Box::new(log_entry)));
+        entries.push(ConversationEntry::Valid(Arc::new(log_entry)));
     }
 
     let mut state = AppState::new();
@@ -663,7 +664,7 @@ fn detect_entry_click_with_single_entry_always_returns_index_0() {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(log_entry)));
+    entries.push(ConversationEntry::Valid(Arc::new(log_entry)));
 
     let mut state = AppState::new();
     state.add_entries(entries);
@@ -721,7 +722,7 @@ fn detect_entry_click_uses_actual_entry_heights_from_layout() {
             Message::new(Role::User, MessageContent::Text(format!("Message {}", i))),
             EntryMetadata::default(),
         );
-        entries.push(ConversationEntry::Valid(Box::new(log_entry)));
+        entries.push(ConversationEntry::Valid(Arc::new(log_entry)));
     }
 
     let mut state = AppState::new();
@@ -788,7 +789,7 @@ fn detect_entry_click_accounts_for_scroll_offset() {
             Message::new(Role::User, MessageContent::Text(long_text)),
             EntryMetadata::default(),
         );
-        entries.push(ConversationEntry::Valid(Box::new(log_entry)));
+        entries.push(ConversationEntry::Valid(Arc::new(log_entry)));
     }
 
     let mut state = AppState::new();
@@ -829,6 +830,102 @@ fn detect_entry_click_accounts_for_scroll_offset() {
     );
 }
 
+#[test]
+fn detect_entry_click_maps_continuous_view_lines_to_sessions() {
+    use crate::state::{SearchState, WrapMode};
+
+    let entry = |uuid: &str, session: &str| {
+        ConversationEntry::Valid(Arc::new(LogEntry::new(
+            make_entry_uuid(uuid),
+            None,
+            make_session_id(session),
+            None,
+            Utc::now(),
+            EntryType::User,
+            Message::new(Role::User, MessageContent::Text(uuid.to_string())),
+            EntryMetadata::default(),
+        )))
+    };
+    let mut state = AppState::new();
+    state.add_entries(vec![
+        entry("s1-a", "session-1"),
+        entry("s1-b", "session-1"),
+        entry("s2-a", "session-2"),
+    ]);
+    for index in 0..2 {
+        state
+            .log_view_mut()
+            .get_session_mut(index)
+            .unwrap()
+            .main_mut()
+            .relayout(80, WrapMode::Wrap, &SearchState::Inactive);
+    }
+    state.continuous_sessions = true;
+    state.log_view_mut().layout_continuous();
+    let area = Rect::new(0, 0, 80, 10);
+    let second_start = state.log_view().get_session(1).unwrap().start_line() as u16;
+    let first_height = get_entry_height(&state, 0);
+
+    // Row 1 is the first session's heading, entries follow it
+    assert_eq!(
+        detect_entry_click(5, 1, area, &state),
+        EntryClickResult::NoEntry
+    );
+    assert_eq!(
+        detect_entry_click(5, 2, area, &state),
+        EntryClickResult::ContinuousEntry {
+            session: 0,
+            entry: 0
+        }
+    );
+    assert_eq!(
+        detect_entry_click(5, 2 + first_height, area, &state),
+        EntryClickResult::ContinuousEntry {
+            session: 0,
+            entry: 1
+        }
+    );
+    // The second session's heading and entry share the viewport
+    assert_eq!(
+        detect_entry_click(5, 1 + second_start, area, &state),
+        EntryClickResult::NoEntry
+    );
+    assert_eq!(
+        detect_entry_click(5, 2 + second_start, area, &state),
+        EntryClickResult::ContinuousEntry {
+            session: 1,
+            entry: 0
+        }
+    );
+
+    // Scrolling past the end is clamped like the view: everything fits
+    state.log_view_mut().set_continuous_scroll(100);
+    assert_eq!(
+        detect_entry_click(5, 2 + second_start, area, &state),
+        EntryClickResult::ContinuousEntry {
+            session: 1,
+            entry: 0
+        }
+    );
+
+    handle_entry_click(
+        &mut state,
+        EntryClickResult::ContinuousEntry {
+            session: 1,
+            entry: 0,
+        },
+        80,
+    );
+    let second = state.log_view().get_session(1).unwrap();
+    assert!(
+        second
+            .main()
+            .get(crate::view_state::types::EntryIndex::new(0))
+            .unwrap()
+            .is_expanded()
+    );
+}
+
 #[test]
 fn detect_entry_click_returns_no_entry_when_clicking_beyond_content() {
     // Create single entry
@@ -845,7 +942,7 @@ fn detect_entry_click_returns_no_entry_when_clicking_beyond_content() {
     );
 
     let mut state = AppState::new();
-    state.add_entries(vec![ConversationEntry::Valid(Box::new(log_entry))]);
+    state.add_entries(vec![ConversationEntry::Valid(Arc::new(log_entry))]);
     init_layout_for_state(&mut state);
 
     let main_area = Rect::new(0, 0, 40, 30);
@@ -0,0 +1,133 @@
+//! State and keyboard handling for the saved search picker.
+//!
+//! Saved searches come from the `[searches]` table in config.toml and are
+//! stored on `AppState::saved_searches`.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::state::{AppState, FocusPane, SearchProgress, SearchQuery, SearchState};
+
+/// State for the saved search picker overlay.
+#[derive(Debug, Clone, Default)]
+pub struct SavedSearchPickerState {
+    /// Whether the picker is visible.
+    visible: bool,
+
+    /// Currently selected row (0-indexed).
+    /// Only meaningful when `visible` is true.
+    selected_index: usize,
+}
+
+impl SavedSearchPickerState {
+    /// Create new picker state (closed).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if picker is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open the picker with the first entry selected.
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.selected_index = 0;
+    }
+
+    /// Close the picker.
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// Toggle picker visibility.
+    pub fn toggle(&mut self) {
+        if self.visible {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Currently selected index.
+    pub fn selected_index(&self) -> usize {
+        self.selected_index
+    }
+
+    /// Move selection up, clamping at 0.
+    pub fn select_prev(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    /// Move selection down, clamping at the last entry.
+    pub fn select_next(&mut self, count: usize) {
+        if count > 0 {
+            self.selected_index = (self.selected_index + 1).min(count - 1);
+        }
+    }
+}
+
+/// Result of routing a key through the saved search picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SavedSearchPickerOutcome {
+    /// Picker not visible; key was not handled.
+    Ignored,
+    /// Key was consumed by the picker.
+    Consumed,
+    /// A saved search was picked. `AppState::search` now holds it as the
+    /// active query; the caller should start the search.
+    Submitted,
+}
+
+/// Handle keyboard input when the saved search picker is visible.
+///
+/// # Key Bindings
+/// - Up/k: Select previous entry (clamps at 0)
+/// - Down/j: Select next entry (clamps at last)
+/// - Enter: Submit the selected search and close the picker
+/// - Esc/Ctrl+r: Close without searching
+///
+/// All other keys are swallowed while the picker is open.
+pub fn handle_saved_search_picker_key(
+    state: &mut AppState,
+    key: KeyEvent,
+) -> SavedSearchPickerOutcome {
+    if !state.saved_search_picker.is_visible() {
+        return SavedSearchPickerOutcome::Ignored;
+    }
+
+    let count = state.saved_searches.len();
+
+    match key.code {
+        KeyCode::Esc => state.saved_search_picker.close(),
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.saved_search_picker.close()
+        }
+        KeyCode::Up | KeyCode::Char('k') => state.saved_search_picker.select_prev(),
+        KeyCode::Down | KeyCode::Char('j') => state.saved_search_picker.select_next(count),
+        KeyCode::Enter => {
+            let selected = state
+                .saved_searches
+                .get(state.saved_search_picker.selected_index())
+                .and_then(|saved| SearchQuery::new(saved.query.clone()));
+            state.saved_search_picker.close();
+            if let Some(query) = selected {
+                state.search = SearchState::Active {
+                    query,
+                    matches: Vec::new(),
+                    current_match: 0,
+                    progress: SearchProgress::Complete,
+                };
+                state.focus = FocusPane::Search;
+                return SavedSearchPickerOutcome::Submitted;
+            }
+        }
+        _ => {}
+    }
+
+    SavedSearchPickerOutcome::Consumed
+}
+
+#[cfg(test)]
+#[path = "saved_search_picker_tests.rs"]
+mod tests;
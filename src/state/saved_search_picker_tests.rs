@@ -0,0 +1,95 @@
+//! Tests for the saved search picker.
+
+use super::*;
+use crate::state::SavedSearch;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+fn state_with_searches() -> AppState {
+    let mut state = AppState::new();
+    state.saved_searches = vec![
+        SavedSearch::new("errors", "error"),
+        SavedSearch::new("todos", "TODO"),
+    ];
+    state
+}
+
+#[test]
+fn keys_are_ignored_when_picker_closed() {
+    let mut state = state_with_searches();
+
+    let outcome = handle_saved_search_picker_key(&mut state, key(KeyCode::Enter));
+
+    assert_eq!(outcome, SavedSearchPickerOutcome::Ignored);
+    assert!(matches!(state.search, SearchState::Inactive));
+}
+
+#[test]
+fn navigation_clamps_to_saved_search_count() {
+    let mut state = state_with_searches();
+    state.saved_search_picker.open();
+
+    for _ in 0..5 {
+        handle_saved_search_picker_key(&mut state, key(KeyCode::Down));
+    }
+    assert_eq!(state.saved_search_picker.selected_index(), 1);
+
+    for _ in 0..5 {
+        handle_saved_search_picker_key(&mut state, key(KeyCode::Char('k')));
+    }
+    assert_eq!(state.saved_search_picker.selected_index(), 0);
+}
+
+#[test]
+fn enter_submits_selected_search() {
+    let mut state = state_with_searches();
+    state.saved_search_picker.open();
+    handle_saved_search_picker_key(&mut state, key(KeyCode::Down));
+
+    let outcome = handle_saved_search_picker_key(&mut state, key(KeyCode::Enter));
+
+    assert_eq!(outcome, SavedSearchPickerOutcome::Submitted);
+    assert!(!state.saved_search_picker.is_visible());
+    assert_eq!(state.focus, FocusPane::Search);
+    match &state.search {
+        SearchState::Active { query, .. } => assert_eq!(query.as_str(), "TODO"),
+        other => panic!("Expected Active state, got {:?}", other),
+    }
+}
+
+#[test]
+fn enter_with_no_saved_searches_just_closes() {
+    let mut state = AppState::new();
+    state.saved_search_picker.open();
+
+    let outcome = handle_saved_search_picker_key(&mut state, key(KeyCode::Enter));
+
+    assert_eq!(outcome, SavedSearchPickerOutcome::Consumed);
+    assert!(!state.saved_search_picker.is_visible());
+    assert!(matches!(state.search, SearchState::Inactive));
+}
+
+#[test]
+fn esc_closes_without_searching() {
+    let mut state = state_with_searches();
+    state.saved_search_picker.open();
+
+    let outcome = handle_saved_search_picker_key(&mut state, key(KeyCode::Esc));
+
+    assert_eq!(outcome, SavedSearchPickerOutcome::Consumed);
+    assert!(!state.saved_search_picker.is_visible());
+    assert!(matches!(state.search, SearchState::Inactive));
+}
+
+#[test]
+fn other_keys_are_swallowed_while_open() {
+    let mut state = state_with_searches();
+    state.saved_search_picker.open();
+
+    let outcome = handle_saved_search_picker_key(&mut state, key(KeyCode::Char('q')));
+
+    assert_eq!(outcome, SavedSearchPickerOutcome::Consumed);
+    assert!(state.saved_search_picker.is_visible());
+}
@@ -6,8 +6,9 @@
 //! All scrolling is handled via ConversationViewState.set_scroll() with ScrollPosition.
 
 use crate::model::KeyAction;
-use crate::state::{AppState, FocusPane};
+use crate::state::{AppState, ConversationSelection, FocusPane};
 use crate::view_state::scroll::ScrollPosition;
+use crate::view_state::types::ViewportDimensions;
 
 /// Handle a scroll keyboard action, dispatching to the appropriate conversation view.
 ///
@@ -28,6 +29,15 @@ pub fn handle_scroll_action(
         _ => {}
     }
 
+    // Continuous mode: the main tab scrolls across all sessions
+    if state.continuous_sessions
+        && state.selected_conversation == ConversationSelection::Main
+        && !matches!(action, KeyAction::ScrollLeft | KeyAction::ScrollRight)
+    {
+        handle_continuous_scroll(state, action, viewport);
+        return;
+    }
+
     // Get mutable reference to the selected conversation using central routing
     let conversation = if let Some(conv) = state.selected_conversation_view_mut() {
         conv
@@ -195,6 +205,34 @@ pub fn handle_scroll_action(
     state.auto_scroll = at_bottom;
 }
 
+/// Scroll the continuous view of all sessions, then make the session at the
+/// top of the viewport the viewed one.
+fn handle_continuous_scroll(state: &mut AppState, action: KeyAction, viewport: ViewportDimensions) {
+    let log_view = state.log_view_mut();
+    log_view.layout_continuous();
+
+    let page = viewport.height as usize;
+    let max_offset = log_view.continuous_height().saturating_sub(page);
+    let offset = log_view.continuous_scroll().min(max_offset);
+    let new_offset = match action {
+        KeyAction::ScrollUp => offset.saturating_sub(1),
+        KeyAction::ScrollDown => offset.saturating_add(1),
+        KeyAction::PageUp => offset.saturating_sub(page),
+        KeyAction::PageDown => offset.saturating_add(page),
+        KeyAction::ScrollToTop => 0,
+        KeyAction::ScrollToBottom => max_offset,
+        // Non-scroll actions are no-ops
+        _ => return,
+    }
+    .min(max_offset);
+    log_view.set_continuous_scroll(new_offset);
+
+    state.sync_continuous_session();
+
+    // FR-036: auto_scroll follows whether the view is at the bottom
+    state.auto_scroll = new_offset >= max_offset;
+}
+
 // ===== Tests =====
 
 #[cfg(test)]
@@ -212,3 +250,7 @@ mod tab_routing_tests;
 #[cfg(test)]
 #[path = "scroll_handler_auto_scroll_tests.rs"]
 mod auto_scroll_tests;
+
+#[cfg(test)]
+#[path = "scroll_handler_continuous_tests.rs"]
+mod continuous_tests;
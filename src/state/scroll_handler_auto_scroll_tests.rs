@@ -12,6 +12,7 @@ use crate::state::{AppState, FocusPane};
 use crate::view_state::layout_params::LayoutParams;
 use crate::view_state::scroll::ScrollPosition;
 use crate::view_state::types::LineOffset;
+use std::sync::Arc;
 
 /// Helper to create test AppState with populated log_view
 fn create_test_state_with_entries(num_entries: usize) -> AppState {
@@ -19,7 +20,7 @@ fn create_test_state_with_entries(num_entries: usize) -> AppState {
 
     for i in 0..num_entries {
         let entry = create_test_log_entry(format!("Entry {} content", i), None);
-        entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+        entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
     }
 
     let mut state = AppState::new();
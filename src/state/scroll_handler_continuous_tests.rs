@@ -0,0 +1,114 @@
+//! Continuous-mode tests for scroll_handler (FR-076, FR-077).
+//!
+//! In continuous mode the main tab scrolls across every session, and the
+//! session at the top of the viewport becomes the viewed session.
+
+use super::*;
+use crate::model::{
+    ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry, Message, MessageContent,
+    Role, SessionId,
+};
+use crate::state::{AppState, ViewedSession, WrapMode};
+use crate::view_state::types::ViewportDimensions;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// AppState with `sessions` sessions of `entries` one-line user entries each.
+fn create_state(sessions: usize, entries: usize) -> AppState {
+    let mut all = Vec::new();
+    for s in 0..sessions {
+        for e in 0..entries {
+            all.push(ConversationEntry::Valid(Arc::new(LogEntry::new(
+                EntryUuid::new(format!("uuid-{}-{}", s, e)).unwrap(),
+                None,
+                SessionId::new(format!("session-{}", s)).unwrap(),
+                None,
+                Utc::now(),
+                EntryType::User,
+                Message::new(
+                    Role::User,
+                    MessageContent::Text(format!("message {} of session {}", e, s)),
+                ),
+                EntryMetadata::default(),
+            ))));
+        }
+    }
+    let mut state = AppState::new();
+    state.add_entries(all);
+    state.log_view_mut().set_viewport_all(80, WrapMode::Wrap);
+    state.viewed_session = ViewedSession::pinned(0, sessions).unwrap();
+    state.toggle_continuous_sessions();
+    state
+}
+
+fn viewport() -> ViewportDimensions {
+    ViewportDimensions::new(80, 5)
+}
+
+fn viewed_index(state: &AppState) -> usize {
+    let count = state.log_view().session_count();
+    state.viewed_session.effective_index(count).unwrap().get()
+}
+
+#[test]
+fn scrolling_flows_into_next_session_and_views_it() {
+    let mut state = create_state(3, 4);
+    let second_start = state.log_view().get_session(1).unwrap().start_line();
+
+    for _ in 0..second_start {
+        handle_scroll_action(&mut state, KeyAction::ScrollDown, viewport());
+    }
+
+    assert_eq!(state.log_view().continuous_scroll(), second_start);
+    assert_eq!(viewed_index(&state), 1);
+    assert!(!state.auto_scroll);
+
+    handle_scroll_action(&mut state, KeyAction::ScrollUp, viewport());
+    assert_eq!(viewed_index(&state), 0);
+}
+
+#[test]
+fn scroll_to_bottom_views_latest_session_and_enables_auto_scroll() {
+    let mut state = create_state(3, 4);
+
+    handle_scroll_action(&mut state, KeyAction::ScrollToBottom, viewport());
+
+    let total = state.log_view().continuous_height();
+    assert_eq!(state.log_view().continuous_scroll(), total - 5);
+    assert_eq!(state.viewed_session, ViewedSession::Latest);
+    assert!(state.auto_scroll);
+
+    handle_scroll_action(&mut state, KeyAction::ScrollToTop, viewport());
+    assert_eq!(state.log_view().continuous_scroll(), 0);
+    assert_eq!(viewed_index(&state), 0);
+}
+
+#[test]
+fn folding_viewed_session_shrinks_it_to_heading() {
+    let mut state = create_state(2, 4);
+    let before = state.log_view().continuous_height();
+    let first_height = state.log_view().get_session(0).unwrap().continuous_height();
+
+    state.toggle_session_fold();
+
+    assert_eq!(
+        state.log_view().continuous_height(),
+        before - first_height + 1
+    );
+    assert_eq!(state.log_view().get_session(1).unwrap().start_line(), 1);
+    assert_eq!(state.log_view().continuous_scroll(), 0);
+
+    handle_scroll_action(&mut state, KeyAction::ScrollDown, viewport());
+    assert_eq!(viewed_index(&state), 1, "next line is the second session");
+}
+
+#[test]
+fn subagent_tab_scrolls_its_own_conversation() {
+    let mut state = create_state(2, 4);
+    state.selected_conversation =
+        ConversationSelection::Subagent(crate::model::AgentId::new("missing").unwrap());
+
+    handle_scroll_action(&mut state, KeyAction::ScrollDown, viewport());
+
+    assert_eq!(state.log_view().continuous_scroll(), 0);
+}
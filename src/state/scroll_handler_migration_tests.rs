@@ -10,6 +10,7 @@ use crate::model::{KeyAction, SessionId};
 use crate::state::{AppState, FocusPane};
 use crate::view_state::scroll::ScrollPosition;
 use crate::view_state::types::LineOffset;
+use std::sync::Arc;
 
 /// Helper to create test AppState with populated log_view
 fn create_test_state_with_log_view(main_entries: usize, subagent_entries: usize) -> AppState {
@@ -18,7 +19,7 @@ fn create_test_state_with_log_view(main_entries: usize, subagent_entries: usize)
     // Add main agent entries
     for i in 0..main_entries {
         let entry = create_test_log_entry(format!("main-{}", i), None);
-        entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+        entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
     }
 
     // Add subagent entries
@@ -26,7 +27,7 @@ fn create_test_state_with_log_view(main_entries: usize, subagent_entries: usize)
         let agent_id = crate::model::AgentId::new("test-agent").unwrap();
         for i in 0..subagent_entries {
             let entry = create_test_log_entry(format!("sub-{}", i), Some(agent_id.clone()));
-            entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+            entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
         }
     }
 
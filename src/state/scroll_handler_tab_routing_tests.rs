@@ -12,6 +12,7 @@ use crate::model::{AgentId, KeyAction, SessionId};
 use crate::state::{AppState, ConversationSelection, FocusPane};
 use crate::view_state::scroll::ScrollPosition;
 use crate::view_state::types::LineOffset;
+use std::sync::Arc;
 
 /// Helper to create test AppState with main agent and multiple subagents
 fn create_test_state_with_multiple_agents(
@@ -24,7 +25,7 @@ fn create_test_state_with_multiple_agents(
     // Add main agent entries
     for i in 0..main_entries {
         let entry = create_test_log_entry(format!("main-{}", i), None);
-        entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+        entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
     }
 
     // Add subagent 1 entries
@@ -32,7 +33,7 @@ fn create_test_state_with_multiple_agents(
         let agent_id = crate::model::AgentId::new("subagent-1").unwrap();
         for i in 0..subagent1_entries {
             let entry = create_test_log_entry(format!("sub1-{}", i), Some(agent_id.clone()));
-            entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+            entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
         }
     }
 
@@ -41,7 +42,7 @@ fn create_test_state_with_multiple_agents(
         let agent_id = crate::model::AgentId::new("subagent-2").unwrap();
         for i in 0..subagent2_entries {
             let entry = create_test_log_entry(format!("sub2-{}", i), Some(agent_id.clone()));
-            entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+            entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
         }
     }
 
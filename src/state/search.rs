@@ -75,6 +75,7 @@
 //!
 //! - Tool use blocks (`ContentBlock::ToolUse`) - these contain tool names and JSON parameters,
 //!   which are structured metadata rather than conversation content
+//!   (`SearchDocument::with_tool_inputs` opts in for `cclv grep --tool-inputs`)
 //!
 //! ## Match Finding
 //!
@@ -100,13 +101,14 @@
 //! let query = SearchQuery::new("error").unwrap();
 //! // Execute search (see execute_search function)
 //! // let matches = execute_search(&session, &query);
-//! // let state = SearchState::Active { query, matches, current_match: 0 };
+//! // let state = SearchState::Active { query, matches, current_match: 0, progress };
 //!
 //! // User presses "n" to go to next match
 //! // current_match = (current_match + 1) % matches.len()
 //! ```
 
-use crate::model::{AgentId, EntryUuid};
+use crate::model::{AgentId, EntryUuid, LogEntry, SessionId};
+use std::sync::Arc;
 
 // ===== SearchState =====
 
@@ -132,9 +134,49 @@ pub enum SearchState {
         matches: Vec<SearchMatch>,
         /// Index of currently focused match (0-based).
         current_match: usize,
+        /// Whether matches are still streaming in from a background search.
+        progress: SearchProgress,
     },
 }
 
+// ===== SearchProgress =====
+
+/// Progress of a search executing in the background.
+///
+/// Large logs are searched on a worker thread (see `search_worker`), so
+/// `SearchState::Active` can hold a partial match list while the scan runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchProgress {
+    /// Worker is still scanning entries.
+    Running {
+        /// Entries scanned so far.
+        searched: usize,
+        /// Entries queued for this search.
+        total: usize,
+    },
+    /// All entries have been scanned; `matches` is final until new entries arrive.
+    #[default]
+    Complete,
+}
+
+impl SearchProgress {
+    /// Whether the search is still scanning.
+    pub fn is_running(&self) -> bool {
+        matches!(self, SearchProgress::Running { .. })
+    }
+
+    /// Completion percentage (0-100). Complete searches report 100.
+    pub fn percent(&self) -> u8 {
+        match *self {
+            SearchProgress::Running { searched, total } if total > 0 => {
+                ((searched.min(total) * 100) / total) as u8
+            }
+            SearchProgress::Running { .. } => 0,
+            SearchProgress::Complete => 100,
+        }
+    }
+}
+
 // ===== SearchQuery =====
 
 /// Validated search query. Never empty.
@@ -236,45 +278,164 @@ fn search_entry(
     query_lower: &str,
     matches: &mut Vec<SearchMatch>,
 ) {
+    let entry_uuid = log_entry.uuid().clone();
+
+    for (block_index, text) in searchable_blocks(log_entry) {
+        find_matches_in_text(
+            text,
+            &entry_uuid,
+            agent_id.clone(),
+            block_index,
+            query_lower,
+            matches,
+        );
+    }
+}
+
+/// Text of every searchable block in an entry, paired with its block index.
+///
+/// Plain text content is reported as block 0. Tool use blocks are skipped (FR-011b).
+fn searchable_blocks(log_entry: &crate::model::LogEntry) -> Vec<(usize, &str)> {
     use crate::model::{ContentBlock, MessageContent};
 
-    let message = log_entry.message();
-    let entry_uuid = log_entry.uuid().clone();
+    match log_entry.message().content() {
+        MessageContent::Text(text) => vec![(0, text.as_str())],
+        MessageContent::Blocks(blocks) => blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(block_index, block)| match block {
+                ContentBlock::Text { text } => Some((block_index, text.as_str())),
+                ContentBlock::Thinking { thinking } => Some((block_index, thinking.as_str())),
+                ContentBlock::ToolResult { content, .. } => Some((block_index, content.as_str())),
+                ContentBlock::ToolUse(_) => None, // Don't search tool use blocks
+            })
+            .collect(),
+    }
+}
+
+// ===== SearchDocument =====
+
+/// Searchable entry handed to the background search worker.
+///
+/// Documents share the view-state's `Arc<LogEntry>` instead of copying its
+/// text, so the worker can keep every entry for later queries at the cost of
+/// a pointer each.
+#[derive(Debug, Clone)]
+pub struct SearchDocument {
+    /// The entry whose blocks are searched.
+    entry: Arc<LogEntry>,
+    /// Pretty-printed tool use inputs as (block_index, json), if opted in.
+    tool_inputs: Vec<(usize, String)>,
+}
+
+impl SearchDocument {
+    /// Build a document sharing a parsed log entry.
+    pub fn from_entry(log_entry: &Arc<LogEntry>) -> Self {
+        Self {
+            entry: Arc::clone(log_entry),
+            tool_inputs: Vec::new(),
+        }
+    }
+
+    /// Also search tool use inputs, as the pretty-printed JSON the TUI shows.
+    ///
+    /// The in-app search leaves them out (FR-011b); `cclv grep --tool-inputs`
+    /// opts in.
+    pub fn with_tool_inputs(mut self) -> Self {
+        use crate::model::{ContentBlock, MessageContent};
+
+        if let MessageContent::Blocks(blocks) = self.entry.message().content() {
+            self.tool_inputs = blocks
+                .iter()
+                .enumerate()
+                .filter_map(|(block_index, block)| match block {
+                    ContentBlock::ToolUse(call) => Some((
+                        block_index,
+                        serde_json::to_string_pretty(call.input()).unwrap_or_default(),
+                    )),
+                    _ => None,
+                })
+                .collect();
+        }
+        self
+    }
+
+    /// Build documents for every valid entry in a batch. Malformed entries are skipped.
+    pub fn from_entries(entries: &[crate::model::ConversationEntry]) -> Vec<Self> {
+        entries
+            .iter()
+            .filter_map(Self::from_conversation_entry)
+            .collect()
+    }
 
-    match message.content() {
-        MessageContent::Text(text) => {
-            // Search in simple text content
+    /// Build documents for every valid entry already held by a log view-state.
+    ///
+    /// Used when the view-state was populated before the search worker existed.
+    pub fn from_log_view(log_view: &crate::view_state::log::LogViewState) -> Vec<Self> {
+        let mut documents = Vec::new();
+        for session in log_view.sessions() {
+            let conversations = std::iter::once(session.main()).chain(session.subagents().values());
+            for conversation in conversations {
+                documents.extend(
+                    conversation
+                        .iter()
+                        .filter_map(|entry_view| Self::from_conversation_entry(entry_view.entry())),
+                );
+            }
+        }
+        documents
+    }
+
+    fn from_conversation_entry(entry: &crate::model::ConversationEntry) -> Option<Self> {
+        match entry {
+            crate::model::ConversationEntry::Valid(log_entry) => Some(Self::from_entry(log_entry)),
+            crate::model::ConversationEntry::Malformed(_) => None,
+        }
+    }
+
+    /// Session the entry belongs to.
+    pub fn session_id(&self) -> &SessionId {
+        self.entry.session_id()
+    }
+
+    /// Agent owning the entry. None = main agent.
+    pub fn agent_id(&self) -> Option<&AgentId> {
+        self.entry.agent_id()
+    }
+
+    /// UUID of the entry.
+    pub fn entry_uuid(&self) -> &EntryUuid {
+        self.entry.uuid()
+    }
+
+    /// Searchable blocks as (block_index, text), in block order.
+    pub fn blocks(&self) -> Vec<(usize, &str)> {
+        let mut blocks = searchable_blocks(&self.entry);
+        if !self.tool_inputs.is_empty() {
+            blocks.extend(
+                self.tool_inputs
+                    .iter()
+                    .map(|(block_index, input)| (*block_index, input.as_str())),
+            );
+            blocks.sort_by_key(|(block_index, _)| *block_index);
+        }
+        blocks
+    }
+
+    /// Append all matches of `query_lower` (already lowercased) in this document.
+    ///
+    /// Produces exactly the matches `execute_search` reports for the same entry.
+    pub fn find_matches(&self, query_lower: &str, matches: &mut Vec<SearchMatch>) {
+        for (block_index, text) in self.blocks() {
             find_matches_in_text(
                 text,
-                &entry_uuid,
-                agent_id.clone(),
-                0, // block_index for Text is always 0
+                self.entry_uuid(),
+                self.agent_id().cloned(),
+                block_index,
                 query_lower,
                 matches,
             );
         }
-        MessageContent::Blocks(blocks) => {
-            // Search in each block
-            for (block_index, block) in blocks.iter().enumerate() {
-                let text = match block {
-                    ContentBlock::Text { text } => Some(text.as_str()),
-                    ContentBlock::Thinking { thinking } => Some(thinking.as_str()),
-                    ContentBlock::ToolResult { content, .. } => Some(content.as_str()),
-                    ContentBlock::ToolUse(_) => None, // Don't search tool use blocks
-                };
-
-                if let Some(text) = text {
-                    find_matches_in_text(
-                        text,
-                        &entry_uuid,
-                        agent_id.clone(),
-                        block_index,
-                        query_lower,
-                        matches,
-                    );
-                }
-            }
-        }
     }
 }
 
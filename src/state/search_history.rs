@@ -0,0 +1,128 @@
+//! Search history and saved searches (pure).
+//!
+//! History is recalled with Up/Down while typing a query. Persistence lives
+//! in `config::history`; this module only models navigation.
+
+/// Maximum number of queries kept in history.
+pub const MAX_SEARCH_HISTORY: usize = 100;
+
+/// Previously submitted search queries, oldest first.
+///
+/// Tracks a navigation cursor while the user walks back through history.
+/// The text typed before navigation started is kept as a draft so that
+/// walking forward past the newest entry restores it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchHistory {
+    /// Queries, oldest first. No duplicates, no blank entries.
+    entries: Vec<String>,
+    /// Index into `entries` while navigating; `None` when editing a fresh query.
+    cursor: Option<usize>,
+    /// Query text at the moment navigation started.
+    draft: String,
+}
+
+impl SearchHistory {
+    /// Create empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build history from stored queries (oldest first).
+    ///
+    /// Blank lines and duplicates are dropped (the newest occurrence wins),
+    /// and only the newest `MAX_SEARCH_HISTORY` queries are kept.
+    pub fn from_entries(entries: impl IntoIterator<Item = String>) -> Self {
+        let mut history = Self::new();
+        for entry in entries {
+            history.record(&entry);
+        }
+        history
+    }
+
+    /// Stored queries, oldest first.
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Record a submitted query as the newest entry.
+    ///
+    /// An existing identical entry is moved to the end rather than duplicated.
+    /// Resets navigation.
+    pub fn record(&mut self, query: &str) {
+        self.reset_navigation();
+        let query = query.trim();
+        if query.is_empty() {
+            return;
+        }
+        self.entries.retain(|e| e != query);
+        self.entries.push(query.to_string());
+        if self.entries.len() > MAX_SEARCH_HISTORY {
+            let excess = self.entries.len() - MAX_SEARCH_HISTORY;
+            self.entries.drain(..excess);
+        }
+    }
+
+    /// Step back to an older query.
+    ///
+    /// `current` is the text in the input box; it is saved as the draft when
+    /// navigation starts. Returns `None` if history is empty. Clamps at the
+    /// oldest entry.
+    pub fn recall_previous(&mut self, current: &str) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let index = match self.cursor {
+            None => {
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+            Some(i) => i.saturating_sub(1),
+        };
+        self.cursor = Some(index);
+        Some(&self.entries[index])
+    }
+
+    /// Step forward to a newer query.
+    ///
+    /// Past the newest entry, returns the draft and ends navigation.
+    /// Returns `None` when not navigating.
+    pub fn recall_next(&mut self) -> Option<String> {
+        let index = self.cursor?;
+        if index + 1 < self.entries.len() {
+            self.cursor = Some(index + 1);
+            Some(self.entries[index + 1].clone())
+        } else {
+            self.cursor = None;
+            Some(std::mem::take(&mut self.draft))
+        }
+    }
+
+    /// Forget the navigation cursor and draft.
+    pub fn reset_navigation(&mut self) {
+        self.cursor = None;
+        self.draft.clear();
+    }
+}
+
+/// A named query from the `[searches]` table in config.toml.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedSearch {
+    /// Name shown in the picker.
+    pub name: String,
+    /// Query text submitted when picked.
+    pub query: String,
+}
+
+impl SavedSearch {
+    /// Create a saved search.
+    pub fn new(name: impl Into<String>, query: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            query: query.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "search_history_tests.rs"]
+mod tests;
@@ -0,0 +1,91 @@
+//! Tests for search history navigation.
+
+use super::*;
+
+fn history(queries: &[&str]) -> SearchHistory {
+    SearchHistory::from_entries(queries.iter().map(|q| q.to_string()))
+}
+
+// ===== record tests =====
+
+#[test]
+fn record_appends_newest_last() {
+    let mut h = SearchHistory::new();
+    h.record("first");
+    h.record("second");
+
+    assert_eq!(h.entries(), &["first", "second"]);
+}
+
+#[test]
+fn record_moves_duplicate_to_end() {
+    let mut h = history(&["a", "b", "c"]);
+    h.record("a");
+
+    assert_eq!(h.entries(), &["b", "c", "a"]);
+}
+
+#[test]
+fn record_ignores_blank_queries() {
+    let mut h = history(&["a"]);
+    h.record("   ");
+
+    assert_eq!(h.entries(), &["a"]);
+}
+
+#[test]
+fn record_caps_history_length() {
+    let mut h = SearchHistory::new();
+    for i in 0..MAX_SEARCH_HISTORY + 5 {
+        h.record(&format!("q{}", i));
+    }
+
+    assert_eq!(h.entries().len(), MAX_SEARCH_HISTORY);
+    assert_eq!(h.entries()[0], "q5", "Oldest entries should be dropped");
+}
+
+// ===== navigation tests =====
+
+#[test]
+fn recall_previous_on_empty_history_returns_none() {
+    let mut h = SearchHistory::new();
+
+    assert_eq!(h.recall_previous("typed"), None);
+}
+
+#[test]
+fn recall_previous_walks_back_and_clamps_at_oldest() {
+    let mut h = history(&["a", "b", "c"]);
+
+    assert_eq!(h.recall_previous(""), Some("c"));
+    assert_eq!(h.recall_previous("c"), Some("b"));
+    assert_eq!(h.recall_previous("b"), Some("a"));
+    assert_eq!(h.recall_previous("a"), Some("a"));
+}
+
+#[test]
+fn recall_next_past_newest_restores_draft() {
+    let mut h = history(&["a", "b"]);
+
+    h.recall_previous("draft");
+    h.recall_previous("b");
+    assert_eq!(h.recall_next(), Some("b".to_string()));
+    assert_eq!(h.recall_next(), Some("draft".to_string()));
+    assert_eq!(h.recall_next(), None, "Navigation should have ended");
+}
+
+#[test]
+fn recall_next_without_navigation_returns_none() {
+    let mut h = history(&["a"]);
+
+    assert_eq!(h.recall_next(), None);
+}
+
+#[test]
+fn record_resets_navigation() {
+    let mut h = history(&["a", "b"]);
+    h.recall_previous("");
+    h.record("c");
+
+    assert_eq!(h.recall_previous(""), Some("c"));
+}
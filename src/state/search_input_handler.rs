@@ -3,7 +3,7 @@
 //! Handles text input for the SearchState::Typing variant.
 //! All functions are pure - no side effects, testable without TUI.
 
-use crate::state::SearchState;
+use crate::state::{SearchHistory, SearchProgress, SearchState};
 
 /// Handle character input when in Typing state.
 /// Inserts the character at cursor position and advances cursor.
@@ -79,6 +79,42 @@ pub fn handle_cursor_right(state: SearchState) -> SearchState {
     }
 }
 
+/// Replace the query with the previous (older) history entry.
+/// Cursor moves to the end of the recalled query.
+///
+/// Returns updated SearchState. No-op if not in Typing state or history is empty.
+pub fn recall_previous_query(state: SearchState, history: &mut SearchHistory) -> SearchState {
+    match state {
+        SearchState::Typing { query, cursor } => match history.recall_previous(&query) {
+            Some(recalled) => SearchState::Typing {
+                query: recalled.to_string(),
+                cursor: recalled.len(),
+            },
+            None => SearchState::Typing { query, cursor },
+        },
+        // No-op for other states
+        other => other,
+    }
+}
+
+/// Replace the query with the next (newer) history entry.
+/// Stepping past the newest entry restores the text typed before navigation.
+///
+/// Returns updated SearchState. No-op if not in Typing state or not navigating.
+pub fn recall_next_query(state: SearchState, history: &mut SearchHistory) -> SearchState {
+    match state {
+        SearchState::Typing { query, cursor } => match history.recall_next() {
+            Some(recalled) => SearchState::Typing {
+                cursor: recalled.len(),
+                query: recalled,
+            },
+            None => SearchState::Typing { query, cursor },
+        },
+        // No-op for other states
+        other => other,
+    }
+}
+
 /// Activate search input mode.
 /// Transitions from Inactive to Typing with empty query and cursor at 0.
 ///
@@ -120,6 +156,7 @@ pub fn submit_search(state: SearchState) -> SearchState {
                     query: search_query,
                     matches: vec![],
                     current_match: 0,
+                    progress: SearchProgress::Complete,
                 },
                 None => SearchState::Inactive, // Empty/whitespace query
             }
@@ -45,6 +45,7 @@ fn activate_from_active_is_noop() {
         query,
         matches: vec![],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     let result = activate_search_input(state);
 
@@ -79,6 +80,7 @@ fn cancel_from_active_returns_inactive() {
         query,
         matches: vec![],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     let result = cancel_search(state);
 
@@ -187,6 +189,7 @@ fn char_input_noop_when_active() {
         query,
         matches: vec![],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     let state = handle_char_input(state, 'x');
 
@@ -284,6 +287,7 @@ fn backspace_noop_when_active() {
         query,
         matches: vec![],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     let result = handle_backspace(state);
 
@@ -471,6 +475,7 @@ fn submit_noop_when_already_active() {
         query,
         matches: vec![],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
     let result = submit_search(state);
 
@@ -481,3 +486,72 @@ fn submit_noop_when_already_active() {
         _ => panic!("Expected Active state"),
     }
 }
+
+// ===== history recall tests =====
+
+#[test]
+fn recall_previous_query_replaces_typed_text() {
+    let mut history = SearchHistory::from_entries(["error".to_string(), "warn".to_string()]);
+    let state = SearchState::Typing {
+        query: "dr".to_string(),
+        cursor: 2,
+    };
+
+    let result = recall_previous_query(state, &mut history);
+
+    match result {
+        SearchState::Typing { query, cursor } => {
+            assert_eq!(query, "warn", "Should recall newest entry first");
+            assert_eq!(cursor, 4, "Cursor should move to end of recalled query");
+        }
+        _ => panic!("Expected Typing state, got {:?}", result),
+    }
+}
+
+#[test]
+fn recall_next_query_restores_typed_text() {
+    let mut history = SearchHistory::from_entries(["error".to_string()]);
+    let state = SearchState::Typing {
+        query: "dr".to_string(),
+        cursor: 2,
+    };
+
+    let state = recall_previous_query(state, &mut history);
+    let result = recall_next_query(state, &mut history);
+
+    match result {
+        SearchState::Typing { query, cursor } => {
+            assert_eq!(query, "dr", "Should restore text typed before navigation");
+            assert_eq!(cursor, 2);
+        }
+        _ => panic!("Expected Typing state, got {:?}", result),
+    }
+}
+
+#[test]
+fn recall_previous_query_with_empty_history_is_noop() {
+    let mut history = SearchHistory::new();
+    let state = SearchState::Typing {
+        query: "abc".to_string(),
+        cursor: 1,
+    };
+
+    let result = recall_previous_query(state, &mut history);
+
+    match result {
+        SearchState::Typing { query, cursor } => {
+            assert_eq!(query, "abc");
+            assert_eq!(cursor, 1);
+        }
+        _ => panic!("Expected Typing state, got {:?}", result),
+    }
+}
+
+#[test]
+fn recall_previous_query_when_inactive_is_noop() {
+    let mut history = SearchHistory::from_entries(["error".to_string()]);
+
+    let result = recall_previous_query(SearchState::Inactive, &mut history);
+
+    assert!(matches!(result, SearchState::Inactive));
+}
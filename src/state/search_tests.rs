@@ -7,6 +7,7 @@ use crate::model::{
 };
 use crate::state::AppState;
 use chrono::Utc;
+use std::sync::Arc;
 
 // ===== SearchQuery::new Tests =====
 
@@ -107,6 +108,7 @@ fn search_state_active_variant_with_no_matches() {
         query,
         matches: vec![],
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
 
     match state {
@@ -114,6 +116,7 @@ fn search_state_active_variant_with_no_matches() {
             query,
             matches,
             current_match,
+            ..
         } => {
             assert_eq!(query.as_str(), "test");
             assert_eq!(matches.len(), 0);
@@ -152,6 +155,7 @@ fn search_state_active_variant_with_matches() {
         query,
         matches: matches.clone(),
         current_match: 1,
+        progress: SearchProgress::Complete,
     };
 
     match state {
@@ -159,6 +163,7 @@ fn search_state_active_variant_with_matches() {
             query,
             matches: result_matches,
             current_match,
+            ..
         } => {
             assert_eq!(query.as_str(), "error");
             assert_eq!(result_matches.len(), 2);
@@ -216,7 +221,7 @@ fn make_blocks_entry(uuid: &str, agent_id: Option<AgentId>, blocks: Vec<ContentB
 
 #[test]
 fn execute_search_finds_match_in_main_agent_text() {
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "This is an error message"),
     ))];
 
@@ -236,7 +241,7 @@ fn execute_search_finds_match_in_main_agent_text() {
 
 #[test]
 fn execute_search_is_case_insensitive() {
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "ERROR in uppercase"),
     ))];
 
@@ -252,7 +257,7 @@ fn execute_search_is_case_insensitive() {
 
 #[test]
 fn execute_search_finds_multiple_matches_in_single_entry() {
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "error at start and error at end"),
     ))];
 
@@ -270,12 +275,12 @@ fn execute_search_finds_multiple_matches_in_single_entry() {
 #[test]
 fn execute_search_finds_matches_across_multiple_entries() {
     let entries = vec![
-        crate::model::ConversationEntry::Valid(Box::new(make_text_entry(
+        crate::model::ConversationEntry::Valid(Arc::new(make_text_entry(
             "entry-1",
             None,
             "first error",
         ))),
-        crate::model::ConversationEntry::Valid(Box::new(make_text_entry(
+        crate::model::ConversationEntry::Valid(Arc::new(make_text_entry(
             "entry-2",
             None,
             "second error",
@@ -297,7 +302,7 @@ fn execute_search_finds_matches_across_multiple_entries() {
 fn execute_search_finds_match_in_subagent() {
     let mut entries = Vec::new();
     let agent_id = make_agent_id("agent-123");
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", Some(agent_id.clone()), "subagent error"),
     )));
 
@@ -317,10 +322,10 @@ fn execute_search_finds_matches_in_main_and_subagent() {
     let mut entries = Vec::new();
     let agent_id = make_agent_id("agent-abc");
 
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "main error"),
     )));
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-2", Some(agent_id.clone()), "sub error"),
     )));
 
@@ -349,7 +354,7 @@ fn execute_search_searches_all_text_blocks_in_blocks_content() {
             text: "second error".to_string(),
         },
     ];
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(
         make_blocks_entry("entry-1", None, blocks),
     )));
 
@@ -368,7 +373,7 @@ fn execute_search_searches_all_text_blocks_in_blocks_content() {
 
 #[test]
 fn execute_search_returns_empty_when_no_matches() {
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "no matching text"),
     ))];
 
@@ -385,7 +390,7 @@ fn execute_search_returns_empty_when_no_matches() {
 fn execute_search_returns_empty_for_empty_session() {
     let mut state = AppState::new();
     // Add a single entry with no searchable content (empty text)
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, ""),
     ))];
     state.add_entries(entries);
@@ -398,7 +403,7 @@ fn execute_search_returns_empty_for_empty_session() {
 
 #[test]
 fn execute_search_handles_overlapping_matches() {
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "aaa"),
     ))];
 
@@ -416,7 +421,7 @@ fn execute_search_handles_overlapping_matches() {
 
 #[test]
 fn execute_search_stores_correct_match_length() {
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "find this pattern"),
     ))];
 
@@ -436,7 +441,7 @@ fn execute_search_searches_thinking_blocks() {
     let blocks = vec![ContentBlock::Thinking {
         thinking: "I'm thinking about the error".to_string(),
     }];
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(
         make_blocks_entry("entry-1", None, blocks),
     )));
 
@@ -460,7 +465,7 @@ fn execute_search_searches_tool_result_blocks() {
         content: "command failed with error".to_string(),
         is_error: true,
     }];
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(
         make_blocks_entry("entry-1", None, blocks),
     )));
 
@@ -652,7 +657,7 @@ fn agent_ids_with_matches_mixed_main_and_subagent_matches() {
 #[test]
 fn execute_search_handles_emoji_in_content_before_match() {
     // Content: "🦀 error" - emoji is 4 bytes, then space (1 byte), then "error" at byte 5
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "🦀 error"),
     ))];
 
@@ -675,7 +680,7 @@ fn execute_search_handles_emoji_in_content_before_match() {
 #[test]
 fn execute_search_finds_emoji_in_content() {
     // Search for emoji within content
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "Rust 🦀 rocks"),
     ))];
 
@@ -695,7 +700,7 @@ fn execute_search_finds_emoji_in_content() {
 #[test]
 fn execute_search_handles_multibyte_unicode_characters() {
     // Japanese characters (3 bytes each in UTF-8)
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "Hello 日本語 world"),
     ))];
 
@@ -714,7 +719,7 @@ fn execute_search_handles_multibyte_unicode_characters() {
 
 #[test]
 fn execute_search_finds_japanese_text() {
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "Searching for 日本語 here"),
     ))];
 
@@ -731,7 +736,7 @@ fn execute_search_finds_japanese_text() {
 
 #[test]
 fn execute_search_multiple_emojis_in_text() {
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "🔥🦀🚀 test 🎉"),
     ))];
 
@@ -750,7 +755,7 @@ fn execute_search_multiple_emojis_in_text() {
 #[test]
 fn execute_search_emoji_case_insensitive_ascii_only() {
     // Case insensitivity should work for ASCII parts, emoji stays as-is
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "ERROR 🔥 here"),
     ))];
 
@@ -768,7 +773,7 @@ fn execute_search_emoji_case_insensitive_ascii_only() {
 #[test]
 fn execute_search_overlapping_matches_with_unicode() {
     // "ää" where ä is 2 bytes each in UTF-8
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "ääää"),
     ))];
 
@@ -789,7 +794,7 @@ fn execute_search_overlapping_matches_with_unicode() {
 #[test]
 fn execute_search_unicode_at_match_boundary() {
     // Emoji right at the end of a match
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "test🦀 more test🦀"),
     ))];
 
@@ -808,7 +813,7 @@ fn execute_search_unicode_at_match_boundary() {
 
 #[test]
 fn execute_search_stores_correct_match_length_for_unicode_query() {
-    let entries = vec![crate::model::ConversationEntry::Valid(Box::new(
+    let entries = vec![crate::model::ConversationEntry::Valid(Arc::new(
         make_text_entry("entry-1", None, "Find the 🚀 emoji"),
     ))];
 
@@ -821,3 +826,34 @@ fn execute_search_stores_correct_match_length_for_unicode_query() {
     assert_eq!(matches.len(), 1);
     assert_eq!(matches[0].length, 4, "Rocket emoji is 4 bytes, not 1 char");
 }
+
+#[test]
+fn search_document_with_tool_inputs_searches_tool_json() {
+    use crate::model::{ToolCall, ToolName, ToolUseId};
+
+    let entry = Arc::new(make_blocks_entry(
+        "entry-1",
+        None,
+        vec![
+            ContentBlock::ToolUse(ToolCall::new(
+                ToolUseId::new("tool-1").expect("valid id"),
+                ToolName::Bash,
+                serde_json::json!({"command": "cargo test"}),
+            )),
+            ContentBlock::Text {
+                text: "ran cargo".to_string(),
+            },
+        ],
+    ));
+    let mut matches = Vec::new();
+
+    SearchDocument::from_entry(&entry).find_matches("cargo", &mut matches);
+    assert_eq!(matches.len(), 1, "tool inputs are not searched by default");
+
+    matches.clear();
+    SearchDocument::from_entry(&entry)
+        .with_tool_inputs()
+        .find_matches("cargo", &mut matches);
+    let blocks: Vec<usize> = matches.iter().map(|m| m.block_index).collect();
+    assert_eq!(blocks, vec![0, 1]);
+}
@@ -1,5 +1,15 @@
 //! State for the session list modal.
-
+//!
+//! The modal lists sessions as a table that can be sorted by any column and
+//! filtered by typing. In the default order, resumed sessions are nested under
+//! the session they continue. Rows are addressed in display order (see
+//! `sorted_sessions`). Session summaries, with their stats-derived columns
+//! such as cost, are built when the modal opens or the log changes; sorting
+//! and filtering only reorder them (see `refresh_session_rows`).
+
+use crate::model::StatsFilter;
+use crate::state::AppState;
+use crate::view_state::session_summary::{SessionSortColumn, SessionSummary};
 use crate::view_state::types::SessionIndex;
 
 /// State for the session list modal.
@@ -14,12 +24,38 @@ pub struct SessionModalState {
     /// Whether the modal is visible.
     visible: bool,
 
-    /// Currently selected row in the modal (0-indexed).
+    /// Currently selected row in the modal (0-indexed, in display order).
     /// Only meaningful when `visible` is true.
     selected_index: usize,
 
     /// Scroll offset for long session lists.
     scroll_offset: usize,
+
+    /// Column the rows are sorted by.
+    sort: SessionSortColumn,
+
+    /// Whether the sort direction is reversed from the column's default.
+    reversed: bool,
+
+    /// Filter text; only matching sessions are listed.
+    filter: String,
+
+    /// Whether keys are typed into the filter.
+    filtering: bool,
+
+    /// Every session's summary; `None` until built by `refresh_session_rows`.
+    summaries: Option<SessionSummaries>,
+
+    /// Rows in display order; `None` until (re)built by `refresh_session_rows`.
+    rows: Option<Vec<SessionSummary>>,
+}
+
+/// Summaries of every session in log order, with the order that lists
+/// chains of resumed sessions as trees.
+#[derive(Debug, Clone, Default)]
+struct SessionSummaries {
+    summaries: Vec<SessionSummary>,
+    tree_order: Vec<usize>,
 }
 
 impl SessionModalState {
@@ -33,11 +69,18 @@ impl SessionModalState {
         self.visible
     }
 
-    /// Open the modal, pre-selecting the given session.
+    /// Open the modal, pre-selecting the given row, with the filter cleared.
+    ///
+    /// The sort order is kept; see `select_session` to select a session
+    /// under a non-default sort.
     pub fn open(&mut self, current_session_index: usize) {
         self.visible = true;
         self.selected_index = current_session_index;
         self.scroll_offset = 0;
+        self.filter.clear();
+        self.filtering = false;
+        self.summaries = None;
+        self.rows = None;
     }
 
     /// Close the modal.
@@ -83,6 +126,108 @@ impl SessionModalState {
         }
     }
 
+    /// Select the row showing `session_index`, if it is listed.
+    pub fn select_session(&mut self, session_index: usize) {
+        if let Some(row) = self
+            .rows()
+            .iter()
+            .position(|s| s.index().get() == session_index)
+        {
+            self.selected_index = row;
+        }
+    }
+
+    /// Rows in display order (empty until built by `refresh_session_rows`).
+    pub fn rows(&self) -> &[SessionSummary] {
+        self.rows.as_deref().unwrap_or(&[])
+    }
+
+    /// Mark the rows and session summaries for rebuilding, e.g. after
+    /// entries were added.
+    pub fn invalidate_rows(&mut self) {
+        self.summaries = None;
+        self.rows = None;
+    }
+
+    /// Sort column.
+    pub fn sort(&self) -> SessionSortColumn {
+        self.sort
+    }
+
+    /// Whether the sort direction is reversed.
+    pub fn reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Sort by the next column (default direction).
+    pub fn next_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.reversed = false;
+        self.rows = None;
+    }
+
+    /// Sort by the previous column (default direction).
+    pub fn prev_sort(&mut self) {
+        self.sort = self.sort.prev();
+        self.reversed = false;
+        self.rows = None;
+    }
+
+    /// Reverse the sort direction.
+    pub fn reverse_sort(&mut self) {
+        self.reversed = !self.reversed;
+        self.rows = None;
+    }
+
+    /// Whether rows are listed as a tree of resumed-session chains: in the
+    /// default order (by number, unfiltered), each session follows the one it
+    /// continues.
+    pub fn shows_tree(&self) -> bool {
+        self.sort == SessionSortColumn::Number && !self.reversed && self.filter.is_empty()
+    }
+
+    /// Filter text.
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Whether keys are typed into the filter.
+    pub fn is_filtering(&self) -> bool {
+        self.filtering
+    }
+
+    /// Start typing into the filter.
+    pub fn start_filter(&mut self) {
+        self.filtering = true;
+    }
+
+    /// Stop typing into the filter, keeping it applied.
+    pub fn stop_filter(&mut self) {
+        self.filtering = false;
+    }
+
+    /// Clear the filter and stop typing into it.
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+        self.filtering = false;
+        self.selected_index = 0;
+        self.rows = None;
+    }
+
+    /// Append a character to the filter, selecting the first row.
+    pub fn push_filter_char(&mut self, c: char) {
+        self.filter.push(c);
+        self.selected_index = 0;
+        self.rows = None;
+    }
+
+    /// Remove the filter's last character, selecting the first row.
+    pub fn pop_filter_char(&mut self) {
+        self.filter.pop();
+        self.selected_index = 0;
+        self.rows = None;
+    }
+
     /// Get selected session index, validated against session count.
     pub fn selected_session_index(&self, session_count: usize) -> Option<SessionIndex> {
         SessionIndex::new(self.selected_index, session_count)
@@ -103,6 +248,96 @@ impl SessionModalState {
     }
 }
 
+/// Build the modal's rows if it is open and they are missing or stale.
+///
+/// Session summaries carry stats-derived columns (reported and estimated
+/// cost, first prompt, ...), so they are kept until the log changes or the
+/// modal reopens; sort and filter changes only reorder them.
+pub fn refresh_session_rows(state: &mut AppState) {
+    if !state.session_modal.is_visible() {
+        return;
+    }
+    if state.session_modal.summaries.is_none() {
+        let summaries = session_summaries(state);
+        state.session_modal.summaries = Some(summaries);
+    }
+    if state.session_modal.rows.is_none() {
+        let modal = &state.session_modal;
+        let rows = modal
+            .summaries
+            .as_ref()
+            .map(|summaries| display_rows(modal, summaries))
+            .unwrap_or_default();
+        state.session_modal.rows = Some(rows);
+    }
+}
+
+/// Sessions matching the modal's filter, in its sort order.
+///
+/// In the default order chains of resumed sessions are listed as trees (see
+/// `SessionModalState::shows_tree`).
+pub fn sorted_sessions(state: &AppState) -> Vec<SessionSummary> {
+    display_rows(&state.session_modal, &session_summaries(state))
+}
+
+/// Summarize every session, with its costs and place in its chain.
+fn session_summaries(state: &AppState) -> SessionSummaries {
+    let session_count = state.log_view().session_count();
+    let links = state.log_view().session_links();
+    let summaries = state
+        .log_view()
+        .sessions()
+        .enumerate()
+        .map(|(i, session_view)| {
+            let index = SessionIndex::new(i, session_count)
+                .expect("enumerated session index is below session_count");
+
+            // Reported cost covers the whole session, so reconcile at session scope
+            let stats = session_view.stats();
+            let filter = StatsFilter::Session(session_view.session_id().clone());
+            let model_id = session_view.main().model_id();
+            let cost = stats.filtered_cost_reconciliation(&filter, &state.pricing, model_id);
+            let estimated = stats.filtered_estimated_cost(&filter, &state.pricing, model_id);
+
+            let parent = links
+                .parent(i)
+                .and_then(|p| SessionIndex::new(p, session_count));
+            SessionSummary::from_session(index, session_view)
+                .with_cost(cost)
+                .with_estimated_cost(estimated)
+                .with_chain(parent, links.depth(i))
+        })
+        .collect();
+    SessionSummaries {
+        summaries,
+        tree_order: links.tree_order(),
+    }
+}
+
+/// The summaries matching the modal's filter, in its sort order.
+fn display_rows(modal: &SessionModalState, all: &SessionSummaries) -> Vec<SessionSummary> {
+    let mut summaries: Vec<SessionSummary> = all
+        .summaries
+        .iter()
+        .filter(|summary| summary.matches_filter(modal.filter()))
+        .cloned()
+        .collect();
+    if modal.shows_tree() {
+        let order = &all.tree_order;
+        summaries.sort_by_key(|s| order.iter().position(|&i| i == s.index().get()));
+        return summaries;
+    }
+    summaries.sort_by(|a, b| {
+        let ordering = modal.sort().compare(a, b);
+        if modal.reversed() {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    summaries
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
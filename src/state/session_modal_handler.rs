@@ -2,23 +2,29 @@
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-use crate::state::{AppState, ConversationSelection, ViewedSession};
+use crate::state::{AppState, ConversationSelection, ViewedSession, refresh_session_rows};
+use crate::view_state::types::SessionIndex;
 
 /// Handle keyboard input when session modal is visible.
 ///
 /// Returns `true` if the key was consumed by the modal, `false` otherwise.
 ///
 /// # Key Bindings
-/// - Up/k: Select previous session (clamps at 0)
-/// - Down/j: Select next session (clamps at last)
-/// - Home/g: Jump to first session
-/// - End/G: Jump to last session
+/// - Up/k: Select previous row (clamps at 0)
+/// - Down/j: Select next row (clamps at last)
+/// - Home/g: Jump to first row
+/// - End/G: Jump to last row
+/// - Tab / Shift+Tab: Sort by next/previous column (keeps the selected session)
+/// - r: Reverse sort direction
+/// - /: Type to filter (Enter keeps the filter, Esc clears it)
 /// - Enter: Confirm selection (sets viewed_session, closes modal)
+/// - c: View the selected session's whole chain of resumed sessions, back to back
 /// - Esc: Cancel (closes modal without changing viewed_session)
 /// - S (lowercase or uppercase): Toggle close (closes modal without changing viewed_session)
-/// - 1-9: Quick select session N (if valid)
+/// - 1-9: Quick select row N (if valid)
 ///
 /// # Behavior
+/// - Rows are sessions matching the filter in sort order (see `sorted_sessions`)
 /// - Navigation clamps to bounds (does NOT wrap)
 /// - Enter on last session sets ViewedSession::Latest (enables live tailing)
 /// - Enter on non-last session sets ViewedSession::Pinned(idx)
@@ -29,7 +35,28 @@ pub fn handle_session_modal_key(state: &mut AppState, key: KeyEvent) -> bool {
         return false;
     }
 
-    let session_count = state.log_view().session_count();
+    refresh_session_rows(state);
+    let row_count = state.session_modal.rows().len();
+    let selected_session = state
+        .session_modal
+        .rows()
+        .get(state.session_modal.selected_index())
+        .map(|summary| summary.index().get());
+
+    if state.session_modal.is_filtering() {
+        match key.code {
+            KeyCode::Esc => state.session_modal.clear_filter(),
+            KeyCode::Enter => state.session_modal.stop_filter(),
+            KeyCode::Backspace => state.session_modal.pop_filter_char(),
+            KeyCode::Up => state.session_modal.select_prev(),
+            KeyCode::Down => state.session_modal.select_next(row_count),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                state.session_modal.push_filter_char(c)
+            }
+            _ => {}
+        }
+        return true;
+    }
 
     match key.code {
         // Close without changing viewed_session
@@ -52,7 +79,7 @@ pub fn handle_session_modal_key(state: &mut AppState, key: KeyEvent) -> bool {
 
         // Navigate down
         KeyCode::Down | KeyCode::Char('j') => {
-            state.session_modal.select_next(session_count);
+            state.session_modal.select_next(row_count);
             true
         }
 
@@ -64,18 +91,50 @@ pub fn handle_session_modal_key(state: &mut AppState, key: KeyEvent) -> bool {
 
         // Jump to last (End key or Shift+G)
         KeyCode::End => {
-            state.session_modal.select_last(session_count);
+            state.session_modal.select_last(row_count);
             true
         }
         KeyCode::Char('G') if key.modifiers.contains(KeyModifiers::SHIFT) => {
-            state.session_modal.select_last(session_count);
+            state.session_modal.select_last(row_count);
+            true
+        }
+
+        // Sorting keeps the selected session selected
+        KeyCode::Tab | KeyCode::BackTab | KeyCode::Char('r') => {
+            match key.code {
+                KeyCode::Tab => state.session_modal.next_sort(),
+                KeyCode::BackTab => state.session_modal.prev_sort(),
+                _ => state.session_modal.reverse_sort(),
+            }
+            if let Some(session_index) = selected_session {
+                refresh_session_rows(state);
+                state.session_modal.select_session(session_index);
+            }
+            true
+        }
+
+        // View the selected session's chain
+        KeyCode::Char('c') => {
+            if let Some(session_index) = selected_session {
+                state.view_chain(session_index);
+            }
+            state.session_modal.close();
+            true
+        }
+
+        // Start typing a filter
+        KeyCode::Char('/') => {
+            state.session_modal.start_filter();
             true
         }
 
         // Enter: Confirm selection
         KeyCode::Enter => {
-            // Get validated session index
-            if let Some(idx) = state.session_modal.selected_session_index(session_count) {
+            let session_count = state.log_view().session_count();
+            // Get validated session index of the selected row
+            let selected =
+                selected_session.and_then(|index| SessionIndex::new(index, session_count));
+            if let Some(idx) = selected {
                 // If selecting last session, switch to Latest mode (enables live tailing)
                 // Otherwise, pin to specific session
                 if idx.is_last(session_count) {
@@ -98,21 +157,27 @@ pub fn handle_session_modal_key(state: &mut AppState, key: KeyEvent) -> bool {
                 if let Some(session) = state.log_view().get_session(idx.get()) {
                     state.on_session_change(session.session_id().clone());
                 }
+
+                // Continuous mode: show every session again, this one's heading at the top
+                if state.continuous_sessions {
+                    state.log_view_mut().set_chain(None);
+                    state.scroll_to_viewed_session();
+                }
             }
             // Close modal even if selection was invalid
             state.session_modal.close();
             true
         }
 
-        // Quick select: 1-9 jumps to session N-1 (0-indexed)
+        // Quick select: 1-9 jumps to row N-1 (0-indexed)
         KeyCode::Char(c @ '1'..='9') => {
             let target_index = (c as usize) - ('1' as usize);
             // Only change selection if target is valid
-            if target_index < session_count {
+            if target_index < row_count {
                 // Manually set selection by navigating to it
                 state.session_modal.select_first();
                 for _ in 0..target_index {
-                    state.session_modal.select_next(session_count);
+                    state.session_modal.select_next(row_count);
                 }
             }
             true
@@ -542,15 +607,186 @@ mod tests {
 
             assert!(!result, "Should return false for space key");
         }
+    }
+
+    mod sort_and_filter {
+        use super::*;
+        use crate::state::sorted_sessions;
+        use crate::view_state::session_summary::SessionSortColumn;
+
+        fn type_filter(state: &mut AppState, text: &str) {
+            handle_session_modal_key(state, key(KeyCode::Char('/')));
+            for c in text.chars() {
+                handle_session_modal_key(state, key(KeyCode::Char(c)));
+            }
+        }
 
         #[test]
-        fn tab_key_returns_false() {
+        fn tab_cycles_sort_column() {
             let mut state = create_test_state(5);
-            state.session_modal.open(2);
 
             let result = handle_session_modal_key(&mut state, key(KeyCode::Tab));
+            assert!(result, "Should return true for tab key");
+            assert_eq!(state.session_modal.sort(), SessionSortColumn::Prompt);
+
+            handle_session_modal_key(&mut state, key(KeyCode::BackTab));
+            handle_session_modal_key(&mut state, key(KeyCode::BackTab));
+            assert_eq!(state.session_modal.sort(), SessionSortColumn::Status);
+        }
+
+        #[test]
+        fn reverse_keeps_selected_session() {
+            let mut state = create_test_state(5);
+            state.session_modal.open(1);
+
+            handle_session_modal_key(&mut state, key(KeyCode::Char('r')));
+
+            assert!(state.session_modal.reversed());
+            assert_eq!(state.session_modal.selected_index(), 3);
+            handle_session_modal_key(&mut state, key(KeyCode::Enter));
+            assert!(matches!(state.viewed_session, ViewedSession::Pinned(idx) if idx.get() == 1));
+        }
+
+        #[test]
+        fn typing_filters_rows_and_enter_selects_match() {
+            let mut state = create_test_state(12);
+
+            type_filter(&mut state, "#11");
+
+            assert!(state.session_modal.is_filtering());
+            assert_eq!(state.session_modal.filter(), "#11");
+            let rows = sorted_sessions(&state);
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].index().get(), 10);
+
+            handle_session_modal_key(&mut state, key(KeyCode::Enter));
+            assert!(!state.session_modal.is_filtering(), "Enter keeps filter");
+            assert_eq!(state.session_modal.filter(), "#11");
+
+            handle_session_modal_key(&mut state, key(KeyCode::Enter));
+            assert!(matches!(state.viewed_session, ViewedSession::Pinned(idx) if idx.get() == 10));
+            assert!(!state.session_modal.is_visible());
+        }
+
+        #[test]
+        fn escape_while_filtering_clears_filter() {
+            let mut state = create_test_state(3);
+            type_filter(&mut state, "session-2x");
+            handle_session_modal_key(&mut state, key(KeyCode::Backspace));
+            assert_eq!(sorted_sessions(&state).len(), 1);
+
+            handle_session_modal_key(&mut state, key(KeyCode::Esc));
+
+            assert!(state.session_modal.is_visible(), "Modal stays open");
+            assert_eq!(state.session_modal.filter(), "");
+            assert_eq!(sorted_sessions(&state).len(), 3);
+        }
+
+        #[test]
+        fn sorting_and_filtering_reuse_session_summaries() {
+            let mut state = create_test_state(3);
+            handle_session_modal_key(&mut state, key(KeyCode::Down));
+
+            // A session added without signalling the log change stays unlisted
+            let session_id = crate::model::SessionId::new("session-3").unwrap();
+            state.log_view_mut().create_empty_session(session_id);
+            handle_session_modal_key(&mut state, key(KeyCode::Tab));
+            type_filter(&mut state, "session");
+            refresh_session_rows(&mut state);
+            assert_eq!(state.session_modal.rows().len(), 3);
+
+            state.session_modal.invalidate_rows();
+            refresh_session_rows(&mut state);
+            assert_eq!(state.session_modal.rows().len(), 4);
+        }
+    }
+
+    mod chains {
+        use super::*;
+        use crate::model::{
+            ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry, Message,
+            MessageContent, Role, SessionId,
+        };
+        use crate::state::sorted_sessions;
+        use chrono::{TimeZone, Utc};
+        use std::sync::Arc;
+
+        fn entry(session: &str, uuid: &str, parent: Option<&str>) -> ConversationEntry {
+            ConversationEntry::Valid(Arc::new(
+                LogEntry::new(
+                    EntryUuid::new(uuid).unwrap(),
+                    None,
+                    SessionId::new(session).unwrap(),
+                    None,
+                    Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+                    EntryType::User,
+                    Message::new(Role::User, MessageContent::Text(uuid.to_string())),
+                    EntryMetadata::default(),
+                )
+                .with_transcript_parent_uuid(parent.map(|p| EntryUuid::new(p).unwrap())),
+            ))
+        }
+
+        /// Sessions a, b and c, where c resumes a.
+        fn create_chained_state() -> AppState {
+            let mut state = AppState::new();
+            let log = state.log_view_mut();
+            log.add_entry(entry("a", "a1", None), None);
+            log.add_entry(entry("b", "b1", None), None);
+            log.add_entry(entry("c", "c1", Some("a1")), None);
+            state.session_modal.open(0);
+            state
+        }
+
+        #[test]
+        fn rows_are_kept_until_entries_arrive() {
+            let mut state = create_chained_state();
+            handle_session_modal_key(&mut state, key(KeyCode::Down));
+            assert_eq!(state.session_modal.rows().len(), 3);
+
+            state.add_entries(vec![entry("d", "d1", None)]);
+            assert!(state.session_modal.rows().is_empty());
+
+            handle_session_modal_key(&mut state, key(KeyCode::Down));
+            assert_eq!(state.session_modal.rows().len(), 4);
+        }
+
+        #[test]
+        fn rows_list_resumed_sessions_under_their_parent() {
+            let state = create_chained_state();
+
+            let rows = sorted_sessions(&state);
+
+            let order: Vec<usize> = rows.iter().map(|r| r.index().get()).collect();
+            assert_eq!(order, vec![0, 2, 1]);
+            assert_eq!(rows[1].parent().map(|p| p.get()), Some(0));
+            assert_eq!(rows[1].depth(), 1);
+        }
+
+        #[test]
+        fn c_views_selected_sessions_chain() {
+            let mut state = create_chained_state();
+            handle_session_modal_key(&mut state, key(KeyCode::Down));
+
+            let result = handle_session_modal_key(&mut state, key(KeyCode::Char('c')));
+
+            assert!(result);
+            assert!(!state.session_modal.is_visible());
+            assert!(state.continuous_sessions);
+            assert_eq!(state.log_view().chain(), Some(&[0, 2][..]));
+            assert!(matches!(state.viewed_session, ViewedSession::Latest));
+        }
+
+        #[test]
+        fn enter_in_continuous_mode_shows_all_sessions_again() {
+            let mut state = create_chained_state();
+            state.view_chain(2);
+            state.session_modal.open(0);
+
+            handle_session_modal_key(&mut state, key(KeyCode::Enter));
 
-            assert!(!result, "Should return false for tab key");
+            assert!(state.continuous_sessions);
+            assert_eq!(state.log_view().chain(), None);
         }
     }
 }
@@ -7,6 +7,7 @@
 use crate::model::{EntryType, LogEntry, Message, MessageContent, Role, SessionId};
 use crate::state::{AppState, ViewedSession};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::sync::Arc;
 
 /// Helper to create a KeyEvent
 fn key(code: KeyCode) -> KeyEvent {
@@ -34,7 +35,7 @@ fn create_test_state_with_sessions(count: usize) -> AppState {
             crate::model::EntryMetadata::default(),
         );
 
-        state.add_entries(vec![crate::model::ConversationEntry::Valid(Box::new(
+        state.add_entries(vec![crate::model::ConversationEntry::Valid(Arc::new(
             entry,
         ))]);
     }
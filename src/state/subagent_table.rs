@@ -0,0 +1,226 @@
+//! State and keyboard handling for the subagent table modal.
+//!
+//! Lists every subagent of the viewed session with its Task label, type,
+//! model, timing, size, cost, tool usage and final status. Confirming a row
+//! opens that subagent's tab. Rows are built when the table opens or the log
+//! changes (see `refresh_subagent_rows`); sort changes only reorder them.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::model::{SubagentSortColumn, SubagentSummary};
+use crate::state::{AppState, ConversationSelection, FocusPane};
+
+/// State for the subagent table modal.
+#[derive(Debug, Clone, Default)]
+pub struct SubagentTableState {
+    /// Whether the modal is visible.
+    visible: bool,
+
+    /// Selected row (0-indexed, in sorted order).
+    selected: usize,
+
+    /// Column the rows are sorted by.
+    sort: SubagentSortColumn,
+
+    /// Whether the sort direction is reversed from the column's default.
+    reversed: bool,
+
+    /// Rows in sort order; `None` until built by `refresh_subagent_rows`.
+    rows: Option<Vec<SubagentSummary>>,
+}
+
+impl SubagentTableState {
+    /// Create new modal state (closed).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if modal is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open the modal with the first row selected, keeping the sort order.
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.selected = 0;
+        self.rows = None;
+    }
+
+    /// Close the modal.
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// Toggle modal visibility.
+    pub fn toggle(&mut self) {
+        if self.visible {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Selected row.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Sort column.
+    pub fn sort(&self) -> SubagentSortColumn {
+        self.sort
+    }
+
+    /// Whether the sort direction is reversed.
+    pub fn reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Rows in sort order (empty until built by `refresh_subagent_rows`).
+    pub fn rows(&self) -> &[SubagentSummary] {
+        self.rows.as_deref().unwrap_or(&[])
+    }
+
+    /// Mark the rows for rebuilding, e.g. after entries arrive.
+    pub fn invalidate_rows(&mut self) {
+        self.rows = None;
+    }
+
+    /// Sort by the next column (default direction).
+    pub fn next_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.reversed = false;
+        self.sort_rows();
+    }
+
+    /// Sort by the previous column (default direction).
+    pub fn prev_sort(&mut self) {
+        self.sort = self.sort.prev();
+        self.reversed = false;
+        self.sort_rows();
+    }
+
+    /// Reverse the sort direction.
+    pub fn reverse_sort(&mut self) {
+        self.reversed = !self.reversed;
+        self.sort_rows();
+    }
+
+    /// Reorder built rows after a sort change.
+    fn sort_rows(&mut self) {
+        let (sort, reversed) = (self.sort, self.reversed);
+        if let Some(rows) = &mut self.rows {
+            sort_summaries(rows, sort, reversed);
+        }
+    }
+
+    /// Move selection up, clamping at 0.
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Move selection down, clamping at the last row.
+    pub fn select_next(&mut self, count: usize) {
+        if count > 0 {
+            self.selected = (self.selected + 1).min(count - 1);
+        }
+    }
+
+    /// Jump to the first row.
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+    }
+
+    /// Jump to the last row.
+    pub fn select_last(&mut self, count: usize) {
+        self.selected = count.saturating_sub(1);
+    }
+}
+
+/// Build the table's rows if it is open and they are missing or stale.
+pub fn refresh_subagent_rows(state: &mut AppState) {
+    if state.subagent_table.is_visible() && state.subagent_table.rows.is_none() {
+        state.subagent_table.rows = Some(sorted_subagents(state));
+    }
+}
+
+/// Subagents of the viewed session in the table's sort order.
+pub fn sorted_subagents(state: &AppState) -> Vec<SubagentSummary> {
+    if state.log_view().session_count() == 0 {
+        return Vec::new();
+    }
+    let session_view = state.session_view();
+    let mut summaries = SubagentSummary::collect(
+        &session_view.log_entries_by_time(),
+        &session_view.stats(),
+        &state.pricing,
+        session_view.main().model_id(),
+    );
+    let table = &state.subagent_table;
+    sort_summaries(&mut summaries, table.sort(), table.reversed());
+    summaries
+}
+
+/// Sort rows by `sort`, reversed from its default direction if `reversed`.
+fn sort_summaries(summaries: &mut [SubagentSummary], sort: SubagentSortColumn, reversed: bool) {
+    summaries.sort_by(|a, b| {
+        let ordering = sort.compare(a, b);
+        if reversed {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+}
+
+/// Handle keyboard input when the subagent table is visible.
+///
+/// Returns `true` if the key was consumed by the modal, `false` otherwise.
+///
+/// # Key Bindings
+/// - Up/k, Down/j: Select previous/next row (clamps at bounds)
+/// - Home/g, End/G: Jump to first/last row
+/// - s / S: Sort by next/previous column
+/// - r: Reverse sort direction
+/// - Enter: Open the selected subagent's tab and close
+/// - Esc/A: Close
+///
+/// All other keys are swallowed while the modal is open.
+pub fn handle_subagent_table_key(state: &mut AppState, key: KeyEvent) -> bool {
+    if !state.subagent_table.is_visible() {
+        return false;
+    }
+
+    refresh_subagent_rows(state);
+    let count = state.subagent_table.rows().len();
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('A') => state.subagent_table.close(),
+        KeyCode::Up | KeyCode::Char('k') => state.subagent_table.select_prev(),
+        KeyCode::Down | KeyCode::Char('j') => state.subagent_table.select_next(count),
+        KeyCode::Home | KeyCode::Char('g') => state.subagent_table.select_first(),
+        KeyCode::End => state.subagent_table.select_last(count),
+        KeyCode::Char('G') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            state.subagent_table.select_last(count)
+        }
+        KeyCode::Char('s') => state.subagent_table.next_sort(),
+        KeyCode::Char('S') => state.subagent_table.prev_sort(),
+        KeyCode::Char('r') => state.subagent_table.reverse_sort(),
+        KeyCode::Enter => {
+            let table = &state.subagent_table;
+            if let Some(summary) = table.rows().get(table.selected()) {
+                state.focus = FocusPane::Subagent;
+                state.selected_conversation =
+                    ConversationSelection::Subagent(summary.agent_id.clone());
+                state.subagent_table.close();
+            }
+        }
+        _ => {}
+    }
+
+    true
+}
+
+#[cfg(test)]
+#[path = "subagent_table_tests.rs"]
+mod tests;
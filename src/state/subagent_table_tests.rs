@@ -0,0 +1,168 @@
+//! Tests for the subagent table modal.
+
+use super::*;
+use crate::model::{
+    AgentId, ContentBlock, ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry,
+    Message, MessageContent, Role, SessionId, SubagentStatus, ToolCall, ToolName, ToolUseId,
+};
+use std::sync::Arc;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+fn log_entry(
+    uuid: &str,
+    agent: Option<&str>,
+    secs: i64,
+    content: MessageContent,
+) -> ConversationEntry {
+    ConversationEntry::Valid(Arc::new(LogEntry::new(
+        EntryUuid::new(uuid).unwrap(),
+        None,
+        SessionId::new("s1").unwrap(),
+        agent.map(|a| AgentId::new(a).unwrap()),
+        chrono::DateTime::UNIX_EPOCH + chrono::Duration::seconds(secs),
+        EntryType::Assistant,
+        Message::new(Role::Assistant, content),
+        EntryMetadata::default(),
+    )))
+}
+
+fn task_call(id: &str, description: &str) -> MessageContent {
+    MessageContent::Blocks(vec![ContentBlock::ToolUse(ToolCall::new(
+        ToolUseId::new(id).unwrap(),
+        ToolName::Task,
+        serde_json::json!({ "description": description }),
+    ))])
+}
+
+fn text() -> MessageContent {
+    MessageContent::Text("working".to_string())
+}
+
+/// `toolu_a` ("Zeta", 1 entry) starts before `toolu_b` ("Alpha", 2 entries).
+fn state_with_subagents() -> AppState {
+    let mut state = AppState::new();
+    state.add_entries(vec![
+        log_entry("m1", None, 0, task_call("toolu_a", "Zeta")),
+        log_entry("a1", Some("toolu_a"), 1, text()),
+        log_entry("m2", None, 2, task_call("toolu_b", "Alpha")),
+        log_entry("b1", Some("toolu_b"), 3, text()),
+        log_entry("b2", Some("toolu_b"), 4, text()),
+    ]);
+    state
+}
+
+fn labels(state: &AppState) -> Vec<String> {
+    sorted_subagents(state)
+        .iter()
+        .map(|s| s.label().to_string())
+        .collect()
+}
+
+#[test]
+fn keys_are_ignored_when_table_closed() {
+    let mut state = state_with_subagents();
+
+    assert!(!handle_subagent_table_key(&mut state, key(KeyCode::Enter)));
+}
+
+#[test]
+fn sorted_subagents_is_empty_without_sessions() {
+    assert!(sorted_subagents(&AppState::new()).is_empty());
+}
+
+#[test]
+fn rows_default_to_start_order_with_task_labels() {
+    let state = state_with_subagents();
+
+    let subagents = sorted_subagents(&state);
+
+    assert_eq!(labels(&state), vec!["Zeta", "Alpha"]);
+    assert_eq!(subagents[1].entries, 2);
+    assert_eq!(subagents[0].status, SubagentStatus::Running);
+}
+
+#[test]
+fn s_cycles_sort_column_and_r_reverses() {
+    let mut state = state_with_subagents();
+    state.subagent_table.open();
+
+    // Start -> End -> Duration -> Entries (descending)
+    for _ in 0..3 {
+        handle_subagent_table_key(&mut state, key(KeyCode::Char('s')));
+    }
+    assert_eq!(state.subagent_table.sort(), SubagentSortColumn::Entries);
+    assert_eq!(labels(&state), vec!["Alpha", "Zeta"]);
+
+    handle_subagent_table_key(&mut state, key(KeyCode::Char('r')));
+    assert_eq!(labels(&state), vec!["Zeta", "Alpha"]);
+
+    handle_subagent_table_key(&mut state, key(KeyCode::Char('S')));
+    assert_eq!(state.subagent_table.sort(), SubagentSortColumn::Duration);
+    assert!(
+        !state.subagent_table.reversed(),
+        "New column resets direction"
+    );
+}
+
+#[test]
+fn enter_opens_selected_subagent_tab() {
+    let mut state = state_with_subagents();
+    state.subagent_table.open();
+
+    for _ in 0..3 {
+        handle_subagent_table_key(&mut state, key(KeyCode::Char('j')));
+    }
+    assert_eq!(state.subagent_table.selected(), 1);
+
+    handle_subagent_table_key(&mut state, key(KeyCode::Enter));
+
+    assert!(!state.subagent_table.is_visible());
+    assert_eq!(state.focus, FocusPane::Subagent);
+    assert_eq!(
+        state.selected_conversation,
+        ConversationSelection::Subagent(AgentId::new("toolu_b").unwrap())
+    );
+}
+
+#[test]
+fn esc_closes_and_reopen_keeps_sort() {
+    let mut state = state_with_subagents();
+    state.subagent_table.open();
+    handle_subagent_table_key(&mut state, key(KeyCode::Char('s')));
+
+    handle_subagent_table_key(&mut state, key(KeyCode::Esc));
+    assert!(!state.subagent_table.is_visible());
+
+    state.subagent_table.toggle();
+    assert_eq!(state.subagent_table.sort(), SubagentSortColumn::End);
+}
+
+#[test]
+fn rows_are_kept_until_entries_arrive_and_sorts_reorder_them() {
+    let mut state = state_with_subagents();
+    state.subagent_table.open();
+    refresh_subagent_rows(&mut state);
+    assert_eq!(state.subagent_table.rows().len(), 2);
+
+    state.subagent_table.next_sort();
+    state.subagent_table.next_sort();
+    state.subagent_table.next_sort();
+    let rows: Vec<&str> = state
+        .subagent_table
+        .rows()
+        .iter()
+        .map(|s| s.label())
+        .collect();
+    assert_eq!(rows, vec!["Alpha", "Zeta"], "sorted without a rebuild");
+
+    state.add_entries(vec![
+        log_entry("m3", None, 5, task_call("toolu_c", "Gamma")),
+        log_entry("c1", Some("toolu_c"), 6, text()),
+    ]);
+    assert!(state.subagent_table.rows().is_empty());
+    refresh_subagent_rows(&mut state);
+    assert_eq!(state.subagent_table.rows().len(), 3);
+}
@@ -15,6 +15,7 @@ use crate::model::{
 };
 use crate::state::{AppState, ConversationSelection, FocusPane};
 use chrono::Utc;
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -41,7 +42,7 @@ fn make_subagent_entry(agent_id: &str) -> ConversationEntry {
         EntryMetadata::default(),
     );
 
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 // ===== NextTab tests =====
@@ -381,7 +382,7 @@ fn make_main_entry(session_id: &str, content: &str) -> ConversationEntry {
         EntryMetadata::default(),
     );
 
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 /// Helper to create a subagent entry for a specific session and agent
@@ -401,7 +402,7 @@ fn make_subagent_entry_for_session(
         EntryMetadata::default(),
     );
 
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 #[test]
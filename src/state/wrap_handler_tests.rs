@@ -11,6 +11,7 @@ use crate::model::{
 use crate::state::ConversationSelection;
 use crate::view_state::types::EntryIndex;
 use chrono::Utc;
+use std::sync::Arc;
 
 #[test]
 fn handle_toggle_wrap_returns_unchanged_state_when_no_focused_message() {
@@ -46,7 +47,7 @@ fn handle_toggle_wrap_adds_override_on_first_toggle() {
         message,
         EntryMetadata::default(),
     );
-    state.add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+    state.add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
 
     // Focus on Main pane and set focused message in view-state
     state.focus = FocusPane::Main;
@@ -97,7 +98,7 @@ fn handle_toggle_wrap_clears_override_on_second_toggle() {
         message,
         EntryMetadata::default(),
     );
-    state.add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+    state.add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
 
     // Focus on Main pane and set focused message in view-state
     state.focus = FocusPane::Main;
@@ -143,7 +144,7 @@ fn test_toggle_wrap_maintains_height_index_invariant() {
         {
             let message = Message::new(Role::User, MessageContent::Text("test 1".to_string()));
             let uuid = EntryUuid::new("uuid-1").unwrap();
-            ConversationEntry::Valid(Box::new(LogEntry::new(
+            ConversationEntry::Valid(Arc::new(LogEntry::new(
                 uuid,
                 None,
                 SessionId::new("session-1").unwrap(),
@@ -157,7 +158,7 @@ fn test_toggle_wrap_maintains_height_index_invariant() {
         {
             let message = Message::new(Role::User, MessageContent::Text("test 2".to_string()));
             let uuid = EntryUuid::new("uuid-2").unwrap();
-            ConversationEntry::Valid(Box::new(LogEntry::new(
+            ConversationEntry::Valid(Arc::new(LogEntry::new(
                 uuid,
                 None,
                 SessionId::new("session-1").unwrap(),
@@ -250,8 +251,8 @@ fn test_toggle_wrap_subagent_correct_indexing() {
     );
 
     state.add_entries(vec![
-        ConversationEntry::Valid(Box::new(entry_alpha)),
-        ConversationEntry::Valid(Box::new(entry_beta)),
+        ConversationEntry::Valid(Arc::new(entry_alpha)),
+        ConversationEntry::Valid(Arc::new(entry_beta)),
     ]);
 
     // Initialize view states for both subagents
@@ -11,6 +11,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent,
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Convert a ratatui buffer to a string representation for snapshot testing.
 ///
@@ -88,7 +89,7 @@ impl AcceptanceTestHarness {
         // Convert LogEntry to ConversationEntry
         let entries: Vec<crate::model::ConversationEntry> = log_entries
             .into_iter()
-            .map(|e| crate::model::ConversationEntry::Valid(Box::new(e)))
+            .map(|e| crate::model::ConversationEntry::Valid(Arc::new(e)))
             .collect();
 
         // Create app state and populate with entries
@@ -180,6 +181,15 @@ impl AcceptanceTestHarness {
         }
     }
 
+    /// Wait for a background search to finish and merge its results
+    ///
+    /// Search runs on a worker thread; call this after submitting a query
+    /// before asserting on matches.
+    #[allow(dead_code)]
+    pub fn wait_for_search(&mut self) {
+        self.app.wait_for_search_test();
+    }
+
     /// Access app state for assertions
     ///
     /// Provides read-only access to AppState for verifying state transitions.
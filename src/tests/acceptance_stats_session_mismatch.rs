@@ -32,12 +32,21 @@
 //! - Session Alpha: Main (2500) + Subagent (200) = 2700 input tokens
 //! - Session Beta: Main (11000) + Subagent (800) = 11800 input tokens
 //! - Stats SHOULD show Beta session (displayed), but show Alpha or global
+//!
+//! ## Test 3: Tool counts per session
+//!
+//! Fixture: tests/fixtures/stats_multi_session_tools.jsonl
+//! - Session Alpha: Main (Read ×2, Bash) + Subagent (Grep, Read)
+//! - Session Beta: Main (Bash) + Subagent (Grep ×3)
 
+use crate::model::{SessionId, ToolName};
 use crate::test_harness::AcceptanceTestHarness;
 use crossterm::event::KeyCode;
+use std::collections::HashMap;
 
 const STATS_FIXTURE: &str = "tests/fixtures/stats_session_mismatch_repro.jsonl";
 const MULTI_SESSION_FIXTURE: &str = "tests/fixtures/stats_multi_session_repro.jsonl";
+const MULTI_SESSION_TOOLS_FIXTURE: &str = "tests/fixtures/stats_multi_session_tools.jsonl";
 
 /// Bug reproduction: stats should change when switching tabs
 ///
@@ -218,3 +227,40 @@ fn bug_multi_session_stats_should_match_displayed_session() {
         output
     );
 }
+
+/// Tool counts are tracked per session and per session main agent.
+#[test]
+fn tool_counts_are_split_by_session_and_main_agent() {
+    let harness = AcceptanceTestHarness::from_fixture(MULTI_SESSION_TOOLS_FIXTURE)
+        .expect("Should load multi-session tools fixture");
+    let stats = harness.state().log_view().stats();
+    let alpha = SessionId::new("session-alpha-001").unwrap();
+    let beta = SessionId::new("session-beta-002").unwrap();
+    let counts = |pairs: &[(ToolName, u32)]| pairs.iter().cloned().collect::<HashMap<_, _>>();
+
+    assert_eq!(
+        stats.session_tool_counts[&alpha],
+        counts(&[
+            (ToolName::Read, 3),
+            (ToolName::Bash, 1),
+            (ToolName::Grep, 1)
+        ])
+    );
+    assert_eq!(
+        stats.main_agent_tool_counts_by_session[&alpha],
+        counts(&[(ToolName::Read, 2), (ToolName::Bash, 1)])
+    );
+    assert_eq!(
+        stats.session_tool_counts[&beta],
+        counts(&[(ToolName::Bash, 1), (ToolName::Grep, 3)])
+    );
+    assert_eq!(
+        stats.main_agent_tool_counts_by_session[&beta],
+        counts(&[(ToolName::Bash, 1)])
+    );
+    assert_eq!(
+        stats.tool_counts[&ToolName::Grep],
+        4,
+        "Global counts cover both sessions"
+    );
+}
@@ -119,7 +119,7 @@ fn us2_scenario2_switch_subagent_tabs() {
 // ===== US2 Scenario 3: Search Highlight =====
 
 #[test]
-#[ignore = "Search execution not wired up - Enter in Typing mode doesn't execute search"]
+#[ignore = "tool_calls.jsonl has 'Read' only in tool_use blocks, which are not searchable (FR-011b)"]
 fn us2_scenario3_search_highlight() {
     // GIVEN: A loaded session
     // WHEN: User searches for "error"
@@ -159,6 +159,7 @@ fn us2_scenario3_search_highlight() {
 
     // WHEN: User presses Enter to execute search
     harness.send_key(KeyCode::Enter);
+    harness.wait_for_search();
 
     // VERIFY: Search becomes active with matches
     let active_state = harness.state();
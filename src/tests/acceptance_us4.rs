@@ -250,7 +250,7 @@ fn us4_scenario4_search_activation() {
 // ===== US4 Scenario 5: Navigate Search Results =====
 
 #[test]
-#[ignore = "Search execution not wired up - Enter doesn't execute search from Typing mode"]
+#[ignore = "tool_calls.jsonl has 'Read' only in tool_use blocks, which are not searchable (FR-011b)"]
 fn us4_scenario5_navigate_search_results() {
     // GIVEN: Search results exist
     // WHEN: User presses n/N
@@ -271,6 +271,7 @@ fn us4_scenario5_navigate_search_results() {
 
     // WHEN: User presses Enter to execute search
     harness.send_key(KeyCode::Enter);
+    harness.wait_for_search();
 
     // VERIFY: Search is active with matches
     let state_after_search = harness.state();
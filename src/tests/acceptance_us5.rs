@@ -53,6 +53,7 @@ fn us5_scenario1_search_highlights() {
 
     // WHEN: User presses Ctrl+S to submit search (Enter is bound to ToggleExpand, not SubmitSearch)
     harness.send_key_with_mods(KeyCode::Char('s'), crossterm::event::KeyModifiers::CONTROL);
+    harness.wait_for_search();
 
     // VERIFY: Search is active with matches
     let state_after_search = harness.state();
@@ -125,6 +126,7 @@ fn us5_scenario2_tab_indicators() {
 
     // WHEN: User submits search with Ctrl+S
     harness.send_key_with_mods(KeyCode::Char('s'), crossterm::event::KeyModifiers::CONTROL);
+    harness.wait_for_search();
 
     // VERIFY: Search is active with matches in subagents
     let state_after_search = harness.state();
@@ -196,6 +198,7 @@ fn us5_scenario3_navigate_to_match() {
 
     // WHEN: User submits search with Ctrl+S
     harness.send_key_with_mods(KeyCode::Char('s'), crossterm::event::KeyModifiers::CONTROL);
+    harness.wait_for_search();
 
     // VERIFY: Search is active with matches
     let state_after_search = harness.state();
@@ -319,6 +322,7 @@ fn us5_scenario4_clear_search() {
 
     // WHEN: User submits search with Ctrl+S
     harness.send_key_with_mods(KeyCode::Char('s'), crossterm::event::KeyModifiers::CONTROL);
+    harness.wait_for_search();
 
     // VERIFY: Search is active
     let state_with_search = harness.state();
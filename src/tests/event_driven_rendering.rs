@@ -11,6 +11,7 @@ use crate::view::TuiApp;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
+use std::sync::Arc;
 
 /// Helper to create a test TuiApp
 fn create_test_app() -> TuiApp<TestBackend> {
@@ -37,7 +38,7 @@ fn create_test_app() -> TuiApp<TestBackend> {
         ),
         crate::model::EntryMetadata::default(),
     );
-    app_state.add_entries(vec![crate::model::ConversationEntry::Valid(Box::new(
+    app_state.add_entries(vec![crate::model::ConversationEntry::Valid(Arc::new(
         entry,
     ))]);
 
@@ -164,5 +165,5 @@ fn create_test_entry(content: &str) -> crate::model::ConversationEntry {
         EntryMetadata::default(),
     );
 
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
@@ -33,15 +33,32 @@ fn test_key_action_variants_exist() {
     let _: KeyAction = KeyAction::CancelSearch;
     let _: KeyAction = KeyAction::NextMatch;
     let _: KeyAction = KeyAction::PrevMatch;
+    let _: KeyAction = KeyAction::ToggleSavedSearches;
 
     // Stats
     let _: KeyAction = KeyAction::ToggleStats;
+    let _: KeyAction = KeyAction::ToggleChartAxis;
+    let _: KeyAction = KeyAction::NextStatsPage;
+    let _: KeyAction = KeyAction::CycleToolSort;
+    let _: KeyAction = KeyAction::ToggleFilesModal;
+    let _: KeyAction = KeyAction::ToggleSubagentTable;
     let _: KeyAction = KeyAction::FilterGlobal;
     let _: KeyAction = KeyAction::FilterMainAgent;
     let _: KeyAction = KeyAction::FilterSubagent;
 
     // Auto-scroll
     let _: KeyAction = KeyAction::ToggleAutoScroll;
+
+    // Timing
+    let _: KeyAction = KeyAction::CycleTimeGutter;
+
+    // Session header
+    let _: KeyAction = KeyAction::ToggleSessionHeader;
+
+    // Continuous sessions
+    let _: KeyAction = KeyAction::ToggleContinuousSessions;
+    let _: KeyAction = KeyAction::ToggleSessionFold;
+    let _: KeyAction = KeyAction::ToggleExportPicker;
     let _: KeyAction = KeyAction::ScrollToLatest;
 
     // Application
@@ -207,6 +224,30 @@ fn test_default_bindings_message_interaction() {
         kb.get(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE)),
         Some(KeyAction::CollapseMessage)
     );
+
+    // T cycles the time gutter
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT)),
+        Some(KeyAction::CycleTimeGutter)
+    );
+
+    // i expands/collapses the session header
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE)),
+        Some(KeyAction::ToggleSessionHeader)
+    );
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT)),
+        Some(KeyAction::ToggleContinuousSessions)
+    );
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE)),
+        Some(KeyAction::ToggleSessionFold)
+    );
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('E'), KeyModifiers::SHIFT)),
+        Some(KeyAction::ToggleExportPicker)
+    );
 }
 
 /// Test that default bindings include search controls.
@@ -235,6 +276,12 @@ fn test_default_bindings_search() {
         kb.get(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)),
         Some(KeyAction::CancelSearch)
     );
+
+    // Ctrl+r for saved search picker
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL)),
+        Some(KeyAction::ToggleSavedSearches)
+    );
 }
 
 /// Test that default bindings include stats controls.
@@ -246,6 +293,26 @@ fn test_default_bindings_stats() {
         kb.get(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE)),
         Some(KeyAction::ToggleStats)
     );
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE)),
+        Some(KeyAction::ToggleChartAxis)
+    );
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE)),
+        Some(KeyAction::NextStatsPage)
+    );
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE)),
+        Some(KeyAction::CycleToolSort)
+    );
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('F'), KeyModifiers::SHIFT)),
+        Some(KeyAction::ToggleFilesModal)
+    );
+    assert_eq!(
+        kb.get(KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT)),
+        Some(KeyAction::ToggleSubagentTable)
+    );
 }
 
 /// Test that default bindings include live mode controls.
@@ -454,14 +521,32 @@ fn test_all_actions_have_default_bindings() {
         ("CancelSearch", KeyAction::CancelSearch),
         ("NextMatch", KeyAction::NextMatch),
         ("PrevMatch", KeyAction::PrevMatch),
+        ("ToggleSavedSearches", KeyAction::ToggleSavedSearches),
         // Stats
         ("ToggleStats", KeyAction::ToggleStats),
         ("FilterGlobal", KeyAction::FilterGlobal),
         ("FilterMainAgent", KeyAction::FilterMainAgent),
         ("FilterSubagent", KeyAction::FilterSubagent),
+        ("ToggleChartAxis", KeyAction::ToggleChartAxis),
+        ("NextStatsPage", KeyAction::NextStatsPage),
+        ("CycleToolSort", KeyAction::CycleToolSort),
+        ("ToggleFilesModal", KeyAction::ToggleFilesModal),
+        ("ToggleSubagentTable", KeyAction::ToggleSubagentTable),
         // Line wrapping
         ("ToggleWrap", KeyAction::ToggleWrap),
         ("ToggleGlobalWrap", KeyAction::ToggleGlobalWrap),
+        // Timing
+        ("CycleTimeGutter", KeyAction::CycleTimeGutter),
+        // Session header
+        ("ToggleSessionHeader", KeyAction::ToggleSessionHeader),
+        // Continuous sessions
+        (
+            "ToggleContinuousSessions",
+            KeyAction::ToggleContinuousSessions,
+        ),
+        ("ToggleSessionFold", KeyAction::ToggleSessionFold),
+        // Export
+        ("ToggleExportPicker", KeyAction::ToggleExportPicker),
         // Auto-scroll
         ("ToggleAutoScroll", KeyAction::ToggleAutoScroll),
         ("ScrollToLatest", KeyAction::ScrollToLatest),
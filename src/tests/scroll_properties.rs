@@ -25,6 +25,7 @@ use crate::model::{
 use crate::state::WrapMode;
 use crate::view::{ConversationView, MessageStyles};
 use crate::view_state::conversation::ConversationViewState;
+use std::sync::Arc;
 // calculate_height is now used internally by ConversationViewState
 use crate::view_state::layout_params::LayoutParams;
 use crate::view_state::scroll::ScrollPosition;
@@ -74,7 +75,7 @@ fn arb_conversation_entry() -> impl Strategy<Value = ConversationEntry> {
                 message,
                 EntryMetadata::default(),
             );
-            ConversationEntry::Valid(Box::new(entry))
+            ConversationEntry::Valid(Arc::new(entry))
         })
 }
 
@@ -89,8 +90,13 @@ fn arb_entry_list(max_len: usize) -> impl Strategy<Value = Vec<ConversationEntry
 /// We don't predict heights - we use actual production logic.
 fn arb_conversation_view_state() -> impl Strategy<Value = ConversationViewState> {
     (arb_entry_list(20), arb_wrap_mode()).prop_map(|(entries, wrap_mode)| {
-        let mut state =
-            ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(
+            None,
+            None,
+            entries,
+            crate::model::ContextWindows::default(),
+            PricingConfig::default(),
+        );
         let params = LayoutParams::new(80, wrap_mode);
         // Use REAL production height calculator - this is still black-box testing
         state.relayout_from(EntryIndex::new(0), params);
@@ -20,6 +20,7 @@ use crate::view_state::layout_params::LayoutParams;
 use crate::view_state::types::{EntryIndex, ViewportDimensions};
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -101,9 +102,14 @@ fn test_tooluse_defaults_to_nowrap_despite_global_wrap() {
         "/some/very/long/path/to/a/file/that/exceeds/sixty/characters/in/total/length/marker.txt";
     let entry = create_test_entry_with_tooluse("tooluse-1", long_path);
 
-    let conversation = vec![ConversationEntry::Valid(Box::new(entry))];
-    let mut view_state =
-        ConversationViewState::new(None, None, conversation, 200_000, PricingConfig::default());
+    let conversation = vec![ConversationEntry::Valid(Arc::new(entry))];
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        conversation,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
 
     // Set global wrap to Wrap and use NARROW viewport (60 chars)
     view_state.relayout(60, WrapMode::Wrap, &crate::state::SearchState::Inactive);
@@ -155,9 +161,14 @@ fn test_tooluse_respects_explicit_wrap_override() {
         "/some/very/long/path/to/a/file/that/exceeds/sixty/characters/in/total/length/marker.txt";
     let entry = create_test_entry_with_tooluse("tooluse-2", long_path);
 
-    let conversation = vec![ConversationEntry::Valid(Box::new(entry))];
-    let mut view_state =
-        ConversationViewState::new(None, None, conversation, 200_000, PricingConfig::default());
+    let conversation = vec![ConversationEntry::Valid(Arc::new(entry))];
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        conversation,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
 
     // Set global wrap to Wrap
     let params = LayoutParams::new(60, WrapMode::Wrap);
@@ -209,9 +220,14 @@ fn test_toolresult_defaults_to_nowrap_despite_global_wrap() {
     let long_line = "This is a very long line of tool output that definitely exceeds sixty characters in width and should NOT wrap unless explicitly overridden.";
     let entry = create_test_entry_with_toolresult("toolresult-1", long_line);
 
-    let conversation = vec![ConversationEntry::Valid(Box::new(entry))];
-    let mut view_state =
-        ConversationViewState::new(None, None, conversation, 200_000, PricingConfig::default());
+    let conversation = vec![ConversationEntry::Valid(Arc::new(entry))];
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        conversation,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
 
     // Set global wrap to Wrap and use NARROW viewport (60 chars)
     view_state.relayout(60, WrapMode::Wrap, &crate::state::SearchState::Inactive);
@@ -264,9 +280,14 @@ fn test_toolresult_respects_explicit_wrap_override() {
     let long_line = "This is a very long line of tool output that definitely exceeds sixty characters in width and should wrap when explicitly overridden.";
     let entry = create_test_entry_with_toolresult("toolresult-2", long_line);
 
-    let conversation = vec![ConversationEntry::Valid(Box::new(entry))];
-    let mut view_state =
-        ConversationViewState::new(None, None, conversation, 200_000, PricingConfig::default());
+    let conversation = vec![ConversationEntry::Valid(Arc::new(entry))];
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        conversation,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
 
     // Set global wrap to Wrap
     let params = LayoutParams::new(60, WrapMode::Wrap);
@@ -324,9 +345,14 @@ fn test_text_block_still_respects_global_wrap() {
         EntryMetadata::default(),
     );
 
-    let conversation = vec![ConversationEntry::Valid(Box::new(entry))];
-    let mut view_state =
-        ConversationViewState::new(None, None, conversation, 200_000, PricingConfig::default());
+    let conversation = vec![ConversationEntry::Valid(Arc::new(entry))];
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        conversation,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
 
     // Set global wrap to Wrap and use NARROW viewport
     view_state.relayout(60, WrapMode::Wrap, &crate::state::SearchState::Inactive);
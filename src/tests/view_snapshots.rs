@@ -17,6 +17,7 @@ use crate::view_state::types::{EntryIndex, ViewportDimensions};
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -316,7 +317,7 @@ fn create_test_log_entry(
 fn create_test_conversation(entries: Vec<LogEntry>) -> Vec<ConversationEntry> {
     entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect()
 }
 
@@ -336,7 +337,7 @@ fn snapshot_message_collapsed_multiline() {
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     view_state.relayout(60, WrapMode::Wrap, &crate::state::SearchState::Inactive);
@@ -372,7 +373,7 @@ fn snapshot_message_expanded_multiline() {
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -420,7 +421,7 @@ That's the code."#;
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -468,7 +469,7 @@ That's the code."#;
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -525,7 +526,7 @@ fn main() {
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -625,7 +626,7 @@ fn snapshot_message_with_tool_use() {
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -675,7 +676,7 @@ Total lines: 3"#;
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -723,7 +724,7 @@ fn snapshot_message_with_thinking_block() {
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -775,7 +776,7 @@ fn bug_entry_indices_not_visible_in_rendered_output() {
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     view_state.relayout(60, WrapMode::Wrap, &crate::state::SearchState::Inactive);
@@ -831,7 +832,7 @@ fn bug_initial_screen_blank_until_keypress() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create app
@@ -890,7 +891,7 @@ fn bug_excessive_blank_lines_in_entry_rendering() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     let entry_count = entries.len();
@@ -986,7 +987,7 @@ fn bug_page_down_twice_causes_blank_viewport() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Build session
@@ -1085,7 +1086,7 @@ fn us1_page_down_to_bottom_always_shows_content() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     assert!(
@@ -1185,7 +1186,7 @@ fn us1_home_key_shows_first_entries_from_bottom() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Build session
@@ -1261,7 +1262,7 @@ fn us1_end_key_shows_last_entries_with_content() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Build session
@@ -1334,7 +1335,7 @@ fn us1_rapid_scroll_updates_within_60fps() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Build session
@@ -1428,7 +1429,7 @@ fn bug_thinking_blocks_not_wrapped_like_prose() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create app with NARROW terminal to force wrapping
@@ -1507,7 +1508,7 @@ fn bug_horizontal_scroll_does_not_work() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create app with narrow terminal (60 chars) so long line is truncated
@@ -1623,7 +1624,7 @@ fn bug_jerky_scroll_line_by_line() {
             message,
             EntryMetadata::default(),
         );
-        ConversationEntry::Valid(Box::new(entry))
+        ConversationEntry::Valid(Arc::new(entry))
     }
 
     let entries = vec![
@@ -1650,8 +1651,13 @@ fn bug_jerky_scroll_line_by_line() {
     ];
 
     // Create view state
-    let mut state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::NoWrap);
     state.relayout_from(EntryIndex::new(0), params);
 
@@ -1763,12 +1769,17 @@ fn bug_collapsed_entry_height_mismatch() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create view state with entries NOT expanded (collapsed by default)
-    let mut state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(211, WrapMode::Wrap);
     state.relayout_from(EntryIndex::new(0), params);
 
@@ -1865,7 +1876,7 @@ fn bug_scroll_stuck_with_thinking_blocks() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     assert!(
@@ -1879,7 +1890,7 @@ fn bug_scroll_stuck_with_thinking_blocks() {
         None,
         None,
         entries.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
     let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -1982,7 +1993,7 @@ fn snapshot_wrap_mode_global_wrap() {
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
 
@@ -2034,7 +2045,7 @@ fn snapshot_wrap_mode_global_nowrap() {
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
 
@@ -2089,7 +2100,7 @@ fn snapshot_wrap_mode_per_entry_override() {
         None,
         None,
         conversation.clone(),
-        200_000,
+        crate::model::ContextWindows::default(),
         PricingConfig::default(),
     );
 
@@ -2151,7 +2162,7 @@ fn bug_subagent_entries_not_in_separate_tabs() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal with standard dimensions
@@ -2226,7 +2237,7 @@ fn bug_header_shows_wrong_agent_for_tab_zero() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal with standard dimensions
@@ -2307,7 +2318,7 @@ fn bug_tab_cycling_limited_to_three_subagents() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal
@@ -2396,7 +2407,7 @@ fn bug_subagent_scroll_does_not_work() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal
@@ -2531,7 +2542,7 @@ fn bug_subagent_entry_expand_collapse_not_working() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal
@@ -2724,7 +2735,7 @@ fn bug_subagent_mouse_click_expand_not_working() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal
@@ -2844,7 +2855,7 @@ fn bug_tab_click_region_mismatch() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal (wide enough to show all tabs)
@@ -2958,7 +2969,7 @@ fn bug_tab_click_x_offset_main_vs_main_agent() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal
@@ -3067,7 +3078,7 @@ fn bug_help_popup_not_triggered_by_question_mark() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create app
@@ -3149,7 +3160,7 @@ fn bug_help_popup_scroll_passthrough() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create app with small height to force scrolling
@@ -3239,7 +3250,7 @@ fn bug_help_popup_mouse_scroll_passthrough() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create app with small height to force scrolling
@@ -3331,7 +3342,7 @@ fn bug_tab_key_should_cycle_tabs_continuously() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal
@@ -3421,7 +3432,7 @@ fn bug_number_keys_should_select_tabs() {
 
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal
@@ -3532,7 +3543,7 @@ fn bug_token_stats_divider_wrong_calculation() {
     // Convert to ConversationEntry
     let entries: Vec<ConversationEntry> = log_entries
         .into_iter()
-        .map(|e| ConversationEntry::Valid(Box::new(e)))
+        .map(|e| ConversationEntry::Valid(Arc::new(e)))
         .collect();
 
     // Create terminal wide enough to show full separator
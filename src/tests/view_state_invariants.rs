@@ -36,6 +36,7 @@ use crate::view_state::{
 };
 use chrono::Utc;
 use proptest::prelude::*;
+use std::sync::Arc;
 
 // ===== Arbitrary Strategies =====
 
@@ -81,7 +82,7 @@ fn arb_conversation_entry() -> impl Strategy<Value = ConversationEntry> {
             message,
             EntryMetadata::default(),
         );
-        ConversationEntry::Valid(Box::new(entry))
+        ConversationEntry::Valid(Arc::new(entry))
     })
 }
 
@@ -151,7 +152,7 @@ proptest! {
     fn malformed_entries_always_get_zero_height(entry in arb_malformed_entry()) {
         // Malformed entries get default height of ZERO when not laid out
         // After relayout(), they get rendered height based on content
-        let mut state = ConversationViewState::new(None, None, vec![entry], 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, vec![entry], crate::model::ContextWindows::default(), PricingConfig::default());
         state.relayout(80, WrapMode::Wrap, &crate::state::SearchState::Inactive);
 
         // After relayout, malformed entries still get a small height for the error message
@@ -174,7 +175,7 @@ proptest! {
             return Ok(());
         }
 
-        let mut state = ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, entries, crate::model::ContextWindows::default(), PricingConfig::default());
         state.relayout(80, WrapMode::Wrap, &crate::state::SearchState::Inactive);
 
         // Check monotonicity: forall i < j: entries[i].cumulative_y <= entries[j].cumulative_y
@@ -203,7 +204,7 @@ proptest! {
             return Ok(());
         }
 
-        let mut state = ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, entries, crate::model::ContextWindows::default(), PricingConfig::default());
         state.relayout(80, WrapMode::Wrap, &crate::state::SearchState::Inactive);
 
         // Check: forall i: entries[i].cumulative_y == sum(entries[0..i].height)
@@ -227,7 +228,7 @@ proptest! {
 proptest! {
     #[test]
     fn total_height_equals_sum_of_all_heights(entries in arb_entry_list(50)) {
-        let mut state = ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, entries, crate::model::ContextWindows::default(), PricingConfig::default());
         state.relayout(80, WrapMode::Wrap, &crate::state::SearchState::Inactive);
 
         let expected_total: usize = (0..state.len())
@@ -258,7 +259,7 @@ proptest! {
         scroll in arb_scroll_position(),
         viewport in arb_viewport()
     ) {
-        let mut state = ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, entries, crate::model::ContextWindows::default(), PricingConfig::default());
         state.relayout(viewport.width, WrapMode::Wrap, &crate::state::SearchState::Inactive);
 
         let total_height = state.total_height();
@@ -289,7 +290,7 @@ proptest! {
         entries in arb_entry_list(50),
         viewport in arb_viewport()
     ) {
-        let mut state = ConversationViewState::new(None, None, entries.clone(), 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, entries.clone(), crate::model::ContextWindows::default(), PricingConfig::default());
         state.relayout(viewport.width, WrapMode::Wrap, &crate::state::SearchState::Inactive);
 
         let visible = state.visible_range(viewport);
@@ -324,7 +325,7 @@ proptest! {
             return Ok(());
         }
 
-        let mut state = ConversationViewState::new(None, None, entries.clone(), 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, entries.clone(), crate::model::ContextWindows::default(), PricingConfig::default());
         state.relayout(80, WrapMode::Wrap, &crate::state::SearchState::Inactive);
 
         let result = state.hit_test(screen_y, screen_x, scroll_offset);
@@ -389,7 +390,7 @@ proptest! {
                 message,
                 EntryMetadata::default(),
             );
-            log.add_entry(ConversationEntry::Valid(Box::new(entry)), None);
+            log.add_entry(ConversationEntry::Valid(Arc::new(entry)), None);
         }
 
         // Verify session start_line monotonicity: forall i < j: sessions[i].start_line <= sessions[j].start_line
@@ -462,10 +463,10 @@ proptest! {
         );
 
         let entry_view = EntryView::new(
-            ConversationEntry::Valid(Box::new(log_entry)),
+            ConversationEntry::Valid(Arc::new(log_entry)),
             EntryIndex::new(0),
             0,
-            200_000,
+            crate::model::ContextWindows::default(),
             PricingConfig::default(),
         );
 
@@ -499,7 +500,7 @@ proptest! {
             return Ok(());
         }
 
-        let mut state = ConversationViewState::new(None, None, entries.clone(), 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, entries.clone(), crate::model::ContextWindows::default(), PricingConfig::default());
 
         // Set focus to an arbitrary index
         state.set_focused_message(Some(EntryIndex::new(focus_index)));
@@ -524,7 +525,7 @@ proptest! {
             return Ok(());
         }
 
-        let mut state = ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, entries, crate::model::ContextWindows::default(), PricingConfig::default());
         let params = LayoutParams::new(80, WrapMode::Wrap);
         let viewport = ViewportDimensions::new(80, 24);
 
@@ -566,7 +567,7 @@ proptest! {
             return Ok(());
         }
 
-        let mut state = ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+        let mut state = ConversationViewState::new(None, None, entries, crate::model::ContextWindows::default(), PricingConfig::default());
         state.relayout(80, WrapMode::Wrap, &crate::state::SearchState::Inactive);
 
         // Pick a valid relayout index and toggle to trigger relayout
@@ -19,6 +19,7 @@ use crate::view_state::{
 };
 use chrono::Utc;
 use std::time::Instant;
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -37,7 +38,7 @@ fn create_test_entry(uuid_str: &str, text: &str) -> ConversationEntry {
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(entry))
+    ConversationEntry::Valid(Arc::new(entry))
 }
 
 /// Create a test entry with long text that will be collapsible.
@@ -73,8 +74,13 @@ fn us2_scenario1_expand_collapsed_entry_remains_visible() {
         create_long_entry("entry-4"),
     ];
 
-    let mut view_state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     let viewport = ViewportDimensions::new(80, 24);
 
@@ -203,8 +209,13 @@ fn us2_scenario2_collapse_expanded_entry_smooth_shift() {
         create_long_entry("entry-3"),
     ];
 
-    let mut view_state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     let viewport = ViewportDimensions::new(80, 24);
 
@@ -309,8 +320,13 @@ fn us2_scenario3_toggle_response_under_16ms() {
         .map(|i| create_test_entry(&format!("entry-{}", i), &format!("Message {}", i)))
         .collect();
 
-    let mut view_state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     let viewport = ViewportDimensions::new(80, 24);
 
@@ -354,8 +370,13 @@ fn us2_scenario4_entries_above_viewport_toggle_visible_stable() {
         .map(|i| create_test_entry(&format!("entry-{}", i), &format!("Message {}", i)))
         .collect();
 
-    let mut view_state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     let viewport = ViewportDimensions::new(80, 24);
 
@@ -442,8 +463,13 @@ fn us2_scenario4_entries_above_viewport_toggle_visible_stable() {
 fn toggle_nonexistent_entry_returns_none() {
     let entries = vec![create_test_entry("entry-0", "Only message")];
 
-    let mut view_state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     let viewport = ViewportDimensions::new(80, 24);
 
@@ -464,8 +490,13 @@ fn toggle_nonexistent_entry_returns_none() {
 fn multiple_toggles_preserve_idempotence() {
     let entries = vec![create_test_entry("entry-0", "Test")];
 
-    let mut view_state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut view_state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     let viewport = ViewportDimensions::new(80, 24);
 
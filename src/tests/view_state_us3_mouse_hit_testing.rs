@@ -19,6 +19,7 @@ use crate::view_state::{
     types::{EntryIndex, LineOffset, ViewportDimensions},
 };
 use chrono::Utc;
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -40,7 +41,7 @@ fn make_entry(uuid: &str) -> ConversationEntry {
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(entry))
+    ConversationEntry::Valid(Arc::new(entry))
 }
 
 // ===== US3 Scenario 1: Click Specific Entry (Not Adjacent) =====
@@ -56,8 +57,13 @@ fn us3_scenario1_click_specific_entry_not_adjacent() {
         make_entry("entry-4"),
     ];
 
-    let mut state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     state.relayout_from(EntryIndex::new(0), params);
 
@@ -119,8 +125,13 @@ fn us3_scenario2_click_expand_collapse_indicator() {
         make_entry("entry-2"),
     ];
 
-    let mut state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     state.relayout_from(EntryIndex::new(0), params);
 
@@ -170,8 +181,13 @@ fn us3_scenario3_scrolled_middle_hit_test_correct() {
         .map(|i| make_entry(&format!("entry-{}", i)))
         .collect();
 
-    let mut state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     state.relayout_from(EntryIndex::new(0), params);
 
@@ -217,8 +233,13 @@ fn us3_scenario4_variable_heights_boundary_clicks() {
         .map(|i| make_entry(&format!("entry-{}", i)))
         .collect();
 
-    let mut state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     state.relayout_from(EntryIndex::new(0), params);
 
@@ -276,8 +297,13 @@ fn us3_performance_hit_test_o_log_n() {
         .map(|i| make_entry(&format!("entry-{}", i)))
         .collect();
 
-    let mut state =
-        ConversationViewState::new(None, None, entries, 200_000, PricingConfig::default());
+    let mut state = ConversationViewState::new(
+        None,
+        None,
+        entries,
+        crate::model::ContextWindows::default(),
+        PricingConfig::default(),
+    );
     let params = LayoutParams::new(80, WrapMode::Wrap);
     state.relayout_from(EntryIndex::new(0), params);
 
@@ -0,0 +1,331 @@
+//! Continuous view of every session's main conversation (FR-076, FR-077).
+//!
+//! Sessions are rendered back to back, each under a collapsible heading (see
+//! `session_separator`); folded sessions show only their heading. Scrolling
+//! uses `LogViewState::continuous_scroll`, and the viewed session (the one at
+//! the top of the viewport) is highlighted and supplies the horizontal offset.
+//! When viewing one chain of resumed sessions, only its sessions are shown.
+
+use crate::model::EntryTiming;
+use crate::state::{TimeGutter, WrapMode};
+use crate::view_state::log::LogViewState;
+use crate::view_state::session::SESSION_HEADING_HEIGHT;
+use crate::view_state::types::EntryIndex;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::Line,
+    widgets::{Paragraph, Widget},
+};
+
+use super::helpers::styled_block;
+use super::message::{add_scroll_indicators_to_title, apply_horizontal_offset, has_long_lines};
+use super::{session_separator, time_gutter};
+
+/// Continuous view widget for the main tab.
+pub(super) struct ContinuousView<'a> {
+    log_view: &'a LogViewState,
+    active: Option<usize>,
+    focused: bool,
+    global_wrap: WrapMode,
+    time_gutter: TimeGutter,
+}
+
+impl<'a> ContinuousView<'a> {
+    /// Create a continuous view of `log_view` with session `active` (0-based) highlighted.
+    pub(super) fn new(log_view: &'a LogViewState, active: Option<usize>, focused: bool) -> Self {
+        Self {
+            log_view,
+            active,
+            focused,
+            global_wrap: WrapMode::default(),
+            time_gutter: TimeGutter::Off,
+        }
+    }
+
+    /// Set the global wrap mode.
+    pub(super) fn global_wrap(mut self, wrap: WrapMode) -> Self {
+        self.global_wrap = wrap;
+        self
+    }
+
+    /// Set the time gutter mode.
+    ///
+    /// Sessions must have been laid out with `TimeGutter::width` reserved.
+    pub(super) fn time_gutter(mut self, mode: TimeGutter) -> Self {
+        self.time_gutter = mode;
+        self
+    }
+
+    /// Visible lines and their gutters, starting at line `offset`.
+    fn visible_lines(
+        &self,
+        offset: usize,
+        height: usize,
+        width: usize,
+    ) -> (Vec<Line<'static>>, Vec<Vec<ratatui::text::Span<'static>>>) {
+        let mut lines = Vec::new();
+        let mut gutters = Vec::new();
+        let gutter_on = self.time_gutter != TimeGutter::Off;
+        let end = offset + height;
+        let count = self.log_view.session_count();
+
+        let mut session_y = 0;
+        for (index, session) in self.log_view.sessions().enumerate() {
+            if session_y >= end {
+                break;
+            }
+            if !self.log_view.is_shown(index) {
+                continue;
+            }
+            let session_height = session.continuous_height();
+            if session_y + session_height <= offset {
+                session_y += session_height;
+                continue;
+            }
+
+            if session_y >= offset {
+                let active = self.active == Some(index);
+                lines.push(session_separator::session_heading(
+                    session, index, count, active, width,
+                ));
+                if gutter_on {
+                    gutters.push(time_gutter::blank_gutter(self.time_gutter));
+                }
+            }
+
+            if !session.is_folded() {
+                let main = session.main();
+                let timings: &[EntryTiming] = if gutter_on { main.timings() } else { &[] };
+                let entries_y = session_y + SESSION_HEADING_HEIGHT;
+                // First entry reaching the viewport, found through the height index
+                let first = main
+                    .height_index
+                    .lower_bound(offset.saturating_sub(entries_y))
+                    .unwrap_or(main.len());
+                let mut entry_y = entries_y
+                    + main
+                        .entry_cumulative_y(EntryIndex::new(first))
+                        .map_or(0, |y| y.get());
+                for (entry_index, entry_view) in main.iter().enumerate().skip(first) {
+                    if entry_y >= end {
+                        break;
+                    }
+                    let entry_lines = entry_view.rendered_lines();
+                    if entry_y + entry_lines.len() > offset {
+                        let skip = offset.saturating_sub(entry_y);
+                        let take = end - entry_y.max(offset);
+                        let shown = skip..entry_lines.len().min(skip + take);
+                        if let Some(timing) = timings.get(entry_index) {
+                            gutters.extend(shown.clone().map(|line_in_entry| {
+                                time_gutter::gutter_spans(self.time_gutter, timing, line_in_entry)
+                            }));
+                        }
+                        lines.extend(entry_lines[shown].iter().cloned());
+                    }
+                    entry_y += entry_lines.len();
+                }
+            }
+            session_y += session_height;
+        }
+        (lines, gutters)
+    }
+}
+
+impl Widget for ContinuousView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let viewport_height = area.height.saturating_sub(2) as usize;
+        let viewport_width = area.width.saturating_sub(2);
+        let content_width = viewport_width.saturating_sub(self.time_gutter.width());
+
+        let count = self.log_view.session_count();
+        let max_offset = self
+            .log_view
+            .continuous_height()
+            .saturating_sub(viewport_height);
+        let offset = self.log_view.continuous_scroll().min(max_offset);
+
+        let active_session = self.active.and_then(|i| self.log_view.get_session(i));
+        let model_info = active_session
+            .and_then(|s| s.main().model())
+            .map(|m| format!(" [{}]", m.display_name()))
+            .unwrap_or_default();
+        let scope = match self.log_view.chain() {
+            Some(chain) => format!("Chain ({} sessions)", chain.len()),
+            None => format!("All sessions ({})", count),
+        };
+        let base_title = match self.active {
+            Some(index) => format!("{} · Session {}{}", scope, index + 1, model_info),
+            None => scope,
+        };
+
+        let (lines, gutters) = if count == 0 {
+            (vec![Line::from("No messages yet...")], Vec::new())
+        } else {
+            self.visible_lines(offset, viewport_height, content_width as usize)
+        };
+
+        // Horizontal scrolling follows the viewed session's main conversation
+        let horizontal_offset = active_session.map_or(0, |s| s.main().horizontal_offset());
+        let has_long_lines_flag = has_long_lines(&lines, content_width as usize);
+        let lines: Vec<Line<'static>> =
+            if self.global_wrap == WrapMode::NoWrap && horizontal_offset > 0 {
+                lines
+                    .into_iter()
+                    .map(|line| apply_horizontal_offset(line, horizontal_offset as usize))
+                    .collect()
+            } else {
+                lines
+            };
+
+        // Prepend the time gutter after scrolling so it stays in place
+        let lines: Vec<Line<'static>> = if gutters.is_empty() {
+            lines
+        } else {
+            lines
+                .into_iter()
+                .zip(gutters)
+                .map(|(line, mut gutter)| {
+                    let style = line.style;
+                    gutter.extend(line.spans);
+                    Line::from(gutter).style(style)
+                })
+                .collect()
+        };
+
+        let title =
+            add_scroll_indicators_to_title(base_title, horizontal_offset > 0, has_long_lines_flag);
+        let block = styled_block(&title, self.focused);
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry, Message, MessageContent,
+        Role, SessionId,
+    };
+    use chrono::{TimeZone, Utc};
+    use std::sync::Arc;
+
+    fn entry(session: &str, uuid: &str, text: &str) -> ConversationEntry {
+        ConversationEntry::Valid(Arc::new(LogEntry::new(
+            EntryUuid::new(uuid).unwrap(),
+            None,
+            SessionId::new(session).unwrap(),
+            None,
+            Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+            EntryType::User,
+            Message::new(Role::User, MessageContent::Text(text.to_string())),
+            EntryMetadata::default(),
+        )))
+    }
+
+    fn log_view() -> LogViewState {
+        let mut log = LogViewState::new();
+        log.add_entry(entry("s1", "u1", "first session prompt"), None);
+        log.add_entry(entry("s2", "u2", "second session prompt"), None);
+        log.set_viewport_all(60, WrapMode::Wrap);
+        log.layout_continuous();
+        log
+    }
+
+    fn render(log: &LogViewState, active: Option<usize>, height: u16) -> String {
+        let area = Rect::new(0, 0, 60, height);
+        let mut buf = Buffer::empty(area);
+        ContinuousView::new(log, active, true).render(area, &mut buf);
+        (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn renders_sessions_back_to_back_under_headings() {
+        let rendered = render(&log_view(), Some(1), 12);
+
+        assert!(rendered.contains("All sessions (2) · Session 2"));
+        let first = rendered.find("▾ Session 1/2").expect("first heading");
+        let first_prompt = rendered.find("first session prompt").expect("first prompt");
+        let second = rendered.find("▾ Session 2/2").expect("second heading");
+        let second_prompt = rendered
+            .rfind("second session prompt")
+            .expect("second prompt");
+        assert!(first < first_prompt && first_prompt < second && second < second_prompt);
+    }
+
+    #[test]
+    fn folded_session_shows_only_heading() {
+        let mut log = log_view();
+        log.get_session_mut(0).unwrap().set_folded(true);
+        log.layout_continuous();
+
+        let rendered = render(&log, Some(0), 12);
+
+        assert!(rendered.contains("▸ Session 1/2 · 2025-01-15 14:00 · 1 entries · first"));
+        assert!(
+            !rendered.contains("first session prompt"),
+            "entries hidden; the heading truncates the prompt"
+        );
+        assert!(rendered.contains("▾ Session 2/2"));
+    }
+
+    #[test]
+    fn chain_view_shows_only_chain_sessions() {
+        let mut log = log_view();
+        log.set_chain(Some(vec![1]));
+        log.layout_continuous();
+
+        let rendered = render(&log, Some(1), 12);
+
+        assert!(rendered.contains("Chain (1 sessions) · Session 2"));
+        assert!(!rendered.contains("Session 1/2"));
+        assert!(rendered.contains("▾ Session 2/2"));
+    }
+
+    #[test]
+    fn scrolling_past_a_session_hides_it() {
+        let mut log = log_view();
+        let second_start = log.get_session(1).unwrap().start_line();
+        log.set_continuous_scroll(second_start);
+
+        let rendered = render(&log, Some(1), 5);
+
+        assert!(!rendered.contains("Session 1/2"));
+        assert!(rendered.contains("▾ Session 2/2"));
+    }
+
+    #[test]
+    fn scrolling_into_a_session_starts_at_the_entry_there() {
+        let mut log = LogViewState::new();
+        for (uuid, text) in [
+            ("u1", "entry one"),
+            ("u2", "entry two"),
+            ("u3", "entry three"),
+        ] {
+            log.add_entry(entry("s1", uuid, text), None);
+        }
+        log.set_viewport_all(60, WrapMode::Wrap);
+        log.layout_continuous();
+        let third_y = log
+            .get_session(0)
+            .unwrap()
+            .main()
+            .entry_cumulative_y(EntryIndex::new(2))
+            .unwrap()
+            .get();
+        log.set_continuous_scroll(SESSION_HEADING_HEIGHT + third_y);
+
+        let rendered = render(&log, Some(0), 4);
+
+        assert!(!rendered.contains("Session 1/1"));
+        assert!(!rendered.contains("entry two"));
+        assert!(rendered.contains("entry three"));
+    }
+}
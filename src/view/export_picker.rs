@@ -0,0 +1,152 @@
+//! Export picker rendering.
+
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::export::ExportScope;
+use crate::state::AppState;
+
+/// Width of the picker in columns.
+const PICKER_WIDTH: u16 = 60;
+
+/// Render the export picker overlay.
+///
+/// Lists the export scopes with the selected row highlighted. The footer
+/// shows the outcome of the last export, or keybinding hints before one.
+///
+/// Only renders when `state.export_picker.is_visible()` is true.
+pub fn render_export_picker(frame: &mut Frame, state: &AppState) {
+    if !state.export_picker.is_visible() {
+        return;
+    }
+
+    let area = frame.area();
+    let picker_area = centered_rect(PICKER_WIDTH, ExportScope::ALL.len(), area);
+
+    // Clear the background for overlay effect
+    frame.render_widget(Clear, picker_area);
+
+    let items: Vec<ListItem> = ExportScope::ALL
+        .iter()
+        .map(|scope| ListItem::new(Line::from(format!(" {}", scope.label()))))
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(
+                    Line::from(vec![Span::styled(
+                        " Export to Markdown ",
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    )])
+                    .alignment(Alignment::Center),
+                )
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .border_style(Style::default().fg(Color::White))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Cyan)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut list_state =
+        ListState::default().with_selected(Some(state.export_picker.selected_index()));
+    frame.render_stateful_widget(list, picker_area, &mut list_state);
+
+    // Footer with the last outcome or keybinding hints
+    let footer_area = Rect {
+        x: picker_area.x + 1,
+        y: picker_area.y + picker_area.height.saturating_sub(2),
+        width: picker_area.width.saturating_sub(2),
+        height: 1,
+    };
+    let footer = match state.export_picker.status() {
+        Some(status) => Paragraph::new(status.to_string()).style(Style::default().fg(Color::White)),
+        None => Paragraph::new("↑/↓: Navigate  Enter: Export  Esc: Close")
+            .style(Style::default().fg(Color::Gray).add_modifier(Modifier::DIM)),
+    };
+    frame.render_widget(footer.alignment(Alignment::Center), footer_area);
+}
+
+/// Calculate centered rect with fixed width.
+///
+/// Height is `row_count + 4` (borders, spacer, footer), capped to the terminal.
+fn centered_rect(width_cols: u16, row_count: usize, area: Rect) -> Rect {
+    let popup_width = width_cols.min(area.width);
+    let popup_height = (row_count as u16 + 4).min(area.height.saturating_sub(4));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    Rect {
+        x: area.x + popup_x,
+        y: area.y + popup_y,
+        width: popup_width,
+        height: popup_height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn render_to_string(state: &AppState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal
+            .draw(|frame| render_export_picker(frame, state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn picker_not_rendered_when_closed() {
+        let state = AppState::new();
+
+        let output = render_to_string(&state);
+
+        assert!(!output.contains("Export to Markdown"));
+    }
+
+    #[test]
+    fn picker_lists_scopes_and_hints() {
+        let mut state = AppState::new();
+        state.export_picker.open();
+
+        let output = render_to_string(&state);
+
+        assert!(output.contains("Export to Markdown"));
+        assert!(output.contains(" Session"));
+        assert!(output.contains(" Tab"));
+        assert!(output.contains(" Focused entry"));
+        assert!(output.contains("Enter: Export"));
+    }
+
+    #[test]
+    fn footer_shows_last_outcome() {
+        let mut state = AppState::new();
+        state.export_picker.open();
+        state
+            .export_picker
+            .set_status("Wrote cclv-5788beec-session.md");
+
+        let output = render_to_string(&state);
+
+        assert!(output.contains("Wrote cclv-5788beec-session.md"));
+        assert!(!output.contains("Enter: Export"));
+    }
+}
@@ -0,0 +1,489 @@
+//! Files-touched modal rendering.
+
+use chrono::{DateTime, Utc};
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::model::{AgentId, ChangeStatus, FileActivity, FileHistory};
+use crate::state::{AppState, FilesModalPage, FilesModalState};
+
+/// Maximum width of the modal in columns.
+const MODAL_WIDTH: u16 = 110;
+
+/// Width of the agents column.
+const AGENTS_WIDTH: usize = 24;
+
+/// Render the files-touched modal overlay.
+///
+/// The file list shows per path: read/edit/write counts, the agents that
+/// touched it and the first/last touch times. With a file opened, the list
+/// shows each call that touched it, or its reconstructed change history.
+///
+/// Only renders when `state.files_modal.is_visible()` is true.
+pub fn render_files_modal(frame: &mut Frame, state: &AppState) {
+    if !state.files_modal.is_visible() {
+        return;
+    }
+
+    let modal = &state.files_modal;
+    let files = modal.files();
+    let page = modal.page();
+    let open_file = files
+        .get(modal.selected_file())
+        .filter(|_| page != FilesModalPage::Files);
+
+    let area = frame.area();
+    let popup_width = MODAL_WIDTH.min(area.width.saturating_sub(4));
+    let popup_height = area.height.saturating_sub(4);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+    frame.render_widget(Clear, popup_area);
+
+    let title = match (page, open_file) {
+        (FilesModalPage::History, Some(file)) => format!(" History: {} ", file.path),
+        (_, Some(file)) => format!(" Files: {} ", file.path),
+        (_, None) => format!(" Files ({}) ", state.stats_filter.short_label()),
+    };
+    let block = Block::default()
+        .title(
+            Line::from(Span::styled(
+                title,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
+        )
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(Color::White))
+        .style(Style::default().bg(Color::DarkGray));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    if page == FilesModalPage::History {
+        if let Some(history) = modal.history() {
+            render_history(frame, inner, history, modal);
+            return;
+        }
+    }
+
+    let [header_area, list_area, footer_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let (header, items, selected, footer) = match open_file {
+        Some(file) => (
+            call_row("Time", "Access", "Tool", "Agent"),
+            file.touches
+                .iter()
+                .map(|touch| {
+                    ListItem::new(call_row(
+                        &format_time(Some(touch.timestamp)),
+                        touch.access.label(),
+                        touch.tool.as_str(),
+                        &agent_label(touch.agent_id.as_ref()),
+                    ))
+                })
+                .collect::<Vec<_>>(),
+            modal.selected_call(),
+            "↑/↓: Navigate  Enter: Jump to call  Esc: Back",
+        ),
+        None => {
+            let path_width = (inner.width as usize).saturating_sub(FIXED_COLUMNS_WIDTH);
+            (
+                file_row(
+                    path_width,
+                    ["Path", "Read", "Edit", "Write", "Agents", "First", "Last"],
+                ),
+                files
+                    .iter()
+                    .map(|file| ListItem::new(file_activity_row(file, path_width)))
+                    .collect::<Vec<_>>(),
+                modal.selected_file(),
+                "↑/↓: Navigate  Enter: Show calls  d: Change history  Esc: Close",
+            )
+        }
+    };
+
+    frame.render_widget(
+        Paragraph::new(header).style(super::styles::SECTION_HEADER),
+        header_area,
+    );
+
+    if items.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No file tool calls in scope").style(super::styles::MUTED_TEXT),
+            list_area,
+        );
+    } else {
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(Color::Cyan)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut list_state = ListState::default().with_selected(Some(selected));
+        frame.render_stateful_widget(list, list_area, &mut list_state);
+    }
+
+    frame.render_widget(
+        Paragraph::new(footer)
+            .style(Style::default().fg(Color::Gray).add_modifier(Modifier::DIM))
+            .alignment(Alignment::Center),
+        footer_area,
+    );
+}
+
+/// Width of the history step list.
+const STEP_LIST_WIDTH: u16 = 44;
+
+/// Render the change history page: step list on the left, diff on the right.
+///
+/// Step 0 is the cumulative diff from the first touch to the end of the log.
+fn render_history(frame: &mut Frame, area: Rect, history: &FileHistory, modal: &FilesModalState) {
+    let [body_area, footer_area] =
+        Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+    let [steps_area, diff_area] =
+        Layout::horizontal([Constraint::Length(STEP_LIST_WIDTH), Constraint::Fill(1)])
+            .areas(body_area);
+
+    let mut items = vec![ListItem::new(format!(
+        " Cumulative ({} changes)",
+        history.changes.len()
+    ))];
+    items.extend(history.changes.iter().map(|change| {
+        let mut spans = vec![Span::raw(format!(
+            " {:<10}{:<9}{}",
+            format_time(Some(change.timestamp)),
+            change.kind.label(),
+            agent_label(change.agent_id.as_ref()),
+        ))];
+        if change.status.is_flagged() {
+            spans.push(Span::styled(" ⚠", FLAG_STYLE));
+        }
+        ListItem::new(Line::from(spans))
+    }));
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::RIGHT))
+        .highlight_style(
+            Style::default()
+                .bg(Color::Cyan)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        );
+    let mut list_state = ListState::default().with_selected(Some(modal.selected_step()));
+    frame.render_stateful_widget(list, steps_area, &mut list_state);
+
+    let lines = match modal.selected_step().checked_sub(1) {
+        None => match history.cumulative_diff() {
+            Some(diff) if diff.is_empty() => vec![muted("No net change")],
+            Some(diff) => diff_lines(&diff),
+            None if history.changes.is_empty() => vec![muted("No Write/Edit calls for this file")],
+            None => vec![flag_line(
+                "Cumulative diff unavailable: content at the first touch or at the end is unknown",
+            )],
+        },
+        Some(step) => match history.changes.get(step) {
+            Some(change) => {
+                let mut lines = Vec::new();
+                match change.status {
+                    ChangeStatus::Applied => {}
+                    ChangeStatus::BaseUnknown => lines.push(flag_line(
+                        "⚠ base content unknown: showing the replaced strings only",
+                    )),
+                    status => {
+                        lines.push(flag_line(&format!("⚠ {}: file unchanged", status.label())))
+                    }
+                }
+                lines.extend(diff_lines(&change.diff()));
+                lines
+            }
+            None => Vec::new(),
+        },
+    };
+    frame.render_widget(
+        Paragraph::new(lines).scroll((modal.diff_scroll(), 0)),
+        diff_area.inner(Margin::new(1, 0)),
+    );
+
+    frame.render_widget(
+        Paragraph::new("↑/↓: Step  Ctrl+d/u: Scroll diff  Enter: Jump to call  Esc: Back")
+            .style(Style::default().fg(Color::Gray).add_modifier(Modifier::DIM))
+            .alignment(Alignment::Center),
+        footer_area,
+    );
+}
+
+/// Style for flagged history steps.
+const FLAG_STYLE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+
+/// Color unified diff lines: additions green, removals red, hunk headers cyan.
+fn diff_lines(diff: &str) -> Vec<Line<'static>> {
+    diff.lines()
+        .map(|line| {
+            let style = if line.starts_with("@@") {
+                Style::default().fg(Color::Cyan)
+            } else if line.starts_with('+') {
+                Style::default().fg(Color::Green)
+            } else if line.starts_with('-') {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default()
+            };
+            Line::styled(line.to_string(), style)
+        })
+        .collect()
+}
+
+fn flag_line(text: &str) -> Line<'static> {
+    Line::styled(text.to_string(), FLAG_STYLE)
+}
+
+fn muted(text: &str) -> Line<'static> {
+    Line::styled(text.to_string(), super::styles::MUTED_TEXT)
+}
+
+/// Columns other than the path: leading space, counts, agents, two times and gaps.
+const FIXED_COLUMNS_WIDTH: usize = 1 + 3 * 7 + (AGENTS_WIDTH + 2) + 2 * 10;
+
+/// Format a file list row: path, reads, edits, writes, agents, first, last.
+///
+/// The path is left-aligned, counts and times right-aligned.
+fn file_row(path_width: usize, cells: [&str; 7]) -> String {
+    let [path, reads, edits, writes, agents, first, last] = cells;
+    format!(
+        " {:<path_width$}{:>7}{:>7}{:>7}  {:<AGENTS_WIDTH$}{:>10}{:>10}",
+        truncate_start(path, path_width),
+        reads,
+        edits,
+        writes,
+        truncate_end(agents, AGENTS_WIDTH),
+        first,
+        last,
+    )
+}
+
+/// Format the file list row for one file.
+fn file_activity_row(file: &FileActivity, path_width: usize) -> String {
+    let agents: Vec<String> = file.agents().into_iter().map(agent_label).collect();
+    file_row(
+        path_width,
+        [
+            &file.path,
+            &file.reads().to_string(),
+            &file.edits().to_string(),
+            &file.writes().to_string(),
+            &agents.join(", "),
+            &format_time(file.first_seen()),
+            &format_time(file.last_seen()),
+        ],
+    )
+}
+
+/// Format a call list row.
+fn call_row(time: &str, access: &str, tool: &str, agent: &str) -> String {
+    format!(" {:<10}{:<8}{:<14}{}", time, access, tool, agent)
+}
+
+/// `main` for the main agent, the agent id otherwise.
+fn agent_label(agent_id: Option<&AgentId>) -> String {
+    agent_id.map_or_else(|| "main".to_string(), |id| id.as_str().to_string())
+}
+
+/// Format a timestamp as `HH:MM:SS` (`-` if unknown).
+fn format_time(timestamp: Option<DateTime<Utc>>) -> String {
+    timestamp.map_or_else(|| "-".to_string(), |t| t.format("%H:%M:%S").to_string())
+}
+
+/// Keep the end of `text` (the file name), eliding the start with `…`.
+fn truncate_start(text: &str, width: usize) -> String {
+    let len = text.chars().count();
+    if len <= width {
+        return text.to_string();
+    }
+    let tail: String = text.chars().skip(len + 1 - width).collect();
+    format!("…{}", tail)
+}
+
+/// Keep the start of `text`, eliding the end with `…`.
+fn truncate_end(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let head: String = text.chars().take(width.saturating_sub(1)).collect();
+    format!("{}…", head)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        ContentBlock, ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry, Message,
+        MessageContent, Role, SessionId, ToolCall, ToolName, ToolUseId,
+    };
+    use chrono::TimeZone;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use std::sync::Arc;
+
+    /// Render as the event loop does, building the modal lists first.
+    fn render_to_string(state: &mut AppState) -> String {
+        crate::state::refresh_files_modal(state);
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|frame| render_files_modal(frame, state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn file_entry(
+        uuid: &str,
+        agent: Option<&str>,
+        secs: u32,
+        tool: &str,
+        path: &str,
+    ) -> ConversationEntry {
+        ConversationEntry::Valid(Arc::new(LogEntry::new(
+            EntryUuid::new(uuid).unwrap(),
+            None,
+            SessionId::new("s1").unwrap(),
+            agent.map(|a| AgentId::new(a).unwrap()),
+            Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, secs).unwrap(),
+            EntryType::Assistant,
+            Message::new(
+                Role::Assistant,
+                MessageContent::Blocks(vec![ContentBlock::ToolUse(ToolCall::new(
+                    ToolUseId::new(format!("t-{}", uuid)).unwrap(),
+                    ToolName::parse(tool),
+                    serde_json::json!({ "file_path": path }),
+                ))]),
+            ),
+            EntryMetadata::default(),
+        )))
+    }
+
+    fn state_with_files() -> AppState {
+        let mut state = AppState::new();
+        state.add_entries(vec![
+            file_entry("e1", None, 5, "Read", "/src/lib.rs"),
+            file_entry("e2", Some("agent-1"), 30, "Edit", "/src/lib.rs"),
+        ]);
+        state.stats_filter = crate::model::StatsFilter::AllSessionsCombined;
+        state
+    }
+
+    #[test]
+    fn modal_not_rendered_when_closed() {
+        let output = render_to_string(&mut state_with_files());
+
+        assert!(!output.contains("Files"));
+    }
+
+    #[test]
+    fn file_list_shows_counts_agents_and_times() {
+        let mut state = state_with_files();
+        state.files_modal.open();
+
+        let output = render_to_string(&mut state);
+
+        assert!(output.contains("Files (All)"), "got:\n{}", output);
+        let row = output
+            .lines()
+            .find(|line| line.contains("/src/lib.rs"))
+            .expect("file row");
+        assert!(row.contains("main, agent-1"), "got: {}", row);
+        assert!(row.contains("14:00:05"), "got: {}", row);
+        assert!(row.contains("14:00:30"), "got: {}", row);
+    }
+
+    #[test]
+    fn call_list_shows_each_touch() {
+        let mut state = state_with_files();
+        state.files_modal.open();
+        state.files_modal.show_calls();
+
+        let output = render_to_string(&mut state);
+
+        assert!(output.contains("Files: /src/lib.rs"), "got:\n{}", output);
+        assert!(
+            output.contains("14:00:05  read    Read"),
+            "got:\n{}",
+            output
+        );
+        assert!(
+            output.contains("14:00:30  edit    Edit          agent-1"),
+            "got:\n{}",
+            output
+        );
+    }
+
+    #[test]
+    fn history_page_flags_edit_with_unknown_base() {
+        let mut state = state_with_files();
+        state.files_modal.open();
+        state.files_modal.show_history();
+
+        let cumulative = render_to_string(&mut state);
+        state.files_modal.select_next(2);
+        let step = render_to_string(&mut state);
+
+        assert!(
+            cumulative.contains("History: /src/lib.rs"),
+            "got:\n{}",
+            cumulative
+        );
+        assert!(
+            cumulative.contains("Cumulative (1 changes)"),
+            "got:\n{}",
+            cumulative
+        );
+        assert!(
+            cumulative.contains("Cumulative diff unavailable"),
+            "got:\n{}",
+            cumulative
+        );
+        assert!(step.contains("edit     agent-1 ⚠"), "got:\n{}", step);
+        assert!(step.contains("base content unknown"), "got:\n{}", step);
+    }
+
+    #[test]
+    fn diff_lines_color_by_prefix() {
+        let lines = diff_lines("@@ -1 +1 @@\n-old\n+new\n same");
+
+        assert_eq!(lines[0].style.fg, Some(Color::Cyan));
+        assert_eq!(lines[1].style.fg, Some(Color::Red));
+        assert_eq!(lines[2].style.fg, Some(Color::Green));
+        assert_eq!(lines[3].style.fg, None);
+    }
+
+    #[test]
+    fn modal_shows_placeholder_without_file_calls() {
+        let mut state = AppState::new();
+        state.files_modal.open();
+
+        let output = render_to_string(&mut state);
+
+        assert!(output.contains("No file tool calls in scope"));
+    }
+
+    #[test]
+    fn truncate_start_keeps_file_name() {
+        assert_eq!(truncate_start("/a/b/c.rs", 20), "/a/b/c.rs");
+        assert_eq!(truncate_start("/long/path/file.rs", 8), "…file.rs");
+    }
+}
@@ -180,6 +180,22 @@ fn build_help_content() -> Vec<Line<'static>> {
             Span::styled("  c           ", key_style),
             Span::styled("Collapse all messages", desc_style),
         ]),
+        Line::from(vec![
+            Span::styled("  T           ", key_style),
+            Span::styled("Time gutter: off / absolute / relative", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  i           ", key_style),
+            Span::styled("Expand/collapse session header", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  C           ", key_style),
+            Span::styled("All sessions back to back / one at a time", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  z           ", key_style),
+            Span::styled("Fold/unfold session (all-sessions view)", desc_style),
+        ]),
         empty_line(),
         // Search (cli.md lines 160-168)
         Line::from(vec![Span::styled("Search", category_style)]),
@@ -191,6 +207,14 @@ fn build_help_content() -> Vec<Line<'static>> {
             Span::styled("  Ctrl+s      ", key_style),
             Span::styled("Submit search", desc_style),
         ]),
+        Line::from(vec![
+            Span::styled("  ↑/↓         ", key_style),
+            Span::styled("Recall search history", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  Ctrl+r      ", key_style),
+            Span::styled("Saved searches", desc_style),
+        ]),
         Line::from(vec![
             Span::styled("  Esc         ", key_style),
             Span::styled("Cancel search", desc_style),
@@ -222,6 +246,26 @@ fn build_help_content() -> Vec<Line<'static>> {
             Span::styled("  S           ", key_style),
             Span::styled("Filter: Current subagent", desc_style),
         ]),
+        Line::from(vec![
+            Span::styled("  t           ", key_style),
+            Span::styled("Charts: turn / time axis", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  v           ", key_style),
+            Span::styled("Stats page: overview / tools", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  o           ", key_style),
+            Span::styled("Tool table: next sort column", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  F           ", key_style),
+            Span::styled("Files touched (Enter: calls, d: history)", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  A           ", key_style),
+            Span::styled("Subagent table (s/r: sort, Enter: open tab)", desc_style),
+        ]),
         empty_line(),
         // Live Mode
         Line::from(vec![Span::styled("Live Mode", category_style)]),
@@ -232,6 +276,10 @@ fn build_help_content() -> Vec<Line<'static>> {
         empty_line(),
         // Application (cli.md lines 186-192)
         Line::from(vec![Span::styled("Application", category_style)]),
+        Line::from(vec![
+            Span::styled("  E           ", key_style),
+            Span::styled("Export session/tab/focused entry to Markdown", desc_style),
+        ]),
         Line::from(vec![
             Span::styled("  q/Ctrl+c    ", key_style),
             Span::styled("Quit", desc_style),
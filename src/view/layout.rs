@@ -3,13 +3,14 @@
 //! Pure layout logic for the unified tab model (FR-083-088). Single conversation pane
 //! with tab bar for switching between main agent and subagent conversations.
 
-use crate::model::{AgentId, PricingConfig};
+use crate::model::AgentId;
 use crate::state::{AppState, FocusPane, SearchState, WrapMode, agent_ids_with_matches};
 use crate::view::{
     MessageStyles, SearchInput,
     constants::{SEARCH_INPUT_HEIGHT, STATS_PANEL_HEIGHT, STATUS_BAR_HEIGHT, TAB_BAR_HEIGHT},
+    continuous::ContinuousView,
     help::render_help_overlay,
-    message,
+    message, session_header,
     stats::StatsPanel,
     tabs,
 };
@@ -207,47 +208,80 @@ pub fn render_layout(frame: &mut Frame, state: &AppState) {
     if state.session_modal.is_visible() {
         crate::view::render_session_modal(frame, state);
     }
+
+    // Render files modal overlay if visible
+    if state.files_modal.is_visible() {
+        crate::view::render_files_modal(frame, state);
+    }
+
+    // Render subagent table overlay if visible
+    if state.subagent_table.is_visible() {
+        crate::view::render_subagent_table(frame, state);
+    }
+
+    // Render saved search picker overlay if visible
+    if state.saved_search_picker.is_visible() {
+        crate::view::render_saved_search_picker(frame, state);
+    }
+
+    // Render export picker overlay if visible
+    if state.export_picker.is_visible() {
+        crate::view::render_export_picker(frame, state);
+    }
 }
 
 /// Render unified conversation pane with tab bar and selected conversation.
 ///
 /// FR-083-088: Unified tab model - single pane, no horizontal split.
-/// Layout: Tab bar (top 3 lines) + selected conversation content (remainder).
+/// Layout: Tab bar (top 3 lines) + session header + selected conversation
+/// content (remainder). The header is omitted when nothing is known about the
+/// session and never takes more than half of the pane.
 ///
 /// Tab 0 = Main Agent, Tabs 1..N = Subagents (in spawn order).
-/// Tab bar always visible, even when only main agent exists.
+/// Tab bar always visible, even when only main agent exists. In continuous
+/// mode the main tab shows every session; tabs and header follow the viewed
+/// session.
 fn render_conversation_pane(
     frame: &mut Frame,
     area: Rect,
     state: &AppState,
     styles: &MessageStyles,
 ) {
-    // Split area vertically: tab bar + conversation content
+    let session_count = state.log_view().session_count();
+    let viewed_session_idx = state.viewed_session.effective_index(session_count);
+    let viewed_session = viewed_session_idx.and_then(|idx| state.log_view().get_session(idx.get()));
+
+    let header_lines = viewed_session
+        .map(|session| {
+            session_header::header_lines(session.info(), state.session_header_expanded, area.width)
+        })
+        .unwrap_or_default();
+    let header_height = (header_lines.len() as u16).min(area.height / 2);
+
+    // Split area vertically: tab bar + session header + conversation content
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(TAB_BAR_HEIGHT), // Tab bar
+            Constraint::Length(header_height),  // Session header
             Constraint::Min(0),                 // Conversation content
         ])
         .split(area);
 
     let tab_area = chunks[0];
-    let content_area = chunks[1];
+    let header_area = chunks[1];
+    let content_area = chunks[2];
+
+    if header_height > 0 {
+        frame.render_widget(Paragraph::new(header_lines), header_area);
+    }
 
     // Build tab list: Main Agent (tab 0) + Subagents (tabs 1..N)
     // FR-011: Get subagents from viewed session, not current session
     // Sort subagent IDs for deterministic tab ordering (HashMap iteration is non-deterministic)
-    let session_count = state.log_view().session_count();
-    let viewed_session_idx = state.viewed_session.effective_index(session_count);
-    let mut subagent_ids: Vec<_> = if let Some(idx) = viewed_session_idx {
-        state
-            .log_view()
-            .get_session(idx.get())
-            .map(|s| s.subagent_ids().collect())
-            .unwrap_or_default()
-    } else {
-        Vec::new()
-    };
+    let mut subagent_ids: Vec<_> = viewed_session
+        .map(|s| s.subagent_ids().collect())
+        .unwrap_or_default();
     subagent_ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
 
     // FR-086: Build ConversationTab list with Main Agent at position 0
@@ -277,7 +311,17 @@ fn render_conversation_pane(
     let selected_tab_index = state.selected_tab_index().unwrap_or(0);
     let is_main_tab = selected_tab_index == 0;
 
-    if let Some(view_state) = state.selected_conversation_view() {
+    // FR-076, FR-077: continuous mode shows every session's main conversation
+    if state.continuous_sessions && is_main_tab {
+        let widget = ContinuousView::new(
+            state.log_view(),
+            viewed_session_idx.map(|idx| idx.get()),
+            state.focus == FocusPane::Main,
+        )
+        .global_wrap(state.global_wrap)
+        .time_gutter(state.time_gutter);
+        frame.render_widget(widget, content_area);
+    } else if let Some(view_state) = state.selected_conversation_view() {
         let conversation_widget = message::ConversationView::new(
             view_state,
             styles,
@@ -289,7 +333,7 @@ fn render_conversation_pane(
         )
         .is_subagent_view(!is_main_tab)
         .global_wrap(state.global_wrap)
-        .max_context_tokens(state.max_context_tokens)
+        .time_gutter(state.time_gutter)
         .pricing(state.pricing.clone());
         frame.render_widget(conversation_widget, content_area);
     }
@@ -328,55 +372,36 @@ fn render_stats_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     // Build session statistics by iterating through entries
     // Uses SessionViewState which contains all entries including pending subagents
     // TODO: This should be cached in SessionViewState once stats are integrated
-    let stats = build_session_stats(session_view);
+    let chain = state
+        .log_view()
+        .chain()
+        .filter(|_| state.continuous_sessions)
+        .map(<[usize]>::len);
+    let stats = match chain {
+        Some(_) => state.log_view().chain_stats().unwrap_or_default(),
+        None => session_view.stats(),
+    };
 
-    // Get model ID for pricing calculation
-    // TODO: Model ID should be in SessionViewState metadata
+    // Fallback model for usage without a reported model; everything else
+    // is priced per model from `SessionStats::model_usage`
     let model_id = session_view.main().model_id();
 
-    // Use default pricing configuration
-    let pricing = PricingConfig::default();
-
     // Create stats panel widget - it handles focus styling internally
     let panel = StatsPanel::new(
         &stats,
         &state.stats_filter,
-        &pricing,
+        &state.pricing,
         model_id,
         state.focus == FocusPane::Stats,
-    );
+    )
+    .with_chart_axis(state.stats_chart_axis)
+    .with_page(state.stats_page)
+    .with_tool_sort(state.tool_sort)
+    .with_chain(chain);
 
     frame.render_widget(panel, area);
 }
 
-/// Build SessionStats by iterating through all entries in SessionViewState.
-/// This is temporary - stats should eventually be maintained in SessionViewState during ingestion.
-fn build_session_stats(
-    session_view: &crate::view_state::session::SessionViewState,
-) -> crate::model::SessionStats {
-    use crate::model::SessionStats;
-
-    let mut stats = SessionStats::default();
-
-    // Process main agent entries
-    for entry_view in session_view.main().iter() {
-        if let Some(log_entry) = entry_view.entry().as_valid() {
-            stats.record_entry(log_entry);
-        }
-    }
-
-    // Process subagent entries (all eagerly initialized)
-    for (_agent_id, conversation_view) in session_view.initialized_subagents() {
-        for entry_view in conversation_view.iter() {
-            if let Some(log_entry) = entry_view.entry().as_valid() {
-                stats.record_entry(log_entry);
-            }
-        }
-    }
-
-    stats
-}
-
 /// Build context-sensitive keyboard hints based on current focus pane.
 ///
 /// Returns a formatted string with keyboard shortcuts appropriate for the
@@ -2,9 +2,10 @@
 
 use super::*;
 use crate::model::{AgentId, ConversationEntry, SessionId};
-use crate::state::{AppState, ConversationSelection, InputMode, WrapMode};
+use crate::state::{AppState, ConversationSelection, InputMode, SearchProgress, WrapMode};
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -53,7 +54,7 @@ fn create_entries_no_subagents() -> Vec<ConversationEntry> {
         Message::new(Role::User, MessageContent::Text("Main message".to_string())),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(main_entry)));
+    entries.push(ConversationEntry::Valid(Arc::new(main_entry)));
 
     entries
 }
@@ -77,7 +78,7 @@ fn create_entries_with_subagents() -> Vec<ConversationEntry> {
         Message::new(Role::User, MessageContent::Text("Main message".to_string())),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(main_entry)));
+    entries.push(ConversationEntry::Valid(Arc::new(main_entry)));
 
     // Add a subagent entry
     let subagent_entry = LogEntry::new(
@@ -93,7 +94,7 @@ fn create_entries_with_subagents() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(subagent_entry)));
+    entries.push(ConversationEntry::Valid(Arc::new(subagent_entry)));
 
     entries
 }
@@ -117,7 +118,7 @@ fn create_entries_with_multiple_subagents() -> Vec<ConversationEntry> {
         Message::new(Role::User, MessageContent::Text("Main message".to_string())),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(main_entry)));
+    entries.push(ConversationEntry::Valid(Arc::new(main_entry)));
 
     // Add three subagent entries
     for i in 1..=3 {
@@ -134,7 +135,7 @@ fn create_entries_with_multiple_subagents() -> Vec<ConversationEntry> {
             ),
             EntryMetadata::default(),
         );
-        entries.push(ConversationEntry::Valid(Box::new(subagent_entry)));
+        entries.push(ConversationEntry::Valid(Arc::new(subagent_entry)));
     }
 
     entries
@@ -910,7 +911,7 @@ fn render_layout_uses_search_highlighting_when_search_active() {
         ),
         EntryMetadata::default(),
     );
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
 
     let mut state = AppState::new();
     state.add_entries(entries);
@@ -932,6 +933,7 @@ fn render_layout_uses_search_highlighting_when_search_active() {
         query,
         matches,
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
 
     terminal
@@ -984,7 +986,7 @@ fn render_layout_no_search_highlighting_when_search_inactive() {
         ),
         EntryMetadata::default(),
     );
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
 
     let mut state = AppState::new();
     state.add_entries(entries);
@@ -1154,7 +1156,7 @@ fn create_entries_multiple_sessions() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(entry1)));
+    entries.push(ConversationEntry::Valid(Arc::new(entry1)));
 
     // Session 2 entries
     let entry2 = LogEntry::new(
@@ -1170,7 +1172,7 @@ fn create_entries_multiple_sessions() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(entry2)));
+    entries.push(ConversationEntry::Valid(Arc::new(entry2)));
 
     // Session 3 entries
     let entry3 = LogEntry::new(
@@ -1186,7 +1188,7 @@ fn create_entries_multiple_sessions() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(entry3)));
+    entries.push(ConversationEntry::Valid(Arc::new(entry3)));
 
     entries
 }
@@ -1492,6 +1494,10 @@ fn status_line_not_rendered() {
         tools: vec!["Read".to_string(), "Write".to_string(), "Bash".to_string()],
         agents: vec!["general-purpose".to_string()],
         skills: vec!["commit".to_string(), "tdd".to_string()],
+        permission_mode: None,
+        mcp_servers: vec![],
+        version: None,
+        compact: None,
     };
 
     let entry = LogEntry::new_with_system_metadata(
@@ -1505,7 +1511,7 @@ fn status_line_not_rendered() {
         EntryMetadata::default(),
         Some(sys_meta),
     );
-    entries.push(crate::model::ConversationEntry::Valid(Box::new(entry)));
+    entries.push(crate::model::ConversationEntry::Valid(Arc::new(entry)));
 
     let mut state = AppState::new();
     state.add_entries(entries);
@@ -1593,7 +1599,7 @@ fn create_entries_two_sessions_different_subagents() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(entry1_main)));
+    entries.push(ConversationEntry::Valid(Arc::new(entry1_main)));
 
     let entry1_alpha = LogEntry::new(
         EntryUuid::new("s1-entry-2").unwrap(),
@@ -1608,7 +1614,7 @@ fn create_entries_two_sessions_different_subagents() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(entry1_alpha)));
+    entries.push(ConversationEntry::Valid(Arc::new(entry1_alpha)));
 
     let entry1_beta = LogEntry::new(
         EntryUuid::new("s1-entry-3").unwrap(),
@@ -1623,7 +1629,7 @@ fn create_entries_two_sessions_different_subagents() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(entry1_beta)));
+    entries.push(ConversationEntry::Valid(Arc::new(entry1_beta)));
 
     // Session 2: Main + subagent-gamma + subagent-delta
     let entry2_main = LogEntry::new(
@@ -1639,7 +1645,7 @@ fn create_entries_two_sessions_different_subagents() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(entry2_main)));
+    entries.push(ConversationEntry::Valid(Arc::new(entry2_main)));
 
     let entry2_gamma = LogEntry::new(
         EntryUuid::new("s2-entry-2").unwrap(),
@@ -1654,7 +1660,7 @@ fn create_entries_two_sessions_different_subagents() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(entry2_gamma)));
+    entries.push(ConversationEntry::Valid(Arc::new(entry2_gamma)));
 
     let entry2_delta = LogEntry::new(
         EntryUuid::new("s2-entry-3").unwrap(),
@@ -1669,7 +1675,7 @@ fn create_entries_two_sessions_different_subagents() -> Vec<ConversationEntry> {
         ),
         EntryMetadata::default(),
     );
-    entries.push(ConversationEntry::Valid(Box::new(entry2_delta)));
+    entries.push(ConversationEntry::Valid(Arc::new(entry2_delta)));
 
     entries
 }
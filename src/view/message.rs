@@ -3,21 +3,21 @@
 //! Implements virtualized rendering to handle large conversations efficiently.
 //! Only renders visible messages (plus ±20 buffer) based on scroll position.
 
-use crate::model::{ContentBlock, ConversationEntry, MessageContent};
-use crate::state::WrapMode;
+use crate::model::{ContentBlock, ConversationEntry, EntryTiming, MessageContent};
+use crate::state::{TimeGutter, WrapMode};
 use crate::view::MessageStyles;
 use crate::view_state::conversation::ConversationViewState;
 use crate::view_state::types::ViewportDimensions;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
     text::Line,
     widgets::{Paragraph, Widget},
 };
 use unicode_width::UnicodeWidthStr;
 
 use super::helpers::styled_block;
+use super::{session_separator, time_gutter};
 
 // ===== Entry Layout =====
 
@@ -67,7 +67,7 @@ pub struct ConversationView<'a> {
     summary_lines: usize,
     buffer_size: usize,
     global_wrap: WrapMode,
-    max_context_tokens: u64,
+    time_gutter: TimeGutter,
     pricing: crate::model::PricingConfig,
 }
 
@@ -92,7 +92,7 @@ impl<'a> ConversationView<'a> {
             summary_lines: 3,
             buffer_size: 20,
             global_wrap: WrapMode::default(), // Default to Wrap
-            max_context_tokens: 200_000,      // Default: Opus 4.5 context window
+            time_gutter: TimeGutter::Off,
             pricing: crate::model::PricingConfig::default(),
         }
     }
@@ -133,9 +133,11 @@ impl<'a> ConversationView<'a> {
         self
     }
 
-    /// Set the maximum context window size in tokens (cclv-5ur.32).
-    pub fn max_context_tokens(mut self, tokens: u64) -> Self {
-        self.max_context_tokens = tokens;
+    /// Set the time gutter mode.
+    ///
+    /// The view state must have been laid out with `TimeGutter::width` reserved.
+    pub fn time_gutter(mut self, mode: TimeGutter) -> Self {
+        self.time_gutter = mode;
         self
     }
 
@@ -176,6 +178,13 @@ impl<'a> Widget for ConversationView<'a> {
 
         // Render content: only render visible entries
         let mut lines = Vec::new();
+        // Time gutter spans, one per line (empty when the gutter is off)
+        let mut gutters = Vec::new();
+        let timings: &[EntryTiming] = if self.time_gutter == TimeGutter::Off {
+            &[]
+        } else {
+            self.view_state.timings()
+        };
 
         if entry_count == 0 {
             lines.push(Line::from("No messages yet..."));
@@ -210,7 +219,12 @@ impl<'a> Widget for ConversationView<'a> {
 
                     if session_changed {
                         // Render session separator line
-                        lines.push(render_session_separator(current_session_id));
+                        lines.push(session_separator::render_session_separator(
+                            current_session_id,
+                        ));
+                        if !timings.is_empty() {
+                            gutters.push(time_gutter::blank_gutter(self.time_gutter));
+                        }
                     }
 
                     // Update tracking for next iteration
@@ -228,6 +242,11 @@ impl<'a> Widget for ConversationView<'a> {
                 let entry_lines = entry_view.rendered_lines().to_vec();
 
                 // Skip lines that are scrolled off the top, then add to final lines
+                if let Some(timing) = timings.get(entry_index.get()) {
+                    gutters.extend((lines_to_skip..entry_lines.len()).map(|line_in_entry| {
+                        time_gutter::gutter_spans(self.time_gutter, timing, line_in_entry)
+                    }));
+                }
                 lines.extend(entry_lines.into_iter().skip(lines_to_skip));
             }
         }
@@ -235,7 +254,8 @@ impl<'a> Widget for ConversationView<'a> {
         // Check if content extends beyond viewport BEFORE applying horizontal offset
         // (because offset trims the lines, hiding the fact they were long)
         let horizontal_offset = self.view_state.horizontal_offset();
-        let has_long_lines_flag = has_long_lines(&lines, viewport_width as usize);
+        let content_width = viewport_width.saturating_sub(self.time_gutter.width());
+        let has_long_lines_flag = has_long_lines(&lines, content_width as usize);
 
         // Apply horizontal scroll offset if in NoWrap mode
         let final_lines: Vec<Line<'static>> =
@@ -248,6 +268,21 @@ impl<'a> Widget for ConversationView<'a> {
                 lines
             };
 
+        // Prepend the time gutter after scrolling so it stays in place
+        let final_lines: Vec<Line<'static>> = if gutters.is_empty() {
+            final_lines
+        } else {
+            final_lines
+                .into_iter()
+                .zip(gutters)
+                .map(|(line, mut gutter)| {
+                    let style = line.style;
+                    gutter.extend(line.spans);
+                    Line::from(gutter).style(style)
+                })
+                .collect()
+        };
+
         // Add scroll indicators to title if content extends beyond viewport
         let has_left = horizontal_offset > 0;
         let has_right = has_long_lines_flag;
@@ -352,7 +387,7 @@ pub fn extract_entry_text(entry: &ConversationEntry) -> String {
     }
 }
 
-fn apply_horizontal_offset(line: Line<'static>, offset: usize) -> Line<'static> {
+pub(super) fn apply_horizontal_offset(line: Line<'static>, offset: usize) -> Line<'static> {
     if offset == 0 {
         return line;
     }
@@ -452,7 +487,7 @@ fn apply_horizontal_offset(line: Line<'static>, offset: usize) -> Line<'static>
 /// Check if any line in the collection exceeds the viewport width.
 ///
 /// Uses visual width (not byte count) for correct Unicode handling.
-fn has_long_lines(lines: &[Line], viewport_width: usize) -> bool {
+pub(super) fn has_long_lines(lines: &[Line], viewport_width: usize) -> bool {
     lines.iter().any(|line| {
         let width: usize = line.spans.iter().map(|s| s.content.width()).sum();
         width > viewport_width
@@ -465,7 +500,11 @@ fn has_long_lines(lines: &[Line], viewport_width: usize) -> bool {
 /// Appends ▶ if content extends beyond viewport (can scroll right).
 ///
 /// Returns modified title string with indicators.
-fn add_scroll_indicators_to_title(base_title: String, has_left: bool, has_right: bool) -> String {
+pub(super) fn add_scroll_indicators_to_title(
+    base_title: String,
+    has_left: bool,
+    has_right: bool,
+) -> String {
     let mut title = base_title;
 
     if has_left {
@@ -478,28 +517,3 @@ fn add_scroll_indicators_to_title(base_title: String, has_left: bool, has_right:
 
     title
 }
-
-// ===== Session Separator Rendering (FR-074) =====
-
-/// Render a session separator line.
-///
-/// Format: "─────────── Session: <session_id> ───────────"
-/// Styling: Dim gray to distinguish from content
-///
-/// # Arguments
-/// * `session_id` - The ID of the new session starting after this separator
-///
-/// # Returns
-/// A single Line with the separator text and dim gray styling
-fn render_session_separator(session_id: &crate::model::SessionId) -> Line<'static> {
-    use ratatui::text::Span;
-
-    let separator_text = format!("─────────── Session: {} ───────────", session_id);
-
-    Line::from(vec![Span::styled(
-        separator_text,
-        Style::default()
-            .fg(Color::DarkGray)
-            .add_modifier(Modifier::DIM),
-    )])
-}
@@ -1,43 +1,59 @@
 //! TUI rendering and terminal management (impure shell)
 
 pub mod constants;
+mod continuous;
+pub mod export_picker;
+pub mod files_modal;
 mod help;
 mod helpers;
 mod layout;
 pub mod live_indicator;
 mod message;
+pub mod print;
+pub mod saved_search_picker;
 mod search_input;
+pub mod search_worker;
+mod session_header;
 pub mod session_modal;
 #[cfg(test)]
 mod session_modal_event_loop_integration_test;
+mod session_separator;
 #[cfg(test)]
 mod session_separator_tests;
 mod stats;
+mod stats_charts;
 #[cfg(test)]
 mod stats_filter_key_wiring_test;
 mod stats_multi_scope;
+mod stats_tools;
 mod styles;
+pub mod subagent_table;
 pub mod tabs;
+mod time_gutter;
 
+pub use export_picker::render_export_picker;
+pub use files_modal::render_files_modal;
 pub use help::render_help_overlay;
 pub use helpers::{empty_line, key_value_line};
 pub use live_indicator::LiveIndicator;
 pub use message::{ConversationView, extract_entry_text, has_code_blocks};
+pub use saved_search_picker::render_saved_search_picker;
 pub use search_input::SearchInput;
+pub use search_worker::SearchWorker;
 pub use session_modal::render_session_modal;
 pub use stats::StatsPanel;
+pub(crate) use stats::{format_cost, format_tokens};
 pub use stats_multi_scope::MultiScopeStatsPanel;
 pub use styles::{ColorConfig, MessageStyles};
+pub use subagent_table::render_subagent_table;
 
 use crate::config::keybindings::KeyBindings;
 use crate::integration;
 use crate::model::{AppError, KeyAction};
 use crate::source::InputSource;
-#[cfg(test)]
-use crate::state::ConversationSelection;
 use crate::state::{
-    AppState, FocusPane, expand_handler, handle_toggle_wrap, next_match, prev_match,
-    scroll_handler, search_input_handler,
+    AppState, ConversationSelection, FocusPane, expand_handler, handle_toggle_wrap, next_match,
+    prev_match, scroll_handler, search_input_handler,
 };
 use crossterm::{
     ExecutableCommand,
@@ -86,6 +102,10 @@ where
     last_tab_area: Option<ratatui::layout::Rect>,
     /// Last rendered main pane area (for entry click detection)
     last_main_area: Option<ratatui::layout::Rect>,
+    /// Background search thread holding a copy of all searchable text
+    search_worker: SearchWorker,
+    /// File submitted queries are persisted to (None disables persistence)
+    search_history_path: Option<std::path::PathBuf>,
 }
 
 impl TuiApp<CrosstermBackend<Stdout>> {
@@ -115,6 +135,10 @@ impl TuiApp<CrosstermBackend<Stdout>> {
             }
         }
 
+        // Hand searchable text to the background search worker
+        let search_worker = SearchWorker::spawn();
+        search_worker.append(crate::state::SearchDocument::from_entries(&entries));
+
         // Create AppState and populate with initial entries
         let line_counter = entries.len();
         let mut app_state = AppState::new();
@@ -142,6 +166,8 @@ impl TuiApp<CrosstermBackend<Stdout>> {
             pending_entries: Vec::new(),
             last_tab_area: None,
             last_main_area: None,
+            search_worker,
+            search_history_path: None,
         })
     }
 
@@ -153,25 +179,38 @@ impl TuiApp<CrosstermBackend<Stdout>> {
     pub fn run(&mut self) -> Result<(), TuiError> {
         // Timer interval for LIVE indicator blink (500ms)
         const TIMER_INTERVAL: Duration = Duration::from_millis(500);
+        // Faster tick while a background search streams results
+        const SEARCH_POLL_INTERVAL: Duration = Duration::from_millis(50);
 
         // Initial render - ensures screen has content immediately (cclv-07v.12.21.4)
         self.draw()?;
+        let mut last_blink = std::time::Instant::now();
 
         loop {
+            let poll_interval = if self.is_search_running() {
+                SEARCH_POLL_INTERVAL
+            } else {
+                TIMER_INTERVAL
+            };
+
             // Poll for events with timer timeout (event-driven)
-            let event_result = if event::poll(TIMER_INTERVAL)? {
+            let event_result = if event::poll(poll_interval)? {
                 match event::read()? {
                     Event::Key(key) => {
                         if self.handle_key(key) {
                             return Ok(()); // User quit
                         }
-                        // Keyboard event - render immediately, no need to poll stdin
+                        // Keyboard event - render immediately, no need to poll stdin.
+                        // Merge streamed search results too: while keys keep
+                        // arriving the poll never times out.
+                        self.poll_search();
                         self.draw()?;
                         continue;
                     }
                     Event::Mouse(mouse) => {
                         self.handle_mouse(mouse);
                         // Mouse event - render immediately, no need to poll stdin
+                        self.poll_search();
                         self.draw()?;
                         continue;
                     }
@@ -200,16 +239,21 @@ impl TuiApp<CrosstermBackend<Stdout>> {
                 // Check if we have new data to render
                 let has_new_data = !self.pending_entries.is_empty();
 
+                // Merge streamed search results
+                let has_search_results = self.poll_search();
+
                 // Toggle blink state on timer event when in Streaming mode
                 // This creates the blinking animation for the LIVE indicator
-                let should_blink = self.app_state.input_mode == crate::state::InputMode::Streaming;
+                let should_blink = self.app_state.input_mode == crate::state::InputMode::Streaming
+                    && last_blink.elapsed() >= TIMER_INTERVAL;
                 if should_blink {
                     self.app_state.toggle_blink();
+                    last_blink = std::time::Instant::now();
                 }
 
                 // Render if: new data arrived OR timer elapsed with blink update
                 // Timer triggers render when Streaming (for LIVE blink) or when new data arrived
-                if has_new_data || should_blink {
+                if has_new_data || has_search_results || should_blink {
                     self.draw()?;
                 }
             }
@@ -253,6 +297,90 @@ where
         Ok(())
     }
 
+    /// Submit the typed query, record it in history and start searching.
+    fn submit_typed_search(&mut self) {
+        self.app_state.search = search_input_handler::submit_search(self.app_state.search.clone());
+        self.record_search_history();
+        self.start_search();
+    }
+
+    /// Record the active query in search history and persist it.
+    ///
+    /// Persistence failures are logged, not surfaced - history is best effort.
+    fn record_search_history(&mut self) {
+        let crate::state::SearchState::Active { query, .. } = &self.app_state.search else {
+            return;
+        };
+        self.app_state.search_history.record(query.as_str());
+
+        if let Some(path) = &self.search_history_path {
+            if let Err(e) =
+                crate::config::save_search_history(path, self.app_state.search_history.entries())
+            {
+                warn!("Failed to save search history: {}", e);
+            }
+        }
+    }
+
+    /// Start a background search for the submitted query.
+    ///
+    /// Searches the currently viewed session. Any running search is cancelled;
+    /// matches stream in through `poll_search`.
+    fn start_search(&mut self) {
+        use crate::state::{SearchProgress, SearchState};
+
+        let SearchState::Active { query, .. } = &self.app_state.search else {
+            return;
+        };
+        let query = query.clone();
+        let session_id = self.app_state.session_view().session_id().clone();
+        self.search_worker.start(&query, session_id);
+        self.app_state.search = SearchState::Active {
+            query,
+            matches: Vec::new(),
+            current_match: 0,
+            progress: SearchProgress::Running {
+                searched: 0,
+                total: 0,
+            },
+        };
+    }
+
+    /// Whether a background search is still scanning.
+    fn is_search_running(&self) -> bool {
+        matches!(
+            &self.app_state.search,
+            crate::state::SearchState::Active { progress, .. } if progress.is_running()
+        )
+    }
+
+    /// Drain the search worker and merge new matches into `SearchState::Active`.
+    ///
+    /// Returns true if the search state changed and a redraw is needed.
+    fn poll_search(&mut self) -> bool {
+        let batches = self.search_worker.poll();
+        self.apply_search_batches(batches)
+    }
+
+    /// Merge result batches into the active search.
+    ///
+    /// Batches are dropped if search was cancelled in the meantime.
+    fn apply_search_batches(&mut self, batches: Vec<search_worker::SearchBatch>) -> bool {
+        let crate::state::SearchState::Active {
+            matches, progress, ..
+        } = &mut self.app_state.search
+        else {
+            return false;
+        };
+        let mut changed = false;
+        for batch in batches {
+            matches.extend(batch.matches);
+            *progress = batch.progress;
+            changed = true;
+        }
+        changed
+    }
+
     /// Handle a single keyboard event
     ///
     /// Returns true if app should quit
@@ -267,6 +395,43 @@ where
             return false; // Key consumed by modal
         }
 
+        // Files modal captures keys while open
+        if crate::state::handle_files_modal_key(&mut self.app_state, key) {
+            return false;
+        }
+
+        // Subagent table captures keys while open
+        if crate::state::handle_subagent_table_key(&mut self.app_state, key) {
+            return false;
+        }
+
+        // Saved search picker captures keys while open
+        match crate::state::handle_saved_search_picker_key(&mut self.app_state, key) {
+            crate::state::SavedSearchPickerOutcome::Ignored => {}
+            crate::state::SavedSearchPickerOutcome::Consumed => return false,
+            crate::state::SavedSearchPickerOutcome::Submitted => {
+                self.start_search();
+                return false;
+            }
+        }
+
+        // Export picker captures keys while open; the file is written here
+        match crate::state::handle_export_picker_key(&mut self.app_state, key) {
+            crate::state::ExportPickerOutcome::Ignored => {}
+            crate::state::ExportPickerOutcome::Consumed => return false,
+            crate::state::ExportPickerOutcome::Export(scope) => {
+                let status = match crate::state::export_document(&self.app_state, scope) {
+                    Ok(document) => match std::fs::write(&document.file_name, &document.contents) {
+                        Ok(()) => format!("Wrote {}", document.file_name),
+                        Err(err) => format!("Failed to write {}: {}", document.file_name, err),
+                    },
+                    Err(message) => message,
+                };
+                self.app_state.export_picker.set_status(status);
+                return false;
+            }
+        }
+
         // Special case: Escape closes help overlay if visible (before key binding dispatch)
         if key.code == KeyCode::Esc && self.app_state.help_visible {
             self.app_state.help_visible = false;
@@ -300,10 +465,23 @@ where
                         );
                         return false;
                     }
+                    KeyCode::Up => {
+                        self.app_state.search = search_input_handler::recall_previous_query(
+                            self.app_state.search.clone(),
+                            &mut self.app_state.search_history,
+                        );
+                        return false;
+                    }
+                    KeyCode::Down => {
+                        self.app_state.search = search_input_handler::recall_next_query(
+                            self.app_state.search.clone(),
+                            &mut self.app_state.search_history,
+                        );
+                        return false;
+                    }
                     KeyCode::Enter => {
                         // Submit search on Enter when typing
-                        self.app_state.search =
-                            search_input_handler::submit_search(self.app_state.search.clone());
+                        self.submit_typed_search();
                         // Keep focus on Search pane after submit (stays active)
                         return false;
                     }
@@ -391,6 +569,19 @@ where
                 self.app_state.stats_visible = !self.app_state.stats_visible;
             }
 
+            // Stats timeline x axis (turn index / wall-clock time)
+            KeyAction::ToggleChartAxis => {
+                self.app_state.stats_chart_axis = self.app_state.stats_chart_axis.toggle();
+            }
+
+            // Stats panel page (overview / tools) and tool table sort column
+            KeyAction::NextStatsPage => {
+                self.app_state.stats_page = self.app_state.stats_page.next();
+            }
+            KeyAction::CycleToolSort => {
+                self.app_state.tool_sort = self.app_state.tool_sort.next();
+            }
+
             // Session modal visibility
             KeyAction::ToggleSessionModal => {
                 let current_index = match self.app_state.viewed_session {
@@ -400,6 +591,18 @@ where
                     crate::state::ViewedSession::Pinned(idx) => idx.get(),
                 };
                 self.app_state.session_modal.toggle(current_index);
+                // Rows may be sorted: select the current session's row
+                if self.app_state.session_modal.is_visible() {
+                    crate::state::refresh_session_rows(&mut self.app_state);
+                    self.app_state.session_modal.select_session(current_index);
+                }
+            }
+
+            KeyAction::ToggleFilesModal => {
+                self.app_state.files_modal.toggle();
+            }
+            KeyAction::ToggleSubagentTable => {
+                self.app_state.subagent_table.toggle();
             }
 
             // Stats filters (legacy keybindings not yet in KeyBindings)
@@ -619,35 +822,26 @@ where
             KeyAction::StartSearch => {
                 self.app_state.search =
                     search_input_handler::activate_search_input(self.app_state.search.clone());
+                self.app_state.search_history.reset_navigation();
                 self.app_state.focus = FocusPane::Search;
             }
             KeyAction::SubmitSearch => {
-                self.app_state.search =
-                    search_input_handler::submit_search(self.app_state.search.clone());
-                // Execute search to populate matches
-                use crate::state::{SearchState, execute_search};
-                if let SearchState::Active { query, .. } = &self.app_state.search {
-                    let session_view = self
-                        .app_state
-                        .log_view()
-                        .get_session(0)
-                        .expect("Session 0 must exist");
-                    let matches = execute_search(session_view, query);
-                    self.app_state.search = SearchState::Active {
-                        query: query.clone(),
-                        matches,
-                        current_match: 0,
-                    };
-                }
+                // Execute search in the background to populate matches
+                self.submit_typed_search();
                 // Keep focus on Search pane after submit (stays active)
             }
             KeyAction::CancelSearch => {
+                self.search_worker.cancel();
                 self.app_state.search =
                     search_input_handler::cancel_search(self.app_state.search.clone());
                 // Return focus to Main pane after cancel
                 self.app_state.focus = FocusPane::Main;
             }
 
+            KeyAction::ToggleSavedSearches => {
+                self.app_state.saved_search_picker.toggle();
+            }
+
             // Match navigation - delegate to pure match navigation handler
             KeyAction::NextMatch => {
                 next_match(&mut self.app_state);
@@ -678,6 +872,11 @@ where
                 let wrap = self.app_state.global_wrap;
                 let search_state = self.app_state.search.clone();
 
+                // Continuous mode shows every session's main conversation
+                if self.app_state.continuous_sessions {
+                    self.app_state.log_view_mut().set_viewport_all(width, wrap);
+                }
+
                 // Relayout main conversation
                 if let Some(main_view) = self.app_state.main_conversation_view_mut() {
                     main_view.relayout(width, wrap, &search_state);
@@ -692,6 +891,35 @@ where
                 }
             }
 
+            // Time gutter (T key): reserve its columns and re-wrap
+            KeyAction::CycleTimeGutter => {
+                self.app_state.cycle_time_gutter();
+                let gutter_width = self.app_state.time_gutter.width();
+                let search_state = self.app_state.search.clone();
+                self.app_state
+                    .log_view_mut()
+                    .set_gutter_width(gutter_width, &search_state);
+            }
+
+            // Session header (i key)
+            KeyAction::ToggleSessionHeader => {
+                self.app_state.session_header_expanded = !self.app_state.session_header_expanded;
+            }
+
+            // Continuous sessions (C key) and folding (z key)
+            KeyAction::ToggleContinuousSessions => {
+                self.app_state.toggle_continuous_sessions();
+                self.app_state.selected_conversation = ConversationSelection::Main;
+            }
+            KeyAction::ToggleSessionFold if self.app_state.continuous_sessions => {
+                self.app_state.toggle_session_fold();
+            }
+
+            // Export picker (E key)
+            KeyAction::ToggleExportPicker => {
+                self.app_state.export_picker.toggle();
+            }
+
             // Help overlay toggle
             KeyAction::Help => {
                 self.app_state.help_visible = !self.app_state.help_visible;
@@ -889,6 +1117,11 @@ where
         let main_area = layout::calculate_pane_area(frame_area, &self.app_state);
         self.last_main_area = Some(main_area);
 
+        // Build modal lists once per change rather than per frame
+        crate::state::refresh_session_rows(&mut self.app_state);
+        crate::state::refresh_files_modal(&mut self.app_state);
+        crate::state::refresh_subagent_rows(&mut self.app_state);
+
         // Render the frame
         self.terminal.draw(|frame| {
             layout::render_layout(frame, &self.app_state);
@@ -916,8 +1149,10 @@ where
             return;
         }
 
-        // Move entries from buffer to session
+        // Move entries from buffer to session; new text is searched incrementally
         let entries = std::mem::take(&mut self.pending_entries);
+        self.search_worker
+            .append(crate::state::SearchDocument::from_entries(&entries));
         self.app_state.add_entries(entries);
 
         // Recompute layout after adding streaming entries (cclv-5ur.7)
@@ -969,6 +1204,12 @@ where
         // Store viewport dimensions and relayout all conversations in all sessions (cclv-5ur.58)
         app_state.log_view_mut().set_viewport_all(width, wrap);
 
+        // Entries were added before the worker existed; seed it from the view-state
+        let search_worker = SearchWorker::spawn();
+        search_worker.append(crate::state::SearchDocument::from_log_view(
+            app_state.log_view(),
+        ));
+
         Self {
             terminal,
             app_state,
@@ -978,6 +1219,8 @@ where
             pending_entries: Vec::new(),
             last_tab_area: None,
             last_main_area: None,
+            search_worker,
+            search_history_path: None,
         }
     }
 
@@ -1016,6 +1259,18 @@ where
         self.draw()
     }
 
+    /// Block until the background search completes, then merge its results (test-only accessor)
+    ///
+    /// **WARNING**: This is for testing only. Do not use in production code.
+    pub(crate) fn wait_for_search_test(&mut self) {
+        if self.is_search_running() {
+            let batches = self
+                .search_worker
+                .wait_complete(std::time::Duration::from_secs(10));
+            self.apply_search_batches(batches);
+        }
+    }
+
     /// Get reference to terminal (test-only accessor)
     ///
     /// Provides access to the terminal backend for buffer inspection.
@@ -1095,13 +1350,14 @@ where
 ///
 /// ```rust,no_run
 /// use cclv::view::CliArgs;
-/// use cclv::model::PricingConfig;
+/// use cclv::model::{ContextWindows, PricingConfig};
 ///
 /// let args = CliArgs::new(
 ///     "base16-ocean".to_string(),  // Theme name
 ///     true,                         // Show stats panel on startup
-///     200_000,                      // Max context tokens
+///     ContextWindows::default(),    // Context window per model
 ///     PricingConfig::default(),     // Pricing config
+///     Vec::new(),                   // Saved searches
 /// );
 /// ```
 pub struct CliArgs {
@@ -1121,16 +1377,21 @@ pub struct CliArgs {
     /// is visible immediately; when false, user can toggle with 's' key.
     pub stats: bool,
 
-    /// Maximum context window size in tokens.
+    /// Context window sizes per model.
     ///
-    /// Used for token divider percentage calculation (cclv-5ur.32).
-    /// Default: 200,000 tokens (Claude Opus 4.5 context window).
-    pub max_context_tokens: u64,
+    /// Used for token divider percentage calculation (cclv-5ur.32). Unknown
+    /// models use `max_context_tokens` from config (default 200,000).
+    pub context_windows: crate::model::ContextWindows,
 
     /// Pricing configuration for cost estimation.
     ///
     /// Used by token divider to show estimated costs (cclv-5ur.32).
     pub pricing: crate::model::PricingConfig,
+
+    /// Named searches from the `[searches]` config table.
+    ///
+    /// Offered in the saved search picker (Ctrl+r).
+    pub saved_searches: Vec<crate::state::SavedSearch>,
 }
 
 impl CliArgs {
@@ -1138,14 +1399,16 @@ impl CliArgs {
     pub fn new(
         theme: String,
         stats: bool,
-        max_context_tokens: u64,
+        context_windows: crate::model::ContextWindows,
         pricing: crate::model::PricingConfig,
+        saved_searches: Vec<crate::state::SavedSearch>,
     ) -> Self {
         Self {
             theme,
             stats,
-            max_context_tokens,
+            context_windows,
             pricing,
+            saved_searches,
         }
     }
 }
@@ -1165,8 +1428,21 @@ pub fn run_with_source(input_source: InputSource, args: CliArgs) -> Result<(), T
     // Apply initial args (stats visible, search query, etc.)
     app.app_state.stats_visible = args.stats;
     app.app_state.live_mode = live_mode;
-    app.app_state.max_context_tokens = args.max_context_tokens;
+    app.app_state
+        .log_view_mut()
+        .set_context_windows(args.context_windows);
     app.app_state.pricing = args.pricing;
+    app.app_state.saved_searches = args.saved_searches;
+
+    // Restore search history from the state directory
+    let history_path = crate::config::default_search_history_path();
+    match crate::config::load_search_history(&history_path) {
+        Ok(entries) => {
+            app.app_state.search_history = crate::state::SearchHistory::from_entries(entries)
+        }
+        Err(e) => warn!("Failed to load search history: {}", e),
+    }
+    app.search_history_path = Some(history_path);
 
     // Run the app and ensure cleanup happens even on error
     let result = app.run();
@@ -1204,6 +1480,7 @@ mod tests {
         THEME_BASE16_OCEAN, THEME_MONOKAI, THEME_SOLARIZED_DARK, THEME_SOLARIZED_LIGHT,
     };
     use crossterm::event::KeyModifiers;
+    use std::sync::Arc;
 
     #[test]
     fn tui_error_from_io_error() {
@@ -1239,12 +1516,17 @@ mod tests {
             ),
             crate::model::EntryMetadata::default(),
         );
-        app_state.add_entries(vec![crate::model::ConversationEntry::Valid(Box::new(
+        app_state.add_entries(vec![crate::model::ConversationEntry::Valid(Arc::new(
             entry,
         ))]);
 
         let key_bindings = KeyBindings::default();
 
+        let search_worker = SearchWorker::spawn();
+        search_worker.append(crate::state::SearchDocument::from_log_view(
+            app_state.log_view(),
+        ));
+
         TuiApp {
             terminal,
             app_state,
@@ -1254,6 +1536,8 @@ mod tests {
             pending_entries: Vec::new(),
             last_tab_area: None,
             last_main_area: None,
+            search_worker,
+            search_history_path: None,
         }
     }
 
@@ -1286,7 +1570,7 @@ mod tests {
                 EntryMetadata::default(),
             );
             app.app_state
-                .add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+                .add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
         }
 
         app
@@ -1504,6 +1788,8 @@ mod tests {
             pending_entries: Vec::new(),
             last_tab_area: None,
             last_main_area: None,
+            search_worker: SearchWorker::spawn(),
+            search_history_path: None,
         };
 
         // Create entries for session 1
@@ -1521,7 +1807,7 @@ mod tests {
                 EntryMetadata::default(),
             );
             app.app_state
-                .add_entries(vec![ConversationEntry::Valid(Box::new(log_entry))]);
+                .add_entries(vec![ConversationEntry::Valid(Arc::new(log_entry))]);
         }
 
         // Create entries for session 2 (most recent)
@@ -1539,7 +1825,7 @@ mod tests {
                 EntryMetadata::default(),
             );
             app.app_state
-                .add_entries(vec![ConversationEntry::Valid(Box::new(log_entry))]);
+                .add_entries(vec![ConversationEntry::Valid(Arc::new(log_entry))]);
         }
 
         // Verify we have 2 sessions
@@ -1572,7 +1858,7 @@ mod tests {
             new_message,
             EntryMetadata::default(),
         );
-        let entries_to_add = vec![ConversationEntry::Valid(Box::new(new_entry))];
+        let entries_to_add = vec![ConversationEntry::Valid(Arc::new(new_entry))];
         app.app_state.add_entries(entries_to_add.clone());
 
         // Try to trigger auto-scroll using is_tailing_enabled (NEW LOGIC)
@@ -1632,6 +1918,8 @@ mod tests {
             pending_entries: Vec::new(),
             last_tab_area: None,
             last_main_area: None,
+            search_worker: SearchWorker::spawn(),
+            search_history_path: None,
         };
 
         // Create entries for session 1
@@ -1649,7 +1937,7 @@ mod tests {
                 EntryMetadata::default(),
             );
             app.app_state
-                .add_entries(vec![ConversationEntry::Valid(Box::new(log_entry))]);
+                .add_entries(vec![ConversationEntry::Valid(Arc::new(log_entry))]);
         }
 
         // Create entries for session 2 (most recent)
@@ -1667,7 +1955,7 @@ mod tests {
                 EntryMetadata::default(),
             );
             app.app_state
-                .add_entries(vec![ConversationEntry::Valid(Box::new(log_entry))]);
+                .add_entries(vec![ConversationEntry::Valid(Arc::new(log_entry))]);
         }
 
         // Verify we have 2 sessions
@@ -1699,7 +1987,7 @@ mod tests {
             new_message,
             EntryMetadata::default(),
         );
-        let entries_to_add = vec![ConversationEntry::Valid(Box::new(new_entry))];
+        let entries_to_add = vec![ConversationEntry::Valid(Arc::new(new_entry))];
         app.app_state.add_entries(entries_to_add.clone());
 
         // Try to trigger auto-scroll using is_tailing_enabled (NEW LOGIC)
@@ -1742,26 +2030,25 @@ mod tests {
         );
     }
 
-    // TODO: This test will panic with todo! until session-aware filtering is implemented
-    // #[test]
-    // fn handle_key_at_sets_main_agent_filter() {
-    //     let mut app = create_test_app();
-    //
-    //     // Set to Global initially
-    //     app.app_state.stats_filter = crate::model::StatsFilter::AllSessionsCombined;
-    //
-    //     // Press 'm' to set MainAgent filter
-    //     let key = KeyEvent::new(KeyCode::Char('m'), KeyModifiers::NONE);
-    //     let should_quit = app.handle_key(key);
-    //
-    //     assert!(!should_quit, "'m' should not trigger quit");
-    //     let session_id = crate::model::SessionId::new("test-session").unwrap();
-    //     assert_eq!(
-    //         app.app_state.stats_filter,
-    //         crate::model::StatsFilter::MainAgent(session_id),
-    //         "'m' should set stats filter to MainAgent"
-    //     );
-    // }
+    #[test]
+    fn handle_key_at_sets_main_agent_filter() {
+        let mut app = create_test_app();
+
+        // Set to Global initially
+        app.app_state.stats_filter = crate::model::StatsFilter::AllSessionsCombined;
+
+        // Press 'm' to set MainAgent filter
+        let key = KeyEvent::new(KeyCode::Char('m'), KeyModifiers::NONE);
+        let should_quit = app.handle_key(key);
+
+        assert!(!should_quit, "'m' should not trigger quit");
+        let session_id = crate::model::SessionId::new("test-session").unwrap();
+        assert_eq!(
+            app.app_state.stats_filter,
+            crate::model::StatsFilter::MainAgent(session_id),
+            "'m' should set stats filter to MainAgent"
+        );
+    }
 
     #[test]
     fn handle_key_hash_sets_subagent_filter_when_tab_selected() {
@@ -1788,7 +2075,7 @@ mod tests {
             EntryMetadata::default(),
         );
         app.app_state
-            .add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+            .add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
 
         // Select the subagent tab (tab 1 in unified tab model: tab 0 = main, tab 1+ = subagents)
         app.app_state.selected_conversation = ConversationSelection::Subagent(agent_id.clone());
@@ -2136,7 +2423,7 @@ mod tests {
                 EntryMetadata::default(),
             );
             app.app_state
-                .add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+                .add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
         }
 
         // Focus on Subagent pane and select main tab
@@ -2250,7 +2537,7 @@ mod tests {
             EntryMetadata::default(),
         );
 
-        ConversationEntry::Valid(Box::new(log_entry))
+        ConversationEntry::Valid(Arc::new(log_entry))
     }
 
     // ===== Batch rendering tests (60fps) =====
@@ -2343,7 +2630,7 @@ mod tests {
                 EntryMetadata::default(),
             );
             app.app_state
-                .add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+                .add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
         }
 
         // Focus on Subagent pane and select first tab (Main)
@@ -2391,7 +2678,7 @@ mod tests {
                 EntryMetadata::default(),
             );
             app.app_state
-                .add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+                .add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
         }
 
         // Focus on Subagent pane and select second tab (first subagent)
@@ -2437,7 +2724,7 @@ mod tests {
                 EntryMetadata::default(),
             );
             app.app_state
-                .add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+                .add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
         }
 
         // Focus on Subagent pane
@@ -2495,6 +2782,158 @@ mod tests {
         );
     }
 
+    #[test]
+    fn handle_key_enter_runs_search_in_background() {
+        use crate::state::SearchState;
+
+        let mut app = create_test_app();
+        app.handle_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        for ch in "TEST".chars() {
+            app.handle_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        // Submitting returns immediately with a running search
+        match &app.app_state.search {
+            SearchState::Active { progress, .. } => assert!(progress.is_running()),
+            other => panic!("Expected Active search, got {:?}", other),
+        }
+
+        app.wait_for_search_test();
+
+        match &app.app_state.search {
+            SearchState::Active {
+                matches, progress, ..
+            } => {
+                assert!(!progress.is_running(), "Search should have completed");
+                assert_eq!(matches.len(), 1, "'test' entry should match once");
+            }
+            other => panic!("Expected Active search, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn handle_key_esc_cancels_background_search() {
+        let mut app = create_test_app();
+        app.handle_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let generation = app.search_worker.generation();
+
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        assert!(matches!(
+            app.app_state.search,
+            crate::state::SearchState::Inactive
+        ));
+        assert!(
+            app.search_worker.generation() > generation,
+            "Esc should cancel the worker's search"
+        );
+        assert!(!app.poll_search(), "Cancelled results must not be applied");
+    }
+
+    #[test]
+    fn submitted_searches_are_recalled_with_up_and_persisted() {
+        use crate::state::SearchState;
+
+        let history_path = std::env::temp_dir().join("cclv_test_tui_search_history");
+        let _ = std::fs::remove_file(&history_path);
+
+        let mut app = create_test_app();
+        app.search_history_path = Some(history_path.clone());
+        for query in ["first", "second"] {
+            app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+            app.handle_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+            for ch in query.chars() {
+                app.handle_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+            }
+            app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        }
+
+        // Start a fresh query and walk back through history
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+
+        match &app.app_state.search {
+            SearchState::Typing { query, .. } => assert_eq!(query, "first"),
+            other => panic!("Expected Typing search, got {:?}", other),
+        }
+
+        let saved = crate::config::load_search_history(&history_path).unwrap();
+        assert_eq!(saved, vec!["first".to_string(), "second".to_string()]);
+        let _ = std::fs::remove_file(&history_path);
+    }
+
+    #[test]
+    fn ctrl_r_picks_saved_search_and_runs_it() {
+        use crate::state::SearchState;
+
+        let mut app = create_test_app();
+        app.app_state.saved_searches = vec![crate::state::SavedSearch::new("t", "TEST")];
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert!(app.app_state.saved_search_picker.is_visible());
+
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(!app.app_state.saved_search_picker.is_visible());
+        app.wait_for_search_test();
+
+        match &app.app_state.search {
+            SearchState::Active { query, matches, .. } => {
+                assert_eq!(query.as_str(), "TEST");
+                assert_eq!(matches.len(), 1, "'test' entry should match once");
+            }
+            other => panic!("Expected Active search, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn flushed_entries_are_searched_incrementally() {
+        use crate::state::SearchState;
+
+        let mut app = create_test_app();
+        app.handle_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        for ch in "needle".chars() {
+            app.handle_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        app.wait_for_search_test();
+
+        // Live entry arrives after the search completed
+        let entry = crate::model::LogEntry::new(
+            crate::model::EntryUuid::new("live-1").unwrap(),
+            None,
+            crate::model::SessionId::new("test-session").unwrap(),
+            None,
+            chrono::Utc::now(),
+            crate::model::EntryType::Assistant,
+            crate::model::Message::new(
+                crate::model::Role::Assistant,
+                crate::model::MessageContent::Text("found the needle".to_string()),
+            ),
+            crate::model::EntryMetadata::default(),
+        );
+        app.accumulate_pending_entries(vec![crate::model::ConversationEntry::Valid(Arc::new(
+            entry,
+        ))]);
+        app.flush_pending_entries();
+        let batches = app
+            .search_worker
+            .wait_complete(std::time::Duration::from_secs(10));
+        app.apply_search_batches(batches);
+
+        match &app.app_state.search {
+            SearchState::Active { matches, .. } => {
+                assert_eq!(matches.len(), 1);
+                assert_eq!(matches[0].entry_uuid.as_str(), "live-1");
+            }
+            other => panic!("Expected Active search, got {:?}", other),
+        }
+    }
+
     #[test]
     fn handle_key_h_scrolls_left() {
         let mut app = create_test_app();
@@ -2574,7 +3013,7 @@ mod tests {
             EntryMetadata::default(),
         );
         app.app_state
-            .add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+            .add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
 
         // Focus on Main pane and set focused message in view-state
         app.app_state.focus = FocusPane::Main;
@@ -2670,7 +3109,7 @@ mod tests {
             EntryMetadata::default(),
         );
         app.app_state
-            .add_entries(vec![ConversationEntry::Valid(Box::new(main_entry))]);
+            .add_entries(vec![ConversationEntry::Valid(Arc::new(main_entry))]);
 
         // Initialize main view HeightIndex
         if let Some(view) = app.app_state.main_conversation_view_mut() {
@@ -2697,7 +3136,7 @@ mod tests {
             EntryMetadata::default(),
         );
         app.app_state
-            .add_entries(vec![ConversationEntry::Valid(Box::new(sub_entry))]);
+            .add_entries(vec![ConversationEntry::Valid(Arc::new(sub_entry))]);
 
         // Focus on Subagent pane and set focused message in view-state
         // Unified tab model (FR-086): tab 0 = main, tab 1 = first subagent
@@ -2850,7 +3289,7 @@ mod tests {
             main_message,
             EntryMetadata::default(),
         );
-        app_state.add_entries(vec![ConversationEntry::Valid(Box::new(main_entry))]);
+        app_state.add_entries(vec![ConversationEntry::Valid(Arc::new(main_entry))]);
 
         // Add subagent entry with long text
         let agent_id = AgentId::new("test-agent").unwrap();
@@ -2867,7 +3306,7 @@ mod tests {
             sub_message,
             EntryMetadata::default(),
         );
-        app_state.add_entries(vec![ConversationEntry::Valid(Box::new(sub_entry))]);
+        app_state.add_entries(vec![ConversationEntry::Valid(Arc::new(sub_entry))]);
 
         let key_bindings = KeyBindings::default();
 
@@ -2880,6 +3319,8 @@ mod tests {
             pending_entries: Vec::new(),
             last_tab_area: None,
             last_main_area: None,
+            search_worker: SearchWorker::spawn(),
+            search_history_path: None,
         };
 
         // Initial relayout at 80 columns
@@ -2937,8 +3378,9 @@ mod tests {
         let args = CliArgs::new(
             THEME_MONOKAI.to_string(),
             false,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
+            Vec::new(),
         );
         assert_eq!(
             args.theme, THEME_MONOKAI,
@@ -2951,11 +3393,17 @@ mod tests {
         let args = CliArgs::new(
             THEME_SOLARIZED_DARK.to_string(),
             true,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
+            vec![crate::state::SavedSearch::new("panics", "panicked at")],
         );
         assert_eq!(args.theme, THEME_SOLARIZED_DARK, "Theme should be stored");
         assert!(args.stats, "Stats flag should be stored");
+        assert_eq!(
+            args.saved_searches,
+            vec![crate::state::SavedSearch::new("panics", "panicked at")],
+            "Saved searches should be stored"
+        );
     }
 
     #[test]
@@ -2972,8 +3420,9 @@ mod tests {
             let args = CliArgs::new(
                 theme.to_string(),
                 false,
-                200_000,
+                crate::model::ContextWindows::default(),
                 crate::model::PricingConfig::default(),
+                Vec::new(),
             );
             assert_eq!(args.theme, theme, "CliArgs should accept theme: {}", theme);
         }
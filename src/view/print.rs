@@ -0,0 +1,261 @@
+//! Non-interactive rendering for pipes.
+//!
+//! When stdout is not a terminal, the conversation is laid out at a fixed
+//! width by the same `compute_entry_lines` pipeline the main pane uses, and
+//! written out as text, either with the TUI's colors as ANSI escapes or plain.
+//! Each session starts with its continuous-view heading.
+
+use std::fmt::Write;
+
+use crossterm::style::{Attribute, Attributes, ContentStyle, StyledContent};
+use ratatui::style::Modifier;
+use ratatui::text::Line;
+
+use super::session_separator::session_heading;
+use crate::model::AgentId;
+use crate::state::{SearchState, WrapMode};
+use crate::view_state::log::LogViewState;
+
+/// Conversation tab to print from each session.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Tab {
+    /// The main agent.
+    #[default]
+    Main,
+    /// The subagent with this ID.
+    Subagent(AgentId),
+}
+
+impl Tab {
+    /// Parse `main` or a subagent ID.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        match raw {
+            "main" => Ok(Self::Main),
+            _ => AgentId::new(raw)
+                .map(Self::Subagent)
+                .map_err(|_| "expected main or a subagent ID".to_string()),
+        }
+    }
+}
+
+/// What to print and how.
+#[derive(Debug, Clone)]
+pub struct PrintOptions {
+    /// Columns to wrap to.
+    pub width: u16,
+    /// Render every entry expanded instead of collapsing long ones.
+    pub expand_all: bool,
+    /// Keep colors and text attributes as ANSI escapes.
+    pub ansi: bool,
+    /// Only this session (0-based); all sessions when `None`.
+    pub session: Option<usize>,
+    /// Tab to print from each session.
+    pub tab: Tab,
+}
+
+/// Render the selected sessions and tab as text, one line per row.
+///
+/// Sessions without the selected subagent are skipped; it is an error if no
+/// session has it, or if the selected session does not exist.
+pub fn print_log(log: &mut LogViewState, options: &PrintOptions) -> Result<String, String> {
+    let count = log.session_count();
+    let indices: Vec<usize> = match options.session {
+        Some(index) if index >= count => {
+            return Err(format!(
+                "session {} not found (the log has {})",
+                index + 1,
+                count
+            ));
+        }
+        Some(index) => vec![index],
+        None => (0..count).collect(),
+    };
+
+    let mut lines: Vec<Line<'static>> = Vec::new();
+    for index in indices {
+        let Some(session) = log.get_session_mut(index) else {
+            continue;
+        };
+        let heading = session_heading(session, index, count, false, options.width as usize);
+        let conversation = match &options.tab {
+            Tab::Main => session.main_mut(),
+            Tab::Subagent(id) => match session.get_subagent_mut(id) {
+                Some(conversation) => conversation,
+                None => continue,
+            },
+        };
+        if options.expand_all {
+            conversation.set_all_expanded(true);
+        }
+        conversation.relayout(options.width, WrapMode::default(), &SearchState::Inactive);
+
+        lines.push(heading);
+        for entry in conversation.entries() {
+            lines.extend(entry.rendered_lines().iter().cloned());
+        }
+    }
+
+    if let Tab::Subagent(id) = &options.tab {
+        if lines.is_empty() && count > 0 {
+            return Err(format!("subagent {} not found", id));
+        }
+    }
+
+    let mut out = String::new();
+    for line in &lines {
+        if options.ansi {
+            write_ansi(&mut out, line);
+        } else {
+            let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            out.push_str(text.trim_end());
+        }
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Append `line` with each span's style as ANSI escapes.
+fn write_ansi(out: &mut String, line: &Line) {
+    for span in &line.spans {
+        let style = line.style.patch(span.style);
+        let content_style = ContentStyle {
+            foreground_color: style.fg.map(Into::into),
+            background_color: style.bg.map(Into::into),
+            underline_color: None,
+            attributes: attributes(style.add_modifier),
+        };
+        let _ = write!(
+            out,
+            "{}",
+            StyledContent::new(content_style, span.content.as_ref())
+        );
+    }
+}
+
+/// Text attributes of a ratatui modifier.
+fn attributes(modifier: Modifier) -> Attributes {
+    [
+        (Modifier::BOLD, Attribute::Bold),
+        (Modifier::DIM, Attribute::Dim),
+        (Modifier::ITALIC, Attribute::Italic),
+        (Modifier::UNDERLINED, Attribute::Underlined),
+        (Modifier::SLOW_BLINK, Attribute::SlowBlink),
+        (Modifier::RAPID_BLINK, Attribute::RapidBlink),
+        (Modifier::REVERSED, Attribute::Reverse),
+        (Modifier::HIDDEN, Attribute::Hidden),
+        (Modifier::CROSSED_OUT, Attribute::CrossedOut),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| modifier.contains(*flag))
+    .fold(Attributes::default(), |attributes, (_, attribute)| {
+        attributes | attribute
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::log_view_from_entries;
+    use crate::model::{EntryMetadata, EntryType, EntryUuid, LogEntry, Message};
+    use crate::model::{MessageContent, Role, SessionId};
+    use chrono::{TimeZone, Utc};
+    use unicode_width::UnicodeWidthStr;
+
+    fn entry(uuid: &str, agent: Option<&str>, text: &str) -> LogEntry {
+        LogEntry::new(
+            EntryUuid::new(uuid).unwrap(),
+            None,
+            SessionId::new("s1").unwrap(),
+            agent.map(|a| AgentId::new(a).unwrap()),
+            Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+            EntryType::Assistant,
+            Message::new(Role::Assistant, MessageContent::Text(text.to_string())),
+            EntryMetadata::default(),
+        )
+    }
+
+    fn log() -> LogViewState {
+        let long = (1..=12)
+            .map(|i| format!("line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        log_view_from_entries(vec![
+            entry("a1", None, &long),
+            entry("s1", Some("agent-1"), "Subagent **reply**"),
+        ])
+    }
+
+    fn options() -> PrintOptions {
+        PrintOptions {
+            width: 60,
+            expand_all: false,
+            ansi: false,
+            session: None,
+            tab: Tab::Main,
+        }
+    }
+
+    #[test]
+    fn plain_text_follows_the_main_pane() {
+        let text = print_log(&mut log(), &options()).unwrap();
+
+        assert!(
+            text.starts_with("▾ Session 1/1 · 2025-01-15 14:00"),
+            "{}",
+            text
+        );
+        assert!(text.contains("line 3"));
+        assert!(!text.contains("line 12"), "long entries stay collapsed");
+        assert!(text.contains("more lines"));
+        assert!(!text.contains('\x1b'));
+        assert!(text.lines().all(|l| l.width() <= 60));
+
+        let expanded = print_log(
+            &mut log(),
+            &PrintOptions {
+                expand_all: true,
+                ..options()
+            },
+        )
+        .unwrap();
+        assert!(expanded.contains("line 12"));
+    }
+
+    #[test]
+    fn ansi_keeps_styles_and_tabs_select_subagents() {
+        let options = PrintOptions {
+            ansi: true,
+            tab: Tab::parse("agent-1").unwrap(),
+            ..options()
+        };
+
+        let text = print_log(&mut log(), &options).unwrap();
+
+        assert!(text.contains("\x1b["));
+        assert!(text.contains("reply"));
+        assert!(!text.contains("line 1"));
+    }
+
+    #[test]
+    fn missing_session_or_subagent_is_an_error() {
+        let missing_session = PrintOptions {
+            session: Some(1),
+            ..options()
+        };
+        assert_eq!(
+            print_log(&mut log(), &missing_session).unwrap_err(),
+            "session 2 not found (the log has 1)"
+        );
+
+        let missing_agent = PrintOptions {
+            tab: Tab::parse("agent-9").unwrap(),
+            ..options()
+        };
+        assert_eq!(
+            print_log(&mut log(), &missing_agent).unwrap_err(),
+            "subagent agent-9 not found"
+        );
+        assert_eq!(Tab::parse("main"), Ok(Tab::Main));
+        assert!(Tab::parse("").is_err());
+    }
+}
@@ -0,0 +1,180 @@
+//! Saved search picker rendering.
+
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::state::AppState;
+
+/// Width of the picker in columns.
+const PICKER_WIDTH: u16 = 60;
+
+/// Render the saved search picker overlay.
+///
+/// Lists each saved search as `name  query`, sorted by name, with the
+/// selected row highlighted. Shows a hint when no `[searches]` table is
+/// configured.
+///
+/// Only renders when `state.saved_search_picker.is_visible()` is true.
+pub fn render_saved_search_picker(frame: &mut Frame, state: &AppState) {
+    if !state.saved_search_picker.is_visible() {
+        return;
+    }
+
+    let area = frame.area();
+    let row_count = state.saved_searches.len().max(1);
+    let picker_area = centered_rect(PICKER_WIDTH, row_count, area);
+
+    // Clear the background for overlay effect
+    frame.render_widget(Clear, picker_area);
+
+    let name_width = state
+        .saved_searches
+        .iter()
+        .map(|s| s.name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let items: Vec<ListItem> = if state.saved_searches.is_empty() {
+        vec![ListItem::new(Line::from(Span::styled(
+            "No saved searches. Add a [searches] table to config.toml",
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::ITALIC),
+        )))]
+    } else {
+        state
+            .saved_searches
+            .iter()
+            .map(|saved| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!(" {:<width$}  ", saved.name, width = name_width),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(saved.query.clone()),
+                ]))
+            })
+            .collect()
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(
+                    Line::from(vec![Span::styled(
+                        " Saved Searches ",
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    )])
+                    .alignment(Alignment::Center),
+                )
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .border_style(Style::default().fg(Color::White))
+                .style(Style::default().bg(Color::DarkGray)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Cyan)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let selected = if state.saved_searches.is_empty() {
+        None
+    } else {
+        Some(state.saved_search_picker.selected_index())
+    };
+    let mut list_state = ListState::default().with_selected(selected);
+    frame.render_stateful_widget(list, picker_area, &mut list_state);
+
+    // Footer with keybinding hints
+    let footer_area = Rect {
+        x: picker_area.x + 1,
+        y: picker_area.y + picker_area.height.saturating_sub(2),
+        width: picker_area.width.saturating_sub(2),
+        height: 1,
+    };
+    let footer = Paragraph::new("↑/↓: Navigate  Enter: Search  Esc: Cancel")
+        .style(Style::default().fg(Color::Gray).add_modifier(Modifier::DIM))
+        .alignment(Alignment::Center);
+    frame.render_widget(footer, footer_area);
+}
+
+/// Calculate centered rect with fixed width.
+///
+/// Height is `row_count + 4` (borders, spacer, footer), capped to the terminal.
+fn centered_rect(width_cols: u16, row_count: usize, area: Rect) -> Rect {
+    let popup_width = width_cols.min(area.width);
+    let popup_height = (row_count as u16 + 4).min(area.height.saturating_sub(4));
+
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    Rect {
+        x: area.x + popup_x,
+        y: area.y + popup_y,
+        width: popup_width,
+        height: popup_height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::SavedSearch;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn render_to_string(state: &AppState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal
+            .draw(|frame| render_saved_search_picker(frame, state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn picker_not_rendered_when_closed() {
+        let mut state = AppState::new();
+        state.saved_searches = vec![SavedSearch::new("panics", "panicked at")];
+
+        let output = render_to_string(&state);
+
+        assert!(!output.contains("Saved Searches"));
+    }
+
+    #[test]
+    fn picker_lists_saved_searches() {
+        let mut state = AppState::new();
+        state.saved_searches = vec![
+            SavedSearch::new("panics", "panicked at"),
+            SavedSearch::new("todos", "TODO"),
+        ];
+        state.saved_search_picker.open();
+
+        let output = render_to_string(&state);
+
+        assert!(output.contains("Saved Searches"));
+        assert!(output.contains("panics  panicked at"));
+        assert!(output.contains("todos   TODO"));
+    }
+
+    #[test]
+    fn picker_shows_hint_when_no_saved_searches() {
+        let mut state = AppState::new();
+        state.saved_search_picker.open();
+
+        let output = render_to_string(&state);
+
+        assert!(output.contains("No saved searches"));
+    }
+}
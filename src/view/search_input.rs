@@ -64,12 +64,27 @@ impl Widget for SearchInput<'_> {
 
                 paragraph.render(area, buf);
             }
-            SearchState::Active { query, .. } => {
-                // Show active search (read-only)
+            SearchState::Active {
+                query,
+                matches,
+                progress,
+                ..
+            } => {
+                // Show active search (read-only), with progress while the
+                // background worker is still scanning
+                let title = if progress.is_running() {
+                    format!(
+                        "Search (searching… {}%, {} matches, Esc to cancel)",
+                        progress.percent(),
+                        matches.len()
+                    )
+                } else {
+                    format!("Search (active, {} matches)", matches.len())
+                };
                 let paragraph = Paragraph::new(Line::from(query.as_str())).block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title("Search (active)")
+                        .title(title)
                         .style(Style::default().bg(Color::Blue)),
                 );
 
@@ -85,6 +100,7 @@ impl Widget for SearchInput<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::SearchProgress;
     use ratatui::Terminal;
     use ratatui::backend::TestBackend;
 
@@ -116,6 +132,7 @@ mod tests {
             query,
             matches: vec![],
             current_match: 0,
+            progress: SearchProgress::Complete,
         };
 
         terminal
@@ -143,4 +160,32 @@ mod tests {
 
         // Should render without panic even when Inactive
     }
+
+    #[test]
+    fn search_input_shows_progress_while_running() {
+        let mut terminal = Terminal::new(TestBackend::new(80, 3)).unwrap();
+
+        let query = crate::state::SearchQuery::new("needle").unwrap();
+        let state = SearchState::Active {
+            query,
+            matches: vec![],
+            current_match: 0,
+            progress: SearchProgress::Running {
+                searched: 25,
+                total: 100,
+            },
+        };
+
+        terminal
+            .draw(|frame| {
+                let widget = SearchInput::new(&state);
+                frame.render_widget(widget, frame.area());
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let top: String = (0..80).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert!(top.contains("searching… 25%"), "Got: {}", top);
+        assert!(top.contains("0 matches"), "Got: {}", top);
+    }
 }
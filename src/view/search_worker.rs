@@ -0,0 +1,474 @@
+//! Background search execution (impure shell).
+//!
+//! Searching hundreds of megabytes of tool output on the UI thread freezes the
+//! TUI, so `SearchWorker` runs the pure matching logic from `state::search` on a
+//! dedicated thread and streams results back over a channel.
+//!
+//! # Protocol
+//!
+//! - The shell feeds every parsed entry to the worker as a `SearchDocument`
+//!   (`append`). Documents share the view-state's entries through `Arc`, so
+//!   the worker keeps them for the lifetime of the app without copying text.
+//! - `start` begins a search and returns its generation number. Starting a new
+//!   search or calling `cancel` bumps the generation; the worker checks it between
+//!   documents and abandons stale scans immediately.
+//! - Results arrive in batches via `poll` (non-blocking), tagged with the
+//!   generation that produced them so late batches from cancelled searches can be
+//!   discarded.
+//! - After a search completes it stays attached: documents appended later
+//!   (live tailing) are scanned incrementally instead of re-scanning everything.
+
+use crate::model::SessionId;
+use crate::state::search::{SearchDocument, SearchMatch, SearchProgress, SearchQuery};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, Sender, TryRecvError, channel};
+use std::thread::{self, JoinHandle};
+
+/// Maximum number of documents scanned between progress reports.
+///
+/// Keeps the UI updating while a scan runs and bounds how long the worker goes
+/// without checking for new commands.
+const BATCH_SIZE: usize = 256;
+
+/// Command sent from the UI thread to the worker.
+enum WorkerCommand {
+    /// New documents to add to the corpus.
+    Append(Vec<SearchDocument>),
+    /// Begin a search restricted to one session.
+    Search {
+        generation: u64,
+        query_lower: String,
+        session_id: SessionId,
+    },
+}
+
+/// Batch of results sent from the worker to the UI thread.
+#[derive(Debug, Clone)]
+pub struct SearchBatch {
+    /// Generation of the search that produced this batch.
+    pub generation: u64,
+    /// Matches found since the previous batch, in document order.
+    pub matches: Vec<SearchMatch>,
+    /// Progress after this batch.
+    pub progress: SearchProgress,
+}
+
+/// Search currently attached to the worker.
+struct ActiveScan {
+    generation: u64,
+    query_lower: String,
+    session_id: SessionId,
+    /// Index of the next document to scan.
+    next: usize,
+    /// Index of the first document scanned by this pass (for progress reporting).
+    pass_start: usize,
+    /// Whether the UI still needs a batch for this pass (even an empty one).
+    unreported: bool,
+}
+
+/// Handle to the background search thread.
+///
+/// Dropping the handle closes the command channel, which stops the thread.
+#[derive(Debug)]
+pub struct SearchWorker {
+    tx: Sender<WorkerCommand>,
+    rx: Receiver<SearchBatch>,
+    generation: Arc<AtomicU64>,
+    _worker_thread: JoinHandle<()>,
+}
+
+impl std::fmt::Debug for WorkerCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkerCommand::Append(docs) => write!(f, "Append({} docs)", docs.len()),
+            WorkerCommand::Search { generation, .. } => write!(f, "Search(gen {})", generation),
+        }
+    }
+}
+
+impl Default for SearchWorker {
+    fn default() -> Self {
+        Self::spawn()
+    }
+}
+
+impl SearchWorker {
+    /// Spawn the worker thread with an empty corpus.
+    pub fn spawn() -> Self {
+        let (cmd_tx, cmd_rx) = channel();
+        let (batch_tx, batch_rx) = channel();
+        let generation = Arc::new(AtomicU64::new(0));
+        let worker_generation = Arc::clone(&generation);
+
+        let worker_thread = thread::spawn(move || {
+            run_worker(cmd_rx, batch_tx, worker_generation);
+        });
+
+        Self {
+            tx: cmd_tx,
+            rx: batch_rx,
+            generation,
+            _worker_thread: worker_thread,
+        }
+    }
+
+    /// Add documents to the corpus.
+    ///
+    /// If a search is attached, the new documents are scanned against it.
+    pub fn append(&self, documents: Vec<SearchDocument>) {
+        if documents.is_empty() {
+            return;
+        }
+        let _ = self.tx.send(WorkerCommand::Append(documents));
+    }
+
+    /// Start searching `session_id` for `query`, cancelling any previous search.
+    ///
+    /// Returns the generation number tagged on this search's batches.
+    pub fn start(&self, query: &SearchQuery, session_id: SessionId) -> u64 {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let _ = self.tx.send(WorkerCommand::Search {
+            generation,
+            query_lower: query.as_str().to_lowercase(),
+            session_id,
+        });
+        generation
+    }
+
+    /// Cancel the running search (if any). Pending batches become stale.
+    pub fn cancel(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Generation of the most recently started (or cancelled) search.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// Drain all batches produced so far. Non-blocking.
+    ///
+    /// Batches from cancelled searches are filtered out.
+    pub fn poll(&self) -> Vec<SearchBatch> {
+        let current = self.generation();
+        let mut batches = Vec::new();
+        while let Ok(batch) = self.rx.try_recv() {
+            if batch.generation == current {
+                batches.push(batch);
+            }
+        }
+        batches
+    }
+
+    /// Block until the current search reports completion or `timeout` elapses.
+    ///
+    /// Returns every batch received in the meantime. Intended for tests and
+    /// headless callers that want synchronous results.
+    pub fn wait_complete(&self, timeout: std::time::Duration) -> Vec<SearchBatch> {
+        let deadline = std::time::Instant::now() + timeout;
+        let current = self.generation();
+        let mut batches = Vec::new();
+        loop {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            match self.rx.recv_timeout(remaining) {
+                Ok(batch) if batch.generation == current => {
+                    let done = !batch.progress.is_running();
+                    batches.push(batch);
+                    if done {
+                        return batches;
+                    }
+                }
+                Ok(_) => {} // Stale batch
+                Err(_) => return batches,
+            }
+        }
+    }
+}
+
+/// Worker thread main loop.
+fn run_worker(rx: Receiver<WorkerCommand>, tx: Sender<SearchBatch>, generation: Arc<AtomicU64>) {
+    let mut documents: Vec<SearchDocument> = Vec::new();
+    let mut active: Option<ActiveScan> = None;
+
+    loop {
+        // Drop the scan if it was cancelled or superseded
+        if active
+            .as_ref()
+            .is_some_and(|scan| scan.generation != generation.load(Ordering::SeqCst))
+        {
+            active = None;
+        }
+
+        let has_work = active
+            .as_ref()
+            .is_some_and(|scan| scan.next < documents.len() || scan.unreported);
+
+        // Block when idle; otherwise just drain pending commands
+        let command = if has_work {
+            match rx.try_recv() {
+                Ok(cmd) => Some(cmd),
+                Err(TryRecvError::Empty) => None,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        } else {
+            match rx.recv() {
+                Ok(cmd) => Some(cmd),
+                Err(_) => return, // UI dropped the handle
+            }
+        };
+
+        if let Some(command) = command {
+            match command {
+                WorkerCommand::Append(docs) => {
+                    if let Some(scan) = active.as_mut() {
+                        if scan.next >= documents.len() {
+                            // Completed scan picks up again at the new documents
+                            scan.pass_start = documents.len();
+                        }
+                        scan.unreported = true;
+                    }
+                    documents.extend(docs);
+                }
+                WorkerCommand::Search {
+                    generation: search_generation,
+                    query_lower,
+                    session_id,
+                } => {
+                    active = Some(ActiveScan {
+                        generation: search_generation,
+                        query_lower,
+                        session_id,
+                        next: 0,
+                        pass_start: 0,
+                        unreported: true,
+                    });
+                }
+            }
+            continue;
+        }
+
+        let Some(scan) = active.as_mut() else {
+            continue;
+        };
+
+        let end = (scan.next + BATCH_SIZE).min(documents.len());
+        let mut matches = Vec::new();
+        let mut cancelled = false;
+        for doc in &documents[scan.next..end] {
+            if generation.load(Ordering::Relaxed) != scan.generation {
+                cancelled = true;
+                break;
+            }
+            if *doc.session_id() == scan.session_id {
+                doc.find_matches(&scan.query_lower, &mut matches);
+            }
+        }
+        if cancelled {
+            active = None;
+            continue;
+        }
+        scan.next = end;
+        scan.unreported = false;
+
+        let progress = if scan.next < documents.len() {
+            SearchProgress::Running {
+                searched: scan.next - scan.pass_start,
+                total: documents.len() - scan.pass_start,
+            }
+        } else {
+            SearchProgress::Complete
+        };
+
+        let batch = SearchBatch {
+            generation: scan.generation,
+            matches,
+            progress,
+        };
+        if tx.send(batch).is_err() {
+            return;
+        }
+    }
+}
+
+// ===== Tests =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        AgentId, ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry, Message,
+        MessageContent, Role,
+    };
+    use std::time::Duration;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn make_entry(uuid: &str, session: &str, agent: Option<&str>, text: &str) -> LogEntry {
+        LogEntry::new(
+            EntryUuid::new(uuid).unwrap(),
+            None,
+            SessionId::new(session).unwrap(),
+            agent.map(|a| AgentId::new(a).unwrap()),
+            chrono::Utc::now(),
+            EntryType::Assistant,
+            Message::new(Role::Assistant, MessageContent::Text(text.to_string())),
+            EntryMetadata::default(),
+        )
+    }
+
+    fn docs(entries: &[LogEntry]) -> Vec<SearchDocument> {
+        entries
+            .iter()
+            .map(|entry| SearchDocument::from_entry(&Arc::new(entry.clone())))
+            .collect()
+    }
+
+    fn session(id: &str) -> SessionId {
+        SessionId::new(id).unwrap()
+    }
+
+    fn all_matches(batches: &[SearchBatch]) -> Vec<SearchMatch> {
+        batches.iter().flat_map(|b| b.matches.clone()).collect()
+    }
+
+    #[test]
+    fn search_finds_matches_and_completes() {
+        let worker = SearchWorker::spawn();
+        worker.append(docs(&[
+            make_entry("e1", "s1", None, "an error here"),
+            make_entry("e2", "s1", Some("agent-1"), "another ERROR"),
+            make_entry("e3", "s1", None, "nothing"),
+        ]));
+
+        let query = SearchQuery::new("error").unwrap();
+        worker.start(&query, session("s1"));
+        let batches = worker.wait_complete(TIMEOUT);
+
+        assert_eq!(
+            batches.last().map(|b| b.progress),
+            Some(SearchProgress::Complete)
+        );
+        let matches = all_matches(&batches);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].entry_uuid.as_str(), "e1");
+        assert_eq!(matches[1].agent_id.as_ref().unwrap().as_str(), "agent-1");
+    }
+
+    #[test]
+    fn search_is_restricted_to_session() {
+        let worker = SearchWorker::spawn();
+        worker.append(docs(&[
+            make_entry("e1", "s1", None, "needle"),
+            make_entry("e2", "s2", None, "needle"),
+        ]));
+
+        worker.start(&SearchQuery::new("needle").unwrap(), session("s2"));
+        let matches = all_matches(&worker.wait_complete(TIMEOUT));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].entry_uuid.as_str(), "e2");
+    }
+
+    #[test]
+    fn worker_matches_execute_search() {
+        let entries: Vec<LogEntry> = (0..600)
+            .map(|i| make_entry(&format!("e{}", i), "s1", None, &format!("line {} foo", i)))
+            .collect();
+        let mut log_view = crate::view_state::log::LogViewState::new();
+        for entry in &entries {
+            log_view.add_entry(ConversationEntry::Valid(Arc::new(entry.clone())), None);
+        }
+        let query = SearchQuery::new("foo").unwrap();
+        let expected = crate::state::execute_search(log_view.get_session(0).unwrap(), &query);
+
+        let worker = SearchWorker::spawn();
+        worker.append(docs(&entries));
+        worker.start(&query, session("s1"));
+        let batches = worker.wait_complete(TIMEOUT);
+        let matches = all_matches(&batches);
+
+        assert!(
+            batches.len() > 1,
+            "600 docs should stream in several batches"
+        );
+        assert_eq!(matches.len(), expected.len());
+        for (got, want) in matches.iter().zip(&expected) {
+            assert_eq!(got.entry_uuid, want.entry_uuid);
+            assert_eq!(got.char_offset, want.char_offset);
+        }
+    }
+
+    #[test]
+    fn appended_documents_are_searched_incrementally() {
+        let worker = SearchWorker::spawn();
+        worker.append(docs(&[make_entry("e1", "s1", None, "needle")]));
+        worker.start(&SearchQuery::new("needle").unwrap(), session("s1"));
+        assert_eq!(all_matches(&worker.wait_complete(TIMEOUT)).len(), 1);
+
+        // Live-appended entry: only the new document is reported
+        worker.append(docs(&[make_entry("e2", "s1", None, "another needle")]));
+        let batches = worker.wait_complete(TIMEOUT);
+        let matches = all_matches(&batches);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].entry_uuid.as_str(), "e2");
+    }
+
+    #[test]
+    fn documents_share_entries_instead_of_copying_text() {
+        let entry = Arc::new(make_entry("e1", "s1", None, "needle"));
+        let documents =
+            SearchDocument::from_entries(&[ConversationEntry::Valid(Arc::clone(&entry))]);
+
+        assert_eq!(Arc::strong_count(&entry), 2, "Document shares the entry");
+        let worker = SearchWorker::spawn();
+        worker.append(documents);
+        worker.start(&SearchQuery::new("needle").unwrap(), session("s1"));
+        assert_eq!(all_matches(&worker.wait_complete(TIMEOUT)).len(), 1);
+    }
+
+    #[test]
+    fn search_over_empty_corpus_completes() {
+        let worker = SearchWorker::spawn();
+        worker.start(&SearchQuery::new("needle").unwrap(), session("s1"));
+        let batches = worker.wait_complete(TIMEOUT);
+
+        assert_eq!(batches.len(), 1);
+        assert!(batches[0].matches.is_empty());
+        assert_eq!(batches[0].progress, SearchProgress::Complete);
+    }
+
+    #[test]
+    fn cancel_discards_pending_results() {
+        let worker = SearchWorker::spawn();
+        worker.append(docs(&[make_entry("e1", "s1", None, "needle")]));
+        worker.start(&SearchQuery::new("needle").unwrap(), session("s1"));
+        worker.cancel();
+
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(worker.poll().is_empty(), "Cancelled search yields nothing");
+
+        // Appending after cancel does not resurrect the old search
+        worker.append(docs(&[make_entry("e2", "s1", None, "needle")]));
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(worker.poll().is_empty());
+    }
+
+    #[test]
+    fn new_search_supersedes_previous_generation() {
+        let worker = SearchWorker::spawn();
+        worker.append(docs(&[
+            make_entry("e1", "s1", None, "alpha"),
+            make_entry("e2", "s1", None, "beta"),
+        ]));
+        let first = worker.start(&SearchQuery::new("alpha").unwrap(), session("s1"));
+        let second = worker.start(&SearchQuery::new("beta").unwrap(), session("s1"));
+        assert!(second > first);
+
+        let batches = worker.wait_complete(TIMEOUT);
+        assert!(batches.iter().all(|b| b.generation == second));
+        let matches = all_matches(&batches);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].entry_uuid.as_str(), "e2");
+    }
+}
@@ -0,0 +1,281 @@
+//! Session header shown between the tab bar and the conversation.
+//!
+//! Collapsed, the header is one summary line: model, Claude Code version,
+//! cwd and branch, permission mode, and MCP/tool/agent/skill counts.
+//! Expanded (`i`), it lists MCP server statuses, tools, agents and skills,
+//! wrapping long lists to the available width.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::model::SessionInfo;
+
+/// Style of the model name.
+const MODEL_STYLE: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+
+/// Style of labels and separators.
+const LABEL_STYLE: Style = Style::new().fg(Color::DarkGray);
+
+/// Style of values.
+const VALUE_STYLE: Style = Style::new().fg(Color::Gray);
+
+/// Style of a connected MCP server.
+const CONNECTED_STYLE: Style = Style::new().fg(Color::Green);
+
+/// Style of an MCP server in any other state.
+const DISCONNECTED_STYLE: Style = Style::new().fg(Color::Red);
+
+/// Separator between summary fields.
+const SEPARATOR: &str = " · ";
+
+/// Header lines for `info`; empty when nothing is known about the session.
+pub(super) fn header_lines(info: &SessionInfo, expanded: bool, width: u16) -> Vec<Line<'static>> {
+    if info.is_empty() {
+        return Vec::new();
+    }
+    if !expanded {
+        return vec![summary_line(info)];
+    }
+
+    let mut lines = vec![title_line(info), location_line(info)];
+    if !info.mcp_servers.is_empty() {
+        let servers: Vec<Span<'static>> = info
+            .mcp_servers
+            .iter()
+            .map(|server| {
+                let status = server.status.as_deref().unwrap_or("unknown");
+                let style = if status == "connected" {
+                    CONNECTED_STYLE
+                } else {
+                    DISCONNECTED_STYLE
+                };
+                Span::styled(format!("{} ({})", server.name, status), style)
+            })
+            .collect();
+        lines.extend(wrapped_list("MCP", servers, width));
+    }
+    for (label, items) in [
+        ("Tools", &info.tools),
+        ("Agents", &info.agents),
+        ("Skills", &info.skills),
+    ] {
+        if !items.is_empty() {
+            let items = items
+                .iter()
+                .map(|item| Span::styled(item.clone(), VALUE_STYLE))
+                .collect();
+            lines.extend(wrapped_list(label, items, width));
+        }
+    }
+    lines
+}
+
+/// Collapsed header: `▸ Opus · v2.0.31 · /repo (main) · acceptEdits · MCP 1/2 · 12 tools`.
+fn summary_line(info: &SessionInfo) -> Line<'static> {
+    let mut fields = Vec::new();
+    if let Some(version) = &info.version {
+        fields.push(format!("v{}", version));
+    }
+    if let Some(location) = info.location() {
+        fields.push(location);
+    }
+    if let Some(mode) = &info.permission_mode {
+        fields.push(mode.clone());
+    }
+    if !info.mcp_servers.is_empty() {
+        fields.push(format!(
+            "MCP {}/{}",
+            info.connected_mcp_servers(),
+            info.mcp_servers.len()
+        ));
+    }
+    for (noun, count) in [
+        ("tool", info.tools.len()),
+        ("agent", info.agents.len()),
+        ("skill", info.skills.len()),
+    ] {
+        if count > 0 {
+            let plural = if count == 1 { "" } else { "s" };
+            fields.push(format!("{} {}{}", count, noun, plural));
+        }
+    }
+
+    let mut spans = vec![
+        Span::styled("▸ ", LABEL_STYLE),
+        Span::styled(model_name(info), MODEL_STYLE),
+    ];
+    for field in fields {
+        spans.push(Span::styled(SEPARATOR, LABEL_STYLE));
+        spans.push(Span::styled(field, VALUE_STYLE));
+    }
+    spans.push(Span::styled("  (i: details)", LABEL_STYLE));
+    Line::from(spans)
+}
+
+/// Expanded first line: model name and ID, and Claude Code version.
+fn title_line(info: &SessionInfo) -> Line<'static> {
+    let mut spans = vec![
+        Span::styled("▾ ", LABEL_STYLE),
+        Span::styled(model_name(info), MODEL_STYLE),
+    ];
+    if let Some(model) = &info.model {
+        if model.id() != model.display_name() {
+            spans.push(Span::styled(format!(" {}", model.id()), LABEL_STYLE));
+        }
+    }
+    if let Some(version) = &info.version {
+        spans.push(Span::styled(SEPARATOR, LABEL_STYLE));
+        spans.push(Span::styled(
+            format!("Claude Code v{}", version),
+            VALUE_STYLE,
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Expanded second line: cwd, branch and permission mode.
+fn location_line(info: &SessionInfo) -> Line<'static> {
+    let mut spans = Vec::new();
+    let fields = [
+        (
+            "cwd",
+            info.cwd.as_ref().map(|cwd| cwd.display().to_string()),
+        ),
+        ("branch", info.git_branch.clone()),
+        ("permissions", info.permission_mode.clone()),
+    ];
+    for (label, value) in fields {
+        spans.push(Span::styled(format!("  {}: ", label), LABEL_STYLE));
+        spans.push(Span::styled(
+            value.unwrap_or_else(|| "-".to_string()),
+            VALUE_STYLE,
+        ));
+    }
+    Line::from(spans)
+}
+
+/// `  Label: a, b, c` wrapped to `width`, continuation lines indented under the items.
+fn wrapped_list(label: &str, items: Vec<Span<'static>>, width: u16) -> Vec<Line<'static>> {
+    let prefix = format!("  {}: ", label);
+    let indent = " ".repeat(prefix.chars().count());
+    let width = width as usize;
+
+    let mut lines = Vec::new();
+    let mut spans = vec![Span::styled(prefix.clone(), LABEL_STYLE)];
+    let mut used = prefix.chars().count();
+    let count = items.len();
+    for (index, item) in items.into_iter().enumerate() {
+        let separator = if index + 1 < count { ", " } else { "" };
+        let item_width = item.content.chars().count() + separator.len();
+        if used + item_width > width && spans.len() > 1 {
+            lines.push(Line::from(std::mem::take(&mut spans)));
+            spans.push(Span::raw(indent.clone()));
+            used = indent.len();
+        }
+        used += item_width;
+        spans.push(item);
+        if !separator.is_empty() {
+            spans.push(Span::styled(separator, LABEL_STYLE));
+        }
+    }
+    lines.push(Line::from(spans));
+    lines
+}
+
+/// Model display name, or `unknown model`.
+fn model_name(info: &SessionInfo) -> String {
+    info.model.as_ref().map_or_else(
+        || "unknown model".to_string(),
+        |m| m.display_name().to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{EntryMetadata, EntryType, EntryUuid, LogEntry, McpServer, Message};
+    use crate::model::{MessageContent, Role, SessionId, SystemMetadata};
+    use chrono::{TimeZone, Utc};
+    use std::path::PathBuf;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn info() -> SessionInfo {
+        let init = LogEntry::new_with_system_metadata(
+            EntryUuid::new("init").unwrap(),
+            None,
+            SessionId::new("s1").unwrap(),
+            None,
+            Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap(),
+            EntryType::System,
+            Message::new(Role::Assistant, MessageContent::Text(String::new())),
+            EntryMetadata {
+                git_branch: Some("main".to_string()),
+                version: Some("2.0.31".to_string()),
+                ..EntryMetadata::default()
+            },
+            Some(SystemMetadata {
+                subtype: "init".to_string(),
+                cwd: Some(PathBuf::from("/work/repo")),
+                model: Some("claude-opus-4-5-20251101".to_string()),
+                tools: vec!["Read".to_string(), "Write".to_string(), "Bash".to_string()],
+                agents: vec![],
+                skills: vec!["commit".to_string()],
+                permission_mode: Some("acceptEdits".to_string()),
+                mcp_servers: vec![
+                    McpServer {
+                        name: "github".to_string(),
+                        status: Some("connected".to_string()),
+                    },
+                    McpServer {
+                        name: "linear".to_string(),
+                        status: Some("failed".to_string()),
+                    },
+                ],
+                version: None,
+                compact: None,
+            }),
+        );
+        SessionInfo::from_entries([&init])
+    }
+
+    #[test]
+    fn collapsed_header_is_one_summary_line() {
+        let lines = header_lines(&info(), false, 120);
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(
+            text(&lines[0]),
+            "▸ Opus · v2.0.31 · /work/repo (main) · acceptEdits · MCP 1/2 · 3 tools · 1 skill  (i: details)"
+        );
+    }
+
+    #[test]
+    fn expanded_header_lists_details() {
+        let lines = header_lines(&info(), true, 120);
+        let texts: Vec<String> = lines.iter().map(text).collect();
+
+        assert_eq!(
+            texts,
+            vec![
+                "▾ Opus claude-opus-4-5-20251101 · Claude Code v2.0.31",
+                "  cwd: /work/repo  branch: main  permissions: acceptEdits",
+                "  MCP: github (connected), linear (failed)",
+                "  Tools: Read, Write, Bash",
+                "  Skills: commit",
+            ]
+        );
+        assert_eq!(lines[2].spans[3].style, DISCONNECTED_STYLE);
+    }
+
+    #[test]
+    fn long_lists_wrap_and_empty_info_has_no_header() {
+        let lines = header_lines(&info(), true, 20);
+        let tools: Vec<String> = lines[4..6].iter().map(text).collect();
+
+        assert_eq!(tools, vec!["  Tools: Read, ", "         Write, Bash"]);
+        assert!(header_lines(&SessionInfo::default(), false, 80).is_empty());
+    }
+}
@@ -2,23 +2,119 @@
 
 use ratatui::prelude::*;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
 
+use super::stats::format_cost;
+use super::stats_tools::format_latency;
 use crate::state::{AppState, ViewedSession};
-use crate::view_state::session_summary::SessionSummary;
+use crate::view_state::session_summary::{SessionSortColumn, SessionSummary};
+
+/// Maximum width of the modal in columns.
+const MODAL_WIDTH: u16 = 160;
+
+/// Narrowest first-prompt column before other columns are dropped.
+const MIN_PROMPT_WIDTH: usize = 12;
+
+/// Widest location column.
+const MAX_LOCATION_WIDTH: usize = 40;
+
+/// Width reserved at the end of each row for the `[CURRENT]` marker.
+const CURRENT_MARKER_WIDTH: usize = 11;
+
+/// Style for rows of sessions whose result reports an error.
+const ERROR_STYLE: Style = Style::new().fg(Color::LightRed);
+
+/// Style of the current-session marker.
+/// Per contract line 110: Current marker | Yellow, italic
+const CURRENT_STYLE: Style = Style::new()
+    .fg(Color::Yellow)
+    .add_modifier(Modifier::ITALIC);
+
+/// A table column: a sortable one, or the session's location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Sort(SessionSortColumn),
+    Location,
+}
+
+impl Column {
+    /// Fixed width; `None` for the flexible prompt and location columns.
+    fn width(self) -> Option<usize> {
+        match self {
+            Column::Sort(SessionSortColumn::Number) => Some(4),
+            Column::Sort(SessionSortColumn::Start) => Some(16),
+            Column::Sort(SessionSortColumn::Duration) => Some(7),
+            Column::Sort(SessionSortColumn::Model) => Some(8),
+            Column::Sort(SessionSortColumn::Messages) => Some(5),
+            Column::Sort(SessionSortColumn::Subagents) => Some(9),
+            Column::Sort(SessionSortColumn::Cost) => Some(9),
+            Column::Sort(SessionSortColumn::Status) => Some(6),
+            Column::Sort(SessionSortColumn::Prompt) | Column::Location => None,
+        }
+    }
+
+    /// Whether cells are right-aligned.
+    fn right_aligned(self) -> bool {
+        matches!(
+            self,
+            Column::Sort(
+                SessionSortColumn::Number
+                    | SessionSortColumn::Duration
+                    | SessionSortColumn::Messages
+                    | SessionSortColumn::Subagents
+                    | SessionSortColumn::Cost
+            )
+        )
+    }
+
+    /// Column header.
+    fn label(self) -> &'static str {
+        match self {
+            Column::Sort(column) => column.label(),
+            Column::Location => "Location",
+        }
+    }
+}
+
+/// Columns in display order.
+const COLUMNS: [Column; 10] = [
+    Column::Sort(SessionSortColumn::Number),
+    Column::Sort(SessionSortColumn::Prompt),
+    Column::Sort(SessionSortColumn::Start),
+    Column::Sort(SessionSortColumn::Duration),
+    Column::Sort(SessionSortColumn::Model),
+    Column::Sort(SessionSortColumn::Messages),
+    Column::Sort(SessionSortColumn::Subagents),
+    Column::Location,
+    Column::Sort(SessionSortColumn::Cost),
+    Column::Sort(SessionSortColumn::Status),
+];
+
+/// Columns dropped, in order, when the modal is too narrow.
+const DROP_ORDER: [Column; 5] = [
+    Column::Sort(SessionSortColumn::Messages),
+    Column::Sort(SessionSortColumn::Subagents),
+    Column::Location,
+    Column::Sort(SessionSortColumn::Model),
+    Column::Sort(SessionSortColumn::Duration),
+];
 
 /// Render the session list modal overlay.
 ///
 /// Displays a centered modal with:
-/// - Session list with current session marked
-/// - Selected row highlighted
+/// - A table of sessions: number, first prompt, start, duration, model,
+///   message and subagent counts, cwd and branch, cost and result status
+/// - The sort column's header marked with `▴`/`▾` (`▲`/`▼` are scroll indicators)
+/// - Only sessions matching the filter, which is shown in the footer
+/// - Current session marked, selected row highlighted
 /// - Footer with keybinding hints
 ///
 /// Only renders when `state.session_modal.is_visible()` is true.
 ///
 /// # Layout
-/// - 60 columns wide, centered horizontally
-/// - Height adapts to session count
+/// - Up to 160 columns wide, centered horizontally; low-priority columns are
+///   dropped on narrow terminals
+/// - Height adapts to row count
 /// - Clears background before rendering for overlay effect
 ///
 /// # FR-002: Session list modal accessible via keyboard
@@ -31,71 +127,28 @@ pub fn render_session_modal(frame: &mut Frame, state: &AppState) {
     }
 
     let area = frame.area();
+    let modal = &state.session_modal;
+    let sessions = modal.rows();
+    let row_count = sessions.len();
     let session_count = state.log_view().session_count();
-    let modal_area = centered_rect(60, session_count, area);
+
+    let width = MODAL_WIDTH.min(area.width.saturating_sub(4));
+    let modal_area = centered_rect(width, row_count.max(1), area);
 
     // Clear the background for overlay effect
     frame.render_widget(Clear, modal_area);
 
-    // Collect session summaries using from_session factory
-    let sessions: Vec<SessionSummary> = state
-        .log_view()
-        .sessions()
-        .enumerate()
-        .map(|(i, session_view)| {
-            let index =
-                crate::view_state::types::SessionIndex::new(i, state.log_view().session_count())
-                    .expect("Index should be valid");
-
-            SessionSummary::from_session(index, session_view)
-        })
-        .collect();
-    let session_count = sessions.len();
-
     // Determine which session is current
     let current_index = match state.viewed_session {
         ViewedSession::Latest => session_count.saturating_sub(1),
         ViewedSession::Pinned(idx) => idx.get(),
     };
 
-    // Build list items
-    let items: Vec<ListItem> = sessions
-        .iter()
-        .enumerate()
-        .map(|(i, summary)| {
-            let mut spans = Vec::new();
-
-            // Add selection prefix
-            if i == state.session_modal.selected_index() {
-                spans.push(Span::raw("> "));
-            } else {
-                spans.push(Span::raw("  "));
-            }
-
-            // Add session info
-            spans.push(Span::raw(summary.display_line()));
-
-            // Add [CURRENT] marker if this is the current session
-            // Per contract line 110: Current marker | Yellow, italic
-            if i == current_index {
-                spans.push(Span::raw("  "));
-                spans.push(Span::styled(
-                    "[CURRENT]",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::ITALIC),
-                ));
-            }
-
-            ListItem::new(Line::from(spans))
-        })
-        .collect();
-
     // Calculate scroll indicators
     // Per contract lines 116-133: Show ▲/▼ when content extends beyond view
     let visible_rows = modal_area.height.saturating_sub(4).max(1);
-    let needs_scrolling = session_count > visible_rows as usize;
-    let selected = state.session_modal.selected_index();
+    let needs_scrolling = row_count > visible_rows as usize;
+    let selected = modal.selected_index();
 
     // Determine if we're scrolled (not showing first item)
     let scroll_offset = if needs_scrolling {
@@ -103,8 +156,8 @@ pub fn render_session_modal(frame: &mut Frame, state: &AppState) {
         let half_visible = visible_rows / 2;
         if selected < half_visible as usize {
             0
-        } else if selected >= session_count.saturating_sub(half_visible as usize) {
-            session_count.saturating_sub(visible_rows as usize)
+        } else if selected >= row_count.saturating_sub(half_visible as usize) {
+            row_count.saturating_sub(visible_rows as usize)
         } else {
             selected.saturating_sub(half_visible as usize)
         }
@@ -113,67 +166,238 @@ pub fn render_session_modal(frame: &mut Frame, state: &AppState) {
     };
 
     let show_up_arrow = needs_scrolling && scroll_offset > 0;
-    let show_down_arrow =
-        needs_scrolling && (scroll_offset + visible_rows as usize) < session_count;
+    let show_down_arrow = needs_scrolling && (scroll_offset + visible_rows as usize) < row_count;
 
-    // Build title with optional scroll indicator
-    let title_text = if show_up_arrow {
-        " Session List                        ▲   "
+    // Build title with row count when filtered and optional scroll indicator
+    let mut title_text = if modal.filter().is_empty() {
+        " Session List ".to_string()
     } else {
-        " Session List "
+        format!(" Session List ({}/{}) ", row_count, session_count)
     };
+    if show_up_arrow {
+        title_text.push_str("                       ▲   ");
+    }
 
-    // Create the list widget
-    let list = List::new(items)
-        .block(
-            Block::default()
-                .title(
-                    ratatui::text::Line::from(vec![ratatui::text::Span::styled(
-                        title_text,
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    )])
-                    .alignment(ratatui::layout::Alignment::Center),
-                )
-                .borders(Borders::ALL)
-                .border_type(ratatui::widgets::BorderType::Rounded)
-                .border_style(Style::default().fg(Color::White))
-                .style(Style::default().bg(Color::DarkGray)),
+    let block = Block::default()
+        .title(
+            Line::from(Span::styled(
+                title_text,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
         )
-        .highlight_style(
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(Color::White))
+        .style(Style::default().bg(Color::DarkGray));
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let [header_area, list_area, footer_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let widths = column_widths(sessions, inner.width as usize);
+    let descending = modal.sort().is_descending() != modal.reversed();
+    let header: Vec<String> = COLUMNS
+        .iter()
+        .map(|column| match column {
+            Column::Sort(sort) if *sort == modal.sort() => {
+                format!("{}{}", sort.label(), if descending { "▾" } else { "▴" })
+            }
+            _ => column.label().to_string(),
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(format_row(&header, &widths)).style(super::styles::SECTION_HEADER),
+        header_area,
+    );
+
+    if sessions.is_empty() {
+        frame.render_widget(
+            Paragraph::new(" No matching sessions").style(super::styles::MUTED_TEXT),
+            list_area,
+        );
+    } else {
+        let tolerance = state.pricing.cost_tolerance_percent();
+        let tree = modal.shows_tree();
+        let items: Vec<ListItem> = sessions
+            .iter()
+            .map(|summary| {
+                let row = format_row(&summary_cells(summary, tolerance, tree), &widths);
+                let mut spans = vec![if summary.is_error() == Some(true) {
+                    Span::styled(row, ERROR_STYLE)
+                } else {
+                    Span::raw(row)
+                }];
+
+                // Add [CURRENT] marker if this is the current session
+                if summary.index().get() == current_index {
+                    spans.push(Span::raw("  "));
+                    spans.push(Span::styled("[CURRENT]", CURRENT_STYLE));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items).highlight_style(
             Style::default()
                 .bg(Color::Cyan)
                 .fg(Color::Black)
                 .add_modifier(Modifier::BOLD),
         );
+        let mut list_state = ListState::default().with_selected(Some(selected));
+        frame.render_stateful_widget(list, list_area, &mut list_state);
+    }
+
+    // Render footer with filter or keybinding hints and optional scroll indicator
+    let footer_text = if modal.is_filtering() {
+        format!("Filter: {}▏  Enter: Keep  Esc: Clear", modal.filter())
+    } else {
+        let filter = if modal.filter().is_empty() {
+            String::new()
+        } else {
+            format!("Filter: {}  ", modal.filter())
+        };
+        let arrow = if show_down_arrow { "  ▼" } else { "" };
+        format!(
+            "{}↑/↓: Move  Tab/r: Sort  /: Filter  Enter: Open  c: Chain  Esc: Cancel{}",
+            filter, arrow
+        )
+    };
 
-    // Create list state for selection
-    let mut list_state =
-        ListState::default().with_selected(Some(state.session_modal.selected_index()));
+    let footer = Paragraph::new(footer_text)
+        .style(Style::default().fg(Color::Gray).add_modifier(Modifier::DIM))
+        .alignment(Alignment::Center);
+
+    frame.render_widget(footer, footer_area);
+}
 
-    // Render the list
-    frame.render_stateful_widget(list, modal_area, &mut list_state);
+/// Width of each column in `COLUMNS` order; 0 for dropped columns.
+///
+/// Fixed columns keep their width; the location column fits the longest
+/// location (up to `MAX_LOCATION_WIDTH`) and the prompt takes the rest.
+/// Columns in `DROP_ORDER` are dropped until the prompt gets at least
+/// `MIN_PROMPT_WIDTH`.
+fn column_widths(sessions: &[SessionSummary], inner_width: usize) -> Vec<usize> {
+    let location_width = sessions
+        .iter()
+        .filter_map(|s| s.location())
+        .map(|l| l.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(MAX_LOCATION_WIDTH);
+    // Leading space and the [CURRENT] marker
+    let available = inner_width.saturating_sub(1 + CURRENT_MARKER_WIDTH);
 
-    // Render footer with keybinding hints and optional scroll indicator
-    let footer_area = Rect {
-        x: modal_area.x + 1,
-        y: modal_area.y + modal_area.height.saturating_sub(2),
-        width: modal_area.width.saturating_sub(2),
-        height: 1,
+    let mut shown: Vec<Column> = COLUMNS
+        .iter()
+        .copied()
+        .filter(|c| *c != Column::Location || location_width > 0)
+        .collect();
+    let fixed_width = |shown: &[Column]| -> usize {
+        shown
+            .iter()
+            .map(|c| match c {
+                Column::Location => location_width,
+                _ => c.width().unwrap_or(0),
+            })
+            .map(|width| width + 2)
+            .sum()
     };
+    for column in DROP_ORDER {
+        if fixed_width(&shown) + MIN_PROMPT_WIDTH <= available {
+            break;
+        }
+        shown.retain(|c| *c != column);
+    }
+    let prompt_width = available.saturating_sub(fixed_width(&shown));
 
-    let footer_text = if show_down_arrow {
-        "↑/↓: Navigate  Enter: Select  Esc: Cancel               ▼   "
-    } else {
-        "↑/↓: Navigate  Enter: Select  Esc: Cancel  S: Close"
+    COLUMNS
+        .iter()
+        .map(|column| match column {
+            _ if !shown.contains(column) => 0,
+            Column::Location => location_width,
+            Column::Sort(SessionSortColumn::Prompt) => prompt_width,
+            _ => column.width().unwrap_or(0),
+        })
+        .collect()
+}
+
+/// Cells of one row, in `COLUMNS` order.
+fn summary_cells(summary: &SessionSummary, tolerance_percent: f64, tree: bool) -> Vec<String> {
+    let prompt = summary
+        .first_prompt()
+        .map(|p| p.split_whitespace().collect::<Vec<_>>().join(" "))
+        .unwrap_or_else(|| "-".to_string());
+    // Resumed sessions: nested under their parent in tree order, else marked
+    let prompt = match summary.parent() {
+        Some(_) if tree => format!("{}└ {}", "  ".repeat(summary.depth() - 1), prompt),
+        Some(parent) => format!("↳{} {}", parent.display(), prompt),
+        None => prompt,
     };
+    let cost = match summary.cost() {
+        Some(cost) if cost.exceeds_tolerance(tolerance_percent) => {
+            format!("{} ⚠", format_cost(cost.reported))
+        }
+        Some(cost) => format_cost(cost.reported),
+        None => format!("~{}", format_cost(summary.display_cost())),
+    };
+    vec![
+        summary.index().display().to_string(),
+        prompt,
+        summary.start_time().map_or_else(
+            || "-".to_string(),
+            |t| t.format("%Y-%m-%d %H:%M").to_string(),
+        ),
+        format_latency(
+            summary
+                .duration()
+                .map(|d| d.num_milliseconds().max(0) as u64),
+        ),
+        summary
+            .model()
+            .map_or_else(|| "-".to_string(), |m| m.display_name().to_string()),
+        summary.message_count().to_string(),
+        summary.subagent_count().to_string(),
+        summary.location().unwrap_or("-").to_string(),
+        cost,
+        summary.status_label().to_string(),
+    ]
+}
 
-    let footer = ratatui::widgets::Paragraph::new(footer_text)
-        .style(Style::default().fg(Color::Gray).add_modifier(Modifier::DIM))
-        .alignment(ratatui::layout::Alignment::Center);
+/// Pad cells to their column widths (truncating with `…`), skipping
+/// zero-width (dropped) columns.
+fn format_row(cells: &[String], widths: &[usize]) -> String {
+    let cells: Vec<String> = COLUMNS
+        .iter()
+        .zip(cells)
+        .zip(widths)
+        .filter(|(_, width)| **width > 0)
+        .map(|((column, cell), width)| {
+            let cell = truncate_end(cell, *width);
+            let pad = " ".repeat(width.saturating_sub(cell.chars().count()));
+            if column.right_aligned() {
+                format!("{}{}", pad, cell)
+            } else {
+                format!("{}{}", cell, pad)
+            }
+        })
+        .collect();
+    format!(" {}", cells.join("  "))
+}
 
-    frame.render_widget(footer, footer_area);
+/// Keep the start of `text`, eliding the end with `…`.
+fn truncate_end(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let head: String = text.chars().take(width.saturating_sub(1)).collect();
+    format!("{}…", head)
 }
 
 /// Calculate centered rect with fixed width.
@@ -181,7 +405,6 @@ pub fn render_session_modal(frame: &mut Frame, state: &AppState) {
 /// Returns a Rect that is centered horizontally with the specified width.
 /// Height is calculated per contract: min(session_count + 4, terminal_height - 4)
 fn centered_rect(width_cols: u16, session_count: usize, area: Rect) -> Rect {
-    // Fixed width of 60 columns
     let popup_width = width_cols.min(area.width);
 
     // Contract line 30: Height = min(session_count + 4, terminal_height - 4)
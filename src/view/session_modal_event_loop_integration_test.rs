@@ -10,6 +10,7 @@ use crate::view::TuiApp;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
+use std::sync::Arc;
 
 /// Helper to create a KeyEvent
 fn key(code: KeyCode) -> KeyEvent {
@@ -44,7 +45,7 @@ fn create_test_app_with_sessions(count: usize) -> TuiApp<TestBackend> {
             crate::model::EntryMetadata::default(),
         );
 
-        app_state.add_entries(vec![crate::model::ConversationEntry::Valid(Box::new(
+        app_state.add_entries(vec![crate::model::ConversationEntry::Valid(Arc::new(
             entry,
         ))]);
     }
@@ -60,6 +61,8 @@ fn create_test_app_with_sessions(count: usize) -> TuiApp<TestBackend> {
         pending_entries: Vec::new(),
         last_tab_area: None,
         last_main_area: None,
+        search_worker: crate::view::SearchWorker::spawn(),
+        search_history_path: None,
     }
 }
 
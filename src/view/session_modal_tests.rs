@@ -2,12 +2,13 @@
 
 use ratatui::Terminal;
 use ratatui::backend::TestBackend;
+use std::sync::Arc;
 
 use crate::model::{
     ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry, Message, MessageContent,
     Role, SessionId,
 };
-use crate::state::AppState;
+use crate::state::{AppState, refresh_session_rows};
 use crate::view::render_session_modal;
 use crate::view_state::types::SessionIndex;
 use chrono::Utc;
@@ -34,7 +35,7 @@ fn create_test_state_with_sessions(session_count: usize) -> AppState {
             ),
             EntryMetadata::default(),
         );
-        state.add_entries(vec![ConversationEntry::Valid(Box::new(entry))]);
+        state.add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
     }
 
     state
@@ -74,6 +75,7 @@ fn render_session_modal_does_not_render_when_invisible() {
 fn render_session_modal_shows_modal_when_visible() {
     let mut state = create_test_state_with_sessions(3);
     state.session_modal.open(0); // Open modal, select first session
+    refresh_session_rows(&mut state);
     assert!(state.session_modal.is_visible());
 
     let backend = TestBackend::new(80, 24);
@@ -103,6 +105,7 @@ fn render_session_modal_shows_modal_when_visible() {
 fn render_session_modal_displays_all_sessions() {
     let mut state = create_test_state_with_sessions(3);
     state.session_modal.open(0);
+    refresh_session_rows(&mut state);
 
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -120,18 +123,29 @@ fn render_session_modal_displays_all_sessions() {
         .map(|cell| cell.symbol())
         .collect::<String>();
 
-    // All sessions should be listed
-    assert!(rendered.contains("Session 1:"), "Should display Session 1");
-    assert!(rendered.contains("Session 2:"), "Should display Session 2");
-    assert!(rendered.contains("Session 3:"), "Should display Session 3");
+    // All sessions should be listed by number and first prompt
+    assert!(
+        rendered.contains("   1  Message in"),
+        "Should display Session 1"
+    );
+    assert!(
+        rendered.contains("   2  Message in"),
+        "Should display Session 2"
+    );
+    assert!(
+        rendered.contains("   3  Message in"),
+        "Should display Session 3"
+    );
 }
 
 #[test]
 fn render_session_modal_displays_message_and_subagent_counts() {
     let mut state = create_test_state_with_sessions(2);
     state.session_modal.open(0);
+    refresh_session_rows(&mut state);
 
-    let backend = TestBackend::new(80, 24);
+    // Wide enough that no column is dropped
+    let backend = TestBackend::new(160, 24);
     let mut terminal = Terminal::new(backend).unwrap();
 
     terminal
@@ -147,15 +161,27 @@ fn render_session_modal_displays_message_and_subagent_counts() {
         .map(|cell| cell.symbol())
         .collect::<String>();
 
-    // Verify format includes message and subagent counts
-    // Contract: "Session N: X messages, Y subagents (HH:MM)"
-    assert!(
-        rendered.contains("messages"),
-        "Should display message count with 'messages' label"
-    );
+    // Verify the table includes every column
+    for header in [
+        "#▴",
+        "First prompt",
+        "Start",
+        "Dur",
+        "Model",
+        "Msgs",
+        "Subagents",
+        "Cost",
+        "Result",
+    ] {
+        assert!(
+            rendered.contains(header),
+            "Should display {:?} column",
+            header
+        );
+    }
     assert!(
-        rendered.contains("subagents"),
-        "Should display subagent count with 'subagents' label"
+        rendered.contains("Message in session 1"),
+        "Should display the untruncated first prompt"
     );
 }
 
@@ -169,6 +195,7 @@ fn render_session_modal_marks_current_session() {
 
     // Open modal
     state.session_modal.open(1);
+    refresh_session_rows(&mut state);
 
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -197,6 +224,7 @@ fn render_session_modal_marks_current_session() {
 fn render_session_modal_shows_footer_with_keybindings() {
     let mut state = create_test_state_with_sessions(2);
     state.session_modal.open(0);
+    refresh_session_rows(&mut state);
 
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -234,6 +262,7 @@ fn render_session_modal_shows_footer_with_keybindings() {
 fn render_session_modal_centers_modal() {
     let mut state = create_test_state_with_sessions(2);
     state.session_modal.open(0);
+    refresh_session_rows(&mut state);
 
     let backend = TestBackend::new(100, 30);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -299,6 +328,7 @@ fn render_session_modal_styles_current_marker_yellow_italic() {
     // Open modal with selection on a DIFFERENT session (session 1, index 0)
     // This way we can test the [CURRENT] marker styling without highlight override
     state.session_modal.open(0);
+    refresh_session_rows(&mut state);
 
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -361,6 +391,7 @@ fn render_session_modal_calculates_height_per_contract() {
     // Test case 1: Few sessions, should be session_count + 4
     let mut state1 = create_test_state_with_sessions(3);
     state1.session_modal.open(0);
+    refresh_session_rows(&mut state1);
 
     let backend1 = TestBackend::new(80, 30);
     let mut terminal1 = Terminal::new(backend1).unwrap();
@@ -404,6 +435,7 @@ fn render_session_modal_calculates_height_per_contract() {
     // Test case 2: Many sessions on small terminal, should be terminal_height - 4
     let mut state2 = create_test_state_with_sessions(20);
     state2.session_modal.open(0);
+    refresh_session_rows(&mut state2);
 
     let backend2 = TestBackend::new(80, 15);
     let mut terminal2 = Terminal::new(backend2).unwrap();
@@ -447,6 +479,7 @@ fn render_session_modal_shows_scroll_indicators_when_list_exceeds_viewport() {
     // Create many sessions to exceed a small viewport
     let mut state = create_test_state_with_sessions(20);
     state.session_modal.open(10); // Select middle session
+    refresh_session_rows(&mut state);
 
     let backend = TestBackend::new(80, 15); // Small terminal
     let mut terminal = Terminal::new(backend).unwrap();
@@ -482,6 +515,7 @@ fn render_session_modal_no_scroll_indicators_when_all_sessions_visible() {
     // Create few sessions that all fit in viewport
     let mut state = create_test_state_with_sessions(3);
     state.session_modal.open(0);
+    refresh_session_rows(&mut state);
 
     let backend = TestBackend::new(80, 30); // Large terminal
     let mut terminal = Terminal::new(backend).unwrap();
@@ -505,3 +539,103 @@ fn render_session_modal_no_scroll_indicators_when_all_sessions_visible() {
         "Should NOT show scroll indicators when all sessions fit in viewport"
     );
 }
+
+#[test]
+fn render_session_modal_shows_reported_vs_estimated_cost() {
+    use crate::model::ResultMetadata;
+
+    let mut state = create_test_state_with_sessions(1);
+    let session_id = SessionId::new("550e8400-e29b-41d4-a716-446655440000").unwrap();
+    let result = LogEntry::new_with_result_metadata(
+        EntryUuid::new("uuid-result").unwrap(),
+        None,
+        session_id,
+        None,
+        Utc::now(),
+        EntryType::Result,
+        Message::new(Role::Assistant, MessageContent::Text("Done".to_string())),
+        EntryMetadata::default(),
+        Some(ResultMetadata {
+            is_error: false,
+            duration_ms: 1000,
+            num_turns: 1,
+            total_cost_usd: 0.5,
+            result_text: "Done".to_string(),
+        }),
+    );
+    state.add_entries(vec![ConversationEntry::Valid(Arc::new(result))]);
+    state.session_modal.open(0);
+    refresh_session_rows(&mut state);
+
+    let backend = TestBackend::new(120, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            render_session_modal(frame, &state);
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let content: String = buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // No usage recorded, so the estimate is $0.00: -100% is outside tolerance
+    assert!(
+        content.contains("$0.50 ⚠  ok"),
+        "Expected per-session cost reconciliation, got:\n{}",
+        content
+    );
+}
+
+#[test]
+fn render_session_modal_shows_cwd_and_branch() {
+    let mut state = AppState::new();
+    for (i, (cwd, branch)) in [("/work/api", "main"), ("/work/web", "feature/login")]
+        .into_iter()
+        .enumerate()
+    {
+        let entry = LogEntry::new(
+            EntryUuid::new(format!("uuid-session-{}", i)).unwrap(),
+            None,
+            SessionId::new(format!("550e8400-e29b-41d4-a716-44665544000{}", i).as_str()).unwrap(),
+            None,
+            Utc::now(),
+            EntryType::User,
+            Message::new(Role::User, MessageContent::Text("Hello".to_string())),
+            EntryMetadata {
+                cwd: Some(cwd.into()),
+                git_branch: Some(branch.to_string()),
+                ..EntryMetadata::default()
+            },
+        );
+        state.add_entries(vec![ConversationEntry::Valid(Arc::new(entry))]);
+    }
+    state.session_modal.open(0);
+    refresh_session_rows(&mut state);
+
+    let backend = TestBackend::new(160, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            render_session_modal(frame, &state);
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let content: String = buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(
+        content.contains("/work/api (main)") && content.contains("/work/web (feature/login)"),
+        "Expected each session's cwd and branch, got:\n{}",
+        content
+    );
+}
@@ -0,0 +1,161 @@
+//! Session separators (FR-074) and the collapsible session headings of the
+//! continuous view.
+//!
+//! A heading summarizes its session on one line: number, start time, model,
+//! entry and subagent counts and the first prompt. `▾` marks an unfolded
+//! session, `▸` a folded one; the session at the top of the viewport is
+//! highlighted.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use unicode_width::UnicodeWidthStr;
+
+use crate::model::SessionId;
+use crate::view_state::session::SessionViewState;
+
+/// Style of separators and inactive headings.
+const SEPARATOR_STYLE: Style = Style::new().fg(Color::DarkGray).add_modifier(Modifier::DIM);
+
+/// Style of the active session's heading.
+const ACTIVE_STYLE: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+
+/// Style of inactive headings.
+const HEADING_STYLE: Style = Style::new().fg(Color::Gray);
+
+/// Separator between heading fields.
+const SEPARATOR: &str = " · ";
+
+/// Render a session separator line.
+///
+/// Format: "─────────── Session: <session_id> ───────────"
+/// Styling: Dim gray to distinguish from content
+///
+/// # Arguments
+/// * `session_id` - The ID of the new session starting after this separator
+///
+/// # Returns
+/// A single Line with the separator text and dim gray styling
+pub(super) fn render_session_separator(session_id: &SessionId) -> Line<'static> {
+    let separator_text = format!("─────────── Session: {} ───────────", session_id);
+
+    Line::from(vec![Span::styled(separator_text, SEPARATOR_STYLE)])
+}
+
+/// One-line heading for session `index` (0-based) of `count`, filled with
+/// `─` to `width`.
+///
+/// Format: "▾ Session 2/3 · 2025-01-15 14:03 · Opus · 12 entries · 1 subagents · Fix the parser ───"
+pub(super) fn session_heading(
+    session: &SessionViewState,
+    index: usize,
+    count: usize,
+    active: bool,
+    width: usize,
+) -> Line<'static> {
+    let marker = if session.is_folded() { "▸" } else { "▾" };
+    let mut fields = vec![format!("{} Session {}/{}", marker, index + 1, count)];
+    if let Some(start) = session.start_time() {
+        fields.push(start.format("%Y-%m-%d %H:%M").to_string());
+    }
+    if let Some(model) = &session.info().model {
+        fields.push(model.display_name().to_string());
+    }
+    fields.push(format!("{} entries", session.main().len()));
+    if session.has_subagents() {
+        fields.push(format!("{} subagents", session.subagents().len()));
+    }
+    let mut text = fields.join(SEPARATOR);
+
+    if let Some(prompt) = session.first_prompt() {
+        let prompt = prompt.split_whitespace().collect::<Vec<_>>().join(" ");
+        // Keep room for the separator and a trailing " ─"
+        let room = width.saturating_sub(text.width() + SEPARATOR.width() + 2);
+        if room > 1 {
+            text.push_str(SEPARATOR);
+            text.push_str(&truncate_end(&prompt, room));
+        }
+    }
+
+    let fill = width.saturating_sub(text.width() + 1);
+    let style = if active { ACTIVE_STYLE } else { HEADING_STYLE };
+    let mut spans = vec![Span::styled(text, style)];
+    if fill > 0 {
+        spans.push(Span::styled(
+            format!(" {}", "─".repeat(fill)),
+            SEPARATOR_STYLE,
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Truncate `text` to `width` columns, marking the cut with `…`.
+fn truncate_end(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    for c in text.chars() {
+        if out.width() + c.to_string().width() + 1 > width {
+            break;
+        }
+        out.push(c);
+    }
+    out.push('…');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ConversationEntry, EntryMetadata, EntryType, EntryUuid, LogEntry};
+    use crate::model::{Message, MessageContent, Role};
+    use chrono::{TimeZone, Utc};
+    use std::sync::Arc;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn session() -> SessionViewState {
+        let mut session = SessionViewState::new(SessionId::new("s1").unwrap());
+        session.add_main_entry(ConversationEntry::Valid(Arc::new(LogEntry::new(
+            EntryUuid::new("u1").unwrap(),
+            None,
+            SessionId::new("s1").unwrap(),
+            None,
+            Utc.with_ymd_and_hms(2025, 1, 15, 14, 3, 0).unwrap(),
+            EntryType::User,
+            Message::new(
+                Role::User,
+                MessageContent::Text("Fix the\nparser please".to_string()),
+            ),
+            EntryMetadata::default(),
+        ))));
+        session
+    }
+
+    #[test]
+    fn heading_summarizes_session_and_fills_width() {
+        let line = session_heading(&session(), 1, 3, true, 72);
+
+        assert_eq!(
+            text(&line),
+            "▾ Session 2/3 · 2025-01-15 14:03 · 1 entries · Fix the parser please ───"
+        );
+        assert_eq!(line.spans[0].style, ACTIVE_STYLE);
+    }
+
+    #[test]
+    fn folded_heading_is_marked_and_prompt_truncated() {
+        let mut session = session();
+        session.set_folded(true);
+
+        let line = session_heading(&session, 0, 1, false, 52);
+
+        assert_eq!(
+            text(&line),
+            "▸ Session 1/1 · 2025-01-15 14:03 · 1 entries · Fi… ─"
+        );
+        assert_eq!(line.spans[0].style, HEADING_STYLE);
+    }
+}
@@ -10,6 +10,7 @@ use crate::view::message::ConversationView;
 use crate::view_state::conversation::ConversationViewState;
 use crate::view_state::layout_params::LayoutParams;
 use ratatui::{Terminal, backend::TestBackend, buffer::Buffer, layout::Rect};
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -40,7 +41,7 @@ fn make_valid_entry(uuid: &str, session_id: &str, text: &str) -> ConversationEnt
         make_message(text),
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 // ===== Session Separator Tests =====
@@ -59,7 +60,7 @@ fn renders_separator_when_session_changes() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -103,7 +104,7 @@ fn no_separator_for_first_session() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -146,7 +147,7 @@ fn separator_contains_session_id() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -190,7 +191,7 @@ fn multiple_session_changes_render_multiple_separators() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -232,7 +233,7 @@ fn separator_styled_with_dim_gray() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -1,12 +1,17 @@
 //! Statistics panel widget for displaying session metrics.
 
 use super::helpers::{empty_line, key_value_line, styled_block};
-use super::styles::SECTION_HEADER;
-use crate::model::{PricingConfig, SessionStats, StatsFilter};
+use super::stats_charts::TimelineCharts;
+use super::stats_tools::format_tool_table;
+use super::styles::{COST_WARNING, SECTION_HEADER};
+use crate::model::{
+    ChartAxis, CostReconciliation, PricingConfig, SessionStats, StatsFilter, StatsPage,
+    ToolSortColumn,
+};
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
-    text::Line,
+    layout::{Constraint, Layout, Rect},
+    text::{Line, Span},
     widgets::{Paragraph, Widget},
 };
 
@@ -16,15 +21,24 @@ use ratatui::{
 ///
 /// Displays:
 /// - Token usage (input, output, total)
-/// - Estimated cost
+/// - Estimated cost, reconciled against the reported cost when available
 /// - Tool usage breakdown
 /// - Subagent count
+/// - Timeline charts (middle column, when any entry in scope reports usage)
+/// - Per-model token and cost table (right column, when any entry reports a model)
+///
+/// The "Tools" page (`StatsPage::Tools`) replaces all of the above with the
+/// per-tool reliability table.
 pub struct StatsPanel<'a> {
     stats: &'a SessionStats,
     filter: &'a StatsFilter,
     pricing: &'a PricingConfig,
     model_id: Option<&'a str>,
     focused: bool,
+    chart_axis: ChartAxis,
+    page: StatsPage,
+    tool_sort: ToolSortColumn,
+    chain: Option<usize>,
 }
 
 impl<'a> StatsPanel<'a> {
@@ -34,7 +48,7 @@ impl<'a> StatsPanel<'a> {
     /// * `stats` - Session statistics to display
     /// * `filter` - Current stats filter (Global, MainAgent, or Subagent)
     /// * `pricing` - Pricing configuration for cost estimation
-    /// * `model_id` - Model ID for pricing lookup (defaults to "opus" if None)
+    /// * `model_id` - Fallback model ID for usage not attributed to a model (defaults to "opus")
     /// * `focused` - Whether this panel currently has focus (affects border color)
     pub fn new(
         stats: &'a SessionStats,
@@ -49,25 +63,100 @@ impl<'a> StatsPanel<'a> {
             pricing,
             model_id,
             focused,
+            chart_axis: ChartAxis::default(),
+            page: StatsPage::default(),
+            tool_sort: ToolSortColumn::default(),
+            chain: None,
         }
     }
+
+    /// Set the page shown in the panel body (overview by default).
+    pub fn with_page(mut self, page: StatsPage) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Set the sort column of the tool table (calls by default).
+    pub fn with_tool_sort(mut self, tool_sort: ToolSortColumn) -> Self {
+        self.tool_sort = tool_sort;
+        self
+    }
+
+    /// Set the x axis of the timeline charts (turn index by default).
+    pub fn with_chart_axis(mut self, chart_axis: ChartAxis) -> Self {
+        self.chart_axis = chart_axis;
+        self
+    }
+
+    /// Mark `stats` as rolled up over a chain of `sessions` resumed sessions.
+    pub fn with_chain(mut self, sessions: Option<usize>) -> Self {
+        self.chain = sessions;
+        self
+    }
 }
 
 impl<'a> Widget for StatsPanel<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         // Create the block with title and borders
-        let title = match self.filter {
-            StatsFilter::AllSessionsCombined => " Statistics: All Sessions ",
-            StatsFilter::Session(_) => " Statistics: Session ",
-            StatsFilter::MainAgent(_) => " Statistics (Main Agent) ",
-            StatsFilter::Subagent(_) => " Statistics (Subagent) ",
+        let title = match (self.filter, self.chain) {
+            (StatsFilter::AllSessionsCombined, Some(sessions)) => {
+                format!(" Statistics: Chain ({} sessions) ", sessions)
+            }
+            (StatsFilter::AllSessionsCombined, None) => " Statistics: All Sessions ".to_string(),
+            (StatsFilter::Session(_), _) => " Statistics: Session ".to_string(),
+            (StatsFilter::MainAgent(_), _) => " Statistics (Main Agent) ".to_string(),
+            (StatsFilter::Subagent(_), _) => " Statistics (Subagent) ".to_string(),
         };
 
-        let block = styled_block(title, self.focused);
+        let title = match self.page {
+            StatsPage::Overview => title,
+            StatsPage::Tools => format!("{}- Tools ", title),
+        };
+        let block = styled_block(&title, self.focused);
 
         let inner = block.inner(area);
         block.render(area, buf);
 
+        if self.page == StatsPage::Tools {
+            Paragraph::new(format_tool_table(
+                self.stats.filtered_tool_stats(self.filter),
+                self.tool_sort,
+            ))
+            .render(inner, buf);
+            return;
+        }
+
+        // Charts and the per-model table get their own columns so they stay
+        // visible in the short panel
+        let model_usage = self.stats.filtered_model_usage(self.filter);
+        let timeline = self.stats.filtered_timeline(self.filter);
+        let show_models = !model_usage.is_empty();
+        let show_charts = !timeline.is_empty();
+        let model_table = format_model_table(model_usage, self.pricing);
+        let constraints: &[Constraint] = match (show_charts, show_models) {
+            (false, false) => &[Constraint::Percentage(100)],
+            (true, false) | (false, true) => {
+                &[Constraint::Percentage(50), Constraint::Percentage(50)]
+            }
+            // Charts take whatever the text and model table leave over
+            (true, true) => &[
+                Constraint::Percentage(35),
+                Constraint::Fill(1),
+                Constraint::Length(
+                    model_table.iter().map(Line::width).max().unwrap_or(0) as u16 + 1,
+                ),
+            ],
+        };
+        let columns = Layout::horizontal(constraints).split(inner);
+        let inner = columns[0];
+        if show_charts {
+            TimelineCharts::new(timeline, self.pricing, self.model_id, self.chart_axis)
+                .render(columns[1], buf);
+        }
+        if show_models {
+            Paragraph::new(model_table).render(columns[columns.len() - 1], buf);
+        }
+
         // Build content lines
         let mut lines = Vec::new();
 
@@ -93,19 +182,33 @@ impl<'a> Widget for StatsPanel<'a> {
         let total_cache = usage.cache_creation_input_tokens + usage.cache_read_input_tokens;
         if total_cache > 0 {
             lines.push(Line::from(format!(
-                "  Cache:  {}",
-                format_tokens(total_cache)
+                "  Cache:  {} ({} write, {} read)",
+                format_tokens(total_cache),
+                format_tokens(usage.cache_creation_input_tokens),
+                format_tokens(usage.cache_read_input_tokens)
             )));
         }
 
         lines.push(empty_line());
 
-        // Cost section - show actual cost from result entry if available, otherwise estimated
-        if let Some(actual_cost) = self.stats.actual_cost_usd {
+        // Cost section - reported cost reconciled against the estimate when the scope has
+        // one, else the actual cost from the result entry, otherwise the estimate alone
+        if let Some(reconciliation) =
+            self.stats
+                .filtered_cost_reconciliation(self.filter, self.pricing, self.model_id)
+        {
+            lines.push(Line::from("Actual Cost:").style(SECTION_HEADER));
+            lines.push(format_cost_reconciliation(
+                &reconciliation,
+                self.pricing.cost_tolerance_percent(),
+            ));
+        } else if let Some(actual_cost) = self.stats.actual_cost_usd {
             lines.push(Line::from("Actual Cost:").style(SECTION_HEADER));
             lines.push(Line::from(format!("  {}", format_cost(actual_cost))));
         } else {
-            let cost = calculate_cost(&usage, self.pricing, self.model_id);
+            let cost = self
+                .stats
+                .filtered_estimated_cost(self.filter, self.pricing, self.model_id);
             lines.push(Line::from("Estimated Cost:").style(SECTION_HEADER));
             lines.push(Line::from(format!("  {}", format_cost(cost))));
         }
@@ -137,37 +240,75 @@ impl<'a> Widget for StatsPanel<'a> {
 
 // ===== Formatting Helpers =====
 
-/// Calculate estimated cost in USD for the given token usage.
+/// Format the per-model breakdown as aligned `model  tokens  cost` rows.
 ///
-/// Cost includes:
-/// - Input tokens at input rate
-/// - Output tokens at output rate
-/// - Cached tokens (cache_creation + cache_read) at cached rate (or input rate if not specified)
-///
-/// Model pricing is determined by matching the model_id string (e.g., "opus", "sonnet").
-fn calculate_cost(
-    usage: &crate::model::TokenUsage,
+/// Rows are sorted by cost descending. Model IDs are shown without the
+/// `claude-` prefix to save width.
+fn format_model_table(
+    model_usage: &std::collections::HashMap<String, crate::model::TokenUsage>,
     pricing: &PricingConfig,
-    model_id: Option<&str>,
-) -> f64 {
-    let model_pricing = pricing.get(model_id.unwrap_or("opus"));
-
-    let input_cost =
-        (usage.input_tokens as f64 / 1_000_000.0) * model_pricing.input_cost_per_million;
-
-    let output_cost =
-        (usage.output_tokens as f64 / 1_000_000.0) * model_pricing.output_cost_per_million;
-
-    // Use cached rate if available, otherwise use standard input rate
-    let cache_rate = model_pricing
-        .cached_input_cost_per_million
-        .unwrap_or(model_pricing.input_cost_per_million);
+) -> Vec<Line<'static>> {
+    let mut rows: Vec<(String, String, f64)> = model_usage
+        .iter()
+        .map(|(model_id, usage)| {
+            let name = model_id
+                .strip_prefix("claude-")
+                .unwrap_or(model_id)
+                .to_string();
+            (
+                name,
+                format_tokens(usage.total()),
+                pricing.get(model_id).cost(usage),
+            )
+        })
+        .collect();
+    rows.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+
+    let name_width = rows.iter().map(|r| r.0.len()).max().unwrap_or(0);
+    let tokens_width = rows.iter().map(|r| r.1.len()).max().unwrap_or(0);
+
+    let mut lines = vec![Line::from("Models:").style(SECTION_HEADER)];
+    for (name, tokens, cost) in rows {
+        lines.push(Line::from(format!(
+            "  {:<name_width$}  {:>tokens_width$}  {}",
+            name,
+            tokens,
+            format_cost(cost),
+        )));
+    }
+    lines
+}
 
-    let cache_cost = ((usage.cache_creation_input_tokens + usage.cache_read_input_tokens) as f64
-        / 1_000_000.0)
-        * cache_rate;
+/// Format reported cost next to the estimate, e.g. `  $1.50  est. $1.42 (-$0.08, -5.3%)`.
+///
+/// Appends a yellow `⚠` when the estimate is outside `tolerance_percent`.
+pub(crate) fn format_cost_reconciliation(
+    reconciliation: &CostReconciliation,
+    tolerance_percent: f64,
+) -> Line<'static> {
+    let mut spans = vec![Span::raw(format!(
+        "  {}  est. {}",
+        format_cost(reconciliation.reported),
+        format_cost(reconciliation.estimated)
+    ))];
+    spans.push(Span::raw(match reconciliation.delta_percent() {
+        Some(percent) => format!(
+            " ({}, {:+.1}%)",
+            format_signed_cost(reconciliation.delta()),
+            percent
+        ),
+        None => format!(" ({})", format_signed_cost(reconciliation.delta())),
+    }));
+    if reconciliation.exceeds_tolerance(tolerance_percent) {
+        spans.push(Span::styled(" ⚠", COST_WARNING));
+    }
+    Line::from(spans)
+}
 
-    input_cost + output_cost + cache_cost
+/// Format a cost delta with an explicit sign, e.g. `+$0.08` or `-$1.20`.
+fn format_signed_cost(delta: f64) -> String {
+    let sign = if delta < 0.0 { '-' } else { '+' };
+    format!("{}{}", sign, format_cost(delta.abs()))
 }
 
 /// Format tool usage breakdown with top N limiting.
@@ -220,7 +361,7 @@ fn format_tool_breakdown(
 /// - `format_tokens(0)` → "0"
 /// - `format_tokens(1234)` → "1,234"
 /// - `format_tokens(1234567)` → "1,234,567"
-fn format_tokens(tokens: u64) -> String {
+pub(crate) fn format_tokens(tokens: u64) -> String {
     let s = tokens.to_string();
     let mut result = String::new();
     let chars: Vec<char> = s.chars().collect();
@@ -241,7 +382,7 @@ fn format_tokens(tokens: u64) -> String {
 /// - `format_cost(0.0)` → "$0.00"
 /// - `format_cost(2.45)` → "$2.45"
 /// - `format_cost(123.456)` → "$123.46" (rounds to 2 decimals)
-fn format_cost(cost: f64) -> String {
+pub(crate) fn format_cost(cost: f64) -> String {
     // Round to 2 decimal places
     let rounded = (cost * 100.0).round() / 100.0;
 
@@ -616,8 +757,8 @@ mod tests {
         use ratatui::layout::Rect;
         use std::collections::HashMap;
 
-        // Setup: 1M input, 1M output, 1M cache using Opus pricing
-        // Expected: $5 (input) + $25 (output) + $0.50 (cache) = $30.50
+        // Setup: 1M input, 1M output, 500k cache writes, 500k cache reads using Opus pricing
+        // Expected: $5 (input) + $25 (output) + $3.125 (writes) + $0.25 (reads) = $33.38
         let stats = SessionStats {
             total_usage: TokenUsage {
                 input_tokens: 1_000_000,
@@ -647,8 +788,8 @@ mod tests {
         let content = buffer_to_string(&buffer);
 
         assert!(
-            content.contains("$30.50"),
-            "Expected cost '$30.50' for Opus with cache (1M input + 1M output + 1M cache), got:\n{}",
+            content.contains("$33.38"),
+            "Expected cost '$33.38' for Opus with cache writes and reads, got:\n{}",
             content
         );
     }
@@ -1503,19 +1644,23 @@ mod tests {
         let mut subagent_counts = HashMap::new();
         subagent_counts.insert(agent1.clone(), agent1_counts);
 
+        let session_id = SessionId::new("test-session").unwrap();
+        let mut main_counts_by_session = HashMap::new();
+        main_counts_by_session.insert(session_id.clone(), main_counts.clone());
+
         let stats = SessionStats {
             total_usage: Default::default(),
             main_agent_usage: Default::default(),
             subagent_usage: HashMap::new(),
             tool_counts: global_counts,
             main_agent_tool_counts: main_counts,
+            main_agent_tool_counts_by_session: main_counts_by_session,
             subagent_tool_counts: subagent_counts,
             subagent_count: 1,
             entry_count: 10,
             ..Default::default()
         };
 
-        let session_id = SessionId::new("test-session").unwrap();
         let filter = StatsFilter::MainAgent(session_id);
         let pricing = PricingConfig::default();
         let panel = StatsPanel::new(&stats, &filter, &pricing, Some("opus"), false);
@@ -1642,12 +1787,26 @@ mod tests {
             subagent_usage: HashMap::new(),
             session_usage: HashMap::new(),
             main_agent_usage_by_session: HashMap::new(),
+            model_usage: HashMap::new(),
+            session_model_usage: HashMap::new(),
+            main_agent_model_usage_by_session: HashMap::new(),
+            subagent_model_usage: HashMap::new(),
             tool_counts: HashMap::new(),
             main_agent_tool_counts: HashMap::new(),
             subagent_tool_counts: HashMap::new(),
+            session_tool_counts: HashMap::new(),
+            main_agent_tool_counts_by_session: HashMap::new(),
             subagent_count: 0,
             entry_count: 5,
             actual_cost_usd: Some(1.5), // Actual cost from result entry
+            session_actual_cost_usd: HashMap::new(),
+            timeline: Vec::new(),
+            tool_stats: HashMap::new(),
+            session_tool_stats: HashMap::new(),
+            main_agent_tool_stats_by_session: HashMap::new(),
+            subagent_tool_stats: HashMap::new(),
+            pending_tool_calls: HashMap::new(),
+            files: HashMap::new(),
         };
 
         let filter = StatsFilter::AllSessionsCombined;
@@ -1693,12 +1852,26 @@ mod tests {
             subagent_usage: HashMap::new(),
             session_usage: HashMap::new(),
             main_agent_usage_by_session: HashMap::new(),
+            model_usage: HashMap::new(),
+            session_model_usage: HashMap::new(),
+            main_agent_model_usage_by_session: HashMap::new(),
+            subagent_model_usage: HashMap::new(),
             tool_counts: HashMap::new(),
             main_agent_tool_counts: HashMap::new(),
             subagent_tool_counts: HashMap::new(),
+            session_tool_counts: HashMap::new(),
+            main_agent_tool_counts_by_session: HashMap::new(),
             subagent_count: 0,
             entry_count: 5,
             actual_cost_usd: None, // No result entry yet
+            session_actual_cost_usd: HashMap::new(),
+            timeline: Vec::new(),
+            tool_stats: HashMap::new(),
+            session_tool_stats: HashMap::new(),
+            main_agent_tool_stats_by_session: HashMap::new(),
+            subagent_tool_stats: HashMap::new(),
+            pending_tool_calls: HashMap::new(),
+            files: HashMap::new(),
         };
 
         let filter = StatsFilter::AllSessionsCombined;
@@ -1722,4 +1895,161 @@ mod tests {
             content
         );
     }
+
+    fn reconciled_stats(reported: f64) -> SessionStats {
+        use crate::model::TokenUsage;
+
+        let mut stats = SessionStats {
+            total_usage: TokenUsage {
+                input_tokens: 1_000_000,
+                output_tokens: 1_000_000,
+                ..Default::default()
+            },
+            actual_cost_usd: Some(reported),
+            ..Default::default()
+        };
+        stats
+            .session_actual_cost_usd
+            .insert(SessionId::new("test-session").unwrap(), reported);
+        stats
+    }
+
+    fn render_panel(stats: &SessionStats, pricing: &PricingConfig) -> String {
+        use ratatui::buffer::Buffer;
+        use ratatui::layout::Rect;
+
+        let filter = StatsFilter::AllSessionsCombined;
+        let panel = StatsPanel::new(stats, &filter, pricing, Some("opus"), false);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 25));
+        panel.render(Rect::new(0, 0, 60, 25), &mut buffer);
+        buffer_to_string(&buffer)
+    }
+
+    #[test]
+    fn stats_panel_reconciles_reported_and_estimated_cost() {
+        // Estimated: $5 (input) + $25 (output) = $30.00 vs reported $29.00
+        let content = render_panel(&reconciled_stats(29.0), &PricingConfig::default());
+
+        assert!(
+            content.contains("$29.00  est. $30.00 (+$1.00, +3.4%)"),
+            "Expected reported, estimated and delta, got:\n{}",
+            content
+        );
+        assert!(
+            !content.contains('⚠'),
+            "3.4% is within the default 10% tolerance, got:\n{}",
+            content
+        );
+    }
+
+    #[test]
+    fn stats_panel_warns_when_cost_diverges_beyond_tolerance() {
+        let content = render_panel(&reconciled_stats(20.0), &PricingConfig::default());
+
+        assert!(
+            content.contains("(+$10.00, +50.0%) ⚠"),
+            "Expected warning marker for 50% divergence, got:\n{}",
+            content
+        );
+    }
+
+    #[test]
+    fn stats_panel_uses_configured_cost_tolerance() {
+        let pricing = PricingConfig::default().with_cost_tolerance_percent(1.0);
+
+        let content = render_panel(&reconciled_stats(29.0), &pricing);
+
+        assert!(
+            content.contains("(+$1.00, +3.4%) ⚠"),
+            "Expected warning marker with 1% tolerance, got:\n{}",
+            content
+        );
+    }
+
+    #[test]
+    fn stats_panel_tools_page_shows_tool_table() {
+        use crate::model::{ToolName, ToolStats};
+        use ratatui::buffer::Buffer;
+        use ratatui::layout::Rect;
+
+        let mut stats = SessionStats::default();
+        let mut bash = ToolStats::default();
+        bash.record_call();
+        bash.record_result(true, 2_000, 100);
+        stats.tool_stats.insert(ToolName::Bash, bash);
+
+        let filter = StatsFilter::AllSessionsCombined;
+        let pricing = PricingConfig::default();
+        let panel = StatsPanel::new(&stats, &filter, &pricing, None, false)
+            .with_page(StatsPage::Tools)
+            .with_tool_sort(ToolSortColumn::ErrorRate);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 80, 10));
+        panel.render(Rect::new(0, 0, 80, 10), &mut buffer);
+        let content = buffer_to_string(&buffer);
+
+        assert!(
+            content.contains("Statistics: All Sessions - Tools"),
+            "got:\n{}",
+            content
+        );
+        assert!(content.contains("Err%▼"), "got:\n{}", content);
+        assert!(content.contains("100.0%"), "got:\n{}", content);
+        assert!(
+            !content.contains("Tokens:"),
+            "Overview hidden on tools page, got:\n{}",
+            content
+        );
+    }
+
+    #[test]
+    fn stats_panel_displays_per_model_table() {
+        use crate::model::TokenUsage;
+        use ratatui::buffer::Buffer;
+        use ratatui::layout::Rect;
+
+        let mut stats = SessionStats::default();
+        stats.model_usage.insert(
+            "claude-opus-4-5-20251101".to_string(),
+            TokenUsage {
+                input_tokens: 1_000_000,
+                ..Default::default()
+            },
+        );
+        stats.model_usage.insert(
+            "claude-haiku-4-5-20251001".to_string(),
+            TokenUsage {
+                input_tokens: 2_000_000,
+                ..Default::default()
+            },
+        );
+        stats.total_usage = TokenUsage {
+            input_tokens: 3_000_000,
+            ..Default::default()
+        };
+
+        let filter = StatsFilter::AllSessionsCombined;
+        let pricing = PricingConfig::default();
+        let panel = StatsPanel::new(&stats, &filter, &pricing, None, false);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 100, 10));
+        panel.render(Rect::new(0, 0, 100, 10), &mut buffer);
+
+        let content = buffer_to_string(&buffer);
+
+        assert!(content.contains("Models:"), "got:\n{}", content);
+        assert!(
+            content.contains("opus-4-5-20251101   1,000,000  $5.00"),
+            "Opus row should show its own tokens and cost, got:\n{}",
+            content
+        );
+        assert!(
+            content.contains("haiku-4-5-20251001  2,000,000  $2.00"),
+            "Haiku row should be priced at Haiku rates, got:\n{}",
+            content
+        );
+        // Total estimate is the sum of per-model costs, not 3M at Opus rates
+        assert!(content.contains("$7.00"), "got:\n{}", content);
+    }
 }
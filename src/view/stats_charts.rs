@@ -0,0 +1,212 @@
+//! Timeline charts for the statistics panel.
+//!
+//! Plots output tokens per turn, context fill per turn and cumulative cost
+//! for the samples in the current `StatsFilter` scope. The x axis is either
+//! the turn index or wall-clock time (`ChartAxis`).
+
+use super::stats::{format_cost, format_tokens};
+use super::styles::{MUTED_TEXT, SECTION_HEADER};
+use crate::model::{ChartAxis, PricingConfig, TimelineSample};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    symbols,
+    text::{Line, Span},
+    widgets::{Axis, Chart, Dataset, GraphType, Paragraph, Sparkline, Widget},
+};
+
+/// Timeline charts widget (rendered as a column of the stats panel).
+pub(super) struct TimelineCharts<'a> {
+    samples: Vec<&'a TimelineSample>,
+    pricing: &'a PricingConfig,
+    fallback_model_id: Option<&'a str>,
+    axis: ChartAxis,
+}
+
+impl<'a> TimelineCharts<'a> {
+    /// Create the charts for `samples`, which must be ordered by timestamp.
+    pub(super) fn new(
+        samples: Vec<&'a TimelineSample>,
+        pricing: &'a PricingConfig,
+        fallback_model_id: Option<&'a str>,
+        axis: ChartAxis,
+    ) -> Self {
+        Self {
+            samples,
+            pricing,
+            fallback_model_id,
+            axis,
+        }
+    }
+}
+
+impl Widget for TimelineCharts<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.samples.is_empty() || area.width == 0 {
+            return;
+        }
+
+        let rows = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+        let xs = x_positions(&self.samples, self.axis);
+        let buckets = bucket_count(&xs, self.axis, area.width as usize);
+
+        // Output tokens per turn
+        let output: Vec<u64> = self.samples.iter().map(|s| s.usage.output_tokens).collect();
+        let output_peak = output.iter().copied().max().unwrap_or(0);
+        caption(
+            "Output/turn",
+            format!("peak {}", format_tokens(output_peak)),
+        )
+        .render(rows[0], buf);
+        Sparkline::default()
+            .data(bucket_max(&xs, &output, buckets))
+            .style(Style::default().fg(Color::Yellow))
+            .render(rows[1], buf);
+
+        // Context fill (input + cache) per turn
+        let context: Vec<u64> = self.samples.iter().map(|s| s.context_tokens()).collect();
+        let context_peak = context.iter().copied().max().unwrap_or(0);
+        caption("Context", format!("peak {}", format_tokens(context_peak))).render(rows[2], buf);
+        Sparkline::default()
+            .data(bucket_max(&xs, &context, buckets))
+            .style(Style::default().fg(Color::Magenta))
+            .render(rows[3], buf);
+
+        // Cumulative cost
+        let points = cumulative_cost(&xs, &self.samples, self.pricing, self.fallback_model_id);
+        let total = points.last().map(|&(_, cost)| cost).unwrap_or(0.0);
+        caption(
+            "Cost",
+            format!("{} by {} (t: axis)", format_cost(total), self.axis.label()),
+        )
+        .render(rows[4], buf);
+
+        if rows[5].height >= 3 {
+            let x_bounds = [
+                xs.first().copied().unwrap_or(0.0),
+                xs.last().copied().unwrap_or(0.0),
+            ];
+            let dataset = Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Green))
+                .data(&points);
+            Chart::new(vec![dataset])
+                .x_axis(
+                    Axis::default()
+                        .bounds(x_bounds)
+                        .labels(x_labels(&self.samples, self.axis))
+                        .style(MUTED_TEXT),
+                )
+                .y_axis(
+                    Axis::default()
+                        .bounds([0.0, total.max(f64::EPSILON)])
+                        .labels(["$0".to_string(), format_cost(total)])
+                        .style(MUTED_TEXT),
+                )
+                .render(rows[5], buf);
+        }
+    }
+}
+
+/// Chart caption: bold title followed by a muted detail.
+fn caption(title: &'static str, detail: String) -> Paragraph<'static> {
+    Paragraph::new(Line::from(vec![
+        Span::styled(title, SECTION_HEADER),
+        Span::styled(format!("  {}", detail), MUTED_TEXT),
+    ]))
+}
+
+/// X coordinate of each sample: turn index, or seconds since the first sample.
+fn x_positions(samples: &[&TimelineSample], axis: ChartAxis) -> Vec<f64> {
+    match axis {
+        ChartAxis::Turn => (0..samples.len()).map(|i| i as f64).collect(),
+        ChartAxis::WallClock => {
+            let Some(start) = samples.first().map(|s| s.timestamp) else {
+                return Vec::new();
+            };
+            samples
+                .iter()
+                .map(|s| (s.timestamp - start).num_milliseconds() as f64 / 1000.0)
+                .collect()
+        }
+    }
+}
+
+/// Number of sparkline bars.
+///
+/// By turn, one bar per sample until the width runs out. By time, the full
+/// width, so idle periods show up as gaps.
+fn bucket_count(xs: &[f64], axis: ChartAxis, width: usize) -> usize {
+    let span = xs.last().copied().unwrap_or(0.0) - xs.first().copied().unwrap_or(0.0);
+    match axis {
+        ChartAxis::Turn => xs.len().min(width),
+        ChartAxis::WallClock if span > 0.0 => width,
+        ChartAxis::WallClock => 1,
+    }
+    .max(1)
+}
+
+/// Spread `values` over `buckets` evenly spaced x ranges, keeping the peak
+/// of each range so spikes stay visible.
+fn bucket_max(xs: &[f64], values: &[u64], buckets: usize) -> Vec<u64> {
+    let mut out = vec![0; buckets];
+    let (Some(&first), Some(&last)) = (xs.first(), xs.last()) else {
+        return out;
+    };
+    let span = last - first;
+
+    for (&x, &value) in xs.iter().zip(values) {
+        let bucket = if span > 0.0 {
+            (((x - first) / span) * (buckets - 1) as f64).round() as usize
+        } else {
+            0
+        };
+        let slot = &mut out[bucket.min(buckets - 1)];
+        *slot = (*slot).max(value);
+    }
+    out
+}
+
+/// Running total of per-sample cost, paired with each sample's x coordinate.
+fn cumulative_cost(
+    xs: &[f64],
+    samples: &[&TimelineSample],
+    pricing: &PricingConfig,
+    fallback_model_id: Option<&str>,
+) -> Vec<(f64, f64)> {
+    let mut total = 0.0;
+    xs.iter()
+        .zip(samples)
+        .map(|(&x, sample)| {
+            total += sample.cost(pricing, fallback_model_id);
+            (x, total)
+        })
+        .collect()
+}
+
+/// First and last x labels: turn numbers (1-based) or `HH:MM` timestamps.
+fn x_labels(samples: &[&TimelineSample], axis: ChartAxis) -> Vec<String> {
+    match axis {
+        ChartAxis::Turn => vec!["1".to_string(), samples.len().to_string()],
+        ChartAxis::WallClock => [samples.first(), samples.last()]
+            .into_iter()
+            .flatten()
+            .map(|s| s.timestamp.format("%H:%M").to_string())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+#[path = "stats_charts_tests.rs"]
+mod tests;
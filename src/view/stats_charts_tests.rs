@@ -0,0 +1,129 @@
+//! Tests for the stats panel timeline charts.
+
+use super::*;
+use crate::model::{SessionId, TokenUsage};
+use chrono::{DateTime, TimeZone, Utc};
+
+fn at(secs: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap() + chrono::Duration::seconds(secs)
+}
+
+fn sample(secs: i64, input: u64, output: u64) -> TimelineSample {
+    TimelineSample {
+        session_id: SessionId::new("s1").unwrap(),
+        agent_id: None,
+        timestamp: at(secs),
+        model_id: Some("claude-opus-4-5".to_string()),
+        usage: TokenUsage {
+            input_tokens: input,
+            output_tokens: output,
+            ..Default::default()
+        },
+    }
+}
+
+fn render_to_string(samples: &[TimelineSample], axis: ChartAxis, width: u16) -> String {
+    let pricing = PricingConfig::default();
+    let charts = TimelineCharts::new(samples.iter().collect(), &pricing, None, axis);
+
+    let area = Rect::new(0, 0, width, 8);
+    let mut buffer = Buffer::empty(area);
+    charts.render(area, &mut buffer);
+
+    buffer
+        .content()
+        .chunks(width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// ===== Bucketing =====
+
+#[test]
+fn bucket_max_keeps_one_value_per_turn_when_it_fits() {
+    let xs = [0.0, 1.0, 2.0];
+
+    assert_eq!(bucket_max(&xs, &[5, 7, 3], 3), vec![5, 7, 3]);
+}
+
+#[test]
+fn bucket_max_keeps_peak_when_turns_share_a_bucket() {
+    let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+
+    // x = 1, 2 round to the middle bucket; x = 3, 4 to the last
+    assert_eq!(bucket_max(&xs, &[1, 9, 2, 3, 4], 3), vec![1, 9, 4]);
+}
+
+#[test]
+fn bucket_max_by_time_leaves_idle_gaps_empty() {
+    // Two bursts 100s apart spread over 5 buckets
+    let xs = [0.0, 1.0, 100.0];
+
+    assert_eq!(bucket_max(&xs, &[4, 6, 8], 5), vec![6, 0, 0, 0, 8]);
+}
+
+#[test]
+fn bucket_count_by_turn_is_capped_by_width() {
+    let xs: Vec<f64> = (0..50).map(|i| i as f64).collect();
+
+    assert_eq!(bucket_count(&xs, ChartAxis::Turn, 20), 20);
+    assert_eq!(bucket_count(&xs[..5], ChartAxis::Turn, 20), 5);
+}
+
+#[test]
+fn bucket_count_by_time_uses_full_width_unless_instantaneous() {
+    assert_eq!(bucket_count(&[0.0, 30.0], ChartAxis::WallClock, 20), 20);
+    assert_eq!(bucket_count(&[0.0, 0.0], ChartAxis::WallClock, 20), 1);
+}
+
+// ===== Series =====
+
+#[test]
+fn x_positions_by_time_are_seconds_since_first_sample() {
+    let samples = [sample(0, 1, 1), sample(90, 1, 1)];
+    let refs: Vec<&TimelineSample> = samples.iter().collect();
+
+    assert_eq!(x_positions(&refs, ChartAxis::WallClock), vec![0.0, 90.0]);
+    assert_eq!(x_positions(&refs, ChartAxis::Turn), vec![0.0, 1.0]);
+}
+
+#[test]
+fn cumulative_cost_accumulates_per_sample_cost() {
+    // Opus: 1M output = $25 per turn
+    let samples = [sample(0, 0, 1_000_000), sample(10, 0, 1_000_000)];
+    let refs: Vec<&TimelineSample> = samples.iter().collect();
+
+    let points = cumulative_cost(&[0.0, 1.0], &refs, &PricingConfig::default(), None);
+
+    assert_eq!(points, vec![(0.0, 25.0), (1.0, 50.0)]);
+}
+
+// ===== Rendering =====
+
+#[test]
+fn charts_show_captions_and_peaks() {
+    let samples = [sample(0, 1_000, 200), sample(60, 3_000, 1_500)];
+
+    let output = render_to_string(&samples, ChartAxis::Turn, 50);
+
+    assert!(
+        output.contains("Output/turn  peak 1,500"),
+        "got:\n{}",
+        output
+    );
+    assert!(output.contains("Context  peak 3,000"), "got:\n{}", output);
+    assert!(output.contains("Cost"), "got:\n{}", output);
+    assert!(output.contains("by turn"), "got:\n{}", output);
+}
+
+#[test]
+fn wall_clock_axis_labels_with_timestamps() {
+    let samples = [sample(0, 1_000, 200), sample(1_800, 3_000, 1_500)];
+
+    let output = render_to_string(&samples, ChartAxis::WallClock, 50);
+
+    assert!(output.contains("by time"), "got:\n{}", output);
+    assert!(output.contains("14:00"), "got:\n{}", output);
+    assert!(output.contains("14:30"), "got:\n{}", output);
+}
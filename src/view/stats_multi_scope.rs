@@ -52,7 +52,7 @@ pub struct MultiScopeStatsPanel<'a> {
     /// Pricing configuration for cost estimation.
     pricing: &'a PricingConfig,
 
-    /// Model ID for pricing lookup (defaults to "opus" if None).
+    /// Fallback model ID for usage not attributed to a model (defaults to "opus" if None).
     model_id: Option<&'a str>,
 
     /// Whether this panel currently has focus (affects border color).
@@ -244,7 +244,7 @@ fn render_stat_scope(
     lines.push(empty_line());
 
     // Cost section
-    let cost = calculate_cost(&usage, pricing, model_id);
+    let cost = stats.filtered_estimated_cost(filter, pricing, model_id);
     lines.push(Line::from("Cost:").style(SECTION_HEADER));
     lines.push(Line::from(format!("  {}", format_cost(cost))));
     lines.push(empty_line());
@@ -278,31 +278,6 @@ fn scope_title(filter: &StatsFilter) -> &'static str {
     }
 }
 
-/// Calculate estimated cost in USD for the given token usage.
-fn calculate_cost(
-    usage: &crate::model::TokenUsage,
-    pricing: &PricingConfig,
-    model_id: Option<&str>,
-) -> f64 {
-    let model_pricing = pricing.get(model_id.unwrap_or("opus"));
-
-    let input_cost =
-        (usage.input_tokens as f64 / 1_000_000.0) * model_pricing.input_cost_per_million;
-
-    let output_cost =
-        (usage.output_tokens as f64 / 1_000_000.0) * model_pricing.output_cost_per_million;
-
-    let cache_rate = model_pricing
-        .cached_input_cost_per_million
-        .unwrap_or(model_pricing.input_cost_per_million);
-
-    let cache_cost = ((usage.cache_creation_input_tokens + usage.cache_read_input_tokens) as f64
-        / 1_000_000.0)
-        * cache_rate;
-
-    input_cost + output_cost + cache_cost
-}
-
 /// Format tool usage breakdown with top N limiting.
 fn format_tool_breakdown(
     tool_counts: &std::collections::HashMap<crate::model::ToolName, u32>,
@@ -0,0 +1,192 @@
+//! Per-tool reliability table for the statistics panel "Tools" page.
+
+use super::stats::format_tokens;
+use super::styles::{MUTED_TEXT, SECTION_HEADER};
+use crate::model::{ToolName, ToolSortColumn, ToolStats};
+use ratatui::text::Line;
+use std::collections::HashMap;
+
+/// Column headers with the sort column each one maps to.
+const COLUMNS: [(&str, Option<ToolSortColumn>); 7] = [
+    ("Tool", None),
+    ("Calls", Some(ToolSortColumn::Calls)),
+    ("Err", Some(ToolSortColumn::Errors)),
+    ("Err%", Some(ToolSortColumn::ErrorRate)),
+    ("p50", Some(ToolSortColumn::MedianLatency)),
+    ("p95", Some(ToolSortColumn::P95Latency)),
+    ("Avg result", Some(ToolSortColumn::ResultSize)),
+];
+
+/// Format per-tool stats as an aligned table sorted by `sort` (descending).
+///
+/// The sorted column's header is marked with `▼`. Missing values (no result
+/// seen yet) are shown as `-`. Ties are broken by tool name.
+pub(super) fn format_tool_table(
+    tool_stats: &HashMap<ToolName, ToolStats>,
+    sort: ToolSortColumn,
+) -> Vec<Line<'static>> {
+    if tool_stats.is_empty() {
+        return vec![Line::from("No tool calls in scope").style(MUTED_TEXT)];
+    }
+
+    let mut tools: Vec<(&ToolName, &ToolStats)> = tool_stats.iter().collect();
+    tools.sort_by(|a, b| {
+        sort.key(b.1)
+            .total_cmp(&sort.key(a.1))
+            .then_with(|| a.0.as_str().cmp(b.0.as_str()))
+    });
+
+    let header: Vec<String> = COLUMNS
+        .iter()
+        .map(|(label, column)| {
+            if *column == Some(sort) {
+                format!("{}▼", label)
+            } else {
+                label.to_string()
+            }
+        })
+        .collect();
+    let rows: Vec<[String; 7]> = tools
+        .iter()
+        .map(|(name, stats)| {
+            [
+                name.as_str().to_string(),
+                stats.calls.to_string(),
+                stats.errors.to_string(),
+                stats
+                    .error_rate()
+                    .map(|rate| format!("{:.1}%", rate * 100.0))
+                    .unwrap_or_else(|| "-".to_string()),
+                format_latency(stats.median_latency_ms()),
+                format_latency(stats.p95_latency_ms()),
+                match (stats.avg_result_chars(), stats.avg_result_tokens()) {
+                    (Some(chars), Some(tokens)) => {
+                        format!("{} ch ~{} tok", format_tokens(chars), format_tokens(tokens))
+                    }
+                    _ => "-".to_string(),
+                },
+            ]
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..COLUMNS.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain(std::iter::once(header[i].chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut lines = vec![Line::from(format_row(&header, &widths)).style(SECTION_HEADER)];
+    lines.extend(rows.iter().map(|row| Line::from(format_row(row, &widths))));
+    lines
+}
+
+/// Join cells with two spaces: first column left-aligned, the rest right-aligned.
+fn format_row(cells: &[String], widths: &[usize]) -> String {
+    cells
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(i, (cell, &width))| {
+            let pad = " ".repeat(width.saturating_sub(cell.chars().count()));
+            if i == 0 {
+                format!("{}{}", cell, pad)
+            } else {
+                format!("{}{}", pad, cell)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+/// Format a latency as `850ms`, `1.2s`, `2m05s` or `1h05m` (`-` if unknown).
+pub(super) fn format_latency(latency_ms: Option<u64>) -> String {
+    match latency_ms {
+        None => "-".to_string(),
+        Some(ms) if ms < 1_000 => format!("{}ms", ms),
+        Some(ms) if ms < 60_000 => format!("{:.1}s", ms as f64 / 1000.0),
+        Some(ms) if ms < 3_600_000 => format!("{}m{:02}s", ms / 60_000, (ms % 60_000) / 1000),
+        Some(ms) => format!("{}h{:02}m", ms / 3_600_000, (ms % 3_600_000) / 60_000),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn stats(calls: u32, results: &[(bool, u64, u64)]) -> ToolStats {
+        let mut stats = ToolStats::default();
+        for _ in 0..calls {
+            stats.record_call();
+        }
+        for &(is_error, latency_ms, chars) in results {
+            stats.record_result(is_error, latency_ms, chars);
+        }
+        stats
+    }
+
+    #[test]
+    fn format_latency_picks_unit_by_magnitude() {
+        assert_eq!(format_latency(None), "-");
+        assert_eq!(format_latency(Some(850)), "850ms");
+        assert_eq!(format_latency(Some(1_250)), "1.2s");
+        assert_eq!(format_latency(Some(125_000)), "2m05s");
+    }
+
+    #[test]
+    fn table_shows_placeholder_when_empty() {
+        let lines = format_tool_table(&HashMap::new(), ToolSortColumn::Calls);
+
+        assert_eq!(line_text(&lines[0]), "No tool calls in scope");
+    }
+
+    #[test]
+    fn table_rows_show_all_metrics() {
+        let mut tool_stats = HashMap::new();
+        tool_stats.insert(
+            ToolName::Bash,
+            stats(2, &[(true, 1_500, 400), (false, 500, 400)]),
+        );
+
+        let lines = format_tool_table(&tool_stats, ToolSortColumn::Calls);
+        let row = line_text(&lines[1]);
+
+        assert!(line_text(&lines[0]).contains("Calls▼"));
+        assert!(row.starts_with("Bash"), "got: {}", row);
+        assert!(row.contains("50.0%"), "got: {}", row);
+        assert!(row.contains("500ms"), "got: {}", row);
+        assert!(row.contains("1.5s"), "got: {}", row);
+        assert!(row.contains("400 ch ~100 tok"), "got: {}", row);
+    }
+
+    #[test]
+    fn table_sorts_by_selected_column_descending() {
+        let mut tool_stats = HashMap::new();
+        tool_stats.insert(ToolName::Read, stats(10, &[(false, 10, 10)]));
+        tool_stats.insert(ToolName::Bash, stats(2, &[(true, 9_000, 10)]));
+
+        let by_calls = format_tool_table(&tool_stats, ToolSortColumn::Calls);
+        let by_errors = format_tool_table(&tool_stats, ToolSortColumn::ErrorRate);
+
+        assert!(line_text(&by_calls[1]).starts_with("Read"));
+        assert!(line_text(&by_errors[1]).starts_with("Bash"));
+        assert!(line_text(&by_errors[0]).contains("Err%▼"));
+    }
+
+    #[test]
+    fn table_shows_dash_for_calls_without_results() {
+        let mut tool_stats = HashMap::new();
+        tool_stats.insert(ToolName::Task, stats(1, &[]));
+
+        let lines = format_tool_table(&tool_stats, ToolSortColumn::Calls);
+
+        assert!(line_text(&lines[1]).ends_with('-'));
+    }
+}
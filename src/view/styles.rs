@@ -18,6 +18,11 @@ pub const SECTION_HEADER: Style = Style::new().fg(Color::Cyan).add_modifier(Modi
 /// Used for less prominent UI elements like disabled indicators.
 pub const MUTED_TEXT: Style = Style::new().fg(Color::Gray);
 
+/// Style for cost warnings: Yellow + Bold.
+///
+/// Used for the marker shown when estimated and reported cost diverge.
+pub const COST_WARNING: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+
 // ===== ColorConfig =====
 
 /// Configuration for color output.
@@ -0,0 +1,279 @@
+//! Subagent table modal rendering.
+
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use super::stats::{format_cost, format_tokens};
+use super::stats_tools::format_latency;
+use crate::model::{SubagentSortColumn, SubagentStatus, SubagentSummary};
+use crate::state::AppState;
+
+/// Maximum width of the modal in columns.
+const MODAL_WIDTH: u16 = 150;
+
+/// Widths of every column but the label, in `SubagentSortColumn::ALL` order.
+const COLUMN_WIDTHS: [usize; 10] = [14, 8, 8, 8, 7, 8, 11, 8, 6, 7];
+
+/// Render the subagent table overlay.
+///
+/// One row per subagent of the viewed session; the sort column's header is
+/// marked with `▲`/`▼`. The line under the table breaks down the selected
+/// subagent's tool calls.
+///
+/// Only renders when `state.subagent_table.is_visible()` is true.
+pub fn render_subagent_table(frame: &mut Frame, state: &AppState) {
+    if !state.subagent_table.is_visible() {
+        return;
+    }
+
+    let table = &state.subagent_table;
+    let subagents = table.rows();
+
+    let area = frame.area();
+    let popup_width = MODAL_WIDTH.min(area.width.saturating_sub(4));
+    let popup_height = area.height.saturating_sub(4);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(
+            Line::from(Span::styled(
+                format!(" Subagents ({}) ", subagents.len()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Center),
+        )
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(Color::White))
+        .style(Style::default().bg(Color::DarkGray));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let [header_area, list_area, tools_area, footer_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let label_width = (inner.width as usize).saturating_sub(FIXED_COLUMNS_WIDTH);
+    let descending = table.sort().is_descending() != table.reversed();
+    let header: Vec<String> = SubagentSortColumn::ALL
+        .iter()
+        .map(|column| {
+            if *column == table.sort() {
+                format!("{}{}", column.label(), if descending { "▼" } else { "▲" })
+            } else {
+                column.label().to_string()
+            }
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(format_row(&header, label_width)).style(super::styles::SECTION_HEADER),
+        header_area,
+    );
+
+    if subagents.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No subagents in this session").style(super::styles::MUTED_TEXT),
+            list_area,
+        );
+    } else {
+        let items: Vec<ListItem> = subagents
+            .iter()
+            .map(|summary| {
+                let line = Line::from(format_row(&summary_cells(summary), label_width));
+                match summary.status {
+                    SubagentStatus::Failed => ListItem::new(line.style(FAILED_STYLE)),
+                    _ => ListItem::new(line),
+                }
+            })
+            .collect();
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(Color::Cyan)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut list_state = ListState::default().with_selected(Some(table.selected()));
+        frame.render_stateful_widget(list, list_area, &mut list_state);
+    }
+
+    if let Some(summary) = subagents.get(table.selected()) {
+        frame.render_widget(
+            Paragraph::new(format!(" Tools: {}", format_tool_counts(summary)))
+                .style(super::styles::MUTED_TEXT),
+            tools_area,
+        );
+    }
+
+    frame.render_widget(
+        Paragraph::new("↑/↓: Navigate  s/S: Sort column  r: Reverse  Enter: Open tab  Esc: Close")
+            .style(Style::default().fg(Color::Gray).add_modifier(Modifier::DIM))
+            .alignment(Alignment::Center),
+        footer_area,
+    );
+}
+
+/// Style for rows of failed subagents.
+const FAILED_STYLE: Style = Style::new().fg(Color::LightRed);
+
+/// Leading space plus every column but the label, with two-space gaps.
+const FIXED_COLUMNS_WIDTH: usize = {
+    let mut width = 1;
+    let mut i = 0;
+    while i < COLUMN_WIDTHS.len() {
+        width += COLUMN_WIDTHS[i] + 2;
+        i += 1;
+    }
+    width
+};
+
+/// Cells of one row, in `SubagentSortColumn::ALL` order.
+fn summary_cells(summary: &SubagentSummary) -> Vec<String> {
+    let time = |t: Option<chrono::DateTime<chrono::Utc>>| {
+        t.map_or_else(|| "-".to_string(), |t| t.format("%H:%M:%S").to_string())
+    };
+    vec![
+        summary.label().to_string(),
+        summary
+            .subagent_type
+            .clone()
+            .unwrap_or_else(|| "-".to_string()),
+        summary
+            .model
+            .as_ref()
+            .map_or_else(|| "-".to_string(), |m| m.display_name().to_string()),
+        time(summary.start),
+        time(summary.end),
+        format_latency(
+            summary
+                .duration()
+                .map(|d| d.num_milliseconds().max(0) as u64),
+        ),
+        summary.entries.to_string(),
+        format_tokens(summary.usage.total()),
+        format_cost(summary.estimated_cost),
+        summary.tool_calls().to_string(),
+        summary.status.label().to_string(),
+    ]
+}
+
+/// Pad cells to their column widths: the label and type left-aligned
+/// (truncated with `…`), the rest right-aligned except the status.
+fn format_row(cells: &[String], label_width: usize) -> String {
+    let widths = std::iter::once(label_width).chain(COLUMN_WIDTHS);
+    let cells: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(i, (cell, width))| {
+            let cell = truncate_end(cell, width);
+            let pad = " ".repeat(width.saturating_sub(cell.chars().count()));
+            match i {
+                0 | 1 | 2 | 10 => format!("{}{}", cell, pad),
+                _ => format!("{}{}", pad, cell),
+            }
+        })
+        .collect();
+    format!(" {}", cells.join("  "))
+}
+
+/// Tool counts as `Read 5, Bash 3`, most used first (ties by name).
+fn format_tool_counts(summary: &SubagentSummary) -> String {
+    if summary.tool_counts.is_empty() {
+        return "none".to_string();
+    }
+    let mut counts: Vec<_> = summary.tool_counts.iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+    counts
+        .iter()
+        .map(|(name, count)| format!("{} {}", name.as_str(), count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Keep the start of `text`, eliding the end with `…`.
+fn truncate_end(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let head: String = text.chars().take(width.saturating_sub(1)).collect();
+    format!("{}…", head)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{AgentId, ModelInfo, TokenUsage, ToolName};
+    use chrono::TimeZone;
+    use std::collections::HashMap;
+
+    fn summary() -> SubagentSummary {
+        SubagentSummary {
+            agent_id: AgentId::new("toolu_a").unwrap(),
+            description: Some("Find the failing tests".to_string()),
+            subagent_type: Some("Explore".to_string()),
+            model: Some(ModelInfo::new("claude-haiku-4-5")),
+            start: Some(chrono::Utc.with_ymd_and_hms(2025, 1, 15, 14, 0, 0).unwrap()),
+            end: Some(chrono::Utc.with_ymd_and_hms(2025, 1, 15, 14, 2, 5).unwrap()),
+            entries: 12,
+            usage: TokenUsage {
+                input_tokens: 1_000,
+                output_tokens: 234,
+                ..Default::default()
+            },
+            estimated_cost: 0.125,
+            tool_counts: HashMap::from([(ToolName::Read, 5), (ToolName::Bash, 3)]),
+            status: SubagentStatus::Succeeded,
+        }
+    }
+
+    #[test]
+    fn row_shows_every_column() {
+        let row = format_row(&summary_cells(&summary()), 24);
+
+        for expected in [
+            "Find the failing tests",
+            "Explore",
+            "Haiku",
+            "14:00:00",
+            "14:02:05",
+            "2m05s",
+            "12",
+            "1,234",
+            "$0.13",
+            "8",
+            "ok",
+        ] {
+            assert!(
+                row.contains(expected),
+                "missing {:?} in {:?}",
+                expected,
+                row
+            );
+        }
+    }
+
+    #[test]
+    fn row_truncates_long_label() {
+        let row = format_row(&summary_cells(&summary()), 10);
+
+        assert!(row.starts_with(" Find the …  Explore"), "got: {:?}", row);
+    }
+
+    #[test]
+    fn tool_counts_list_most_used_first() {
+        assert_eq!(format_tool_counts(&summary()), "Read 5, Bash 3");
+    }
+}
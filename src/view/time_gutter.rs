@@ -0,0 +1,145 @@
+//! Time gutter rendering for the conversation view.
+//!
+//! The gutter's first line per entry shows the entry's time (absolute or
+//! relative) and the gap since the previous entry, highlighted when unusually
+//! long; a user prompt's second line shows its turn latency. Entries without
+//! a timestamp show `--:--:--`.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+
+use super::stats_tools::format_latency;
+use crate::model::EntryTiming;
+use crate::state::TimeGutter;
+
+/// Style of times and ordinary gaps.
+const TIME_STYLE: Style = Style::new().fg(Color::DarkGray);
+
+/// Style of unusually long gaps.
+const LONG_GAP_STYLE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+
+/// Style of turn latencies.
+const LATENCY_STYLE: Style = Style::new().fg(Color::Magenta);
+
+/// Gutter spans for line `line_in_entry` of an entry, padded to `mode.width()`.
+pub(super) fn gutter_spans(
+    mode: TimeGutter,
+    timing: &EntryTiming,
+    line_in_entry: usize,
+) -> Vec<Span<'static>> {
+    let width = mode.width() as usize;
+    match line_in_entry {
+        0 => {
+            let time = match (mode, timing.timestamp, timing.elapsed) {
+                (TimeGutter::Absolute, Some(timestamp), _) => {
+                    timestamp.format("%H:%M:%S").to_string()
+                }
+                (TimeGutter::Relative, _, Some(elapsed)) => format_elapsed(elapsed),
+                _ => "--:--:--".to_string(),
+            };
+            let gap = timing.gap.map_or_else(String::new, |gap| {
+                format!(
+                    "+{}",
+                    format_latency(Some(gap.num_milliseconds().max(0) as u64))
+                )
+            });
+            let gap_style = if timing.is_long_gap() {
+                LONG_GAP_STYLE
+            } else {
+                TIME_STYLE
+            };
+            vec![
+                Span::styled(format!("{:>8} ", time), TIME_STYLE),
+                Span::styled(format!("{:>7}", gap), gap_style),
+                Span::raw(" ".repeat(width.saturating_sub(16))),
+            ]
+        }
+        1 if timing.turn_latency.is_some() => {
+            let latency = timing
+                .turn_latency
+                .map(|l| format_latency(Some(l.num_milliseconds().max(0) as u64)))
+                .unwrap_or_default();
+            vec![
+                Span::styled(
+                    format!("{:>16}", format!("turn {}", latency)),
+                    LATENCY_STYLE,
+                ),
+                Span::raw(" ".repeat(width.saturating_sub(16))),
+            ]
+        }
+        _ => vec![Span::raw(" ".repeat(width))],
+    }
+}
+
+/// Blank gutter for lines outside entries (session separators).
+pub(super) fn blank_gutter(mode: TimeGutter) -> Vec<Span<'static>> {
+    vec![Span::raw(" ".repeat(mode.width() as usize))]
+}
+
+/// Format time since the conversation started as `+2:05`, `+1:02:05` or `+12h05m`.
+fn format_elapsed(elapsed: chrono::Duration) -> String {
+    let secs = elapsed.num_seconds().max(0);
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours >= 10 {
+        format!("+{}h{:02}m", hours, minutes)
+    } else if hours > 0 {
+        format!("+{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("+{}:{:02}", minutes, seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn text(spans: &[Span]) -> String {
+        spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn timing(gap_secs: i64) -> EntryTiming {
+        EntryTiming {
+            timestamp: Some(Utc.with_ymd_and_hms(2025, 1, 15, 14, 3, 12).unwrap()),
+            elapsed: Some(Duration::seconds(3725)),
+            gap: Some(Duration::milliseconds(gap_secs * 1000 + 400)),
+            turn_latency: Some(Duration::seconds(72)),
+        }
+    }
+
+    #[test]
+    fn first_line_shows_time_and_gap() {
+        let absolute = gutter_spans(TimeGutter::Absolute, &timing(12), 0);
+        let relative = gutter_spans(TimeGutter::Relative, &timing(12), 0);
+
+        assert_eq!(text(&absolute), "14:03:12  +12.4s  ");
+        assert_eq!(text(&relative), "+1:02:05  +12.4s  ");
+        assert_eq!(absolute[1].style, TIME_STYLE);
+    }
+
+    #[test]
+    fn long_gap_is_highlighted() {
+        let spans = gutter_spans(TimeGutter::Absolute, &timing(300), 0);
+
+        assert_eq!(text(&spans), "14:03:12  +5m00s  ");
+        assert_eq!(spans[1].style, LONG_GAP_STYLE);
+    }
+
+    #[test]
+    fn second_line_shows_turn_latency_and_unknown_time_is_marked() {
+        let latency = gutter_spans(TimeGutter::Absolute, &timing(1), 1);
+        let unknown = gutter_spans(TimeGutter::Absolute, &EntryTiming::default(), 0);
+
+        assert_eq!(text(&latency), "      turn 1m12s  ");
+        assert_eq!(text(&unknown), "--:--:--          ");
+        assert_eq!(
+            text(&gutter_spans(
+                TimeGutter::Relative,
+                &EntryTiming::default(),
+                1
+            ))
+            .len(),
+            18
+        );
+    }
+}
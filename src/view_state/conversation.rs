@@ -40,6 +40,8 @@ pub struct ConversationViewState {
     model: Option<crate::model::ModelInfo>,
     /// Entries with computed layouts and per-entry view state.
     entries: Vec<EntryView>,
+    /// Wall-clock timing of each entry, extended on append.
+    timings: crate::model::EntryTimings,
     /// Current scroll position.
     scroll: ScrollPosition,
     /// Fenwick tree for O(log n) cumulative height queries.
@@ -48,6 +50,9 @@ pub struct ConversationViewState {
     /// Viewport width used for last layout.
     /// Needed for recomputing rendered_lines when entries change.
     viewport_width: u16,
+    /// Columns reserved left of the entries (time gutter).
+    /// Entries are wrapped to `viewport_width - gutter_width`.
+    gutter_width: u16,
     /// Global wrap mode used for last layout.
     /// Needed for recomputing rendered_lines when entries change.
     global_wrap: WrapMode,
@@ -64,9 +69,9 @@ pub struct ConversationViewState {
     /// Only relevant when line wrapping is disabled (FR-040).
     /// 0 means viewing from the leftmost column.
     horizontal_offset: u16,
-    /// Maximum context window size (from config).
+    /// Context window size per model (from config).
     /// Used for rendering context dividers with percentages.
-    max_context_tokens: usize,
+    context_windows: crate::model::ContextWindows,
     /// Pricing configuration (from config).
     /// Used for cost calculation in dividers.
     pricing: crate::model::PricingConfig,
@@ -79,41 +84,47 @@ impl ConversationViewState {
         agent_id: Option<crate::model::AgentId>,
         model: Option<crate::model::ModelInfo>,
         entries: Vec<ConversationEntry>,
-        max_context_tokens: usize,
+        context_windows: crate::model::ContextWindows,
         pricing: crate::model::PricingConfig,
     ) -> Self {
-        // Compute accumulated tokens as running sum
+        // Compute accumulated tokens as running sum (restarting at compactions)
         let mut accumulated = 0;
+        let mut timings = crate::model::EntryTimings::default();
         let entry_views: Vec<EntryView> = entries
             .into_iter()
             .enumerate()
             .map(|(idx, entry)| {
-                accumulated += entry.token_count();
+                accumulated = accumulate_tokens(accumulated, &entry);
+                timings.push(&entry);
                 EntryView::new(
                     entry,
                     EntryIndex::new(idx),
                     accumulated,
-                    max_context_tokens,
+                    context_windows.clone(),
                     pricing.clone(),
                 )
             })
             .collect();
         let capacity = entry_views.len().max(100);
-        Self {
+        let mut state = Self {
             agent_id,
             model,
             entries: entry_views,
+            timings,
             scroll: ScrollPosition::Top,
             height_index: HeightIndex::new(capacity),
             viewport_width: 0,
+            gutter_width: 0,
             global_wrap: WrapMode::Wrap, // Default to Wrap
             total_height: 0,
             focused_message: None,
             last_layout_params: None,
             horizontal_offset: 0,
-            max_context_tokens,
+            context_windows,
             pricing,
-        }
+        };
+        state.resolve_post_compact_tokens(0);
+        state
     }
 
     /// Create empty conversation view-state for main agent.
@@ -122,7 +133,7 @@ impl ConversationViewState {
             None,
             None,
             Vec::new(),
-            200_000, // Default max_context_tokens
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         )
     }
@@ -180,6 +191,11 @@ impl ConversationViewState {
         self.entries.iter()
     }
 
+    /// Wall-clock timing of each entry, in entry order.
+    pub fn timings(&self) -> &[crate::model::EntryTiming] {
+        self.timings.as_slice()
+    }
+
     /// Get agent ID (None for main agent, Some(id) for subagents).
     pub fn agent_id(&self) -> Option<&crate::model::AgentId> {
         self.agent_id.as_ref()
@@ -298,19 +314,43 @@ impl ConversationViewState {
             .unwrap_or(0);
 
         for (offset, entry) in entries.into_iter().enumerate() {
-            accumulated += entry.token_count();
+            accumulated = accumulate_tokens(accumulated, &entry);
+            self.timings.push(&entry);
             self.entries.push(EntryView::new(
                 entry,
                 EntryIndex::new(start_idx + offset),
                 accumulated,
-                self.max_context_tokens,
+                self.context_windows.clone(),
                 self.pricing.clone(),
             ));
         }
+        self.resolve_post_compact_tokens(start_idx);
         // Invalidate layout
         self.last_layout_params = None;
     }
 
+    /// Set context window sizes for all entries and entries appended later.
+    /// Call `relayout` to re-render the dividers.
+    pub fn set_context_windows(&mut self, context_windows: crate::model::ContextWindows) {
+        for entry in &mut self.entries {
+            entry.set_context_windows(context_windows.clone());
+        }
+        self.context_windows = context_windows;
+        self.last_layout_params = None;
+    }
+
+    /// Reserve columns left of the entries (time gutter).
+    /// Call `relayout` to re-wrap the entries.
+    pub fn set_gutter_width(&mut self, width: u16) {
+        self.gutter_width = width;
+        self.last_layout_params = None;
+    }
+
+    /// Width entries are wrapped to: the viewport minus the gutter.
+    fn content_width(&self) -> u16 {
+        self.viewport_width.saturating_sub(self.gutter_width)
+    }
+
     /// Recompute layout for all entries.
     ///
     /// # Deprecated
@@ -426,6 +466,15 @@ impl ConversationViewState {
         Some(previous)
     }
 
+    /// Expand or collapse every entry.
+    /// Call `relayout` to re-render the entries.
+    pub fn set_all_expanded(&mut self, expanded: bool) {
+        for entry in &mut self.entries {
+            entry.set_expanded(expanded);
+        }
+        self.last_layout_params = None;
+    }
+
     /// Compute visible range using binary search.
     /// O(log n) complexity.
     ///
@@ -622,10 +671,11 @@ impl ConversationViewState {
         self.global_wrap = wrap;
         self.height_index.clear();
 
+        let content_width = self.content_width();
         for (idx, entry_view) in self.entries.iter_mut().enumerate() {
             let effective_wrap = entry_view.effective_wrap(wrap);
             let is_focused = self.focused_message.is_some_and(|f| f.get() == idx);
-            entry_view.recompute_lines(effective_wrap, width, search_state, is_focused);
+            entry_view.recompute_lines(effective_wrap, content_width, search_state, is_focused);
 
             let height = entry_view.height().get() as usize;
             self.height_index.push(height);
@@ -656,6 +706,7 @@ impl ConversationViewState {
             return;
         }
 
+        let content_width = self.content_width();
         let entry = &mut self.entries[index];
         let old_expanded = entry.is_expanded();
         let old_height = entry.height();
@@ -674,12 +725,7 @@ impl ConversationViewState {
         // Recompute lines with new expand state
         let effective_wrap = entry.effective_wrap(self.global_wrap);
         let is_focused = self.focused_message.is_some_and(|f| f.get() == index);
-        entry.recompute_lines(
-            effective_wrap,
-            self.viewport_width,
-            search_state,
-            is_focused,
-        );
+        entry.recompute_lines(effective_wrap, content_width, search_state, is_focused);
 
         let new_height = entry.height().get() as usize;
 
@@ -718,6 +764,7 @@ impl ConversationViewState {
             return;
         }
 
+        let content_width = self.content_width();
         let entry = &mut self.entries[index];
 
         // Set wrap override
@@ -726,12 +773,7 @@ impl ConversationViewState {
         // Recompute lines with new wrap mode
         let effective_wrap = entry.effective_wrap(self.global_wrap);
         let is_focused = self.focused_message.is_some_and(|f| f.get() == index);
-        entry.recompute_lines(
-            effective_wrap,
-            self.viewport_width,
-            search_state,
-            is_focused,
-        );
+        entry.recompute_lines(effective_wrap, content_width, search_state, is_focused);
 
         let new_height = entry.height().get() as usize;
 
@@ -775,13 +817,14 @@ impl ConversationViewState {
 
         for (offset, entry) in entries.into_iter().enumerate() {
             let index = EntryIndex::new(start_idx + offset);
-            accumulated += entry.token_count();
+            accumulated = accumulate_tokens(accumulated, &entry);
+            self.timings.push(&entry);
 
             let mut entry_view = EntryView::new(
                 entry,
                 index,
                 accumulated,
-                self.max_context_tokens,
+                self.context_windows.clone(),
                 self.pricing.clone(),
             );
 
@@ -792,7 +835,7 @@ impl ConversationViewState {
                 .is_some_and(|f| f.get() == (start_idx + offset));
             entry_view.recompute_lines(
                 effective_wrap,
-                self.viewport_width,
+                self.content_width(),
                 search_state,
                 is_focused,
             );
@@ -805,8 +848,79 @@ impl ConversationViewState {
             self.entries.push(entry_view);
         }
 
+        // A boundary appended earlier may only now learn its post-compaction
+        // context; re-render it with the new figure
+        for index in self.resolve_post_compact_tokens(start_idx) {
+            if index < start_idx {
+                self.recompute_entry(index, search_state);
+            }
+        }
+
         self.total_height = self.height_index.total();
     }
+
+    /// Recompute one entry's lines and update its height.
+    fn recompute_entry(&mut self, index: usize, search_state: &crate::state::SearchState) {
+        let is_focused = self.focused_message.is_some_and(|f| f.get() == index);
+        let content_width = self.content_width();
+        let entry = &mut self.entries[index];
+        let effective_wrap = entry.effective_wrap(self.global_wrap);
+        entry.recompute_lines(effective_wrap, content_width, search_state, is_focused);
+        self.height_index.set(index, entry.height().get() as usize);
+    }
+
+    /// Give compact boundaries that lack a post-compaction count the context
+    /// of the first response with usage after them.
+    ///
+    /// Scans from `from`, or from the last boundary before it if that one is
+    /// still waiting for a response. Returns the indices of updated boundaries.
+    fn resolve_post_compact_tokens(&mut self, from: usize) -> Vec<usize> {
+        let is_boundary = |view: &EntryView| {
+            view.entry()
+                .as_valid()
+                .is_some_and(|e| e.compact_metadata().is_some())
+        };
+        let is_pending = |view: &EntryView| {
+            view.post_compact_tokens().is_none()
+                && view
+                    .entry()
+                    .as_valid()
+                    .and_then(|e| e.compact_metadata())
+                    .is_some_and(|c| c.post_tokens.is_none())
+        };
+
+        let mut pending = self.entries[..from]
+            .iter()
+            .rposition(is_boundary)
+            .filter(|&index| is_pending(&self.entries[index]));
+        let mut updated = Vec::new();
+        for index in from..self.entries.len() {
+            let view = &self.entries[index];
+            if is_boundary(view) {
+                pending = is_pending(view).then_some(index);
+            } else if let Some(boundary) = pending {
+                let tokens = view.entry().token_count();
+                if tokens > 0 {
+                    self.entries[boundary].set_post_compact_tokens(Some(tokens as u64));
+                    updated.push(boundary);
+                    pending = None;
+                }
+            }
+        }
+        updated
+    }
+}
+
+/// Running token sum after `entry`; compact boundaries restart it at zero.
+fn accumulate_tokens(accumulated: usize, entry: &ConversationEntry) -> usize {
+    let is_boundary = entry
+        .as_valid()
+        .is_some_and(|e| e.compact_metadata().is_some());
+    if is_boundary {
+        0
+    } else {
+        accumulated + entry.token_count()
+    }
 }
 
 #[cfg(test)]
@@ -816,6 +930,7 @@ mod tests {
         EntryMetadata, EntryType, EntryUuid, LogEntry, MalformedEntry, Message, MessageContent,
         Role, SessionId,
     };
+    use std::sync::Arc;
 
     // ===== Test Helpers =====
 
@@ -846,7 +961,7 @@ mod tests {
             make_message("Test message"),
             EntryMetadata::default(),
         );
-        ConversationEntry::Valid(Box::new(log_entry))
+        ConversationEntry::Valid(Arc::new(log_entry))
     }
 
     fn make_malformed_entry() -> ConversationEntry {
@@ -877,7 +992,7 @@ mod tests {
             message,
             EntryMetadata::default(),
         );
-        ConversationEntry::Valid(Box::new(log_entry))
+        ConversationEntry::Valid(Arc::new(log_entry))
     }
 
     /// Test helper: Create ConversationViewState with default config values
@@ -890,7 +1005,7 @@ mod tests {
             agent_id,
             model,
             entries,
-            200_000, // Default test max_context_tokens
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         )
     }
@@ -909,7 +1024,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -924,7 +1039,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -946,7 +1061,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -960,7 +1075,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -982,7 +1097,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1023,7 +1138,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1059,7 +1174,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1094,7 +1209,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1131,7 +1246,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1165,7 +1280,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1198,7 +1313,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1231,7 +1346,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1293,14 +1408,14 @@ mod tests {
             None,
             None,
             entries.clone(),
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
         let mut state2 = ConversationViewState::new(
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1329,7 +1444,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1361,7 +1476,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1421,7 +1536,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1515,7 +1630,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1538,7 +1653,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1569,7 +1684,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1600,7 +1715,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1624,7 +1739,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1657,7 +1772,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1691,7 +1806,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1741,7 +1856,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1779,7 +1894,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1797,7 +1912,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1815,7 +1930,7 @@ mod tests {
             None,
             None,
             vec![make_valid_entry("uuid-1")],
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1828,13 +1943,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn appended_entries_get_the_same_timings_as_loaded_ones() {
+        let entries = vec![
+            make_valid_entry("uuid-1"),
+            make_malformed_entry(),
+            make_valid_entry("uuid-2"),
+        ];
+        let loaded = ConversationViewState::new(
+            None,
+            None,
+            entries.clone(),
+            crate::model::ContextWindows::default(),
+            crate::model::PricingConfig::default(),
+        );
+        let mut streamed = ConversationViewState::empty();
+
+        streamed.append(entries[..1].to_vec());
+        streamed.append_entries(entries[1..].to_vec(), &crate::state::SearchState::Inactive);
+
+        assert_eq!(
+            streamed.timings(),
+            crate::model::EntryTiming::for_entries(&entries).as_slice()
+        );
+        assert_eq!(streamed.timings(), loaded.timings());
+    }
+
     #[test]
     fn append_invalidates_layout() {
         let mut state = ConversationViewState::new(
             None,
             None,
             vec![make_valid_entry("uuid-1")],
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1858,7 +1999,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1891,7 +2032,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -1925,7 +2066,7 @@ mod tests {
             None,
             None,
             vec![make_valid_entry("uuid-1")],
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
         state.recompute_layout(params);
@@ -1946,7 +2087,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2007,7 +2148,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2025,7 +2166,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2045,7 +2186,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2073,7 +2214,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2102,7 +2243,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2133,7 +2274,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2157,7 +2298,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2207,7 +2348,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
         let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -2229,7 +2370,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
         let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -2251,7 +2392,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
         let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -2277,7 +2418,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
         let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -2304,7 +2445,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
         let params = LayoutParams::new(80, WrapMode::Wrap);
@@ -2341,7 +2482,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2386,7 +2527,7 @@ mod tests {
             None,
             None,
             entries,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -2414,6 +2555,148 @@ mod tests {
             height_after
         );
     }
+
+    #[test]
+    fn gutter_width_narrows_wrapping() {
+        let long_line = "word ".repeat(26);
+        let entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
+            make_entry_uuid("uuid-1"),
+            None,
+            make_session_id("session-1"),
+            None,
+            make_timestamp(),
+            EntryType::User,
+            make_message(&long_line),
+            EntryMetadata::default(),
+        )));
+        let mut state = make_test_state(None, None, vec![entry]);
+
+        state.relayout(80, WrapMode::Wrap, &crate::state::SearchState::Inactive);
+        let height_without_gutter = state.total_height();
+        state.set_gutter_width(18);
+        state.relayout(80, WrapMode::Wrap, &crate::state::SearchState::Inactive);
+
+        assert!(
+            state.total_height() > height_without_gutter,
+            "Entries should wrap to the width left by the gutter"
+        );
+    }
+
+    // ===== Compaction Tests =====
+
+    fn make_compact_boundary(uuid: &str, post_tokens: Option<u64>) -> ConversationEntry {
+        let system_metadata = crate::model::SystemMetadata {
+            subtype: crate::model::SystemMetadata::COMPACT_BOUNDARY.to_string(),
+            cwd: None,
+            model: None,
+            tools: vec![],
+            agents: vec![],
+            skills: vec![],
+            permission_mode: None,
+            mcp_servers: vec![],
+            version: None,
+            compact: Some(crate::model::CompactMetadata {
+                trigger: Some("auto".to_string()),
+                pre_tokens: Some(150_000),
+                post_tokens,
+                summary: None,
+            }),
+        };
+        ConversationEntry::Valid(Arc::new(LogEntry::new_with_system_metadata(
+            make_entry_uuid(uuid),
+            None,
+            make_session_id("session-1"),
+            None,
+            make_timestamp(),
+            EntryType::System,
+            Message::new(Role::Assistant, MessageContent::Text(String::new())),
+            EntryMetadata::default(),
+            Some(system_metadata),
+        )))
+    }
+
+    fn make_entry_with_input(uuid: &str, input_tokens: u64) -> ConversationEntry {
+        let message = Message::new(Role::Assistant, MessageContent::Text("ok".to_string()))
+            .with_usage(crate::model::TokenUsage {
+                input_tokens,
+                ..Default::default()
+            });
+        ConversationEntry::Valid(Arc::new(LogEntry::new(
+            make_entry_uuid(uuid),
+            None,
+            make_session_id("session-1"),
+            None,
+            make_timestamp(),
+            EntryType::Assistant,
+            message,
+            EntryMetadata::default(),
+        )))
+    }
+
+    fn first_line_text(state: &ConversationViewState, index: usize) -> String {
+        state.entries()[index].rendered_lines()[0]
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn compact_boundary_restarts_accumulated_tokens() {
+        let state = make_test_state(
+            None,
+            None,
+            vec![
+                make_entry_with_input("a", 100),
+                make_entry_with_input("b", 200),
+                make_compact_boundary("c", None),
+                make_entry_with_input("d", 50),
+            ],
+        );
+
+        let accumulated: Vec<usize> = state.iter().map(|e| e.accumulated_tokens()).collect();
+        assert_eq!(accumulated, vec![100, 300, 0, 50]);
+    }
+
+    #[test]
+    fn compact_boundary_takes_post_tokens_from_next_response() {
+        let state = make_test_state(
+            None,
+            None,
+            vec![
+                make_compact_boundary("a", None),
+                make_entry_with_n_lines("b", 1),
+                make_entry_with_input("c", 12_000),
+                make_compact_boundary("d", Some(9_000)),
+                make_entry_with_input("e", 20_000),
+            ],
+        );
+
+        assert_eq!(state.entries()[0].post_compact_tokens(), Some(12_000));
+        assert_eq!(
+            state.entries()[3].post_compact_tokens(),
+            None,
+            "Recorded post tokens need no fallback"
+        );
+    }
+
+    #[test]
+    fn append_entries_rerenders_boundary_waiting_for_post_tokens() {
+        let mut state = make_test_state(None, None, vec![make_compact_boundary("a", None)]);
+        state.relayout(120, WrapMode::Wrap, &crate::state::SearchState::Inactive);
+        assert!(first_line_text(&state, 0).contains("150.0k → ? tokens"));
+
+        state.append_entries(
+            vec![make_entry_with_input("b", 12_000)],
+            &crate::state::SearchState::Inactive,
+        );
+
+        assert!(
+            first_line_text(&state, 0).contains("150.0k → 12.0k tokens"),
+            "got: {}",
+            first_line_text(&state, 0)
+        );
+    }
 }
 
 // HeightIndex integration tests
@@ -7,6 +7,7 @@ use super::*;
 use crate::model::{
     EntryMetadata, EntryType, EntryUuid, LogEntry, Message, MessageContent, Role, SessionId,
 };
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -37,7 +38,7 @@ fn make_valid_entry(uuid: &str) -> ConversationEntry {
         make_message("Test message"),
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 // ===== HeightIndex Integration Tests =====
@@ -48,7 +49,7 @@ fn new_state_has_empty_height_index() {
         None,
         None,
         vec![],
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -68,7 +69,7 @@ fn relayout_populates_height_index() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -109,7 +110,7 @@ fn toggle_entry_expanded_updates_height_index() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -158,7 +159,7 @@ fn set_entry_wrap_override_updates_height_index() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -191,7 +192,7 @@ fn append_entries_updates_height_index() {
         None,
         None,
         vec![make_valid_entry("uuid-1")],
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -240,7 +241,7 @@ fn visible_range_uses_height_index() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -291,7 +292,7 @@ fn total_height_equals_height_index_total() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -321,7 +322,7 @@ fn height_index_invariant_maintained_across_operations() {
         None,
         None,
         entries,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
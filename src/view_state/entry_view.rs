@@ -27,8 +27,10 @@ use ratatui::text::Line;
 /// # Per-Entry Presentation State
 /// - `expanded`: Whether entry shows full content or collapsed summary (FR-031)
 /// - `wrap_override`: Optional per-entry wrap mode override (FR-048)
-/// - `accumulated_tokens`: Running sum of tokens from conversation start to this entry (inclusive)
-/// - `max_context_tokens`: Context window size (for percentage calculation)
+/// - `accumulated_tokens`: Running sum of tokens from the last compaction (or conversation
+///   start) to this entry (inclusive)
+/// - `post_compact_tokens`: Context after compaction, for compact boundaries that do not record it
+/// - `context_windows`: Context window size per model (for percentage calculation)
 /// - `pricing`: Model pricing info (for cost calculation)
 ///
 /// # Malformed Entries
@@ -51,12 +53,16 @@ pub struct EntryView {
     /// `None` = use global wrap mode.
     /// `Some(mode)` = override global with this specific mode.
     wrap_override: Option<WrapMode>,
-    /// Accumulated tokens from conversation start up to and including this entry.
+    /// Accumulated tokens from the last compaction (or conversation start) up to
+    /// and including this entry.
     /// Used for rendering context divider with percentage.
     accumulated_tokens: usize,
-    /// Maximum context window size (from config).
+    /// Context after compaction, derived from the next response's usage.
+    /// Only set for compact boundaries whose log entry lacks the figure.
+    post_compact_tokens: Option<u64>,
+    /// Context window size per model (from config).
     /// Used for percentage calculation in divider.
-    max_context_tokens: usize,
+    context_windows: crate::model::ContextWindows,
     /// Pricing configuration (from config).
     /// Used for cost calculation in divider.
     pricing: crate::model::PricingConfig,
@@ -81,13 +87,13 @@ impl EntryView {
     /// * `entry` - Domain entry to wrap
     /// * `index` - Position within conversation
     /// * `accumulated_tokens` - Running sum of tokens up to and including this entry
-    /// * `max_context_tokens` - Context window size from config
+    /// * `context_windows` - Context window size per model from config
     /// * `pricing` - Model pricing information from config
     pub fn new(
         entry: ConversationEntry,
         index: EntryIndex,
         accumulated_tokens: usize,
-        max_context_tokens: usize,
+        context_windows: crate::model::ContextWindows,
         pricing: crate::model::PricingConfig,
     ) -> Self {
         Self {
@@ -97,7 +103,8 @@ impl EntryView {
             expanded: false,
             wrap_override: None,
             accumulated_tokens,
-            max_context_tokens,
+            post_compact_tokens: None,
+            context_windows,
             pricing,
         }
     }
@@ -116,7 +123,7 @@ impl EntryView {
     /// * `wrap_mode` - Effective wrap mode for this entry
     /// * `width` - Viewport width for text wrapping
     /// * `accumulated_tokens` - Running sum of tokens up to and including this entry
-    /// * `max_context_tokens` - Context window size from config
+    /// * `context_windows` - Context window size per model from config
     /// * `pricing` - Model pricing information from config
     pub fn with_rendered_lines(
         entry: ConversationEntry,
@@ -124,7 +131,7 @@ impl EntryView {
         wrap_mode: WrapMode,
         width: u16,
         accumulated_tokens: usize,
-        max_context_tokens: usize,
+        context_windows: crate::model::ContextWindows,
         pricing: crate::model::PricingConfig,
     ) -> Self {
         let expanded = false; // Start collapsed
@@ -145,7 +152,8 @@ impl EntryView {
             &crate::state::SearchState::Inactive, // TODO: Pass search_state from caller
             false,                                // Default to not focused on creation
             accumulated_tokens as u64,
-            max_context_tokens as u64,
+            None,
+            &context_windows,
             &pricing,
         );
         Self {
@@ -155,7 +163,8 @@ impl EntryView {
             expanded,
             wrap_override: None,
             accumulated_tokens,
-            max_context_tokens,
+            post_compact_tokens: None,
+            context_windows,
             pricing,
         }
     }
@@ -188,6 +197,17 @@ impl EntryView {
         self.accumulated_tokens
     }
 
+    /// Get the derived post-compaction context (compact boundaries only).
+    pub fn post_compact_tokens(&self) -> Option<u64> {
+        self.post_compact_tokens
+    }
+
+    /// Set the derived post-compaction context (takes effect on the next
+    /// `recompute_lines`).
+    pub fn set_post_compact_tokens(&mut self, tokens: Option<u64>) {
+        self.post_compact_tokens = tokens;
+    }
+
     /// Get the height of this entry (count of rendered lines).
     ///
     /// This is derived from `rendered_lines.len()` and is the source of truth
@@ -245,11 +265,17 @@ impl EntryView {
             search_state,           // Bug fix cclv-5ur.73: Pass search_state for highlighting
             focused,
             self.accumulated_tokens as u64,
-            self.max_context_tokens as u64,
+            self.post_compact_tokens,
+            &self.context_windows,
             &self.pricing,
         );
     }
 
+    /// Set context window sizes (takes effect on the next `recompute_lines`).
+    pub fn set_context_windows(&mut self, context_windows: crate::model::ContextWindows) {
+        self.context_windows = context_windows;
+    }
+
     /// Check if this entry is expanded.
     pub fn is_expanded(&self) -> bool {
         self.expanded
@@ -300,6 +326,7 @@ mod legacy_tests {
         EntryMetadata, EntryType, EntryUuid, LogEntry, MalformedEntry, Message, MessageContent,
         Role, SessionId,
     };
+    use std::sync::Arc;
 
     // ===== Test Helpers =====
 
@@ -330,7 +357,7 @@ mod legacy_tests {
             make_message("Test message"),
             EntryMetadata::default(),
         );
-        ConversationEntry::Valid(Box::new(log_entry))
+        ConversationEntry::Valid(Arc::new(log_entry))
     }
 
     #[allow(dead_code)] // Will be used when updating more tests
@@ -357,7 +384,7 @@ mod legacy_tests {
             entry,
             index,
             0,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
 
@@ -14,6 +14,7 @@ use crate::model::{
 use crate::state::WrapMode;
 use crate::view_state::types::{EntryIndex, LineHeight};
 use ratatui::text::Line;
+use std::sync::Arc;
 
 // ===== Test Helpers =====
 
@@ -44,7 +45,7 @@ fn make_valid_entry() -> ConversationEntry {
         make_message("Test message"),
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 fn make_malformed_entry() -> ConversationEntry {
@@ -71,7 +72,7 @@ fn make_entry_with_thinking(thinking_text: &str) -> ConversationEntry {
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 // ===== Constructor Tests =====
@@ -87,7 +88,7 @@ fn with_rendered_lines_computes_for_valid_entry() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -110,7 +111,7 @@ fn with_rendered_lines_computes_for_malformed_entry() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -139,7 +140,7 @@ fn with_rendered_lines_uses_collapsed_state_by_default() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -164,7 +165,7 @@ fn height_returns_line_count_from_rendered_lines() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -189,7 +190,7 @@ fn height_returns_one_for_minimal_entry() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -218,7 +219,7 @@ fn height_reflects_collapsed_vs_expanded_difference() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
     let height_collapsed = view_collapsed.height();
@@ -246,7 +247,7 @@ fn rendered_lines_returns_reference_to_lines() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -267,7 +268,7 @@ fn rendered_lines_has_static_lifetime() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -295,7 +296,7 @@ fn recompute_lines_updates_rendered_lines() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -333,7 +334,7 @@ fn recompute_lines_with_different_width() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -376,7 +377,7 @@ fn height_and_rendered_lines_stay_consistent() {
             WrapMode::Wrap,
             80,
             0,
-            200_000,
+            crate::model::ContextWindows::default(),
             crate::model::PricingConfig::default(),
         );
         let height = view.height().get();
@@ -402,7 +403,7 @@ fn constructor_parameters_affect_rendering() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
     let view2 = EntryView::with_rendered_lines(
@@ -411,7 +412,7 @@ fn constructor_parameters_affect_rendering() {
         WrapMode::NoWrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -468,7 +469,7 @@ fn make_entry_with_long_line() -> ConversationEntry {
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 #[test]
@@ -482,7 +483,7 @@ fn recompute_lines_respects_global_wrap_mode() {
         WrapMode::Wrap,
         40,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -523,7 +524,7 @@ fn recompute_lines_respects_per_entry_wrap_override() {
         WrapMode::Wrap,
         40,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -558,7 +559,7 @@ fn recompute_lines_override_none_uses_global() {
         WrapMode::NoWrap,
         40,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -596,7 +597,7 @@ fn effective_wrap_returns_override_when_set() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
@@ -621,7 +622,7 @@ fn effective_wrap_returns_global_when_no_override() {
         WrapMode::Wrap,
         80,
         0,
-        200_000,
+        crate::model::ContextWindows::default(),
         crate::model::PricingConfig::default(),
     );
 
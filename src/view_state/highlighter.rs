@@ -20,6 +20,7 @@ use ratatui::{
 };
 use std::sync::{LazyLock, OnceLock};
 use syntect::highlighting::FontStyle;
+use syntect::parsing::{SyntaxReference, SyntaxSet};
 use two_face::theme::{EmbeddedLazyThemeSet, EmbeddedThemeName};
 
 /// Global configured theme name, set at startup.
@@ -38,7 +39,10 @@ pub fn init_theme(theme_name: &str) {
 
 /// Get the currently configured theme name.
 pub fn get_configured_theme() -> &'static str {
-    CONFIGURED_THEME.get().map(|s| s.as_str()).unwrap_or(DEFAULT_THEME)
+    CONFIGURED_THEME
+        .get()
+        .map(|s| s.as_str())
+        .unwrap_or(DEFAULT_THEME)
 }
 
 /// All valid theme names that can be used in configuration.
@@ -80,6 +84,9 @@ pub const DEFAULT_THEME: &str = "base16-ocean-dark";
 /// Lazy-loaded theme set containing all two-face themes.
 static THEME_SET: LazyLock<EmbeddedLazyThemeSet> = LazyLock::new(two_face::theme::extra);
 
+/// Lazy-loaded syntax definitions (syntect defaults plus two-face extras).
+static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(two_face::syntax::extra_newlines);
+
 /// Map a theme name string to the corresponding EmbeddedThemeName.
 fn theme_name_to_embedded(name: &str) -> Option<EmbeddedThemeName> {
     match name {
@@ -156,19 +163,10 @@ impl SyntaxHighlighter {
     /// Vector of ratatui Lines with syntax highlighting applied.
     pub fn highlight_code(&self, code: &str, language: Option<&str>) -> Vec<Line<'static>> {
         use syntect::easy::HighlightLines;
-        use syntect::parsing::SyntaxSet;
         use syntect::util::LinesWithEndings;
 
-        // Load syntax definitions
-        static SYNTAX_SET: LazyLock<SyntaxSet> =
-            LazyLock::new(two_face::syntax::extra_newlines);
-
         let theme = THEME_SET.get(self.theme_name);
-
-        // Find syntax definition for language
-        let syntax = language
-            .and_then(|lang| SYNTAX_SET.find_syntax_by_token(lang))
-            .unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text());
+        let syntax = find_syntax(language);
 
         let mut highlighter = HighlightLines::new(syntax, theme);
         let mut lines = Vec::new();
@@ -194,6 +192,43 @@ impl SyntaxHighlighter {
 
         lines
     }
+
+    /// Highlight a code block as HTML.
+    ///
+    /// Returns a `<pre>` element with inline styles (syntect's HTML output),
+    /// so the result needs no stylesheet. Falls back to escaped plain text if
+    /// highlighting fails.
+    pub fn highlight_html(&self, code: &str, language: Option<&str>) -> String {
+        let theme = THEME_SET.get(self.theme_name);
+        syntect::html::highlighted_html_for_string(code, &SYNTAX_SET, find_syntax(language), theme)
+            .unwrap_or_else(|_| {
+                let escaped = code
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;");
+                format!("<pre>{}</pre>", escaped)
+            })
+    }
+
+    /// Background and foreground colors of the theme as CSS hex colors.
+    pub fn page_colors(&self) -> (String, String) {
+        let settings = &THEME_SET.get(self.theme_name).settings;
+        let hex = |c: Option<syntect::highlighting::Color>, fallback: &str| match c {
+            Some(c) => format!("#{:02x}{:02x}{:02x}", c.r, c.g, c.b),
+            None => fallback.to_string(),
+        };
+        (
+            hex(settings.background, "#ffffff"),
+            hex(settings.foreground, "#000000"),
+        )
+    }
+}
+
+/// Syntax definition for a language hint, plain text if unknown.
+fn find_syntax(language: Option<&str>) -> &'static SyntaxReference {
+    language
+        .and_then(|lang| SYNTAX_SET.find_syntax_by_token(lang))
+        .unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text())
 }
 
 impl Default for SyntaxHighlighter {
@@ -229,11 +264,7 @@ mod tests {
     #[test]
     fn valid_themes_are_recognized() {
         for theme in VALID_THEMES {
-            assert!(
-                is_valid_theme(theme),
-                "Theme '{}' should be valid",
-                theme
-            );
+            assert!(is_valid_theme(theme), "Theme '{}' should be valid", theme);
         }
     }
 
@@ -279,4 +310,14 @@ mod tests {
         let lines = highlighter.highlight_code("some text", Some("not-a-language"));
         assert!(!lines.is_empty());
     }
+
+    #[test]
+    fn highlight_html_uses_inline_theme_styles() {
+        let highlighter = SyntaxHighlighter::new("gruvbox-dark");
+        let html = highlighter.highlight_html("let x = \"<b>\";", Some("rust"));
+        assert!(html.starts_with("<pre style=\"background-color:#"));
+        assert!(html.contains("<span style=\"color:#"));
+        assert!(html.contains("&lt;b&gt;"));
+        assert!(!html.contains("<b>"));
+    }
 }
@@ -1,7 +1,9 @@
 //! Top-level view-state for entire log
 
 use super::session::SessionViewState;
-use crate::model::{AgentId, ConversationEntry, SessionId};
+use crate::model::{
+    AgentId, ContextWindows, ConversationEntry, SessionId, SessionLinks, SessionStats,
+};
 
 /// Top-level view-state for an entire log file.
 ///
@@ -19,6 +21,14 @@ pub struct LogViewState {
     sessions: Vec<SessionViewState>,
     /// Current session ID (for streaming detection).
     current_session_id: Option<SessionId>,
+    /// Context window size per model (from config), given to new sessions.
+    context_windows: ContextWindows,
+    /// Columns reserved for the time gutter, given to new sessions.
+    gutter_width: u16,
+    /// Top line of the viewport in continuous mode, across all sessions.
+    continuous_scroll: usize,
+    /// Sessions shown in continuous mode when viewing one chain (None = all).
+    chain: Option<Vec<usize>>,
 }
 
 impl LogViewState {
@@ -27,6 +37,10 @@ impl LogViewState {
         Self {
             sessions: Vec::new(),
             current_session_id: None,
+            context_windows: ContextWindows::default(),
+            gutter_width: 0,
+            continuous_scroll: 0,
+            chain: None,
         }
     }
 
@@ -60,17 +74,104 @@ impl LogViewState {
     ///
     /// Returns the LAST session whose start_line is <= scroll_line.
     /// This matches the specification which uses rfind.
+    ///
+    /// Sessions outside the viewed chain (see `set_chain`) are skipped.
     pub fn active_session(&self, scroll_line: usize) -> Option<&SessionViewState> {
-        self.sessions
-            .iter()
-            .rfind(|s| s.start_line() <= scroll_line)
+        self.active_session_index(scroll_line)
+            .and_then(|index| self.sessions.get(index))
     }
 
     /// Active session index.
     pub fn active_session_index(&self, scroll_line: usize) -> Option<usize> {
         self.sessions
             .iter()
-            .rposition(|s| s.start_line() <= scroll_line)
+            .enumerate()
+            .rfind(|(index, s)| self.is_shown(*index) && s.start_line() <= scroll_line)
+            .map(|(index, _)| index)
+    }
+
+    /// Recompute session start lines for the continuous view, where each
+    /// session is its heading plus (unless folded) its main conversation.
+    ///
+    /// Call after heights change (new entries, relayout, folding) and before
+    /// using `active_session` for a continuous scroll position.
+    pub fn layout_continuous(&mut self) {
+        let mut start_line = 0;
+        for index in 0..self.sessions.len() {
+            let shown = self.is_shown(index);
+            let session = &mut self.sessions[index];
+            session.set_start_line(start_line);
+            if shown {
+                start_line += session.continuous_height();
+            }
+        }
+    }
+
+    /// Height of all shown sessions in the continuous view.
+    pub fn continuous_height(&self) -> usize {
+        self.sessions
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.is_shown(*index))
+            .map(|(_, s)| s.continuous_height())
+            .sum()
+    }
+
+    /// Show only these sessions (one chain, in log order) in continuous
+    /// mode, or every session with `None`.
+    pub fn set_chain(&mut self, chain: Option<Vec<usize>>) {
+        self.chain = chain;
+    }
+
+    /// Sessions of the viewed chain, if continuous mode shows only one chain.
+    pub fn chain(&self) -> Option<&[usize]> {
+        self.chain.as_deref()
+    }
+
+    /// Whether session `index` is shown in continuous mode.
+    pub fn is_shown(&self, index: usize) -> bool {
+        self.chain
+            .as_ref()
+            .is_none_or(|chain| chain.contains(&index))
+    }
+
+    /// Links between resumed and continued sessions.
+    pub fn session_links(&self) -> SessionLinks {
+        SessionLinks::detect(
+            self.sessions
+                .iter()
+                .map(|session| (session.session_id(), session.log_entries())),
+        )
+    }
+
+    /// Statistics of every session rolled up.
+    pub fn stats(&self) -> SessionStats {
+        let mut stats = SessionStats::default();
+        for session in &self.sessions {
+            session.record_stats(&mut stats);
+        }
+        stats
+    }
+
+    /// Statistics of the viewed chain's sessions rolled up, if continuous
+    /// mode shows only one chain.
+    pub fn chain_stats(&self) -> Option<SessionStats> {
+        let chain = self.chain.as_ref()?;
+        let mut stats = SessionStats::default();
+        for session in chain.iter().filter_map(|&index| self.sessions.get(index)) {
+            session.record_stats(&mut stats);
+        }
+        Some(stats)
+    }
+
+    /// Top line of the viewport in continuous mode.
+    pub fn continuous_scroll(&self) -> usize {
+        self.continuous_scroll
+    }
+
+    /// Set the top line of the viewport in continuous mode.
+    pub fn set_continuous_scroll(&mut self, line: usize) {
+        self.continuous_scroll = line;
     }
 
     /// Add entry, routing to correct session/conversation.
@@ -97,6 +198,9 @@ impl LogViewState {
 
                 let mut new_session = SessionViewState::new(new_id);
                 new_session.set_start_line(start_line);
+                new_session.set_context_windows(self.context_windows.clone());
+                new_session
+                    .set_gutter_width(self.gutter_width, &crate::state::SearchState::Inactive);
 
                 // Propagate viewport settings if previous session had them
                 if viewport_width > 0 {
@@ -137,12 +241,30 @@ impl LogViewState {
         }
     }
 
+    /// Set context window sizes on all sessions and on sessions created later.
+    pub fn set_context_windows(&mut self, context_windows: ContextWindows) {
+        for session in &mut self.sessions {
+            session.set_context_windows(context_windows.clone());
+        }
+        self.context_windows = context_windows;
+    }
+
+    /// Reserve columns for the time gutter in all sessions and sessions added later.
+    pub fn set_gutter_width(&mut self, width: u16, search_state: &crate::state::SearchState) {
+        for session in &mut self.sessions {
+            session.set_gutter_width(width, search_state);
+        }
+        self.gutter_width = width;
+    }
+
     /// Create an empty session (used when model session has no entries).
     /// This ensures session_view() doesn't panic in tests/edge cases.
     pub fn create_empty_session(&mut self, session_id: SessionId) {
         let start_line = self.sessions.iter().map(|s| s.total_height()).sum();
         let mut new_session = SessionViewState::new(session_id.clone());
         new_session.set_start_line(start_line);
+        new_session.set_context_windows(self.context_windows.clone());
+        new_session.set_gutter_width(self.gutter_width, &crate::state::SearchState::Inactive);
         self.sessions.push(new_session);
         self.current_session_id = Some(session_id);
     }
@@ -160,6 +282,7 @@ mod tests {
     use crate::model::{
         EntryMetadata, EntryType, EntryUuid, LogEntry, Message, MessageContent, Role,
     };
+    use std::sync::Arc;
 
     // ===== Test Helpers =====
 
@@ -191,7 +314,7 @@ mod tests {
             message,
             EntryMetadata::default(),
         );
-        ConversationEntry::Valid(Box::new(log_entry))
+        ConversationEntry::Valid(Arc::new(log_entry))
     }
 
     // ===== Basic Operations =====
@@ -441,4 +564,86 @@ mod tests {
         assert_eq!(session_ids[1], make_session_id("session-2"));
         assert_eq!(session_ids[2], make_session_id("session-3"));
     }
+
+    // ===== Continuous Layout =====
+
+    #[test]
+    fn layout_continuous_stacks_headings_and_main_conversations() {
+        let mut log = LogViewState::new();
+        log.add_entry(make_entry("session-1", "uuid-1", Role::User), None);
+        log.add_entry(make_entry("session-1", "uuid-2", Role::Assistant), None);
+        log.add_entry(make_entry("session-2", "uuid-3", Role::User), None);
+
+        log.layout_continuous();
+
+        let main_1 = log.get_session(0).unwrap().main_height();
+        let main_2 = log.get_session(1).unwrap().main_height();
+        assert_eq!(log.get_session(1).unwrap().start_line(), 1 + main_1);
+        assert_eq!(log.continuous_height(), 2 + main_1 + main_2);
+        assert_eq!(log.active_session_index(main_1), Some(0));
+        assert_eq!(log.active_session_index(main_1 + 1), Some(1));
+    }
+
+    #[test]
+    fn folded_session_takes_only_its_heading() {
+        let mut log = LogViewState::new();
+        log.add_entry(make_entry("session-1", "uuid-1", Role::User), None);
+        log.add_entry(make_entry("session-2", "uuid-2", Role::User), None);
+
+        log.get_session_mut(0).unwrap().set_folded(true);
+        log.layout_continuous();
+
+        assert!(log.get_session(0).unwrap().is_folded());
+        assert_eq!(log.get_session(0).unwrap().continuous_height(), 1);
+        assert_eq!(log.get_session(1).unwrap().start_line(), 1);
+        assert_eq!(log.active_session_index(1), Some(1));
+    }
+
+    #[test]
+    fn chain_hides_other_sessions_from_continuous_layout() {
+        let mut log = LogViewState::new();
+        log.add_entry(make_entry("session-1", "uuid-1", Role::User), None);
+        log.add_entry(make_entry("session-2", "uuid-2", Role::User), None);
+        log.add_entry(make_entry("session-3", "uuid-3", Role::User), None);
+
+        log.set_chain(Some(vec![0, 2]));
+        log.layout_continuous();
+
+        assert!(log.is_shown(0));
+        assert!(!log.is_shown(1));
+        let main_1 = log.get_session(0).unwrap().main_height();
+        let main_3 = log.get_session(2).unwrap().main_height();
+        assert_eq!(log.continuous_height(), 2 + main_1 + main_3);
+        assert_eq!(log.active_session_index(main_1 + 1), Some(2));
+    }
+
+    #[test]
+    fn chain_stats_roll_up_chain_sessions() {
+        let mut log = LogViewState::new();
+        for (session, input_tokens) in [("session-1", 10), ("session-2", 100), ("session-3", 1)] {
+            let message = Message::new(Role::Assistant, MessageContent::Text("ok".to_string()))
+                .with_usage(crate::model::TokenUsage {
+                    input_tokens,
+                    ..Default::default()
+                });
+            let entry = LogEntry::new(
+                make_entry_uuid(session),
+                None,
+                make_session_id(session),
+                None,
+                make_timestamp(),
+                EntryType::Assistant,
+                message,
+                EntryMetadata::default(),
+            );
+            log.add_entry(ConversationEntry::Valid(Arc::new(entry)), None);
+        }
+        assert!(log.chain_stats().is_none(), "no chain viewed");
+
+        log.set_chain(Some(vec![0, 2]));
+
+        let stats = log.chain_stats().unwrap();
+        assert_eq!(stats.total_usage.input_tokens, 11);
+        assert_eq!(stats.session_usage.len(), 2);
+    }
 }
@@ -18,11 +18,15 @@
 //! - Collapse decision made once at entry level, not per-block
 //! - Rendered line count matches height calculation
 
-use crate::model::{ContentBlock, ConversationEntry, MessageContent, stats::PricingConfig};
+use crate::model::{
+    ContentBlock, ContextWindows, ConversationEntry, MessageContent, stats::PricingConfig,
+};
 use crate::state::{WrapContext, WrapMode};
 use crate::view::MessageStyles;
 use crate::view_state::highlighter::SyntaxHighlighter;
-use crate::view_state::token_divider::{ContextWindowTokens, render_token_divider};
+use crate::view_state::token_divider::{
+    ContextWindowTokens, render_compact_boundary, render_token_divider,
+};
 use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
@@ -61,7 +65,10 @@ use tui_markdown::from_str;
 /// * `search_state` - Current search state for highlighting
 /// * `focused` - Whether the pane is focused
 /// * `accumulated_tokens` - Cumulative input tokens up to this entry (cclv-5ur.32)
-/// * `max_context_tokens` - Maximum context window size in tokens (cclv-5ur.32)
+/// * `post_compact_tokens` - For compact boundaries, the context after compaction
+///   when the boundary does not record it
+/// * `context_windows` - Context window size per model; the entry's own model
+///   picks the window (cclv-5ur.32)
 /// * `pricing` - Pricing configuration for cost estimation (cclv-5ur.32)
 ///
 /// # Returns
@@ -101,7 +108,8 @@ pub fn compute_entry_lines(
     search_state: &crate::state::SearchState,
     focused: bool,
     _accumulated_tokens: u64,
-    max_context_tokens: u64,
+    post_compact_tokens: Option<u64>,
+    context_windows: &ContextWindows,
     pricing: &PricingConfig,
 ) -> Vec<Line<'static>> {
     // Extract match information if search is active
@@ -142,6 +150,11 @@ pub fn compute_entry_lines(
         )]));
     }
 
+    // Compact boundaries get a prominent separator before any content
+    if let Some(compact) = valid_entry.compact_metadata() {
+        lines.push(render_compact_boundary(compact, post_compact_tokens));
+    }
+
     // Handle message content
     match message.content() {
         MessageContent::Text(text) => {
@@ -240,10 +253,12 @@ pub fn compute_entry_lines(
 
             // Add token divider separator at end (cclv-5ur.32)
             if let Some(usage) = message.usage() {
-                let max_context = ContextWindowTokens::new(max_context_tokens);
                 let model_id = valid_entry
                     .system_metadata()
                     .and_then(|m| m.model.as_deref());
+                let max_context = ContextWindowTokens::new(
+                    context_windows.get(message.model().map(|m| m.id()).or(model_id)),
+                );
 
                 let divider =
                     render_token_divider(usage, message.content(), max_context, pricing, model_id);
@@ -272,10 +287,12 @@ pub fn compute_entry_lines(
 
             // Add token divider separator at end (cclv-5ur.32)
             if let Some(usage) = message.usage() {
-                let max_context = ContextWindowTokens::new(max_context_tokens);
                 let model_id = valid_entry
                     .system_metadata()
                     .and_then(|m| m.model.as_deref());
+                let max_context = ContextWindowTokens::new(
+                    context_windows.get(message.model().map(|m| m.id()).or(model_id)),
+                );
 
                 let divider =
                     render_token_divider(usage, message.content(), max_context, pricing, model_id);
@@ -323,17 +340,20 @@ fn get_highlighter() -> SyntaxHighlighter {
 
 /// Markdown chunk - either regular text or a code block.
 #[derive(Debug)]
-enum MarkdownChunk<'a> {
+pub(crate) enum MarkdownChunk<'a> {
     /// Regular markdown text (not inside a code block)
     Text(&'a str),
     /// Fenced code block with optional language specifier
-    CodeBlock { language: Option<&'a str>, code: &'a str },
+    CodeBlock {
+        language: Option<&'a str>,
+        code: &'a str,
+    },
 }
 
 /// Parse markdown into chunks of text and code blocks.
 ///
 /// Handles fenced code blocks (``` or ~~~) and extracts the language specifier.
-fn parse_markdown_chunks(markdown: &str) -> Vec<MarkdownChunk<'_>> {
+pub(crate) fn parse_markdown_chunks(markdown: &str) -> Vec<MarkdownChunk<'_>> {
     let mut chunks = Vec::new();
     let mut current_pos = 0;
     let mut in_code_block = false;
@@ -341,11 +361,16 @@ fn parse_markdown_chunks(markdown: &str) -> Vec<MarkdownChunk<'_>> {
     let mut code_lang: Option<&str> = None;
     let mut fence_char = '`';
 
-    for (line_start, line) in markdown.match_indices('\n').map(|(i, _)| i).chain(std::iter::once(markdown.len())).scan(0, |start, end| {
-        let line_start = *start;
-        *start = end + 1;
-        Some((line_start, &markdown[line_start..end]))
-    }) {
+    for (line_start, line) in markdown
+        .match_indices('\n')
+        .map(|(i, _)| i)
+        .chain(std::iter::once(markdown.len()))
+        .scan(0, |start, end| {
+            let line_start = *start;
+            *start = end + 1;
+            Some((line_start, &markdown[line_start..end]))
+        })
+    {
         let trimmed = line.trim_start();
 
         if !in_code_block {
@@ -368,7 +393,9 @@ fn parse_markdown_chunks(markdown: &str) -> Vec<MarkdownChunk<'_>> {
             }
         } else {
             // Check for closing fence (must match opening fence char)
-            if trimmed.starts_with(fence_char) && trimmed.chars().take_while(|&c| c == fence_char).count() >= 3 {
+            if trimmed.starts_with(fence_char)
+                && trimmed.chars().take_while(|&c| c == fence_char).count() >= 3
+            {
                 // End of code block
                 let code_end = line_start;
                 let code = if code_start < code_end {
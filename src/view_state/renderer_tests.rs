@@ -6,9 +6,10 @@ use crate::model::{
     ContentBlock, ConversationEntry, EntryMetadata, EntryType, LogEntry, Message, MessageContent,
     Role,
 };
-use crate::state::{WrapContext, WrapMode};
+use crate::state::{SearchProgress, WrapContext, WrapMode};
 use crate::view::MessageStyles;
 use chrono::Utc;
+use std::sync::Arc;
 
 /// Helper to create default MessageStyles for tests.
 fn default_styles() -> MessageStyles {
@@ -42,8 +43,9 @@ fn compute_test_lines(
         is_subagent_view,
         search_state,
         focused,
-        0,       // accumulated_tokens (default for tests)
-        200_000, // max_context_tokens (default)
+        0,    // accumulated_tokens (default for tests)
+        None, // post_compact_tokens
+        &crate::model::ContextWindows::default(),
         &crate::model::PricingConfig::default(),
     )
 }
@@ -149,7 +151,7 @@ fn create_entry_with_thinking(thinking_text: &str) -> ConversationEntry {
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 #[test]
@@ -324,7 +326,7 @@ fn create_entry_with_text(text: &str) -> ConversationEntry {
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 #[test]
@@ -591,7 +593,7 @@ fn create_entry_with_tool_result(content: &str, is_error: bool) -> ConversationE
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 #[test]
@@ -695,7 +697,7 @@ fn create_entry_with_tool_use(tool_name: &str, input_json: serde_json::Value) ->
         message,
         EntryMetadata::default(),
     );
-    ConversationEntry::Valid(Box::new(log_entry))
+    ConversationEntry::Valid(Arc::new(log_entry))
 }
 
 #[test]
@@ -1498,6 +1500,7 @@ fn test_search_match_highlighted_with_yellow_background() {
         query,
         matches,
         current_match: 0,
+        progress: SearchProgress::Complete,
     };
 
     let styles = default_styles();
@@ -1567,6 +1570,7 @@ fn test_current_search_match_has_reversed_modifier() {
         query,
         matches,
         current_match: 1, // Current match is the second "test"
+        progress: SearchProgress::Complete,
     };
 
     let styles = default_styles();
@@ -1631,6 +1635,7 @@ fn test_non_current_search_matches_no_reversed_modifier() {
         query,
         matches,
         current_match: 1, // Current match is the second "test"
+        progress: SearchProgress::Complete,
     };
 
     let styles = default_styles();
@@ -2260,6 +2265,7 @@ fn test_search_highlights_matches_in_text() {
         query,
         matches,
         current_match: 0, // First match is current
+        progress: SearchProgress::Complete,
     };
 
     let styles = default_styles();
@@ -2302,3 +2308,56 @@ fn test_search_highlights_matches_in_text() {
         "Current search match should be highlighted with REVERSED modifier"
     );
 }
+
+// ===== Token Divider Context Window =====
+
+fn divider_text(model_id: &str, context_windows: &crate::model::ContextWindows) -> String {
+    let usage = crate::model::TokenUsage {
+        input_tokens: 100_000,
+        ..Default::default()
+    };
+    let message = Message::new(Role::Assistant, MessageContent::Text("Done".to_string()))
+        .with_usage(usage)
+        .with_model(crate::model::ModelInfo::new(model_id));
+    let entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
+        EntryUuid::new("test-divider-001").unwrap(),
+        None,
+        SessionId::new("test-session").unwrap(),
+        None,
+        Utc::now(),
+        EntryType::Assistant,
+        message,
+        EntryMetadata::default(),
+    )));
+
+    let lines = compute_entry_lines(
+        &entry,
+        true,
+        WrapContext::from_global(WrapMode::Wrap),
+        120,
+        10,
+        3,
+        &default_styles(),
+        None,
+        false,
+        &crate::state::SearchState::Inactive,
+        false,
+        0,
+        None,
+        context_windows,
+        &crate::model::PricingConfig::default(),
+    );
+    lines
+        .last()
+        .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+        .unwrap_or_default()
+}
+
+#[test]
+fn token_divider_uses_entry_models_context_window() {
+    let windows = crate::model::ContextWindows::default().with_model("haiku", 400_000);
+
+    assert!(divider_text("claude-opus-4-5", &windows).contains("(50%)"));
+    assert!(divider_text("claude-haiku-4-5", &windows).contains("(25%)"));
+    assert!(divider_text("claude-sonnet-4-5[1m]", &windows).contains("(10%)"));
+}
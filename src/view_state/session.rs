@@ -1,11 +1,14 @@
 //! View-state for a single session
 
 use super::conversation::ConversationViewState;
-use crate::model::{AgentId, ConversationEntry, SessionId};
+use crate::model::{AgentId, ConversationEntry, SessionId, SessionInfo};
 use crate::state::WrapMode;
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
+/// Lines taken by a session's heading in the continuous view.
+pub const SESSION_HEADING_HEIGHT: usize = 1;
+
 /// View-state for a single session.
 ///
 /// Contains:
@@ -26,16 +29,26 @@ pub struct SessionViewState {
     subagents: HashMap<AgentId, ConversationViewState>,
     /// Cumulative line offset from start of log (for multi-session).
     start_line: usize,
-    /// Maximum context window size (from config).
-    max_context_tokens: usize,
+    /// Context window size per model (from config).
+    context_windows: crate::model::ContextWindows,
+    /// Model named by the session's system:init entry.
+    init_model: Option<String>,
     /// Pricing configuration (from config).
     pricing: crate::model::PricingConfig,
     /// Current viewport width (for propagating to newly created subagents).
     viewport_width: u16,
+    /// Columns reserved for the time gutter (for propagating to newly created subagents).
+    gutter_width: u16,
     /// Global wrap mode (for propagating to newly created subagents).
     global_wrap: WrapMode,
     /// Timestamp of the first entry added to this session (main or subagent).
     start_time: Option<DateTime<Utc>>,
+    /// Latest timestamp of any entry in this session (main or subagent).
+    end_time: Option<DateTime<Utc>>,
+    /// Environment details collected from main conversation entries.
+    info: SessionInfo,
+    /// Whether the session is folded to its heading in continuous mode.
+    folded: bool,
 }
 
 impl SessionViewState {
@@ -46,11 +59,16 @@ impl SessionViewState {
             main: ConversationViewState::empty(),
             subagents: HashMap::new(),
             start_line: 0,
-            max_context_tokens: 200_000, // Default
+            context_windows: crate::model::ContextWindows::default(),
+            init_model: None,
             pricing: crate::model::PricingConfig::default(),
             viewport_width: 0,
+            gutter_width: 0,
             global_wrap: WrapMode::default(),
             start_time: None,
+            end_time: None,
+            info: SessionInfo::default(),
+            folded: false,
         }
     }
 
@@ -66,6 +84,40 @@ impl SessionViewState {
         self.start_time
     }
 
+    /// Latest timestamp of any entry in this session.
+    ///
+    /// Returns None if no entry has a timestamp.
+    pub fn end_time(&self) -> Option<DateTime<Utc>> {
+        self.end_time
+    }
+
+    /// First prompt typed by the user in the main conversation.
+    pub fn first_prompt(&self) -> Option<String> {
+        self.main
+            .iter()
+            .filter_map(|entry_view| entry_view.entry().as_valid())
+            .find(|log_entry| crate::model::is_user_prompt(log_entry))
+            .map(|log_entry| log_entry.message().text())
+    }
+
+    /// Whether the session's result entry reports an error.
+    ///
+    /// Returns None if the session has no result entry (yet).
+    pub fn result_is_error(&self) -> Option<bool> {
+        self.main
+            .entries()
+            .iter()
+            .rev()
+            .filter_map(|entry_view| entry_view.entry().as_valid())
+            .find_map(|log_entry| log_entry.result_metadata())
+            .map(|result| result.is_error)
+    }
+
+    /// Environment details (model, cwd, branch, tools, ...) for the session header.
+    pub fn info(&self) -> &SessionInfo {
+        &self.info
+    }
+
     /// Reference to main conversation view-state.
     pub fn main(&self) -> &ConversationViewState {
         &self.main
@@ -85,13 +137,14 @@ impl SessionViewState {
     pub fn subagent(&mut self, id: &AgentId) -> &ConversationViewState {
         if !self.subagents.contains_key(id) {
             // Create empty view-state
-            let view_state = ConversationViewState::new(
+            let mut view_state = ConversationViewState::new(
                 Some(id.clone()),
                 None,
                 vec![],
-                self.max_context_tokens,
+                self.context_windows.clone(),
                 self.pricing.clone(),
             );
+            view_state.set_gutter_width(self.gutter_width);
             self.subagents.insert(id.clone(), view_state);
         }
         self.subagents.get(id).unwrap()
@@ -105,13 +158,14 @@ impl SessionViewState {
         if !self.subagents.contains_key(id) {
             tracing::trace!(agent_id = ?id, viewport_width = self.viewport_width, "Creating new subagent");
             // Create empty view-state
-            let view_state = ConversationViewState::new(
+            let mut view_state = ConversationViewState::new(
                 Some(id.clone()),
                 None,
                 vec![],
-                self.max_context_tokens,
+                self.context_windows.clone(),
                 self.pricing.clone(),
             );
+            view_state.set_gutter_width(self.gutter_width);
             self.subagents.insert(id.clone(), view_state);
 
             // Propagate viewport dimensions to newly created subagent
@@ -170,6 +224,41 @@ impl SessionViewState {
         self.subagents.iter()
     }
 
+    /// All valid entries (main agent and subagents) ordered by timestamp.
+    pub fn log_entries_by_time(&self) -> Vec<&crate::model::LogEntry> {
+        let mut entries: Vec<&crate::model::LogEntry> = std::iter::once(&self.main)
+            .chain(self.subagents.values())
+            .flat_map(|conversation| conversation.iter())
+            .filter_map(|entry_view| entry_view.entry().as_valid())
+            .collect();
+        entries.sort_by_key(|entry| entry.timestamp());
+        entries
+    }
+
+    /// Build `SessionStats` from every valid entry (main agent, then each subagent).
+    pub fn stats(&self) -> crate::model::SessionStats {
+        let mut stats = crate::model::SessionStats::default();
+        self.record_stats(&mut stats);
+        stats
+    }
+
+    /// Record every valid entry (main agent, then each subagent) into `stats`.
+    ///
+    /// Used to roll several sessions up into one `SessionStats`.
+    pub fn record_stats(&self, stats: &mut crate::model::SessionStats) {
+        for log_entry in self.log_entries() {
+            stats.record_entry(log_entry);
+        }
+    }
+
+    /// Every valid entry: main agent, then each subagent.
+    pub fn log_entries(&self) -> impl Iterator<Item = &crate::model::LogEntry> {
+        std::iter::once(&self.main)
+            .chain(self.subagents.values())
+            .flat_map(|conversation| conversation.iter())
+            .filter_map(|entry_view| entry_view.entry().as_valid())
+    }
+
     /// Get subagent entry count.
     ///
     /// Returns the number of entries for the given agent ID without requiring mutation.
@@ -185,6 +274,13 @@ impl SessionViewState {
     ///
     /// # Start Time Tracking (cclv-463.6.3)
     /// If this is the first entry added to the session, captures its timestamp as start_time.
+    ///
+    /// # Session Info
+    /// Valid entries are folded into the session's `SessionInfo`.
+    ///
+    /// # Context Windows
+    /// The first system:init model is applied to the context windows, so a
+    /// long-context session's responses use the long-context window.
     pub fn add_main_entry(&mut self, entry: ConversationEntry) {
         // Track start time from first entry (cclv-463.6.3)
         if self.start_time.is_none() {
@@ -192,6 +288,18 @@ impl SessionViewState {
                 self.start_time = Some(timestamp);
             }
         }
+        self.end_time = self.end_time.max(entry.timestamp());
+
+        if let Some(log_entry) = entry.as_valid() {
+            self.info.record_entry(log_entry);
+            if self.init_model.is_none() {
+                let init_model = log_entry.system_metadata().and_then(|m| m.model.clone());
+                if init_model.is_some() {
+                    self.init_model = init_model;
+                    self.set_context_windows(self.context_windows.clone());
+                }
+            }
+        }
 
         // Extract model from assistant message if present
         if let ConversationEntry::Valid(log_entry) = &entry {
@@ -226,6 +334,7 @@ impl SessionViewState {
                 self.start_time = Some(timestamp);
             }
         }
+        self.end_time = self.end_time.max(entry.timestamp());
 
         // Extract model from assistant message if present (cclv-5ur.40.13)
         if let ConversationEntry::Valid(log_entry) = &entry {
@@ -278,6 +387,46 @@ impl SessionViewState {
         }
     }
 
+    /// Set context window sizes on main and all subagent conversations.
+    ///
+    /// Conversations created later inherit them. The session's init model is
+    /// applied on top. Relayouts when the viewport is known so dividers show
+    /// the new percentages.
+    pub fn set_context_windows(&mut self, context_windows: crate::model::ContextWindows) {
+        let context_windows = match &self.init_model {
+            Some(model) => context_windows.with_session_model(model),
+            None => context_windows,
+        };
+        if context_windows == self.context_windows {
+            return;
+        }
+        let conversations = std::iter::once(&mut self.main).chain(self.subagents.values_mut());
+        for conversation in conversations {
+            conversation.set_context_windows(context_windows.clone());
+            if self.viewport_width > 0 {
+                conversation.relayout(
+                    self.viewport_width,
+                    self.global_wrap,
+                    &crate::state::SearchState::Inactive,
+                );
+            }
+        }
+        self.context_windows = context_windows;
+    }
+
+    /// Reserve columns for the time gutter in every conversation, relaying
+    /// them out (keeping `search_state`'s highlights) if a viewport is set.
+    pub fn set_gutter_width(&mut self, width: u16, search_state: &crate::state::SearchState) {
+        let conversations = std::iter::once(&mut self.main).chain(self.subagents.values_mut());
+        for conversation in conversations {
+            conversation.set_gutter_width(width);
+            if self.viewport_width > 0 {
+                conversation.relayout(self.viewport_width, self.global_wrap, search_state);
+            }
+        }
+        self.gutter_width = width;
+    }
+
     /// Get current viewport width.
     pub fn viewport_width(&self) -> u16 {
         self.viewport_width
@@ -293,6 +442,26 @@ impl SessionViewState {
         self.main.total_height()
     }
 
+    /// Whether the session is folded to its heading in continuous mode.
+    pub fn is_folded(&self) -> bool {
+        self.folded
+    }
+
+    /// Fold the session to its heading, or unfold it, in continuous mode.
+    pub fn set_folded(&mut self, folded: bool) {
+        self.folded = folded;
+    }
+
+    /// Height of this session in the continuous view: its heading, plus the
+    /// main conversation unless folded.
+    pub fn continuous_height(&self) -> usize {
+        if self.folded {
+            SESSION_HEADING_HEIGHT
+        } else {
+            SESSION_HEADING_HEIGHT + self.main.total_height()
+        }
+    }
+
     /// Total height of all conversations in this session.
     /// In continuous scroll display mode, this is the height contribution
     /// of this entire session to the log view.
@@ -313,6 +482,7 @@ mod tests {
     use crate::model::{
         EntryMetadata, EntryType, EntryUuid, LogEntry, Message, MessageContent, Role,
     };
+    use std::sync::Arc;
 
     // ===== Test Helpers =====
 
@@ -347,7 +517,27 @@ mod tests {
             make_message(uuid), // Use UUID as message text for easy identification
             EntryMetadata::default(),
         );
-        ConversationEntry::Valid(Box::new(log_entry))
+        ConversationEntry::Valid(Arc::new(log_entry))
+    }
+
+    // ===== Context Window Tests =====
+
+    #[test]
+    fn long_context_init_model_applies_to_context_windows() {
+        let raw = r#"{"type":"system","subtype":"init","uuid":"sys-001","session_id":"session-1","model":"claude-sonnet-4-5-20250929[1m]"}"#;
+        let init = LogEntry::parse(raw).expect("valid init entry");
+        let mut session = SessionViewState::new(make_session_id("session-1"));
+
+        session.add_main_entry(ConversationEntry::Valid(Arc::new(init)));
+        session.set_context_windows(crate::model::ContextWindows::default());
+
+        assert_eq!(
+            session
+                .context_windows
+                .get(Some("claude-sonnet-4-5-20250929")),
+            1_000_000,
+            "Responses of a [1m] session should use the long-context window"
+        );
     }
 
     // ===== SessionViewState::new Tests =====
@@ -684,7 +874,7 @@ mod tests {
         )
         .with_model(model_info.clone());
 
-        let entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
@@ -737,7 +927,7 @@ mod tests {
         )
         .with_model(first_model.clone());
 
-        let first_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let first_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
@@ -768,7 +958,7 @@ mod tests {
         )
         .with_model(second_model.clone());
 
-        let second_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let second_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-2"),
             None,
             make_session_id("session-1"),
@@ -809,7 +999,7 @@ mod tests {
             MessageContent::Text("User question".to_string()),
         );
 
-        let user_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let user_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
@@ -833,6 +1023,41 @@ mod tests {
         );
     }
 
+    // ===== set_gutter_width Tests =====
+
+    #[test]
+    fn set_gutter_width_keeps_search_highlights() {
+        use crate::state::{SearchMatch, SearchProgress, SearchQuery, SearchState};
+
+        let mut session = SessionViewState::new(make_session_id("session-1"));
+        session.add_main_entry(make_valid_entry("needle", "session-1"));
+        session.main_mut().set_all_expanded(true);
+        session.set_viewport(80, WrapMode::Wrap);
+        let search = SearchState::Active {
+            query: SearchQuery::new("needle").expect("valid query"),
+            matches: vec![SearchMatch {
+                agent_id: None,
+                entry_uuid: make_entry_uuid("needle"),
+                block_index: 0,
+                char_offset: 0,
+                length: 6,
+            }],
+            current_match: 0,
+            progress: SearchProgress::Complete,
+        };
+
+        session.set_gutter_width(9, &search);
+
+        let highlighted = session.main().iter().any(|entry| {
+            entry.rendered_lines().iter().any(|line| {
+                line.spans
+                    .iter()
+                    .any(|span| span.style.bg == Some(ratatui::style::Color::Yellow))
+            })
+        });
+        assert!(highlighted, "Relayout should keep the match highlighted");
+    }
+
     // ===== Main Agent Model Extraction Tests =====
 
     #[test]
@@ -857,7 +1082,7 @@ mod tests {
         )
         .with_model(model_info.clone());
 
-        let entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
@@ -907,7 +1132,7 @@ mod tests {
         )
         .with_model(first_model.clone());
 
-        let first_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let first_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
@@ -935,7 +1160,7 @@ mod tests {
         )
         .with_model(second_model.clone());
 
-        let second_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let second_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-2"),
             None,
             make_session_id("session-1"),
@@ -972,7 +1197,7 @@ mod tests {
             MessageContent::Text("User question".to_string()),
         );
 
-        let user_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let user_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
@@ -1015,7 +1240,7 @@ mod tests {
         let mut state = SessionViewState::new(session_id);
 
         let timestamp1 = "2025-01-09T10:00:00Z".parse().expect("valid timestamp");
-        let entry1 = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let entry1 = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
@@ -1044,7 +1269,7 @@ mod tests {
         let timestamp1 = "2025-01-09T10:00:00Z".parse().expect("valid timestamp");
         let timestamp2 = "2025-01-09T11:00:00Z".parse().expect("valid timestamp");
 
-        let entry1 = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let entry1 = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
@@ -1055,7 +1280,7 @@ mod tests {
             EntryMetadata::default(),
         )));
 
-        let entry2 = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let entry2 = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-2"),
             None,
             make_session_id("session-1"),
@@ -1084,7 +1309,7 @@ mod tests {
         let agent_id = make_agent_id("agent-1");
 
         let timestamp1 = "2025-01-09T10:00:00Z".parse().expect("valid timestamp");
-        let entry1 = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let entry1 = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
@@ -1114,7 +1339,7 @@ mod tests {
         let timestamp_main = "2025-01-09T10:00:00Z".parse().expect("valid timestamp");
         let timestamp_subagent = "2025-01-09T11:00:00Z".parse().expect("valid timestamp");
 
-        let main_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let main_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-main"),
             None,
             make_session_id("session-1"),
@@ -1125,7 +1350,7 @@ mod tests {
             EntryMetadata::default(),
         )));
 
-        let subagent_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let subagent_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-sub"),
             None,
             make_session_id("session-1"),
@@ -1156,7 +1381,7 @@ mod tests {
         let timestamp_subagent = "2025-01-09T10:00:00Z".parse().expect("valid timestamp");
         let timestamp_main = "2025-01-09T11:00:00Z".parse().expect("valid timestamp");
 
-        let subagent_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let subagent_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-sub"),
             None,
             make_session_id("session-1"),
@@ -1167,7 +1392,7 @@ mod tests {
             EntryMetadata::default(),
         )));
 
-        let main_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let main_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-main"),
             None,
             make_session_id("session-1"),
@@ -1226,7 +1451,7 @@ mod tests {
         ));
 
         let timestamp1 = "2025-01-09T10:00:00Z".parse().expect("valid timestamp");
-        let valid_entry = ConversationEntry::Valid(Box::new(LogEntry::new(
+        let valid_entry = ConversationEntry::Valid(Arc::new(LogEntry::new(
             make_entry_uuid("uuid-1"),
             None,
             make_session_id("session-1"),
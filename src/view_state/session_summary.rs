@@ -1,8 +1,9 @@
 //! Session summary metadata for display in session list modal.
 
-use crate::model::SessionId;
+use crate::model::{CostReconciliation, ModelInfo, SessionId};
 use crate::view_state::types::SessionIndex;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use std::cmp::Ordering;
 
 /// Summary metadata for a session, used in the session list modal.
 ///
@@ -13,6 +14,9 @@ use chrono::{DateTime, Utc};
 /// - Session number (index + 1)
 /// - Start timestamp
 /// - Message count
+/// - Working directory and git branch
+/// - First prompt, duration, model, cost and result status
+/// - Place in its chain of resumed sessions
 #[derive(Debug, Clone)]
 pub struct SessionSummary {
     /// Validated index of this session.
@@ -29,6 +33,33 @@ pub struct SessionSummary {
 
     /// Number of subagents spawned in this session.
     subagent_count: usize,
+
+    /// Reported vs estimated cost, if the session has a result entry.
+    cost: Option<CostReconciliation>,
+
+    /// Working directory and git branch, as "cwd (branch)".
+    location: Option<String>,
+
+    /// First prompt typed by the user.
+    first_prompt: Option<String>,
+
+    /// Latest timestamp of any entry in the session.
+    end_time: Option<DateTime<Utc>>,
+
+    /// Session model (see `SessionInfo::model`).
+    model: Option<ModelInfo>,
+
+    /// Whether the result entry reports an error (None without a result entry).
+    is_error: Option<bool>,
+
+    /// Cost estimated from token usage.
+    estimated_cost: f64,
+
+    /// Session this one resumes or continues (see `SessionLinks`).
+    parent: Option<SessionIndex>,
+
+    /// Number of sessions between this one and the first of its chain.
+    depth: usize,
 }
 
 impl SessionSummary {
@@ -53,9 +84,24 @@ impl SessionSummary {
             message_count,
             start_time,
             subagent_count,
+            cost: None,
+            location: None,
+            first_prompt: None,
+            end_time: None,
+            model: None,
+            is_error: None,
+            estimated_cost: 0.0,
+            parent: None,
+            depth: 0,
         }
     }
 
+    /// Attach the session's reported vs estimated cost.
+    pub fn with_cost(mut self, cost: Option<CostReconciliation>) -> Self {
+        self.cost = cost;
+        self
+    }
+
     /// Session index.
     pub fn index(&self) -> SessionIndex {
         self.index
@@ -81,6 +127,103 @@ impl SessionSummary {
         self.subagent_count
     }
 
+    /// Reported vs estimated cost, if the session has a result entry.
+    pub fn cost(&self) -> Option<&CostReconciliation> {
+        self.cost.as_ref()
+    }
+
+    /// Where the session ran, as "cwd (branch)" (see `SessionInfo::location`).
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// Attach the session's cost estimated from token usage.
+    pub fn with_estimated_cost(mut self, estimated_cost: f64) -> Self {
+        self.estimated_cost = estimated_cost;
+        self
+    }
+
+    /// Attach the session's place in its chain of resumed sessions.
+    pub fn with_chain(mut self, parent: Option<SessionIndex>, depth: usize) -> Self {
+        self.parent = parent;
+        self.depth = depth;
+        self
+    }
+
+    /// Session this one resumes or continues.
+    pub fn parent(&self) -> Option<SessionIndex> {
+        self.parent
+    }
+
+    /// Number of sessions between this one and the first of its chain.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// First prompt typed by the user.
+    pub fn first_prompt(&self) -> Option<&str> {
+        self.first_prompt.as_deref()
+    }
+
+    /// Latest timestamp of any entry in the session.
+    pub fn end_time(&self) -> Option<DateTime<Utc>> {
+        self.end_time
+    }
+
+    /// Time from the first to the latest entry.
+    pub fn duration(&self) -> Option<Duration> {
+        Some(self.end_time? - self.start_time?)
+    }
+
+    /// Session model.
+    pub fn model(&self) -> Option<&ModelInfo> {
+        self.model.as_ref()
+    }
+
+    /// Whether the result entry reports an error (None without a result entry).
+    pub fn is_error(&self) -> Option<bool> {
+        self.is_error
+    }
+
+    /// Result status label: "ok", "error", or "-" without a result entry.
+    pub fn status_label(&self) -> &'static str {
+        match self.is_error {
+            Some(false) => "ok",
+            Some(true) => "error",
+            None => "-",
+        }
+    }
+
+    /// Reported cost when the session has a result entry, else the estimate.
+    pub fn display_cost(&self) -> f64 {
+        self.cost.map_or(self.estimated_cost, |cost| cost.reported)
+    }
+
+    /// Whether the summary matches a session list filter.
+    ///
+    /// `#N` matches session N exactly; anything else is a case-insensitive
+    /// substring of the first prompt, model, location, session ID or status.
+    pub fn matches_filter(&self, filter: &str) -> bool {
+        let filter = filter.trim();
+        if filter.is_empty() {
+            return true;
+        }
+        if let Some(number) = filter.strip_prefix('#') {
+            return number.parse() == Ok(self.index.display());
+        }
+        let filter = filter.to_lowercase();
+        [
+            self.first_prompt.as_deref(),
+            self.model.as_ref().map(|m| m.id()),
+            self.location.as_deref(),
+            Some(self.session_id.as_str()),
+            Some(self.status_label()),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|field| field.to_lowercase().contains(&filter))
+    }
+
     /// Format for display in session list.
     ///
     /// Returns: "Session N: X messages, Y subagents (HH:MM)"
@@ -106,6 +249,9 @@ impl SessionSummary {
     /// - `message_count` from session.main().len()
     /// - `start_time` from session.start_time()
     /// - `subagent_count` from session.subagents().len()
+    /// - `location` from session.info().location()
+    /// - `first_prompt`, `end_time` and `is_error` from the session's entries
+    /// - `model` from session.info()
     ///
     /// # Arguments
     /// - `index`: Validated session index
@@ -120,13 +266,136 @@ impl SessionSummary {
             message_count: session.main().len(),
             start_time: session.start_time(),
             subagent_count: session.subagents().len(),
+            cost: None,
+            location: session.info().location(),
+            first_prompt: session.first_prompt(),
+            end_time: session.end_time(),
+            model: session.info().model.clone(),
+            is_error: session.result_is_error(),
+            estimated_cost: 0.0,
+            parent: None,
+            depth: 0,
         }
     }
 }
 
+// ===== SessionSortColumn =====
+
+/// Column the session list is sorted by.
+///
+/// Text and time columns sort ascending, amounts sort descending; the list
+/// can reverse either.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SessionSortColumn {
+    /// Session number (log order).
+    #[default]
+    Number,
+    /// First prompt.
+    Prompt,
+    /// Start time.
+    Start,
+    /// Duration.
+    Duration,
+    /// Model display name.
+    Model,
+    /// Message count in main conversation.
+    Messages,
+    /// Subagent count.
+    Subagents,
+    /// Reported (or estimated) cost.
+    Cost,
+    /// Result status (errors first).
+    Status,
+}
+
+impl SessionSortColumn {
+    /// Every column in display order.
+    pub const ALL: [SessionSortColumn; 9] = [
+        SessionSortColumn::Number,
+        SessionSortColumn::Prompt,
+        SessionSortColumn::Start,
+        SessionSortColumn::Duration,
+        SessionSortColumn::Model,
+        SessionSortColumn::Messages,
+        SessionSortColumn::Subagents,
+        SessionSortColumn::Cost,
+        SessionSortColumn::Status,
+    ];
+
+    /// Next column in display order, wrapping around.
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|c| *c == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    /// Previous column in display order, wrapping around.
+    pub fn prev(self) -> Self {
+        let i = Self::ALL.iter().position(|c| *c == self).unwrap_or(0);
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Column header.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SessionSortColumn::Number => "#",
+            SessionSortColumn::Prompt => "First prompt",
+            SessionSortColumn::Start => "Start",
+            SessionSortColumn::Duration => "Dur",
+            SessionSortColumn::Model => "Model",
+            SessionSortColumn::Messages => "Msgs",
+            SessionSortColumn::Subagents => "Subagents",
+            SessionSortColumn::Cost => "Cost",
+            SessionSortColumn::Status => "Result",
+        }
+    }
+
+    /// Whether the default direction is descending (amounts) rather than
+    /// ascending (text, times and status).
+    pub fn is_descending(&self) -> bool {
+        matches!(
+            self,
+            SessionSortColumn::Duration
+                | SessionSortColumn::Messages
+                | SessionSortColumn::Subagents
+                | SessionSortColumn::Cost
+        )
+    }
+
+    /// Compare two summaries in this column's default direction.
+    ///
+    /// Ties are broken by session number.
+    pub fn compare(&self, a: &SessionSummary, b: &SessionSummary) -> Ordering {
+        let prompt = |s: &SessionSummary| s.first_prompt().unwrap_or_default().to_lowercase();
+        let model = |s: &SessionSummary| {
+            s.model()
+                .map(|m| m.display_name().to_string())
+                .unwrap_or_default()
+        };
+        // Errors first, then successes, then sessions without a result
+        let status = |s: &SessionSummary| match s.is_error() {
+            Some(true) => 0,
+            Some(false) => 1,
+            None => 2,
+        };
+        let primary = match self {
+            SessionSortColumn::Number => Ordering::Equal,
+            SessionSortColumn::Prompt => prompt(a).cmp(&prompt(b)),
+            SessionSortColumn::Start => a.start_time.cmp(&b.start_time),
+            SessionSortColumn::Duration => b.duration().cmp(&a.duration()),
+            SessionSortColumn::Model => model(a).cmp(&model(b)),
+            SessionSortColumn::Messages => b.message_count.cmp(&a.message_count),
+            SessionSortColumn::Subagents => b.subagent_count.cmp(&a.subagent_count),
+            SessionSortColumn::Cost => b.display_cost().total_cmp(&a.display_cost()),
+            SessionSortColumn::Status => status(a).cmp(&status(b)),
+        };
+        primary.then_with(|| a.index.get().cmp(&b.index.get()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
 
     fn make_test_session_id() -> SessionId {
         SessionId::new("550e8400-e29b-41d4-a716-446655440000").unwrap()
@@ -220,6 +489,23 @@ mod tests {
         assert_eq!(summary.subagent_count(), 7);
     }
 
+    #[test]
+    fn cost_defaults_to_none() {
+        let summary = SessionSummary::new(make_test_index(), make_test_session_id(), 1, None, 0);
+
+        assert_eq!(summary.cost(), None);
+    }
+
+    #[test]
+    fn with_cost_attaches_reconciliation() {
+        let cost = CostReconciliation::new(1.5, 1.2);
+
+        let summary = SessionSummary::new(make_test_index(), make_test_session_id(), 1, None, 0)
+            .with_cost(Some(cost));
+
+        assert_eq!(summary.cost(), Some(&cost));
+    }
+
     #[test]
     fn display_line_with_time() {
         let index = SessionIndex::new(0, 3).unwrap(); // Display as "Session 1"
@@ -294,7 +580,7 @@ mod tests {
 
         // Add 3 messages to main conversation
         for i in 0..3 {
-            let entry = crate::model::ConversationEntry::Valid(Box::new(LogEntry::new(
+            let entry = crate::model::ConversationEntry::Valid(Arc::new(LogEntry::new(
                 EntryUuid::new(format!("uuid-{}", i)).unwrap(),
                 None,
                 session_id.clone(),
@@ -325,7 +611,7 @@ mod tests {
             EntryMetadata, EntryType, EntryUuid, LogEntry, Message, MessageContent, Role,
         };
         let timestamp = make_test_time();
-        let entry = crate::model::ConversationEntry::Valid(Box::new(LogEntry::new(
+        let entry = crate::model::ConversationEntry::Valid(Arc::new(LogEntry::new(
             EntryUuid::new("uuid-1").unwrap(),
             None,
             session_id.clone(),
@@ -361,7 +647,7 @@ mod tests {
         let agent1 = AgentId::new("agent-1").unwrap();
         let agent2 = AgentId::new("agent-2").unwrap();
 
-        let entry1 = crate::model::ConversationEntry::Valid(Box::new(LogEntry::new(
+        let entry1 = crate::model::ConversationEntry::Valid(Arc::new(LogEntry::new(
             EntryUuid::new("uuid-1").unwrap(),
             None,
             session_id.clone(),
@@ -376,7 +662,7 @@ mod tests {
         )));
         session.add_subagent_entry(agent1, entry1);
 
-        let entry2 = crate::model::ConversationEntry::Valid(Box::new(LogEntry::new(
+        let entry2 = crate::model::ConversationEntry::Valid(Arc::new(LogEntry::new(
             EntryUuid::new("uuid-2").unwrap(),
             None,
             session_id.clone(),
@@ -423,4 +709,55 @@ mod tests {
 
         assert_eq!(summary.index(), index);
     }
+
+    #[test]
+    fn matches_filter_by_number_or_text() {
+        let mut summary = SessionSummary::new(
+            SessionIndex::new(11, 20).unwrap(),
+            make_test_session_id(),
+            1,
+            None,
+            0,
+        );
+        summary.first_prompt = Some("Fix the Parser".to_string());
+        summary.is_error = Some(true);
+
+        assert!(summary.matches_filter(""));
+        assert!(summary.matches_filter("#12"));
+        assert!(!summary.matches_filter("#1"));
+        assert!(summary.matches_filter("parser"));
+        assert!(summary.matches_filter("ERROR"));
+        assert!(summary.matches_filter("550e8400"));
+        assert!(!summary.matches_filter("lexer"));
+    }
+
+    #[test]
+    fn sort_columns_order_amounts_descending_and_break_ties_by_number() {
+        let make = |i: usize, messages: usize, is_error: Option<bool>| {
+            let mut summary = SessionSummary::new(
+                SessionIndex::new(i, 3).unwrap(),
+                make_test_session_id(),
+                messages,
+                None,
+                0,
+            );
+            summary.is_error = is_error;
+            summary
+        };
+        let sorted = |column: SessionSortColumn| {
+            let mut rows = [
+                make(0, 5, None),
+                make(1, 9, Some(false)),
+                make(2, 5, Some(true)),
+            ];
+            rows.sort_by(|a, b| column.compare(a, b));
+            rows.map(|s| s.index().get())
+        };
+
+        assert_eq!(sorted(SessionSortColumn::Number), [0, 1, 2]);
+        assert_eq!(sorted(SessionSortColumn::Messages), [1, 0, 2]);
+        assert_eq!(sorted(SessionSortColumn::Status), [2, 1, 0]);
+        assert_eq!(SessionSortColumn::Number.prev(), SessionSortColumn::Status);
+        assert!(SessionSortColumn::Cost.is_descending());
+    }
 }
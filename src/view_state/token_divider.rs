@@ -3,7 +3,9 @@
 //! Displays per-entry token usage, cost, and accumulated context window usage
 //! as a subtle divider line between entries (FR-XXX).
 
-use crate::model::{ContentBlock, MessageContent, TokenUsage, stats::PricingConfig};
+use crate::model::{
+    CompactMetadata, ContentBlock, MessageContent, TokenUsage, stats::PricingConfig,
+};
 use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
@@ -106,26 +108,8 @@ pub fn render_token_divider(
     pricing: &PricingConfig,
     model_id: Option<&str>,
 ) -> Line<'static> {
-    // Calculate entry cost using pricing config
-    let model_pricing = pricing.get(model_id.unwrap_or("opus"));
-
-    let input_cost =
-        (entry_usage.input_tokens as f64 / 1_000_000.0) * model_pricing.input_cost_per_million;
-
-    let output_cost =
-        (entry_usage.output_tokens as f64 / 1_000_000.0) * model_pricing.output_cost_per_million;
-
-    // Use cached rate if available, otherwise use standard input rate
-    let cache_rate = model_pricing
-        .cached_input_cost_per_million
-        .unwrap_or(model_pricing.input_cost_per_million);
-
-    let cache_cost = ((entry_usage.cache_creation_input_tokens
-        + entry_usage.cache_read_input_tokens) as f64
-        / 1_000_000.0)
-        * cache_rate;
-
-    let total_cost = input_cost + output_cost + cache_cost;
+    // Calculate entry cost using pricing config (cache writes/reads at their own rates)
+    let total_cost = pricing.get(model_id.unwrap_or("opus")).cost(entry_usage);
 
     // Estimate tokens from thinking and tool_use blocks (chars/4)
     // Per Claude Code statusline: these are NOT billed but contribute to write total
@@ -184,6 +168,38 @@ pub fn render_token_divider(
     )])
 }
 
+/// Render the separator marking a conversation compaction.
+///
+/// Format: `━━ ⟲ Context compacted (auto): 155.1k → 12.3k tokens ━━`
+///
+/// Unknown token counts show as `?`; the trigger is omitted when unknown.
+/// `post_tokens` is used when the boundary itself does not record the
+/// post-compaction count (typically the next response's context).
+pub fn render_compact_boundary(
+    compact: &CompactMetadata,
+    post_tokens: Option<u64>,
+) -> Line<'static> {
+    let count = |tokens: Option<u64>| tokens.map_or_else(|| "?".to_string(), format_token_count);
+    let trigger = compact
+        .trigger
+        .as_deref()
+        .map(|t| format!(" ({})", t))
+        .unwrap_or_default();
+    let text = format!(
+        "━━ ⟲ Context compacted{}: {} → {} tokens ━━",
+        trigger,
+        count(compact.pre_tokens),
+        count(compact.post_tokens.or(post_tokens)),
+    );
+
+    Line::from(vec![Span::styled(
+        text,
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )])
+}
+
 /// Estimate tokens from thinking and tool_use blocks.
 ///
 /// Per Claude Code statusline reference, thinking and tool_use tokens are NOT
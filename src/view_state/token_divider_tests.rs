@@ -219,13 +219,77 @@ fn render_token_divider_includes_cache_tokens_in_cost() {
     let content = MessageContent::Text("".to_string());
     let max_context = ContextWindowTokens::new(200_000);
 
-    // Opus 4.5 pricing: $0.50 per million cached tokens
+    // Opus 4.5 pricing: $6.25 per million 5-minute cache writes
     let pricing = PricingConfig::default();
 
     let line = render_token_divider(&usage, &content, max_context, &pricing, Some("opus"));
 
     let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
 
-    // 1M cache tokens * $0.50/M = $0.50
-    assert!(text.contains("$0.50"));
+    // 1M cache writes (no TTL breakdown, billed as 5m) * $6.25/M = $6.25
+    assert!(text.contains("$6.25"));
+}
+
+#[test]
+fn render_token_divider_prices_cache_reads_and_1h_writes_separately() {
+    let usage = TokenUsage {
+        input_tokens: 0,
+        output_tokens: 0,
+        cache_creation_input_tokens: 1_000_000,
+        cache_read_input_tokens: 1_000_000,
+        ephemeral_5m_input_tokens: 0,
+        ephemeral_1h_input_tokens: 1_000_000,
+    };
+    let content = MessageContent::Text("".to_string());
+    let max_context = ContextWindowTokens::new(200_000);
+    let pricing = PricingConfig::default();
+
+    let line = render_token_divider(&usage, &content, max_context, &pricing, Some("opus"));
+
+    let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+
+    // 1M 1h writes * $10/M + 1M reads * $0.50/M = $10.50
+    assert!(text.contains("$10.50"), "got: {}", text);
+}
+
+// ===== render_compact_boundary Tests =====
+
+fn line_text(line: &Line<'_>) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+#[test]
+fn render_compact_boundary_shows_pre_and_post_tokens() {
+    let compact = CompactMetadata {
+        trigger: Some("auto".to_string()),
+        pre_tokens: Some(155_123),
+        post_tokens: Some(12_300),
+        summary: None,
+    };
+
+    let line = render_compact_boundary(&compact, Some(99_999));
+
+    assert_eq!(
+        line_text(&line),
+        "━━ ⟲ Context compacted (auto): 155.1k → 12.3k tokens ━━",
+        "Recorded post tokens win over the fallback"
+    );
+    assert_eq!(line.spans[0].style.fg, Some(Color::Yellow));
+}
+
+#[test]
+fn render_compact_boundary_falls_back_and_marks_unknown_counts() {
+    let compact = CompactMetadata {
+        pre_tokens: Some(90_000),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        line_text(&render_compact_boundary(&compact, Some(8_000))),
+        "━━ ⟲ Context compacted: 90.0k → 8.0k tokens ━━"
+    );
+    assert_eq!(
+        line_text(&render_compact_boundary(&CompactMetadata::default(), None)),
+        "━━ ⟲ Context compacted: ? → ? tokens ━━"
+    );
 }